@@ -0,0 +1,110 @@
+//! This module contains the [`AnchorError`] returned by
+//! [`ConvexHull::convex_hull_with_anchors`](crate::ConvexHull::convex_hull_with_anchors).
+
+use std::collections::HashMap;
+
+use crate::predicates::on_segment;
+use crate::{ConvexHull, ConvexHullIter};
+
+/// Reasons why the anchors cannot be part of the hull boundary, see
+/// [`ConvexHull::convex_hull_with_anchors`](crate::ConvexHull::convex_hull_with_anchors).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnchorError {
+    /// The collection does not contain a point with the given key.
+    NotFound {
+        /// Key of the anchor.
+        key: usize,
+    },
+    /// The anchor contains NaN or infinite values.
+    NonReal {
+        /// Key of the anchor.
+        key: usize,
+    },
+    /// The anchor is located strictly inside the hull, so a boundary through
+    /// it would not be convex.
+    Interior {
+        /// Key of the anchor.
+        key: usize,
+    },
+    /// The anchor has the same coordinates as another anchor.
+    Coincident {
+        /// Key of the anchor.
+        key: usize,
+        /// Key of the other anchor.
+        other: usize,
+    },
+}
+
+impl std::fmt::Display for AnchorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnchorError::NotFound { key } => write!(f, "there is no point with the key {key}"),
+            AnchorError::NonReal { key } => {
+                write!(f, "anchor {key} contains NaN or infinite values")
+            }
+            AnchorError::Interior { key } => {
+                write!(f, "anchor {key} is located strictly inside the hull")
+            }
+            AnchorError::Coincident { key, other } => {
+                write!(f, "anchors {key} and {other} have the same coordinates")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnchorError {}
+
+pub(crate) fn convex_hull_with_anchors<T: ConvexHull + ?Sized>(
+    this: &T,
+    anchors: &[usize],
+) -> Result<ConvexHullIter, AnchorError> {
+    let mut positions: HashMap<usize, Option<[f64; 2]>> =
+        anchors.iter().map(|key| (*key, None)).collect();
+    for (key, point) in this.convex_hull_iter() {
+        if let Some(position) = positions.get_mut(&key) {
+            *position = Some(point);
+        }
+    }
+
+    let mut corners: Vec<(usize, [f64; 2])> = this.convex_hull_exact().collect();
+    let n = corners.len();
+
+    // Anchors located on each edge (from corner i to corner i + 1) and the
+    // anchors which replace a corner with the same coordinates.
+    let mut on_edges: Vec<Vec<(usize, [f64; 2])>> = vec![Vec::new(); n];
+    let mut placed: HashMap<[u64; 2], usize> = HashMap::new();
+    for key in anchors.iter().copied() {
+        let Some(point) = positions[&key] else {
+            return Err(AnchorError::NotFound { key });
+        };
+        if !point[0].is_finite() || !point[1].is_finite() {
+            return Err(AnchorError::NonReal { key });
+        }
+        // -0.0 and 0.0 are the same position
+        let bits = [(point[0] + 0.0).to_bits(), (point[1] + 0.0).to_bits()];
+        match placed.get(&bits) {
+            Some(other) if *other == key => continue,
+            Some(other) => return Err(AnchorError::Coincident { key, other: *other }),
+            None => placed.insert(bits, key),
+        };
+
+        if let Some(corner) = corners.iter_mut().find(|corner| corner.1 == point) {
+            corner.0 = key;
+            continue;
+        }
+        let edge = (0..n).find(|i| on_segment(corners[*i].1, corners[(i + 1) % n].1, point));
+        match edge {
+            Some(edge) => on_edges[edge].push((key, point)),
+            None => return Err(AnchorError::Interior { key }),
+        }
+    }
+
+    let distance = |a: [f64; 2], b: [f64; 2]| (b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2);
+    let mut points = Vec::with_capacity(n + anchors.len());
+    for (corner, mut edge) in corners.into_iter().zip(on_edges) {
+        edge.sort_by(|a, b| distance(corner.1, a.1).total_cmp(&distance(corner.1, b.1)));
+        points.push(corner);
+        points.extend(edge);
+    }
+    return Ok(ConvexHullIter::from_vec(points));
+}
@@ -0,0 +1,455 @@
+//! This module contains [`min_width_annulus`], which calculates the thinnest
+//! ring containing all points of a collection. In metrology, the width of this
+//! ring is the roundness (minimum zone) deviation of a measured circle.
+//!
+//! The center of the minimum-width annulus is either a vertex of the
+//! farthest-point Voronoi diagram, a vertex of the nearest-point Voronoi
+//! diagram or an intersection of an edge of the former with an edge of the
+//! latter \[1\]. Both diagrams are derived from their dual triangulations: The
+//! farthest-point Delaunay triangulation of the hull corners and the
+//! (nearest-point) Delaunay triangulation of all points.
+//!
+//! # Literature
+//!
+//! 1. Ebara, H., Fukuyama, N., Nakano, H., Nakanishi, Y.: Roundness
+//!    algorithms using the Voronoi diagrams. First Canadian Conference on
+//!    Computational Geometry (1989).
+//! 2. Skyum, S.: A simple algorithm for computing the smallest enclosing
+//!    circle. Information Processing Letters 37, 121–125 (1991).
+//!    <https://doi.org/10.1016/0020-0190(91)90030-L>
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::geometry::distance;
+use crate::predicates::orientation;
+use crate::{ConvexHull, Hull};
+
+/// Relative error bound of the floating-point approximation of [`incircle`].
+const INCIRCLE_ERROR_BOUND: f64 = (10.0 + 96.0 * f64::EPSILON) * f64::EPSILON;
+
+/**
+A ring between two concentric circles, as returned by [`min_width_annulus`].
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct Annulus {
+    /// Common center of both circles.
+    pub center: [f64; 2],
+    /// Radius of the inner circle.
+    pub inner_radius: f64,
+    /// Radius of the outer circle.
+    pub outer_radius: f64,
+}
+
+impl Annulus {
+    /// Returns the difference between the outer and the inner radius.
+    pub fn width(&self) -> f64 {
+        return self.outer_radius - self.inner_radius;
+    }
+
+    /// Returns `true` if `point` is located inside the ring or on one of its
+    /// circles.
+    pub fn contains(&self, point: [f64; 2]) -> bool {
+        let distance = distance(self.center, point);
+        return distance >= self.inner_radius && distance <= self.outer_radius;
+    }
+}
+
+/// Returns the center of the circle through `a`, `b` and `c`.
+pub(crate) fn circumcenter(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> Option<[f64; 2]> {
+    let (bx, by) = (b[0] - a[0], b[1] - a[1]);
+    let (cx, cy) = (c[0] - a[0], c[1] - a[1]);
+    let d = 2.0 * (bx * cy - by * cx);
+    if d == 0.0 {
+        return None;
+    }
+    let (b2, c2) = (bx * bx + by * by, cx * cx + cy * cy);
+    let center = [
+        a[0] + (cy * b2 - by * c2) / d,
+        a[1] + (bx * c2 - cx * b2) / d,
+    ];
+    if !center[0].is_finite() || !center[1].is_finite() {
+        return None;
+    }
+    return Some(center);
+}
+
+/**
+Returns `true` if `d` is certainly located strictly inside the circle through
+the counter-clockwise triangle `a`, `b`, `c`. Results too close to zero to
+determine their sign reliably are treated as "not inside", so the
+triangulation is only changed where it certainly violates the Delaunay
+property.
+ */
+fn incircle(a: [f64; 2], b: [f64; 2], c: [f64; 2], d: [f64; 2]) -> bool {
+    let (adx, ady) = (a[0] - d[0], a[1] - d[1]);
+    let (bdx, bdy) = (b[0] - d[0], b[1] - d[1]);
+    let (cdx, cdy) = (c[0] - d[0], c[1] - d[1]);
+    let (alift, blift, clift) = (
+        adx * adx + ady * ady,
+        bdx * bdx + bdy * bdy,
+        cdx * cdx + cdy * cdy,
+    );
+    let det = alift * (bdx * cdy - cdx * bdy)
+        + blift * (cdx * ady - adx * cdy)
+        + clift * (adx * bdy - bdx * ady);
+    let permanent = alift * ((bdx * cdy).abs() + (cdx * bdy).abs())
+        + blift * ((cdx * ady).abs() + (adx * cdy).abs())
+        + clift * ((adx * bdy).abs() + (bdx * ady).abs());
+    return det > INCIRCLE_ERROR_BOUND * permanent;
+}
+
+/**
+Delaunay triangulation of `points`, which must be sorted lexicographically,
+free of duplicates and not all collinear. The triangles are oriented
+counter-clockwise.
+
+A sweep in x-direction creates an initial triangulation, which is then
+turned into a Delaunay triangulation by edge flips.
+ */
+fn delaunay(points: &[[f64; 2]]) -> Vec<[usize; 3]> {
+    let n = points.len();
+    let Some(first) =
+        (2..n).find(|k| orientation(points[0], points[1], points[*k]) != Ordering::Equal)
+    else {
+        return Vec::new();
+    };
+
+    // The points before `first` are located on a line. They form a fan with
+    // the point `first`.
+    let left = orientation(points[0], points[1], points[first]) == Ordering::Greater;
+    let mut triangles: Vec<[usize; 3]> = (0..first - 1)
+        .map(|i| match left {
+            true => [i, i + 1, first],
+            false => [i + 1, i, first],
+        })
+        .collect();
+    let mut boundary: Vec<usize> = match left {
+        true => (0..=first).collect(),
+        false => (0..=first).rev().collect(),
+    };
+
+    // Each following point is located outside of the triangulated area and
+    // is connected to all boundary edges visible from it.
+    for index in first + 1..n {
+        let point = points[index];
+        let m = boundary.len();
+        let visible: Vec<bool> = (0..m)
+            .map(|i| {
+                let (a, b) = (points[boundary[i]], points[boundary[(i + 1) % m]]);
+                return orientation(a, b, point) == Ordering::Less;
+            })
+            .collect();
+        let Some(start) = (0..m).find(|i| visible[*i] && !visible[(i + m - 1) % m]) else {
+            continue;
+        };
+        let len = (0..m)
+            .take_while(|offset| visible[(start + offset) % m])
+            .count();
+        for offset in 0..len {
+            let a = boundary[(start + offset) % m];
+            let b = boundary[(start + offset + 1) % m];
+            triangles.push([b, a, index]);
+        }
+        let mut next: Vec<usize> = (0..=m - len)
+            .map(|offset| boundary[(start + len + offset) % m])
+            .collect();
+        next.push(index);
+        boundary = next;
+    }
+
+    // Lawson's flip algorithm
+    let mut edges: HashMap<(usize, usize), usize> = HashMap::with_capacity(3 * triangles.len());
+    for (t, triangle) in triangles.iter().enumerate() {
+        for i in 0..3 {
+            edges.insert((triangle[i], triangle[(i + 1) % 3]), t);
+        }
+    }
+    let mut stack: Vec<(usize, usize)> = edges.keys().copied().collect();
+    while let Some((a, b)) = stack.pop() {
+        let (Some(&t1), Some(&t2)) = (edges.get(&(a, b)), edges.get(&(b, a))) else {
+            continue;
+        };
+        let third = |triangle: [usize; 3]| {
+            return triangle
+                .into_iter()
+                .find(|v| *v != a && *v != b)
+                .unwrap_or(a);
+        };
+        let c = third(triangles[t1]);
+        let d = third(triangles[t2]);
+        if !incircle(points[a], points[b], points[c], points[d]) {
+            continue;
+        }
+        triangles[t1] = [a, d, c];
+        triangles[t2] = [d, b, c];
+        edges.remove(&(a, b));
+        edges.remove(&(b, a));
+        for (edge, t) in [
+            ((a, d), t1),
+            ((d, c), t1),
+            ((c, a), t1),
+            ((d, b), t2),
+            ((b, c), t2),
+            ((c, d), t2),
+        ] {
+            edges.insert(edge, t);
+        }
+        stack.extend([(a, d), (d, b), (b, c), (c, a)]);
+    }
+    return triangles;
+}
+
+/// Circumradius of the triangle `a`, `b`, `c` and the angle at `b`.
+fn ear_size(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> (f64, f64) {
+    let radius = circumcenter(a, b, c).map_or(f64::INFINITY, |center| distance(center, b));
+    let (u, v) = ([a[0] - b[0], a[1] - b[1]], [c[0] - b[0], c[1] - b[1]]);
+    let angle = (u[0] * v[1] - u[1] * v[0])
+        .abs()
+        .atan2(u[0] * v[0] + u[1] * v[1]);
+    return (radius, angle);
+}
+
+/**
+Farthest-point Delaunay triangulation of the convex polygon `corners`
+(counter-clockwise, no three corners collinear). The ear with the largest
+circumcircle (and the largest angle among those) is part of the
+triangulation, so the ears are cut off one by one \[2\].
+ */
+fn farthest_delaunay(corners: &[[f64; 2]]) -> Vec<[usize; 3]> {
+    let mut polygon: Vec<usize> = (0..corners.len()).collect();
+    let mut triangles = Vec::with_capacity(corners.len().saturating_sub(2));
+    while polygon.len() > 3 {
+        let m = polygon.len();
+        let ear = |i: usize| {
+            return [polygon[(i + m - 1) % m], polygon[i], polygon[(i + 1) % m]];
+        };
+        let size = |i: usize| {
+            let [a, b, c] = ear(i);
+            return ear_size(corners[a], corners[b], corners[c]);
+        };
+        let Some(largest) = (0..m).reduce(|best, i| {
+            let (value, best_value) = (size(i), size(best));
+            match value
+                .0
+                .total_cmp(&best_value.0)
+                .then(value.1.total_cmp(&best_value.1))
+            {
+                Ordering::Greater => return i,
+                _ => return best,
+            }
+        }) else {
+            break;
+        };
+        triangles.push(ear(largest));
+        polygon.remove(largest);
+    }
+    if polygon.len() == 3 {
+        triangles.push([polygon[0], polygon[1], polygon[2]]);
+    }
+    return triangles;
+}
+
+/**
+Part of the perpendicular bisector of the sites `a` and `b` which is an edge of
+a Voronoi diagram. The points of the bisector are `mid + t * normal` with `t`
+between `lower` and `upper`.
+ */
+struct Bisector {
+    site: [f64; 2],
+    mid: [f64; 2],
+    normal: [f64; 2],
+    lower: f64,
+    upper: f64,
+}
+
+impl Bisector {
+    fn at(&self, t: f64) -> [f64; 2] {
+        return [
+            self.mid[0] + t * self.normal[0],
+            self.mid[1] + t * self.normal[1],
+        ];
+    }
+
+    /// Returns the intersection with `other`, if there is one.
+    fn intersection(&self, other: &Bisector) -> Option<[f64; 2]> {
+        let (n1, n2) = (self.normal, other.normal);
+        let r = [other.mid[0] - self.mid[0], other.mid[1] - self.mid[1]];
+        let det = n2[0] * n1[1] - n1[0] * n2[1];
+        if det == 0.0 {
+            return None;
+        }
+        let t = (n2[0] * r[1] - r[0] * n2[1]) / det;
+        let s = (n1[0] * r[1] - n1[1] * r[0]) / det;
+        if t < self.lower || t > self.upper || s < other.lower || s > other.upper {
+            return None;
+        }
+        return Some(self.at(t));
+    }
+}
+
+/**
+Voronoi edges dual to the edges of the counter-clockwise `triangles`. The
+edges dual to boundary edges of the triangulation are rays, which point
+outwards for the nearest-point diagram and inwards for the farthest-point
+diagram.
+ */
+fn voronoi_edges(points: &[[f64; 2]], triangles: &[[usize; 3]], farthest: bool) -> Vec<Bisector> {
+    let mut edges: HashMap<(usize, usize), Option<[f64; 2]>> = HashMap::new();
+    for triangle in triangles.iter() {
+        let center = circumcenter(
+            points[triangle[0]],
+            points[triangle[1]],
+            points[triangle[2]],
+        );
+        for i in 0..3 {
+            edges.insert((triangle[i], triangle[(i + 1) % 3]), center);
+        }
+    }
+
+    let mut bisectors = Vec::with_capacity(edges.len());
+    for (&(a, b), &center) in edges.iter() {
+        let Some(center) = center else {
+            continue;
+        };
+        let (pa, pb) = (points[a], points[b]);
+        let mid = [0.5 * (pa[0] + pb[0]), 0.5 * (pa[1] + pb[1])];
+        let normal = [pa[1] - pb[1], pb[0] - pa[0]];
+        let norm = normal[0] * normal[0] + normal[1] * normal[1];
+        let param =
+            |p: [f64; 2]| ((p[0] - mid[0]) * normal[0] + (p[1] - mid[1]) * normal[1]) / norm;
+        let t = param(center);
+        let (lower, upper) = match edges.get(&(b, a)) {
+            // Each inner edge is visited twice
+            Some(_) if a > b => continue,
+            Some(Some(other)) => (t.min(param(*other)), t.max(param(*other))),
+            Some(None) => continue,
+            // The triangle is located on the left side of the edge
+            None if farthest => (t, f64::INFINITY),
+            None => (f64::NEG_INFINITY, t),
+        };
+        bisectors.push(Bisector {
+            site: pa,
+            mid,
+            normal,
+            lower,
+            upper,
+        });
+    }
+    return bisectors;
+}
+
+/**
+Calculates the annulus with the smallest width which contains all points of
+`collection`. Returns `None` if the collection does not contain at least
+three real points which are not collinear, since the thinnest "annulus" of
+collinear points is a line with an infinitely distant center.
+
+The points on the outer circle are always corners of the convex hull, so the
+outer radius is measured against the hull only. The algorithm evaluates the
+candidate centers given by the Voronoi diagrams (see the
+[module documentation](crate::annulus)) in O(n·h) time, where n is the number
+of points and h the number of hull corners. The Delaunay triangulation is
+created by a sweep followed by edge flips, which takes O(n²) time in the
+worst case, but is much faster for typical inputs. It is built with exact orientation tests and floating-point circle tests; for
+nearly cocircular points (e.g. precise measurements of a circle), its Voronoi
+vertices coincide up to rounding errors, which can make the calculated width
+deviate from the exact minimum by a few ulps.
+
+# Examples
+
+```
+use planar_convex_hull::annulus::min_width_annulus;
+
+// Points on the unit circle around [3.0, 4.0] and one point further inside
+let points = [[4.0, 4.0], [3.0, 5.0], [2.0, 4.0], [3.0, 3.0], [3.5, 4.0]];
+let annulus = min_width_annulus(&points).unwrap();
+assert!(points.iter().all(|p| annulus.contains(*p)));
+assert!(annulus.width() < 0.5);
+
+// Without the inner point, all points are located on a circle
+let annulus = min_width_annulus(&points[..4].to_vec()).unwrap();
+assert_eq!(annulus.center, [3.0, 4.0]);
+assert_eq!(annulus.width(), 0.0);
+```
+ */
+pub fn min_width_annulus<T: ConvexHull + ?Sized>(collection: &T) -> Option<Annulus> {
+    let hull = Hull::new(collection);
+    if hull.len() < 3 {
+        return None;
+    }
+    let corners = hull.points();
+
+    let mut points: Vec<[f64; 2]> = collection
+        .convex_hull_iter()
+        .map(|(_, point)| point)
+        .filter(|point| point[0].is_finite() && point[1].is_finite())
+        .collect();
+    points.sort_by(|a, b| a[0].total_cmp(&b[0]).then(a[1].total_cmp(&b[1])));
+    points.dedup();
+
+    let outer = |center: [f64; 2]| {
+        return corners
+            .iter()
+            .map(|corner| distance(center, *corner))
+            .fold(0.0, f64::max);
+    };
+    let inner = |center: [f64; 2]| {
+        return points
+            .iter()
+            .map(|point| distance(center, *point))
+            .fold(f64::INFINITY, f64::min);
+    };
+
+    let nearest = delaunay(&points);
+    let farthest = farthest_delaunay(corners);
+    let circles = |points: &[[f64; 2]], triangles: &[[usize; 3]]| {
+        return triangles
+            .iter()
+            .filter_map(|[a, b, c]| {
+                let center = circumcenter(points[*a], points[*b], points[*c])?;
+                return Some((center, distance(center, points[*a])));
+            })
+            .collect::<Vec<_>>();
+    };
+
+    // Candidates on vertices of the Voronoi diagrams. Each vertex of the
+    // nearest-point diagram is the center of an empty circle, each vertex of
+    // the farthest-point diagram is the center of an enclosing circle.
+    let mut best: Option<([f64; 2], f64)> = None;
+    let mut consider = |center: [f64; 2], width: f64| {
+        if best.is_none_or(|(_, best_width)| width < best_width) {
+            best = Some((center, width));
+        }
+    };
+    for (center, radius) in circles(&points, &nearest) {
+        consider(center, outer(center) - radius);
+    }
+    for (center, radius) in circles(corners, &farthest) {
+        consider(center, radius - inner(center));
+    }
+
+    // Candidates on intersections of the Voronoi edges. The closest and the
+    // farthest point are the sites of the respective edges.
+    let nearest_edges = voronoi_edges(&points, &nearest, false);
+    let farthest_edges = voronoi_edges(corners, &farthest, true);
+    for far in farthest_edges.iter() {
+        for near in nearest_edges.iter() {
+            if let Some(center) = far.intersection(near) {
+                consider(
+                    center,
+                    distance(center, far.site) - distance(center, near.site),
+                );
+            }
+        }
+    }
+
+    let (center, _) = best?;
+    return Some(Annulus {
+        center,
+        inner_radius: inner(center),
+        outer_radius: outer(center),
+    });
+}
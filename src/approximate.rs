@@ -0,0 +1,45 @@
+//! The approximate convex hull of Bentley, Faust and Preparata \[1\], see
+//! [`ConvexHull::convex_hull_approximate`](crate::ConvexHull::convex_hull_approximate).
+//!
+//! # Literature
+//!
+//! 1. Bentley, J. L., Faust, G. M., Preparata, F. P.: Approximation algorithms
+//!    for convex hulls. Communications of the ACM 25, 64–68 (1982).
+//!    <https://doi.org/10.1145/358315.358392>
+
+use std::collections::HashMap;
+
+use crate::ConvexHull;
+use crate::monotone_chain::monotone_chain;
+
+/**
+Calculates the approximate convex hull of `collection`: The plane is divided
+into vertical strips of width `tolerance`, and only the lowest and the highest
+point of each strip are kept. Returns the exact hull of these points.
+ */
+pub(crate) fn approximate<T: ConvexHull + ?Sized>(
+    collection: &T,
+    tolerance: f64,
+) -> Vec<(usize, [f64; 2])> {
+    assert!(
+        tolerance > 0.0 && tolerance.is_finite(),
+        "the tolerance must be positive and finite"
+    );
+
+    // Lowest and highest point of each strip
+    let mut strips: HashMap<i64, [(usize, [f64; 2]); 2]> = HashMap::new();
+    for (key, point) in collection.convex_hull_iter() {
+        if !point[0].is_finite() || !point[1].is_finite() {
+            continue;
+        }
+        let strip = (point[0] / tolerance).floor() as i64;
+        let [lowest, highest] = strips.entry(strip).or_insert([(key, point); 2]);
+        if point[1] < lowest.1[1] {
+            *lowest = (key, point);
+        }
+        if point[1] > highest.1[1] {
+            *highest = (key, point);
+        }
+    }
+    return monotone_chain(strips.into_values().flatten().collect());
+}
@@ -0,0 +1,45 @@
+//! This module contains [`convex_hulls`] for calculating the hulls of many
+//! independent collections at once.
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{ConvexHull, Hull};
+
+/**
+Calculates the convex hull of each of the `collections` with
+[`ConvexHull::convex_hull`] and returns them in the same order.
+
+When the `rayon` feature is enabled, the collections are distributed over the
+threads of the rayon thread pool. This pays off for many small collections
+(e.g. the clusters of a point cloud), for which the parallelization within a
+single hull calculation has too little work to do. The degeneracies of each
+hull are derived from its corners only (as for
+[`Hull::from_vertices_unchecked`]).
+
+# Examples
+
+```
+use planar_convex_hull::convex_hulls;
+
+let clusters = vec![
+    vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.2, 0.2]],
+    vec![[5.0, 5.0], [6.0, 6.0]],
+    vec![],
+];
+let hulls = convex_hulls(&clusters);
+assert_eq!(hulls.len(), 3);
+assert_eq!(hulls[0].keys(), &[1, 2, 0]);
+assert_eq!(hulls[1].keys(), &[1, 0]);
+assert!(hulls[2].is_empty());
+```
+ */
+pub fn convex_hulls<T: ConvexHull>(collections: &[T]) -> Vec<Hull> {
+    #[cfg(feature = "rayon")]
+    let iter = collections.par_iter();
+    #[cfg(not(feature = "rayon"))]
+    let iter = collections.iter();
+    return iter
+        .map(|collection| Hull::from(collection.convex_hull()))
+        .collect();
+}
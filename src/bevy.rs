@@ -0,0 +1,86 @@
+//! This module integrates the crate with [bevy_math](https://docs.rs/bevy_math).
+//! It is only available with the `bevy` feature flag enabled.
+//!
+//! [`ConvexHull`] is implemented for slices of `Vec2` and `DVec2`, so that
+//! vectors, arrays and slices of these types can be used without a newtype
+//! wrapper (the methods are reached via auto-deref). The keys are the element
+//! indices. Since the hulls are calculated in `f64`, the corners of a `Vec2`
+//! collection are exactly representable in `f32` and
+//! [`Hull::to_vec2`] returns them losslessly.
+//!
+//! With the `bevy_reflect` feature flag enabled, the public data types of the
+//! crate (e.g. [`Hull`]) additionally derive `Reflect`.
+//!
+//! # Examples
+//!
+//! ```
+//! use bevy_math::Vec2;
+//! use bevy_math::primitives::BoxedPolygon;
+//! use planar_convex_hull::{ConvexHull, Hull};
+//!
+//! let points = vec![
+//!     Vec2::new(0.0, 0.0),
+//!     Vec2::new(1.0, 0.0),
+//!     Vec2::new(0.0, 1.0),
+//!     Vec2::new(0.2, 0.2),
+//! ];
+//! let hull = Hull::new(points.as_slice());
+//! assert_eq!(hull.keys(), &[1, 2, 0]);
+//!
+//! let polygon = BoxedPolygon::from(&hull);
+//! assert_eq!(
+//!     polygon.vertices.as_ref(),
+//!     &[Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0), Vec2::new(0.0, 0.0)]
+//! );
+//! assert_eq!(points.convex_hull().count(), 3);
+//! ```
+
+use bevy_math::primitives::BoxedPolygon;
+use bevy_math::{DVec2, Vec2};
+
+use crate::{ConvexHull, Hull};
+
+impl ConvexHull for [Vec2] {
+    fn convex_hull_iter(&self) -> impl Iterator<Item = (usize, [f64; 2])> {
+        return self
+            .iter()
+            .map(|point| [point.x.into(), point.y.into()])
+            .enumerate();
+    }
+}
+
+impl ConvexHull for [DVec2] {
+    fn convex_hull_iter(&self) -> impl Iterator<Item = (usize, [f64; 2])> {
+        return self.iter().map(|point| point.to_array()).enumerate();
+    }
+}
+
+impl Hull {
+    /**
+    Returns the corners as `Vec2`, e.g. for creating a `Polygon` or
+    `BoxedPolygon` from bevy_math via `FromIterator`. The coordinates are
+    rounded to `f32`.
+     */
+    pub fn to_vec2(&self) -> Vec<Vec2> {
+        return self
+            .points()
+            .iter()
+            .map(|point| Vec2::new(point[0] as f32, point[1] as f32))
+            .collect();
+    }
+
+    /// Returns the corners as `DVec2`.
+    pub fn to_dvec2(&self) -> Vec<DVec2> {
+        return self
+            .points()
+            .iter()
+            .map(|point| DVec2::from(*point))
+            .collect();
+    }
+}
+
+impl From<&Hull> for BoxedPolygon {
+    fn from(hull: &Hull) -> Self {
+        return BoxedPolygon::new(hull.to_vec2());
+    }
+}
@@ -0,0 +1,423 @@
+//! This module contains the results of the rotating calipers algorithms of
+//! [`Hull`], such as the [`Width`] returned by [`Hull::width`].
+
+use crate::Hull;
+
+/**
+Minimum width of a hull, see [`Hull::width`]: The smallest distance between two
+parallel lines enclosing the hull. One of the lines is flush with an edge of
+the hull and the other one touches a corner of the hull.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct Width {
+    /// Distance between the two supporting lines.
+    pub distance: f64,
+    /// Keys and points of the start and end corner of the hull edge which is
+    /// flush with one supporting line (in counter-clockwise order).
+    pub edge: [(usize, [f64; 2]); 2],
+    /// Key and point of the corner touched by the other supporting line.
+    pub corner: (usize, [f64; 2]),
+}
+
+/// Corners which support a hull edge, see [`edge_supports`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct EdgeSupport {
+    /// Unit vector along the edge.
+    pub(crate) direction: [f64; 2],
+    /// Inward unit normal of the edge.
+    pub(crate) normal: [f64; 2],
+    /// Corner farthest in the edge direction.
+    pub(crate) ahead: usize,
+    /// Corner farthest against the edge direction.
+    pub(crate) behind: usize,
+    /// Corner farthest from the edge along its inward normal (the first one
+    /// in counter-clockwise order if an edge is parallel).
+    pub(crate) top: usize,
+}
+
+impl EdgeSupport {
+    /// Coordinate of `point` along the edge, relative to `origin`.
+    pub(crate) fn along(&self, origin: [f64; 2], point: [f64; 2]) -> f64 {
+        return self.direction[0] * (point[0] - origin[0])
+            + self.direction[1] * (point[1] - origin[1]);
+    }
+
+    /// Distance of `point` from the line through `origin` along the edge.
+    pub(crate) fn across(&self, origin: [f64; 2], point: [f64; 2]) -> f64 {
+        return self.normal[0] * (point[0] - origin[0]) + self.normal[1] * (point[1] - origin[1]);
+    }
+}
+
+/**
+Returns the supporting corners of each edge of the convex polygon `points`
+(counter-clockwise, no collinear corners), where edge `i` runs from corner `i`
+to corner `i + 1`. Returns an empty vector for polygons with fewer than three
+corners.
+
+When the edges are processed in counter-clockwise order, the support points in
+the edge direction, against it and along its inward normal advance
+monotonically, so all edges are processed in O(h).
+ */
+pub(crate) fn edge_supports(points: &[[f64; 2]]) -> Vec<EdgeSupport> {
+    let n = points.len();
+    if n < 3 {
+        return Vec::new();
+    }
+
+    let mut supports = Vec::with_capacity(n);
+    let (mut ahead, mut behind, mut top) = (0, 0, 0);
+    for i in 0..n {
+        let (a, b) = (points[i], points[(i + 1) % n]);
+        let length = (b[0] - a[0]).hypot(b[1] - a[1]);
+        let direction = [(b[0] - a[0]) / length, (b[1] - a[1]) / length];
+        let mut support = EdgeSupport {
+            direction,
+            normal: [-direction[1], direction[0]],
+            ahead,
+            behind,
+            top,
+        };
+        let along = |j: usize| support.along(a, points[j]);
+        let across = |j: usize| support.across(a, points[j]);
+
+        if i == 0 {
+            ahead = (0..n)
+                .max_by(|x, y| along(*x).total_cmp(&along(*y)))
+                .unwrap_or(0);
+            behind = (0..n)
+                .min_by(|x, y| along(*x).total_cmp(&along(*y)))
+                .unwrap_or(0);
+            top = (0..n)
+                .rev()
+                .max_by(|x, y| across(*x).total_cmp(&across(*y)))
+                .unwrap_or(0);
+        }
+        for _ in 0..n {
+            if along((ahead + 1) % n) <= along(ahead) {
+                break;
+            }
+            ahead = (ahead + 1) % n;
+        }
+        for _ in 0..n {
+            if along((behind + 1) % n) >= along(behind) {
+                break;
+            }
+            behind = (behind + 1) % n;
+        }
+        for _ in 0..n {
+            if across((top + 1) % n) <= across(top) {
+                break;
+            }
+            top = (top + 1) % n;
+        }
+        (support.ahead, support.behind, support.top) = (ahead, behind, top);
+        supports.push(support);
+    }
+    return supports;
+}
+
+/**
+Returns all antipodal pairs of corners of the convex polygon `points`
+(counter-clockwise, no collinear corners), i.e. the pairs of corners which
+admit two parallel supporting lines. Each pair is reported once, with the
+smaller index first. A segment has a single pair and a point has none.
+
+Every antipodal pair contains an endpoint of an edge and the corner farthest
+from it (or both corners of a parallel edge), so the pairs are collected from
+[`edge_supports`] in O(h).
+ */
+pub(crate) fn antipodal_pairs(points: &[[f64; 2]]) -> Vec<[usize; 2]> {
+    let n = points.len();
+    if n == 2 {
+        return vec![[0, 1]];
+    }
+    let mut pairs = Vec::new();
+    for (i, support) in edge_supports(points).iter().enumerate() {
+        let a = points[i];
+        let mut tops = vec![support.top];
+        let next = (support.top + 1) % n;
+        if support.across(a, points[next]) == support.across(a, points[support.top]) {
+            tops.push(next);
+        }
+        for top in tops {
+            for corner in [i, (i + 1) % n] {
+                if corner != top {
+                    pairs.push([corner.min(top), corner.max(top)]);
+                }
+            }
+        }
+    }
+    pairs.sort_unstable();
+    pairs.dedup();
+    return pairs;
+}
+
+/**
+Returns the rectangle enclosing the convex polygon `points` (counter-clockwise,
+no collinear corners) which minimizes `cost` of its side lengths, among the
+rectangles flush with a polygon edge. Returns `None` for polygons with fewer
+than three corners.
+ */
+fn flush_rectangle(
+    points: &[[f64; 2]],
+    cost: impl Fn([f64; 2]) -> f64,
+) -> Option<OrientedRectangle> {
+    let mut best: Option<(f64, OrientedRectangle)> = None;
+    for (i, support) in edge_supports(points).iter().enumerate() {
+        let a = points[i];
+        let behind = support.along(a, points[support.behind]);
+        let sides = [
+            support.along(a, points[support.ahead]) - behind,
+            support.across(a, points[support.top]),
+        ];
+        let value = cost(sides);
+        if best
+            .as_ref()
+            .is_none_or(|(best_value, _)| value < *best_value)
+        {
+            let [dx, dy] = support.direction;
+            let [nx, ny] = support.normal;
+            let corner = |s: f64, t: f64| [a[0] + s * dx + t * nx, a[1] + s * dy + t * ny];
+            let ahead = behind + sides[0];
+            let rectangle = OrientedRectangle {
+                corners: [
+                    corner(behind, 0.0),
+                    corner(ahead, 0.0),
+                    corner(ahead, sides[1]),
+                    corner(behind, sides[1]),
+                ],
+                angle: dy.atan2(dx),
+                sides,
+            };
+            best = Some((value, rectangle));
+        }
+    }
+    return best.map(|(_, rectangle)| rectangle);
+}
+
+/**
+Rectangle with arbitrary orientation enclosing a hull, see
+[`Hull::min_area_rect`] and [`Hull::min_perimeter_rect`].
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct OrientedRectangle {
+    /// Corners of the rectangle in counter-clockwise order. The side from the
+    /// first to the second corner is flush with a hull edge.
+    pub corners: [[f64; 2]; 4],
+    /// Angle in radians between the x-axis and the side from the first to the
+    /// second corner, in the range `[-π, π]`.
+    pub angle: f64,
+    /// Length of the side from the first to the second corner and of the side
+    /// perpendicular to it.
+    pub sides: [f64; 2],
+}
+
+impl OrientedRectangle {
+    /// Returns the area of the rectangle.
+    pub fn area(&self) -> f64 {
+        return self.sides[0] * self.sides[1];
+    }
+
+    /// Returns the perimeter of the rectangle.
+    pub fn perimeter(&self) -> f64 {
+        return 2.0 * (self.sides[0] + self.sides[1]);
+    }
+}
+
+impl Hull {
+    /**
+    Returns the minimum [`Width`] of the hull, i.e. the smallest distance
+    between two parallel lines enclosing it, together with the edge and the
+    corner defining these lines. This is e.g. the narrowest slot the hull fits
+    through. Returns `None` if the hull has fewer than three corners, since the
+    defining edge and corner do not exist in this case (the width of a segment
+    or a point is zero).
+
+    The supporting corner opposite to each edge is tracked by rotating
+    calipers, so the width is found in O(h). If multiple edges have the same
+    width, the first one in counter-clockwise order is returned.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [4.0, 0.0], [1.0, 3.0], [1.0, 1.0]]);
+    let width = hull.width().unwrap();
+    assert!((width.distance - 8.0f64.sqrt()).abs() < 1e-12);
+    assert_eq!(width.edge, [(1, [4.0, 0.0]), (2, [1.0, 3.0])]);
+    assert_eq!(width.corner, (0, [0.0, 0.0]));
+
+    let segment = Hull::new(&[[0.0, 0.0], [2.0, 0.0]]);
+    assert_eq!(segment.width(), None);
+    ```
+     */
+    pub fn width(&self) -> Option<Width> {
+        let points = self.points();
+        let keys = self.keys();
+        let n = points.len();
+        let corner = |i: usize| (keys[i], points[i]);
+        return edge_supports(points)
+            .iter()
+            .enumerate()
+            .map(|(i, support)| Width {
+                distance: support.across(points[i], points[support.top]),
+                edge: [corner(i), corner((i + 1) % n)],
+                corner: corner(support.top),
+            })
+            .reduce(|best, width| {
+                if width.distance < best.distance {
+                    return width;
+                }
+                return best;
+            });
+    }
+
+    /**
+    Returns an iterator over all antipodal pairs of corners of the hull, i.e.
+    the pairs of corners which admit two parallel lines enclosing the hull.
+    The pairs are yielded as keys and points, sorted by the positions of the
+    corners in the hull, and the first corner of each pair precedes the second
+    one. A segment has a single pair and a point has none.
+
+    Many extremal measures of a hull are attained at antipodal pairs, e.g. the
+    [`Hull::diameter`] or the width in a given direction. There are at most
+    3h/2 of them and they are enumerated by rotating calipers in O(h).
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    // In a rectangle, all pairs of corners are antipodal
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 1.0], [0.0, 1.0]]);
+    assert_eq!(hull.antipodal_pairs().len(), 6);
+
+    // In a trapezoid, the corners of the short side are not
+    let hull = Hull::new(&[[0.0, 0.0], [4.0, 0.0], [3.0, 1.0], [1.0, 1.0]]);
+    let pairs: Vec<[usize; 2]> = hull.antipodal_pairs().map(|[a, b]| [a.0, b.0]).collect();
+    assert_eq!(pairs, [[1, 2], [1, 3], [1, 0], [2, 0], [3, 0]]);
+    ```
+     */
+    pub fn antipodal_pairs(&self) -> impl ExactSizeIterator<Item = [(usize, [f64; 2]); 2]> {
+        let corner = |i: usize| (self.keys()[i], self.points()[i]);
+        return antipodal_pairs(self.points())
+            .into_iter()
+            .map(move |[i, j]| [corner(i), corner(j)]);
+    }
+}
+
+impl Hull {
+    /**
+    Returns the [`OrientedRectangle`] with the smallest area which encloses the
+    hull. Returns `None` if the hull has fewer than three corners.
+
+    One side of the minimum-area rectangle is flush with an edge of the hull
+    (Freeman and Shapira, 1975). The rotating calipers track the corners
+    supporting the rectangle of each edge, so all candidates are checked in
+    O(h). If multiple rectangles have the same area, the one flush with the
+    first edge in counter-clockwise order is returned.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    // A square rotated by 45°
+    let hull = Hull::new(&[[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0], [0.0, -1.0], [0.1, 0.2]]);
+    let rectangle = hull.min_area_rect().unwrap();
+    assert!((rectangle.area() - 2.0).abs() < 1e-12);
+    assert!((rectangle.angle - 0.75 * std::f64::consts::PI).abs() < 1e-12);
+    assert_eq!(rectangle.corners[0], [1.0, 0.0]);
+    ```
+     */
+    pub fn min_area_rect(&self) -> Option<OrientedRectangle> {
+        return flush_rectangle(self.points(), |[a, b]| a * b);
+    }
+
+    /**
+    Returns the [`OrientedRectangle`] with the smallest perimeter which
+    encloses the hull. Returns `None` if the hull has fewer than three
+    corners. This is the relevant measure e.g. for packaging, whereas
+    [`Hull::min_area_rect`] minimizes the area.
+
+    Like the minimum-area rectangle, the minimum-perimeter rectangle has a
+    side flush with an edge of the hull (Toussaint, 1983), so it is found by
+    the same O(h) sweep of the rotating calipers.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 1.0], [0.0, 1.0], [1.0, 0.5]]);
+    let rectangle = hull.min_perimeter_rect().unwrap();
+    assert_eq!(rectangle.perimeter(), 6.0);
+    assert_eq!(rectangle.corners, [[2.0, 1.0], [0.0, 1.0], [0.0, 0.0], [2.0, 0.0]]);
+    ```
+     */
+    pub fn min_perimeter_rect(&self) -> Option<OrientedRectangle> {
+        return flush_rectangle(self.points(), |[a, b]| a + b);
+    }
+
+    /**
+    Returns the triangle with the largest area whose corners are corners of
+    the hull, together with its area. The corners of the triangle are given in
+    counter-clockwise order, starting with the first one in the hull. Returns
+    `None` if the hull has fewer than three corners.
+
+    For a fixed first corner, the third corner of the largest triangle advances
+    monotonically with the second one, so the calipers find the largest
+    triangle with a given first corner in O(h) and the largest triangle
+    overall in O(h²). The linear-time algorithm of Dobkin and Snyder (1979) is
+    not used, since it was shown to be incorrect (Keikha, Löffler, Urhausen
+    and van der Hoog, 2017).
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [4.0, 0.0], [4.0, 2.0], [2.0, 3.0], [0.0, 2.0]]);
+    let (triangle, area) = hull.largest_inscribed_triangle().unwrap();
+    assert_eq!(triangle.map(|(_, point)| point), [[2.0, 3.0], [0.0, 0.0], [4.0, 0.0]]);
+    assert_eq!(area, 6.0);
+    ```
+     */
+    #[allow(clippy::type_complexity)]
+    pub fn largest_inscribed_triangle(&self) -> Option<([(usize, [f64; 2]); 3], f64)> {
+        let points = self.points();
+        let n = points.len();
+        if n < 3 {
+            return None;
+        }
+        let area = |i: usize, j: usize, k: usize| {
+            let (a, b, c) = (points[i % n], points[j % n], points[k % n]);
+            return 0.5 * ((b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]));
+        };
+
+        let mut best = (0.0, [0, 1, 2]);
+        for i in 0..n {
+            let mut k = i + 2;
+            for j in i + 1..i + n - 1 {
+                k = k.max(j + 1);
+                while k + 1 < i + n && area(i, j, k + 1) >= area(i, j, k) {
+                    k += 1;
+                }
+                if area(i, j, k) > best.0 {
+                    best = (area(i, j, k), [i, j % n, k % n]);
+                }
+            }
+        }
+        let mut corners = best.1;
+        while corners[0] > corners[1].min(corners[2]) {
+            corners.rotate_left(1);
+        }
+        let [i, j, k] = corners;
+        return Some((corners.map(|i| (self.keys()[i], points[i])), area(i, j, k)));
+    }
+}
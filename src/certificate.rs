@@ -0,0 +1,231 @@
+//! This module contains the [`Certificate`] returned by
+//! [`ConvexHull::convex_hull_with_certificate`](crate::ConvexHull::convex_hull_with_certificate),
+//! which allows checking a hull independently of the algorithm that
+//! calculated it.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::predicates::{on_segment, orientation};
+use crate::{ConvexHull, ConvexHullIter, Hull, HullError};
+
+/// Reasons why a [`Certificate`] does not prove a hull, see
+/// [`Certificate::verify`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CertificateError {
+    /// The hull is not a convex polygon in counter-clockwise order.
+    InvalidHull(HullError),
+    /// The hull corner with the given key is not a point of the collection
+    /// (or has different coordinates).
+    UnknownCorner {
+        /// Key of the corner.
+        key: usize,
+    },
+    /// The point with the given key is neither a hull corner nor covered by
+    /// the certificate.
+    Missing {
+        /// Key of the point.
+        key: usize,
+    },
+    /// The witness edge of the point with the given key is not an edge of the
+    /// hull.
+    UnknownEdge {
+        /// Key of the point.
+        key: usize,
+    },
+    /// The point with the given key is not located inside the triangle of its
+    /// witness edge.
+    Outside {
+        /// Key of the point.
+        key: usize,
+    },
+}
+
+impl std::fmt::Display for CertificateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CertificateError::InvalidHull(error) => write!(f, "invalid hull: {error}"),
+            CertificateError::UnknownCorner { key } => {
+                write!(f, "hull corner {key} is not a point of the collection")
+            }
+            CertificateError::Missing { key } => {
+                write!(f, "point {key} is neither a hull corner nor certified")
+            }
+            CertificateError::UnknownEdge { key } => {
+                write!(f, "the witness edge of point {key} is not a hull edge")
+            }
+            CertificateError::Outside { key } => {
+                write!(f, "point {key} is not located inside its witness triangle")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CertificateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CertificateError::InvalidHull(error) => return Some(error),
+            _ => return None,
+        }
+    }
+}
+
+/**
+Proof that all points of a collection which are not hull corners are located
+inside the hull.
+
+For each such point, the certificate stores a witness: the keys `[a, b]` of a
+hull edge. The supporting line of this edge and the rays from the first hull
+corner `c` through `a` and `b` enclose the triangle `c`, `a`, `b`, which
+contains the point. Since these triangles partition the hull (a fan
+triangulation), checking a point takes three orientation tests, and
+[`Certificate::verify`] checks a hull in O(n) time with exact predicates,
+without relying on the algorithm which calculated it. For a hull with two
+corners (collinear input), the witness is the segment between them; for a
+hull with a single corner (coincident input), it is `[c, c]`.
+
+Duplicates of corners and points on the hull edges are covered by witnesses
+as well. Nonreal points are ignored.
+
+# Examples
+
+```
+use planar_convex_hull::ConvexHull;
+
+let points = vec![[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0], [1.0, 2.0], [3.0, 1.0]];
+let (hull, certificate) = points.convex_hull_with_certificate();
+let hull: Vec<(usize, [f64; 2])> = hull.collect();
+assert_eq!(hull.iter().map(|(key, _)| *key).collect::<Vec<_>>(), vec![2, 3, 0, 1]);
+
+// The fan triangles start at the first corner 2
+assert_eq!(certificate.witnesses(), &[(4, [3, 0]), (5, [0, 1])]);
+assert_eq!(certificate.verify(&points, &hull), Ok(()));
+
+// A hull which misses a corner is rejected
+let wrong = &hull[..3];
+assert!(certificate.verify(&points, wrong).is_err());
+```
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct Certificate {
+    witnesses: Vec<(usize, [usize; 2])>,
+}
+
+impl Certificate {
+    /**
+    Returns the keys of all points which are not hull corners together with
+    the keys of their witness edges, in the iteration order of the
+    collection.
+     */
+    pub fn witnesses(&self) -> &[(usize, [usize; 2])] {
+        return &self.witnesses;
+    }
+
+    /**
+    Checks that `hull` (e.g. the collected result of
+    [`ConvexHull::convex_hull_with_certificate`]) is the convex hull of
+    `collection`: The hull must be a valid convex polygon (see
+    [`Hull::from_vertices`]), its corners must be points of the collection and
+    every other real point must be located inside the triangle of its
+    witness. Returns the first violation found.
+     */
+    pub fn verify<T: ConvexHull + ?Sized>(
+        &self,
+        collection: &T,
+        hull: &[(usize, [f64; 2])],
+    ) -> Result<(), CertificateError> {
+        Hull::from_vertices(hull.iter().copied()).map_err(CertificateError::InvalidHull)?;
+        let n = hull.len();
+        let corners: HashMap<usize, usize> = hull
+            .iter()
+            .enumerate()
+            .map(|(position, (key, _))| (*key, position))
+            .collect();
+        let witnesses: HashMap<usize, [usize; 2]> = self.witnesses.iter().copied().collect();
+
+        let mut found = vec![false; n];
+        for (key, point) in collection.convex_hull_iter() {
+            if !point[0].is_finite() || !point[1].is_finite() {
+                continue;
+            }
+            if let Some(position) = corners.get(&key) {
+                if hull[*position].1 != point {
+                    return Err(CertificateError::UnknownCorner { key });
+                }
+                found[*position] = true;
+                continue;
+            }
+            let Some([a, b]) = witnesses.get(&key).copied() else {
+                return Err(CertificateError::Missing { key });
+            };
+            let (Some(i), Some(j)) = (corners.get(&a).copied(), corners.get(&b).copied()) else {
+                return Err(CertificateError::UnknownEdge { key });
+            };
+
+            let inside = match n {
+                1 => point == hull[0].1,
+                2 => i != j && on_segment(hull[i].1, hull[j].1, point),
+                _ => {
+                    if i == 0 || j != i + 1 {
+                        return Err(CertificateError::UnknownEdge { key });
+                    }
+                    let (c, a, b) = (hull[0].1, hull[i].1, hull[j].1);
+                    orientation(c, a, point) != Ordering::Less
+                        && orientation(a, b, point) != Ordering::Less
+                        && orientation(b, c, point) != Ordering::Less
+                }
+            };
+            if !inside {
+                return Err(CertificateError::Outside { key });
+            }
+        }
+        if let Some(position) = found.iter().position(|found| !found) {
+            return Err(CertificateError::UnknownCorner {
+                key: hull[position].0,
+            });
+        }
+        return Ok(());
+    }
+}
+
+pub(crate) fn convex_hull_with_certificate<T: ConvexHull + ?Sized>(
+    this: &T,
+) -> (ConvexHullIter, Certificate) {
+    // The witnesses are checked with exact predicates, so the hull must be
+    // exact as well.
+    let hull: Vec<(usize, [f64; 2])> = this.convex_hull_exact().collect();
+    let n = hull.len();
+    let corners: HashMap<usize, [f64; 2]> = hull.iter().copied().collect();
+
+    let mut witnesses = Vec::new();
+    for (key, point) in this.convex_hull_iter() {
+        if !point[0].is_finite() || !point[1].is_finite() || corners.get(&key) == Some(&point) {
+            continue;
+        }
+        let edge = match n {
+            1 => [hull[0].0, hull[0].0],
+            2 => [hull[0].0, hull[1].0],
+            _ => {
+                // Binary search for the fan triangle containing the point.
+                // Invariant: The point is not right of the ray from the first
+                // corner through corner lo, and not left of the ray through
+                // corner hi.
+                let origin = hull[0].1;
+                let (mut lo, mut hi) = (1, n - 1);
+                while hi - lo > 1 {
+                    let mid = (lo + hi) / 2;
+                    if orientation(origin, hull[mid].1, point) != Ordering::Less {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                [hull[lo].0, hull[hi].0]
+            }
+        };
+        witnesses.push((key, edge));
+    }
+    return (ConvexHullIter::from_vec(hull), Certificate { witnesses });
+}
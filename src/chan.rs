@@ -0,0 +1,187 @@
+//! Chan's output-sensitive algorithm \[1\], see
+//! [`ConvexHull::convex_hull_chan`](crate::ConvexHull::convex_hull_chan).
+//!
+//! # Literature
+//!
+//! 1. Chan, T. M.: Optimal output-sensitive convex hull algorithms in two and
+//!    three dimensions. Discrete & Computational Geometry 16, 361–368 (1996).
+//!    <https://doi.org/10.1007/BF02712873>
+
+use std::cmp::Ordering;
+
+use crate::Hull;
+use crate::monotone_chain::monotone_chain;
+use crate::predicates::{compare_along_ray, orientation};
+
+/// Hull of a group of input points.
+struct Group {
+    /// The hull, whose corners are keyed by their position.
+    hull: Hull,
+    /// Input index of each corner.
+    indices: Vec<usize>,
+}
+
+impl Group {
+    fn new(points: &[(usize, [f64; 2])], range: std::ops::Range<usize>) -> Self {
+        let corners = monotone_chain(range.map(|index| (index, points[index].1)).collect());
+        return Self {
+            hull: Hull::from_vertices_unchecked(
+                corners
+                    .iter()
+                    .enumerate()
+                    .map(|(position, (_, point))| (position, *point)),
+            ),
+            indices: corners.iter().map(|(index, _)| *index).collect(),
+        };
+    }
+
+    /**
+    Returns the position of the corner which follows `point` when wrapping
+    the hull counter-clockwise: All corners are located left of the line from
+    `point` to this corner or on it, and of multiple corners on the line, the
+    farthest one is returned. Returns `None` if all corners are identical to
+    `point`.
+     */
+    fn wrap(&self, point: [f64; 2]) -> Option<usize> {
+        let corners = self.hull.points();
+        let n = corners.len();
+        if let Some([_, (right, _)]) = self.hull.tangents_from(point) {
+            // If the line touches an edge, the tangent corner is the nearer
+            // end of the edge.
+            let next = (right + 1) % n;
+            if n > 1 && orientation(point, corners[right], corners[next]) == Ordering::Equal {
+                return Some(next);
+            }
+            return Some(right);
+        }
+
+        // The point is a corner of this hull (or located on its boundary due
+        // to duplicates), hence the tangent is not defined.
+        let mut best: Option<usize> = None;
+        for (position, corner) in corners.iter().enumerate() {
+            if *corner == point {
+                continue;
+            }
+            best = match best {
+                Some(best) => match orientation(point, corners[best], *corner) {
+                    Ordering::Less => Some(position),
+                    Ordering::Equal
+                        if compare_along_ray(point, *corner, corners[best])
+                            == Ordering::Greater =>
+                    {
+                        Some(position)
+                    }
+                    _ => Some(best),
+                },
+                None => Some(position),
+            };
+        }
+        return best;
+    }
+}
+
+/**
+Wraps the hulls of groups of size `m` starting from the corner `start`. Returns
+`None` if the hull has more than `m` corners.
+ */
+fn wrap(points: &[(usize, [f64; 2])], start: usize, m: usize) -> Option<Vec<(usize, [f64; 2])>> {
+    let n = points.len();
+    let groups: Vec<Group> = (0..n)
+        .step_by(m)
+        .map(|first| Group::new(points, first..(first + m).min(n)))
+        .collect();
+
+    // The current corner as (input index, group, position in the group hull).
+    // The first of identical points is a corner of its group hull.
+    let group = start / m;
+    let position = groups[group]
+        .indices
+        .iter()
+        .position(|index| *index == start)?;
+    let mut current = (start, group, position);
+    let mut hull = vec![points[start]];
+    for _ in 0..m {
+        let point = points[current.0].1;
+        let mut next: Option<(usize, usize, usize)> = None;
+        for (group, candidate) in groups.iter().enumerate() {
+            let position = if group == current.1 {
+                // The next corner of the own group hull
+                let len = candidate.indices.len();
+                if len < 2 {
+                    continue;
+                }
+                (current.2 + 1) % len
+            } else {
+                let Some(position) = candidate.wrap(point) else {
+                    continue;
+                };
+                position
+            };
+            let index = candidate.indices[position];
+            let replace = match next {
+                None => true,
+                Some((best, _, _)) => match orientation(point, points[best].1, points[index].1) {
+                    Ordering::Less => true,
+                    Ordering::Equal => {
+                        match compare_along_ray(point, points[index].1, points[best].1) {
+                            Ordering::Greater => true,
+                            Ordering::Equal => index < best,
+                            Ordering::Less => false,
+                        }
+                    }
+                    Ordering::Greater => false,
+                },
+            };
+            if replace {
+                next = Some((index, group, position));
+            }
+        }
+
+        match next {
+            Some(next) if points[next.0].1 != points[start].1 => {
+                hull.push(points[next.0]);
+                current = next;
+            }
+            _ => return Some(hull),
+        }
+    }
+    return None;
+}
+
+/**
+Calculates the convex hull of `points` with Chan's algorithm and returns its
+corners in the same order as [`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull).
+Collinear points on the hull edges and nonreal points are discarded, and of
+multiple identical points the first one is kept.
+ */
+pub(crate) fn chan(mut points: Vec<(usize, [f64; 2])>) -> Vec<(usize, [f64; 2])> {
+    points.retain(|(_, p)| p[0].is_finite() && p[1].is_finite());
+    let n = points.len();
+
+    // The first point with the largest x-value (and the largest y-value if
+    // there are multiple such points)
+    let Some(start) = (0..n).min_by(|i, j| {
+        let (a, b) = (points[*i].1, points[*j].1);
+        return b[0].total_cmp(&a[0]).then(b[1].total_cmp(&a[1]));
+    }) else {
+        return points;
+    };
+
+    // Guess the hull size as m = 2^(2^t) for t = 1, 2, ...
+    let mut exponent: u32 = 1;
+    loop {
+        let m = 1usize
+            .checked_shl(1 << exponent)
+            .unwrap_or(usize::MAX)
+            .min(n);
+        if let Some(hull) = wrap(&points, start, m) {
+            return hull;
+        }
+        if m == n {
+            // With a single group, the wrapping always succeeds. This only
+            // guards against an endless loop.
+            return monotone_chain(points);
+        }
+        exponent += 1;
+    }
+}
@@ -0,0 +1,178 @@
+//! This module contains the [`Circle`] type, which is returned by
+//! [`Hull::min_enclosing_circle`] and [`Hull::max_inscribed_circle`].
+//!
+//! # Literature
+//!
+//! 1. Welzl, E.: Smallest enclosing disks (balls and ellipsoids). New Results
+//!    and New Trends in Computer Science, LNCS 555, 359–370 (1991).
+//!    <https://doi.org/10.1007/BFb0038202>
+//! 2. Aichholzer, O., Aurenhammer, F., Alberts, D., Gärtner, B.: A novel type
+//!    of skeleton for polygons. Journal of Universal Computer Science 1,
+//!    752–761 (1995). <https://doi.org/10.3217/jucs-001-12-0752>
+
+use crate::Hull;
+use crate::annulus::circumcenter;
+use crate::generators::Rng;
+use crate::geometry::distance;
+use crate::offset::{Shrunk, shrink};
+
+/**
+A circle, e.g. the smallest circle enclosing a hull (see
+[`Hull::min_enclosing_circle`]) or the largest circle inside it (see
+[`Hull::max_inscribed_circle`]).
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct Circle {
+    /// Center of the circle.
+    pub center: [f64; 2],
+    /// Radius of the circle.
+    pub radius: f64,
+}
+
+impl Circle {
+    /// Returns `true` if `point` is located inside the circle or on it.
+    pub fn contains(&self, point: [f64; 2]) -> bool {
+        return distance(self.center, point) <= self.radius;
+    }
+}
+
+/// Smallest circle through `a` and `b`.
+fn diametral(a: [f64; 2], b: [f64; 2]) -> Circle {
+    let center = [0.5 * (a[0] + b[0]), 0.5 * (a[1] + b[1])];
+    return Circle {
+        center,
+        radius: distance(center, a),
+    };
+}
+
+impl Hull {
+    /**
+    Returns the smallest [`Circle`] enclosing the hull (and therefore all
+    points it was created from), or `None` for an empty hull.
+
+    Only the corners of the hull can lie on the smallest enclosing circle, so
+    Welzl's randomized incremental algorithm \[1\] is applied to the corners,
+    which takes expected O(h) time. The corners are shuffled with a fixed seed,
+    so the result is deterministic. The radius is the largest distance of a
+    corner from the center, so the circle contains all corners despite
+    rounding errors.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [4.0, 0.0], [0.0, 3.0], [1.0, 1.0]]);
+    let circle = hull.min_enclosing_circle().unwrap();
+    assert_eq!(circle.center, [2.0, 1.5]);
+    assert_eq!(circle.radius, 2.5);
+    ```
+     */
+    pub fn min_enclosing_circle(&self) -> Option<Circle> {
+        let mut points = self.points().to_vec();
+        let mut rng = Rng::new(0x5EED);
+        for i in (1..points.len()).rev() {
+            points.swap(i, rng.index(i + 1));
+        }
+
+        // Tolerance for rounding errors of the circle construction, so that
+        // points on the current circle do not trigger a rebuild.
+        let scale = points
+            .iter()
+            .fold(0.0f64, |scale, p| scale.max(p[0].abs()).max(p[1].abs()));
+        let tolerance = 1e-12 * scale;
+        let outside = |circle: &Circle, point: [f64; 2]| {
+            return distance(circle.center, point) > circle.radius + tolerance;
+        };
+
+        let first = *points.first()?;
+        let mut circle = Circle {
+            center: first,
+            radius: 0.0,
+        };
+        for i in 1..points.len() {
+            if !outside(&circle, points[i]) {
+                continue;
+            }
+            circle = Circle {
+                center: points[i],
+                radius: 0.0,
+            };
+            for j in 0..i {
+                if !outside(&circle, points[j]) {
+                    continue;
+                }
+                circle = diametral(points[i], points[j]);
+                for k in 0..j {
+                    if !outside(&circle, points[k]) {
+                        continue;
+                    }
+                    circle = match circumcenter(points[i], points[j], points[k]) {
+                        Some(center) => Circle {
+                            center,
+                            radius: distance(center, points[i]),
+                        },
+                        // Nearly collinear: The outer two points span the
+                        // circle.
+                        None => [
+                            diametral(points[i], points[k]),
+                            diametral(points[j], points[k]),
+                        ]
+                        .into_iter()
+                        .fold(circle, |a, b| if b.radius > a.radius { b } else { a }),
+                    };
+                }
+            }
+        }
+
+        circle.radius = points.iter().fold(0.0, |radius: f64, point| {
+            radius.max(distance(circle.center, *point))
+        });
+        return Some(circle);
+    }
+}
+
+impl Hull {
+    /**
+    Returns the largest [`Circle`] inside the hull, whose center is also known
+    as the Chebyshev center of the hull polygon. Returns `None` if the hull
+    has fewer than three corners. If the largest circle is not unique (e.g.
+    for a rectangle), one of them is returned.
+
+    The hull polygon is shrunk by moving all edge lines inwards at the same
+    speed (see [`Hull::offset`]). Whenever an edge shrinks to a point, its
+    line no longer bounds the shrunk polygon and is removed. The center of the
+    largest circle is the point where the polygon finally vanishes, which is
+    the root of the straight skeleton of the polygon \[2\]. With a priority
+    queue of the edge collapses, this takes O(h log h) time.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    // A 3-4-5 triangle with inradius 1
+    let hull = Hull::new(&[[0.0, 0.0], [4.0, 0.0], [0.0, 3.0], [1.0, 1.0]]);
+    let circle = hull.max_inscribed_circle().unwrap();
+    assert!((circle.center[0] - 1.0).abs() < 1e-12);
+    assert!((circle.center[1] - 1.0).abs() < 1e-12);
+    assert!((circle.radius - 1.0).abs() < 1e-12);
+    ```
+     */
+    pub fn max_inscribed_circle(&self) -> Option<Circle> {
+        let points = self.points();
+        if points.len() < 3 {
+            return None;
+        }
+        let Shrunk::Vanished { center, .. } = shrink(points, f64::INFINITY) else {
+            return None;
+        };
+
+        // The radius is the distance to the nearest edge, so the circle
+        // is inside the hull despite rounding errors.
+        let radius = (-self.signed_distance(center)).max(0.0);
+        return Some(Circle { center, radius });
+    }
+}
@@ -0,0 +1,113 @@
+//! Clipping of segments and lines against a hull, see [`Hull::clip_segment`]
+//! and [`Hull::clip_line`].
+//!
+//! # Literature
+//!
+//! 1. Cyrus, M., Beck, J.: Generalized two- and three-dimensional clipping.
+//!    Computers & Graphics 3, 23–28 (1978).
+//!    <https://doi.org/10.1016/0097-8493(78)90021-3>
+
+use crate::Hull;
+
+/**
+Clips the parameter range `[start, end]` of the line `p + t * direction` to
+the halfspaces of `hull` with the Cyrus–Beck algorithm \[1\]. Returns `None`
+if the clipped range is empty.
+ */
+fn cyrus_beck(
+    hull: &Hull,
+    p: [f64; 2],
+    direction: [f64; 2],
+    mut start: f64,
+    mut end: f64,
+) -> Option<(f64, f64)> {
+    for halfspace in hull.to_halfspaces() {
+        let normal = halfspace.normal;
+        let slope = normal[0] * direction[0] + normal[1] * direction[1];
+        let excess = normal[0] * p[0] + normal[1] * p[1] - halfspace.offset;
+        if slope > 0.0 {
+            end = end.min(-excess / slope);
+        } else if slope < 0.0 {
+            start = start.max(-excess / slope);
+        } else if excess > 0.0 {
+            return None;
+        }
+    }
+    if start > end {
+        return None;
+    }
+    return Some((start, end));
+}
+
+impl Hull {
+    /**
+    Returns the part of the segment from `a` to `b` which is located inside
+    the hull (including its boundary), e.g. to clip a trajectory to the data
+    hull of a plot. The clipped segment has the same direction as the original
+    one and its ends are `a` or `b` exactly if they are inside the hull.
+    Returns `None` if the segment misses the hull. If it only touches the
+    hull, both ends of the clipped segment are the same point.
+
+    The segment is clipped with the Cyrus–Beck algorithm \[1\] against the
+    halfspaces of the hull (see [`Hull::to_halfspaces`]), which takes O(h)
+    time.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    assert_eq!(
+        hull.clip_segment([-1.0, 1.0], [1.0, 1.0]),
+        Some([[0.0, 1.0], [1.0, 1.0]])
+    );
+    assert_eq!(hull.clip_segment([-1.0, 3.0], [3.0, 3.0]), None);
+    ```
+     */
+    pub fn clip_segment(&self, a: [f64; 2], b: [f64; 2]) -> Option<[[f64; 2]; 2]> {
+        let direction = [b[0] - a[0], b[1] - a[1]];
+        let (start, end) = cyrus_beck(self, a, direction, 0.0, 1.0)?;
+        let at = |t: f64| {
+            if t == 0.0 {
+                return a;
+            }
+            if t == 1.0 {
+                return b;
+            }
+            return [a[0] + t * direction[0], a[1] + t * direction[1]];
+        };
+        return Some([at(start), at(end)]);
+    }
+
+    /**
+    Returns the part of the line through `point` with the given `direction`
+    which is located inside the hull (including its boundary), ordered along
+    `direction`, or `None` if the line misses the hull or `direction` is zero.
+    See [`Hull::clip_segment`].
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    assert_eq!(
+        hull.clip_line([5.0, 1.0], [-1.0, 0.0]),
+        Some([[2.0, 1.0], [0.0, 1.0]])
+    );
+    assert_eq!(hull.clip_line([5.0, 3.0], [-1.0, 0.0]), None);
+    ```
+     */
+    pub fn clip_line(&self, point: [f64; 2], direction: [f64; 2]) -> Option<[[f64; 2]; 2]> {
+        if direction == [0.0, 0.0] {
+            return None;
+        }
+        let (start, end) = cyrus_beck(self, point, direction, f64::NEG_INFINITY, f64::INFINITY)?;
+        if !start.is_finite() || !end.is_finite() {
+            return None;
+        }
+        let at = |t: f64| [point[0] + t * direction[0], point[1] + t * direction[1]];
+        return Some([at(start), at(end)]);
+    }
+}
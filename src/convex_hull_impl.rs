@@ -31,9 +31,7 @@ impl<S: BuildHasher + std::marker::Sync, P: Into<[f64; 2]> + std::marker::Sync +
     for HashMap<usize, P, S>
 {
     fn convex_hull_iter(&self) -> impl Iterator<Item = (usize, [f64; 2])> {
-        return self
-            .iter()
-            .map(|(key, val)| (key.clone(), val.clone().into()));
+        return self.iter().map(|(key, val)| (*key, val.clone().into()));
     }
 }
 
@@ -51,9 +49,7 @@ impl<S: BuildHasher + std::marker::Sync, P: Into<[f64; 2]> + std::marker::Sync +
 #[cfg(feature = "slab")]
 impl<P: Into<[f64; 2]> + std::marker::Sync + Clone> ConvexHull for slab::Slab<P> {
     fn convex_hull_iter(&self) -> impl Iterator<Item = (usize, [f64; 2])> {
-        return self
-            .iter()
-            .map(|(key, val)| (key.clone(), val.clone().into()));
+        return self.iter().map(|(key, val)| (key, val.clone().into()));
     }
 }
 
@@ -62,9 +58,7 @@ impl<S: BuildHasher + std::marker::Sync, P: Into<[f64; 2]> + std::marker::Sync +
     for ahash::AHashMap<usize, P, S>
 {
     fn convex_hull_iter(&self) -> impl Iterator<Item = (usize, [f64; 2])> {
-        return self
-            .iter()
-            .map(|(key, val)| (key.clone(), val.clone().into()));
+        return self.iter().map(|(key, val)| (*key, val.clone().into()));
     }
 }
 
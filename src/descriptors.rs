@@ -0,0 +1,167 @@
+//! This module contains the [`ShapeDescriptors`] and the [`SecondMoments`] of
+//! a hull, see [`Hull::descriptors`] and [`Hull::second_moments`].
+
+use std::f64::consts::PI;
+
+use crate::Hull;
+
+/**
+Dimensionless morphology features of a hull polygon, as used for classifying
+shapes (e.g. particles or blobs in image analysis). All descriptors are
+invariant under translation, rotation and scaling.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct ShapeDescriptors {
+    /// Circularity `4πA / P²` of the hull with area `A` and perimeter `P`.
+    /// It is 1 for a circle and smaller for all other shapes.
+    pub circularity: f64,
+    /// Eccentricity of the ellipse with the same second area moments as the
+    /// hull. It is 0 for shapes with a circular moment ellipse (e.g. a circle
+    /// or a square) and approaches 1 for very elongated shapes.
+    pub eccentricity: f64,
+    /// Elongation `1 - w / l` of the minimum-area enclosing rectangle with the
+    /// short side `w` and the long side `l`. It is 0 for a square and
+    /// approaches 1 for very elongated shapes.
+    pub elongation: f64,
+    /// Rectangularity `A / R` of the hull with area `A` and the area `R` of
+    /// its minimum-area enclosing rectangle. It is 1 for a rectangle and
+    /// smaller for all other shapes.
+    pub rectangularity: f64,
+}
+
+/**
+Second area moments of a hull polygon about its centroid, see
+[`Hull::second_moments`]. The moments of a polygon with area `A` are
+`Ixx = ∫y² dA`, `Iyy = ∫x² dA` and `Ixy = ∫xy dA`, with the coordinates
+relative to the centroid.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct SecondMoments {
+    /// Second moment about the horizontal axis through the centroid.
+    pub ixx: f64,
+    /// Second moment about the vertical axis through the centroid.
+    pub iyy: f64,
+    /// Product moment.
+    pub ixy: f64,
+}
+
+impl SecondMoments {
+    /// Returns the principal moments `[largest, smallest]`, i.e. the moments
+    /// about the principal axes through the centroid.
+    pub fn principal_moments(&self) -> [f64; 2] {
+        let mean = 0.5 * (self.ixx + self.iyy);
+        let deviation = (0.5 * (self.ixx - self.iyy)).hypot(self.ixy);
+        return [mean + deviation, mean - deviation];
+    }
+
+    /// Returns the angle of the principal axis with the smallest moment
+    /// (the long axis of the polygon) in radians, counter-clockwise from the
+    /// x-axis and in the range [-π/2, π/2].
+    pub fn principal_angle(&self) -> f64 {
+        return 0.5 * (2.0 * self.ixy).atan2(self.iyy - self.ixx);
+    }
+}
+
+impl Hull {
+    /**
+    Calculates the [`ShapeDescriptors`] of the hull polygon. Returns `None` if
+    the hull has fewer than three corners, since the descriptors are not
+    defined for shapes without area.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    // A 2 x 1 rectangle
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 1.0], [0.0, 1.0], [1.0, 0.5]]);
+    let descriptors = hull.descriptors().unwrap();
+    assert_eq!(descriptors.circularity, 4.0 * std::f64::consts::PI * 2.0 / 36.0);
+    assert!((descriptors.eccentricity - 0.75f64.sqrt()).abs() < 1e-12);
+    assert_eq!(descriptors.elongation, 0.5);
+    assert_eq!(descriptors.rectangularity, 1.0);
+
+    let segment = Hull::new(&[[0.0, 0.0], [2.0, 0.0]]);
+    assert_eq!(segment.descriptors(), None);
+    ```
+     */
+    pub fn descriptors(&self) -> Option<ShapeDescriptors> {
+        let [first, second] = self.min_area_rect()?.sides;
+        let area = self.area();
+        let perimeter = self.perimeter();
+
+        // Eigenvalues of the matrix of second moments, which are
+        // proportional to the squared semi-axes of the moment ellipse
+        let [major, minor] = self.second_moments().principal_moments();
+        let minor = minor.max(0.0);
+
+        let (short, long) = (first.min(second), first.max(second));
+        return Some(ShapeDescriptors {
+            circularity: 4.0 * PI * area / (perimeter * perimeter),
+            eccentricity: (1.0 - minor / major).max(0.0).sqrt(),
+            elongation: 1.0 - short / long,
+            rectangularity: area / (first * second),
+        });
+    }
+
+    /**
+    Calculates the [`SecondMoments`] of the hull polygon about its centroid,
+    e.g. for particle analysis or the stiffness of a cross section. The
+    principal axes are available via [`SecondMoments::principal_moments`] and
+    [`SecondMoments::principal_angle`]. The moments of hulls without area are
+    zero. Takes O(h) time.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    // A 2 x 1 rectangle
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 1.0], [0.0, 1.0]]);
+    let moments = hull.second_moments();
+    assert!((moments.ixx - 2.0 / 12.0).abs() < 1e-12);
+    assert!((moments.iyy - 8.0 / 12.0).abs() < 1e-12);
+    assert!(moments.ixy.abs() < 1e-12);
+    assert!(moments.principal_angle().abs() < 1e-12);
+    ```
+     */
+    pub fn second_moments(&self) -> SecondMoments {
+        let points = self.points();
+        let n = points.len();
+        let area = self.area();
+        if n < 3 || area == 0.0 {
+            return SecondMoments {
+                ixx: 0.0,
+                iyy: 0.0,
+                ixy: 0.0,
+            };
+        }
+
+        // Moments about the first corner, which are shifted to the centroid
+        // afterwards (parallel axis theorem).
+        let origin = points[0];
+        let (mut cx, mut cy) = (0.0, 0.0);
+        let (mut ixx, mut iyy, mut ixy) = (0.0, 0.0, 0.0);
+        for i in 0..n {
+            let (a, b) = (points[i], points[(i + 1) % n]);
+            let (x0, y0) = (a[0] - origin[0], a[1] - origin[1]);
+            let (x1, y1) = (b[0] - origin[0], b[1] - origin[1]);
+            let cross = x0 * y1 - x1 * y0;
+            cx += (x0 + x1) * cross;
+            cy += (y0 + y1) * cross;
+            ixx += (y0 * y0 + y0 * y1 + y1 * y1) * cross;
+            iyy += (x0 * x0 + x0 * x1 + x1 * x1) * cross;
+            ixy += (x0 * y1 + 2.0 * x0 * y0 + 2.0 * x1 * y1 + x1 * y0) * cross;
+        }
+        let (cx, cy) = (cx / (6.0 * area), cy / (6.0 * area));
+        return SecondMoments {
+            ixx: ixx / 12.0 - area * cy * cy,
+            iyy: iyy / 12.0 - area * cx * cx,
+            ixy: ixy / 24.0 - area * cx * cy,
+        };
+    }
+}
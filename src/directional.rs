@@ -0,0 +1,108 @@
+//! Directionally restricted hull, see
+//! [`ConvexHull::convex_hull_facing`](crate::ConvexHull::convex_hull_facing).
+
+use std::cmp::Ordering;
+use std::f64::consts::{PI, TAU};
+
+use crate::ConvexHull;
+use crate::monotone_chain::monotone_chain;
+use crate::predicates::orientation;
+
+/**
+Returns a key for comparing points by how far they extend in `direction`.
+Ties are broken along `tie_break`, which selects the first or the last of
+multiple points on an edge perpendicular to `direction`.
+ */
+fn extent(direction: [f64; 2], tie_break: [f64; 2]) -> impl Fn(&[f64; 2]) -> (f64, f64) {
+    return move |point: &[f64; 2]| {
+        return (
+            direction[0] * point[0] + direction[1] * point[1],
+            tie_break[0] * point[0] + tie_break[1] * point[1],
+        );
+    };
+}
+
+/// Returns the item with the largest extent, preferring the first one.
+fn max_by_extent<'a>(
+    items: impl Iterator<Item = &'a (usize, [f64; 2])>,
+    extent: &impl Fn(&[f64; 2]) -> (f64, f64),
+) -> Option<&'a (usize, [f64; 2])> {
+    return items.reduce(|best, item| {
+        let (value, best_value) = (extent(&item.1), extent(&best.1));
+        match value
+            .0
+            .total_cmp(&best_value.0)
+            .then(value.1.total_cmp(&best_value.1))
+        {
+            Ordering::Greater => return item,
+            _ => return best,
+        }
+    });
+}
+
+pub(crate) fn convex_hull_facing<T: ConvexHull + ?Sized>(
+    this: &T,
+    start: f64,
+    end: f64,
+) -> Vec<(usize, [f64; 2])> {
+    let full = end - start >= TAU;
+    let span = (end - start).rem_euclid(TAU);
+    let first_direction = [start.cos(), start.sin()];
+    let last_direction = [end.cos(), end.sin()];
+
+    // The chain starts with the first point (in counter-clockwise order)
+    // which is extreme in the start direction and ends with the last point
+    // which is extreme in the end direction.
+    let first_extent = extent(first_direction, [first_direction[1], -first_direction[0]]);
+    let last_extent = extent(last_direction, [-last_direction[1], last_direction[0]]);
+
+    let points: Vec<(usize, [f64; 2])> = this
+        .convex_hull_iter()
+        .filter(|(_, point)| point[0].is_finite() && point[1].is_finite())
+        .collect();
+    let (Some(first), Some(last)) = (
+        max_by_extent(points.iter(), &first_extent).copied(),
+        max_by_extent(points.iter(), &last_extent).copied(),
+    ) else {
+        return Vec::new();
+    };
+
+    let corners = if full || span >= PI {
+        monotone_chain(points)
+    } else if first.1 == last.1 {
+        return vec![first];
+    } else {
+        // For a range below π, the chain is located on the outer side of the
+        // line from its first to its last point, so all other points can be
+        // discarded right away.
+        let mut candidates: Vec<(usize, [f64; 2])> = points
+            .into_iter()
+            .filter(|(_, point)| orientation(first.1, last.1, *point) == Ordering::Less)
+            .collect();
+        candidates.push(first);
+        candidates.push(last);
+        monotone_chain(candidates)
+    };
+
+    let n = corners.len();
+    let index = |extent: &dyn Fn(&[f64; 2]) -> (f64, f64)| {
+        let best = max_by_extent(corners.iter(), &extent).map(|item| item.1);
+        return corners
+            .iter()
+            .position(|item| Some(item.1) == best)
+            .unwrap_or(0);
+    };
+    let first_index = index(&first_extent);
+    let last_index = index(&last_extent);
+    // A range of at least π can start and end at the same corner, in which
+    // case the chain is the whole boundary.
+    if full || (first_index == last_index && span >= PI) {
+        let mut corners = corners;
+        corners.rotate_left(first_index);
+        return corners;
+    }
+    let len = (last_index + n - first_index) % n + 1;
+    return (0..len)
+        .map(|offset| corners[(first_index + offset) % n])
+        .collect();
+}
@@ -0,0 +1,297 @@
+//! This module contains the [`DiscHull`], the convex hull of a union of discs.
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::f64::consts::TAU;
+
+/// Angles closer than this are treated as the same event while wrapping.
+const ANGLE_TOLERANCE: f64 = 1e-12;
+
+/**
+Arc of the boundary of a [`DiscHull`]. The arc runs counter-clockwise along
+the circle of the disc from `start_angle` to `end_angle`. The angles are
+measured from the positive x-axis and are also the angles of the outward
+normals of the boundary at the ends of the arc.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct DiscArc {
+    /// Key of the disc.
+    pub key: usize,
+    /// Center of the disc.
+    pub center: [f64; 2],
+    /// Radius of the disc.
+    pub radius: f64,
+    /// Angle of the first point of the arc.
+    pub start_angle: f64,
+    /// Angle of the last point of the arc, which is not smaller than
+    /// `start_angle`.
+    pub end_angle: f64,
+}
+
+impl DiscArc {
+    /// Returns the point of the circle of the disc at `angle`.
+    pub fn point_at(&self, angle: f64) -> [f64; 2] {
+        return [
+            self.center[0] + self.radius * angle.cos(),
+            self.center[1] + self.radius * angle.sin(),
+        ];
+    }
+
+    /// Returns the first point of the arc.
+    pub fn start(&self) -> [f64; 2] {
+        return self.point_at(self.start_angle);
+    }
+
+    /// Returns the last point of the arc.
+    pub fn end(&self) -> [f64; 2] {
+        return self.point_at(self.end_angle);
+    }
+
+    /// Returns the length of the arc.
+    pub fn length(&self) -> f64 {
+        return self.radius * (self.end_angle - self.start_angle);
+    }
+}
+
+/**
+Convex hull of a union of discs, e.g. of the coverage footprints of sensors.
+
+Its boundary consists of circular arcs, which are stored in counter-clockwise
+order, and the segments between consecutive arcs. Each segment is tangent to
+the discs of both arcs it connects. Following the convention of
+[`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull), the boundary
+starts with the arc containing the point with the largest x-value, i.e. the
+point with the outward normal angle 0. Since the angles increase along the
+boundary, the first arc can have a negative start angle.
+
+A disc can contribute multiple arcs (e.g. a large disc with small discs
+poking out of it at several places), but there are at most 2n - 1 arcs for
+n discs. Discs with a radius of zero are points; the hull of points only has
+arcs of radius zero, whose angles span the normal cones at the corners.
+
+# Examples
+
+```
+use std::f64::consts::PI;
+use planar_convex_hull::discs::DiscHull;
+
+// Two discs of radius 1: a "stadium" shape
+let hull = DiscHull::new([(0, [0.0, 0.0], 1.0), (1, [4.0, 0.0], 1.0), (2, [2.0, 0.0], 0.5)]);
+let arcs = hull.arcs();
+assert_eq!(arcs.len(), 2);
+assert_eq!(arcs[0].key, 1);
+assert_eq!(arcs[0].start_angle, -PI / 2.0);
+assert_eq!(arcs[0].end_angle, PI / 2.0);
+assert_eq!(arcs[1].key, 0);
+
+let segments = hull.segments();
+assert_eq!(segments.len(), 2);
+assert!((segments[0].0[0] - 4.0).abs() < 1e-15 && segments[0].0[1] == 1.0);
+assert!((hull.perimeter() - (2.0 * PI + 8.0)).abs() < 1e-12);
+assert!((hull.area() - (PI + 8.0)).abs() < 1e-12);
+```
+ */
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct DiscHull {
+    arcs: Vec<DiscArc>,
+}
+
+/// Disc in the representation used while wrapping: `(key, center, radius)`.
+type Disc = (usize, [f64; 2], f64);
+
+/// Support function of `disc` in the direction `angle`.
+fn support(disc: &Disc, angle: f64) -> f64 {
+    return disc.1[0] * angle.cos() + disc.1[1] * angle.sin() + disc.2;
+}
+
+/// Derivative of the support function of `disc` with respect to the angle.
+fn support_slope(disc: &Disc, angle: f64) -> f64 {
+    return -disc.1[0] * angle.sin() + disc.1[1] * angle.cos();
+}
+
+/**
+Compares the support functions of two discs which are equal at `angle`:
+The disc which is larger right after `angle` is greater.
+ */
+fn compare_after(a: &Disc, b: &Disc, angle: f64) -> Ordering {
+    return support_slope(a, angle)
+        .total_cmp(&support_slope(b, angle))
+        .then(a.2.total_cmp(&b.2));
+}
+
+/**
+Returns the angle in (`angle`, `angle` + 2π] at which the support function of
+`other` overtakes the one of `current`, if it does so at all.
+ */
+fn overtaking(current: &Disc, other: &Disc, angle: f64) -> Option<f64> {
+    let d = [other.1[0] - current.1[0], other.1[1] - current.1[1]];
+    let distance = d[0].hypot(d[1]);
+    let difference = current.2 - other.2;
+    // One disc contains the other one
+    if difference.abs() >= distance {
+        return None;
+    }
+    // other - current = distance * cos(θ - φ) - difference, which turns
+    // positive at θ = φ - acos(difference / distance)
+    let crossing = d[1].atan2(d[0]) - (difference / distance).acos();
+    let delta = (crossing - angle).rem_euclid(TAU);
+    return Some(angle + delta);
+}
+
+impl DiscHull {
+    /**
+    Calculates the hull of the discs given as `(key, center, radius)`.
+    Discs with nonreal values or a negative radius are ignored, and of
+    multiple identical discs only the first one is used.
+
+    The boundary is traced by gift wrapping over the angle of its outward
+    normal, which takes O(n·h) time for n discs and h arcs.
+     */
+    pub fn new(discs: impl IntoIterator<Item = (usize, [f64; 2], f64)>) -> Self {
+        let mut discs: Vec<Disc> = discs
+            .into_iter()
+            .filter(|(_, center, radius)| {
+                return center[0].is_finite()
+                    && center[1].is_finite()
+                    && radius.is_finite()
+                    && *radius >= 0.0;
+            })
+            .collect();
+        let mut seen = HashSet::with_capacity(discs.len());
+        discs.retain(|disc| {
+            return seen.insert([
+                (disc.1[0] + 0.0).to_bits(),
+                (disc.1[1] + 0.0).to_bits(),
+                (disc.2 + 0.0).to_bits(),
+            ]);
+        });
+
+        // The disc which is extreme in the direction 0 starts the boundary
+        let Some(mut current) = (0..discs.len()).reduce(|best, i| {
+            let ordering = support(&discs[i], 0.0)
+                .total_cmp(&support(&discs[best], 0.0))
+                .then(compare_after(&discs[i], &discs[best], 0.0));
+            match ordering {
+                Ordering::Greater => return i,
+                _ => return best,
+            }
+        }) else {
+            return Self::default();
+        };
+
+        let mut arcs: Vec<DiscArc> = Vec::new();
+        let mut angle = 0.0;
+        // The envelope of the support functions has at most 2n - 1 pieces
+        for _ in 0..2 * discs.len() {
+            let mut next: Option<(usize, f64)> = None;
+            for (i, disc) in discs.iter().enumerate() {
+                if i == current {
+                    continue;
+                }
+                let Some(crossing) = overtaking(&discs[current], disc, angle) else {
+                    continue;
+                };
+                next = match next {
+                    None => Some((i, crossing)),
+                    Some((best, best_crossing)) => {
+                        if crossing < best_crossing - ANGLE_TOLERANCE
+                            || (crossing <= best_crossing + ANGLE_TOLERANCE
+                                && compare_after(disc, &discs[best], crossing) == Ordering::Greater)
+                        {
+                            Some((i, crossing))
+                        } else {
+                            Some((best, best_crossing))
+                        }
+                    }
+                };
+            }
+
+            let (key, center, radius) = discs[current];
+            let end_angle = match next {
+                Some((_, crossing)) if crossing < TAU => crossing,
+                _ => TAU,
+            };
+            arcs.push(DiscArc {
+                key,
+                center,
+                radius,
+                start_angle: angle,
+                end_angle,
+            });
+            match next {
+                Some((i, crossing)) if crossing < TAU => {
+                    current = i;
+                    angle = crossing;
+                }
+                _ => break,
+            }
+        }
+
+        // The last arc continues at the start of the first arc
+        arcs.retain(|arc| arc.end_angle > arc.start_angle);
+        let same_disc = |a: &DiscArc, b: &DiscArc| {
+            return a.key == b.key && a.center == b.center && a.radius == b.radius;
+        };
+        if arcs.len() > 1 && same_disc(&arcs[0], &arcs[arcs.len() - 1]) {
+            let last = arcs.remove(arcs.len() - 1);
+            arcs[0].start_angle = last.start_angle - TAU;
+        }
+        return Self { arcs };
+    }
+
+    /// Returns the arcs of the boundary in counter-clockwise order.
+    pub fn arcs(&self) -> &[DiscArc] {
+        return &self.arcs;
+    }
+
+    /**
+    Returns the segments between the end of each arc and the start of the
+    next arc, in counter-clockwise order. A hull consisting of a single disc
+    has no segments.
+     */
+    pub fn segments(&self) -> Vec<([f64; 2], [f64; 2])> {
+        let n = self.arcs.len();
+        if n < 2 {
+            return Vec::new();
+        }
+        return (0..n)
+            .map(|i| (self.arcs[i].end(), self.arcs[(i + 1) % n].start()))
+            .collect();
+    }
+
+    /// Returns the length of the boundary.
+    pub fn perimeter(&self) -> f64 {
+        let arcs: f64 = self.arcs.iter().map(DiscArc::length).sum();
+        let segments: f64 = self
+            .segments()
+            .into_iter()
+            .map(|(a, b)| (b[0] - a[0]).hypot(b[1] - a[1]))
+            .sum();
+        return arcs + segments;
+    }
+
+    /// Returns the enclosed area.
+    pub fn area(&self) -> f64 {
+        // Green's theorem: area = 1/2 ∮ x dy - y dx
+        let arcs: f64 = self
+            .arcs
+            .iter()
+            .map(|arc| {
+                let (r, c) = (arc.radius, arc.center);
+                let (start, end) = (arc.start_angle, arc.end_angle);
+                return r * r * (end - start)
+                    + r * (c[0] * (end.sin() - start.sin()) - c[1] * (end.cos() - start.cos()));
+            })
+            .sum();
+        let segments: f64 = self
+            .segments()
+            .into_iter()
+            .map(|(a, b)| a[0] * b[1] - a[1] * b[0])
+            .sum();
+        return 0.5 * (arcs + segments);
+    }
+}
@@ -0,0 +1,59 @@
+//! This module contains the [`DynConvexHull`] trait, an object-safe variant of
+//! [`ConvexHull`] for collections behind trait objects.
+
+use crate::ConvexHull;
+
+/**
+Object-safe variant of [`ConvexHull`].
+
+[`ConvexHull::convex_hull_iter`] returns `impl Iterator`, which makes
+[`ConvexHull`] zero-cost but prevents its use as `dyn ConvexHull`. This trait
+returns a boxed iterator instead. It is implemented for all types which
+implement [`ConvexHull`], and in turn `dyn DynConvexHull` (as well as
+`dyn DynConvexHull + Send`) implements [`ConvexHull`], so all hull methods are
+available for trait objects. The price is a heap allocation per call of
+[`DynConvexHull::dyn_convex_hull_iter`] and a dynamic dispatch per point.
+
+# Examples
+
+```
+use std::collections::HashMap;
+use planar_convex_hull::{ConvexHull, DynConvexHull};
+
+let sources: Vec<Box<dyn DynConvexHull>> = vec![
+    Box::new(vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.2, 0.2]]),
+    Box::new(HashMap::from([(7, [2.0, 2.0]), (3, [-1.0, 0.0]), (5, [0.0, 0.0])])),
+];
+
+let hulls: Vec<Vec<usize>> = sources
+    .iter()
+    .map(|source| source.convex_hull().map(|(key, _)| key).collect())
+    .collect();
+assert_eq!(hulls, vec![vec![1, 2, 0], vec![7, 3, 5]]);
+```
+ */
+pub trait DynConvexHull: std::marker::Sync {
+    /**
+    Iterates over all keys of a collection and the associated points in any
+    order, see [`ConvexHull::convex_hull_iter`].
+     */
+    fn dyn_convex_hull_iter(&self) -> Box<dyn Iterator<Item = (usize, [f64; 2])> + '_>;
+}
+
+impl<T: ConvexHull> DynConvexHull for T {
+    fn dyn_convex_hull_iter(&self) -> Box<dyn Iterator<Item = (usize, [f64; 2])> + '_> {
+        return Box::new(self.convex_hull_iter());
+    }
+}
+
+impl ConvexHull for dyn DynConvexHull + '_ {
+    fn convex_hull_iter(&self) -> impl Iterator<Item = (usize, [f64; 2])> {
+        return self.dyn_convex_hull_iter();
+    }
+}
+
+impl ConvexHull for dyn DynConvexHull + Send + '_ {
+    fn convex_hull_iter(&self) -> impl Iterator<Item = (usize, [f64; 2])> {
+        return self.dyn_convex_hull_iter();
+    }
+}
@@ -0,0 +1,163 @@
+//! This module contains an out-of-core (external memory) mode for point sets
+//! which do not fit into memory, see [`ExternalHull`] and [`from_reader`].
+//!
+//! The points are processed in blocks of a fixed size. After each block, only
+//! the corners of the convex hull of everything seen so far are kept, since no
+//! other point can become a corner of the final hull. The memory consumption
+//! is therefore bounded by the block size plus the number of hull corners,
+//! independent of the total number of points.
+
+use std::io::{ErrorKind, Read};
+
+use crate::options::Keyed;
+use crate::{ConvexHull, Hull};
+
+/// Size of a point record in the binary format read by [`from_reader`].
+pub const RECORD_LEN: usize = 16;
+
+/**
+Builds the convex hull of a point set which is fed in blocks.
+
+Each call of [`ExternalHull::insert_block`] merges a block of keyed points
+into the current hull, which is the only state kept between blocks. The keys
+are chosen by the caller (e.g. the index of the point in a file) and should be
+unique across all blocks.
+
+The degeneracies of the hull are derived from its corners, as for a hull
+converted from a [`ConvexHullIter`](crate::ConvexHullIter) (see
+[`Hull::degeneracy`]).
+
+Unlike other out-of-core designs, this type does not spill boundary candidates
+into temporary files. This is intentional: The only candidates which have to
+survive a block are the corners of the current hull, so the state is bounded
+by the number of hull corners. Spill files would only pay off if the hull
+itself did not fit into memory, which requires an input with billions of
+points in convex position.
+
+Each block costs a full rebuild of the hull from its current corners plus the
+points of the block, i.e. O((h + b) log(h + b)) for h corners and a block of b
+points. The block size should therefore be much larger than the expected
+number of hull corners.
+
+# Examples
+
+```
+use planar_convex_hull::external::ExternalHull;
+
+let mut external = ExternalHull::new();
+external.insert_block([(0, [0.0, 0.0]), (1, [1.0, 0.0]), (2, [0.2, 0.2])]);
+external.insert_block([(3, [0.0, 1.0]), (4, [0.1, 0.1])]);
+assert_eq!(external.hull().keys(), &[1, 3, 0]);
+```
+ */
+#[derive(Debug, Clone, Default)]
+pub struct ExternalHull {
+    hull: Hull,
+}
+
+impl ExternalHull {
+    /// Creates an empty [`ExternalHull`].
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Merges a block of keyed points into the hull by rebuilding it from the
+    /// current corners plus the points of the block.
+    pub fn insert_block(&mut self, block: impl IntoIterator<Item = (usize, [f64; 2])>) {
+        let mut candidates: Vec<(usize, [f64; 2])> = self.hull.iter().collect();
+        candidates.extend(block);
+        self.hull = Hull::from(Keyed(candidates).convex_hull());
+    }
+
+    /// Returns the convex hull of all points inserted so far.
+    pub fn hull(&self) -> &Hull {
+        return &self.hull;
+    }
+
+    /// Consumes `self` and returns the convex hull of all points inserted so
+    /// far.
+    pub fn into_hull(self) -> Hull {
+        return self.hull;
+    }
+}
+
+/// Reads from `reader` until `buffer` is full or the end of the data is
+/// reached and returns the number of bytes read.
+fn fill(reader: &mut impl Read, buffer: &mut [u8]) -> std::io::Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break,
+            Ok(read) => filled += read,
+            Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        }
+    }
+    return Ok(filled);
+}
+
+/**
+Calculates the convex hull of the points stored in `reader` while keeping at
+most `block_len` points (plus the current hull corners) in memory.
+
+The data must consist of consecutive records of [`RECORD_LEN`] bytes, each
+holding the x- and y-coordinate of a point as little-endian `f64`. The key of
+a point is the index of its record. Nonreal points are ignored as in
+[`ConvexHull::convex_hull`]. If the data ends within a record, an error of the
+kind [`ErrorKind::UnexpectedEof`] is returned. Any error of `reader` is
+forwarded. A `block_len` of zero is treated as one.
+
+Wrapping a [`File`](std::fs::File) in a
+[`BufReader`](std::io::BufReader) is not necessary, since the data is read in
+large blocks anyway.
+
+# Examples
+
+```
+use planar_convex_hull::external::from_reader;
+
+let points: [[f64; 2]; 5] = [[0.0, 0.0], [1.0, 0.0], [0.2, 0.2], [0.0, 1.0], [0.1, 0.1]];
+let bytes: Vec<u8> = points
+    .iter()
+    .flat_map(|point| [point[0].to_le_bytes(), point[1].to_le_bytes()])
+    .flatten()
+    .collect();
+
+let hull = from_reader(bytes.as_slice(), 2).unwrap();
+assert_eq!(hull.keys(), &[1, 3, 0]);
+```
+ */
+pub fn from_reader(mut reader: impl Read, block_len: usize) -> std::io::Result<Hull> {
+    let block_len = block_len.max(1);
+    let mut buffer = vec![0u8; block_len * RECORD_LEN];
+    let mut external = ExternalHull::new();
+    let mut first_key = 0;
+
+    loop {
+        let filled = fill(&mut reader, &mut buffer)?;
+        if filled % RECORD_LEN != 0 {
+            return Err(std::io::Error::new(
+                ErrorKind::UnexpectedEof,
+                format!(
+                    "the data ends within the record {}",
+                    first_key + filled / RECORD_LEN
+                ),
+            ));
+        }
+
+        let records = buffer[..filled].chunks_exact(RECORD_LEN);
+        external.insert_block(records.enumerate().map(|(index, record)| {
+            let coordinate = |offset: usize| {
+                let mut bytes = [0u8; 8];
+                bytes.copy_from_slice(&record[offset..offset + 8]);
+                return f64::from_le_bytes(bytes);
+            };
+            return (first_key + index, [coordinate(0), coordinate(8)]);
+        }));
+
+        first_key += filled / RECORD_LEN;
+        if filled < buffer.len() {
+            return Ok(external.into_hull());
+        }
+    }
+}
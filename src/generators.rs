@@ -0,0 +1,200 @@
+//! This module contains generators for seeded synthetic point clouds. They are
+//! meant for benchmarking and for reproducing degenerate cases in bug reports:
+//! The same seed always produces the same points on every platform.
+//!
+//! # Examples
+//!
+//! ```
+//! use planar_convex_hull::{ConvexHull, generators};
+//!
+//! let points = generators::uniform_in_disk(1000, [0.0, 0.0], 1.0, 42);
+//! assert_eq!(points.len(), 1000);
+//! assert_eq!(points, generators::uniform_in_disk(1000, [0.0, 0.0], 1.0, 42));
+//!
+//! // All points on a circle are part of the hull
+//! let points = generators::on_circle(100, [0.0, 0.0], 1.0, 7);
+//! assert_eq!(points.convex_hull().count(), 100);
+//! ```
+
+use std::f64::consts::TAU;
+
+/**
+A small, fast and portable pseudo-random number generator (SplitMix64).
+
+It is not suitable for cryptographic purposes, but its output only depends on
+the seed, which makes generated datasets reproducible.
+ */
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a new generator from the given seed.
+    pub fn new(seed: u64) -> Self {
+        return Self { state: seed };
+    }
+
+    /// Returns the next pseudo-random 64-bit integer.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        return z ^ (z >> 31);
+    }
+
+    /// Returns a pseudo-random number uniformly distributed in `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        return (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+    }
+
+    /// Returns a pseudo-random number uniformly distributed in
+    /// `[lower, upper)`.
+    pub fn uniform(&mut self, lower: f64, upper: f64) -> f64 {
+        return lower + (upper - lower) * self.next_f64();
+    }
+
+    /// Returns a pseudo-random number from a standard normal distribution
+    /// (Box-Muller transform).
+    pub fn normal(&mut self) -> f64 {
+        // 1 - next_f64 is in (0, 1], so the logarithm is finite.
+        let radius = (-2.0 * (1.0 - self.next_f64()).ln()).sqrt();
+        return radius * (TAU * self.next_f64()).cos();
+    }
+
+    /// Returns a pseudo-random index in `[0, upper)`. `upper` must not be zero.
+    pub fn index(&mut self, upper: usize) -> usize {
+        return (self.next_u64() % upper as u64) as usize;
+    }
+}
+
+/**
+Generates `n` points uniformly distributed inside the disk with the given
+`center` and `radius`.
+ */
+pub fn uniform_in_disk(n: usize, center: [f64; 2], radius: f64, seed: u64) -> Vec<[f64; 2]> {
+    let mut rng = Rng::new(seed);
+    return (0..n)
+        .map(|_| {
+            let r = radius * rng.next_f64().sqrt();
+            let angle = TAU * rng.next_f64();
+            [center[0] + r * angle.cos(), center[1] + r * angle.sin()]
+        })
+        .collect();
+}
+
+/**
+Generates `n` points uniformly distributed inside the axis-aligned rectangle
+spanned by the corners `min` and `max`.
+ */
+pub fn uniform_in_rectangle(n: usize, min: [f64; 2], max: [f64; 2], seed: u64) -> Vec<[f64; 2]> {
+    let mut rng = Rng::new(seed);
+    return (0..n)
+        .map(|_| [rng.uniform(min[0], max[0]), rng.uniform(min[1], max[1])])
+        .collect();
+}
+
+/**
+Generates `n` points distributed over `clusters` Gaussian clusters. The cluster
+centers are uniformly distributed inside the rectangle spanned by `min` and
+`max`, and the points of each cluster are normally distributed around its
+center with the standard deviation `sigma`.
+ */
+pub fn gaussian_clusters(
+    n: usize,
+    clusters: usize,
+    min: [f64; 2],
+    max: [f64; 2],
+    sigma: f64,
+    seed: u64,
+) -> Vec<[f64; 2]> {
+    let mut rng = Rng::new(seed);
+    let centers: Vec<[f64; 2]> = (0..clusters.max(1))
+        .map(|_| [rng.uniform(min[0], max[0]), rng.uniform(min[1], max[1])])
+        .collect();
+    return (0..n)
+        .map(|_| {
+            let center = centers[rng.index(centers.len())];
+            [
+                center[0] + sigma * rng.normal(),
+                center[1] + sigma * rng.normal(),
+            ]
+        })
+        .collect();
+}
+
+/**
+Generates `n` points on the circle with the given `center` and `radius` at
+random angles. Every point is part of the convex hull (up to floating-point
+rounding for very dense circles).
+ */
+pub fn on_circle(n: usize, center: [f64; 2], radius: f64, seed: u64) -> Vec<[f64; 2]> {
+    let mut rng = Rng::new(seed);
+    return (0..n)
+        .map(|_| {
+            let angle = TAU * rng.next_f64();
+            [
+                center[0] + radius * angle.cos(),
+                center[1] + radius * angle.sin(),
+            ]
+        })
+        .collect();
+}
+
+/**
+Generates `n` points on the line segment from `start` to `end` in random order.
+
+The points are located at `start + t * (end - start)`, where `t` is a random
+multiple of 2^-16 in `[0, 1)`. If all coordinates of `start` and `end` are
+integers with a magnitude below 2^32, every point is computed without rounding,
+so the points are exactly collinear and their convex hull degenerates to two
+points. Otherwise, they are only collinear up to floating-point rounding.
+ */
+pub fn collinear(n: usize, start: [f64; 2], end: [f64; 2], seed: u64) -> Vec<[f64; 2]> {
+    let mut rng = Rng::new(seed);
+    return (0..n)
+        .map(|_| {
+            let t = (rng.next_u64() >> 48) as f64 / 65536.0;
+            [
+                start[0] + t * (end[0] - start[0]),
+                start[1] + t * (end[1] - start[1]),
+            ]
+        })
+        .collect();
+}
+
+/**
+Generates a regular grid with `columns` x `rows` points, starting at `origin`
+with the given `spacing` between neighboring points. The points are ordered
+row by row. Grids contain many collinear points on their hull edges.
+ */
+pub fn grid(columns: usize, rows: usize, origin: [f64; 2], spacing: f64) -> Vec<[f64; 2]> {
+    return (0..rows)
+        .flat_map(|row| {
+            (0..columns).map(move |column| {
+                [
+                    origin[0] + column as f64 * spacing,
+                    origin[1] + row as f64 * spacing,
+                ]
+            })
+        })
+        .collect();
+}
+
+/**
+Generates `n` points which only take `distinct` different values. The distinct
+values are uniformly distributed inside the rectangle spanned by `min` and
+`max`, and each point is a copy of a randomly chosen distinct value.
+ */
+pub fn with_duplicates(
+    n: usize,
+    distinct: usize,
+    min: [f64; 2],
+    max: [f64; 2],
+    seed: u64,
+) -> Vec<[f64; 2]> {
+    let mut rng = Rng::new(seed);
+    let values = uniform_in_rectangle(distinct.max(1), min, max, rng.next_u64());
+    return (0..n).map(|_| values[rng.index(values.len())]).collect();
+}
@@ -0,0 +1,32 @@
+//! Small floating-point helpers on plain coordinates which are shared by
+//! several modules.
+
+/// Euclidean distance between `a` and `b`.
+pub(crate) fn distance(a: [f64; 2], b: [f64; 2]) -> f64 {
+    return (b[0] - a[0]).hypot(b[1] - a[1]);
+}
+
+/// Difference `a - b` of two points.
+pub(crate) fn difference(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    return [a[0] - b[0], a[1] - b[1]];
+}
+
+/// Cross product (z-component) of the vectors `u` and `v`.
+pub(crate) fn cross(u: [f64; 2], v: [f64; 2]) -> f64 {
+    return u[0] * v[1] - u[1] * v[0];
+}
+
+/// Signed area of a polygon with the shoelace formula, taking the corners
+/// relative to the first corner to reduce the cancellation for polygons far
+/// away from the origin. Polygons with fewer than three corners have zero area.
+pub(crate) fn signed_area(polygon: &[[f64; 2]]) -> f64 {
+    let Some(&origin) = polygon.first() else {
+        return 0.0;
+    };
+    let twice_area: f64 = polygon
+        .windows(2)
+        .skip(1)
+        .map(|edge| cross(difference(edge[0], origin), difference(edge[1], origin)))
+        .sum();
+    return 0.5 * twice_area;
+}
@@ -0,0 +1,563 @@
+//! This module contains the [`GpuHull`], which discards most points that
+//! cannot be part of the convex hull on the GPU before the hull of the
+//! remaining points is calculated on the CPU. It is only available with the
+//! `gpu` feature flag enabled and uses [wgpu](https://docs.rs/wgpu), so it runs
+//! on Vulkan, Metal, DirectX 12 and OpenGL devices.
+//!
+//! The filter follows Akl and Toussaint \[1\]: A first compute pass finds the
+//! extreme points of the input in 16 evenly spaced directions, a second one
+//! discards all points located strictly inside the polygon formed by these
+//! extremes. Since the extremes are input points, the polygon is located
+//! inside the hull and none of the discarded points can be a hull corner. The
+//! GPU works with `f32` coordinates relative to the first point; the inside
+//! test keeps a safety margin which covers their rounding errors, so the
+//! filter is conservative and the result is exact.
+//!
+//! # Literature
+//!
+//! 1. Akl, S. G., Toussaint, G. T.: A fast convex hull algorithm. Information
+//!    Processing Letters 7, 219–222 (1978).
+//!    <https://doi.org/10.1016/0020-0190(78)90003-0>
+
+use std::f64::consts::TAU;
+use std::sync::mpsc;
+
+use wgpu::util::DeviceExt;
+
+use crate::monotone_chain::monotone_chain;
+use crate::{ConvexHull, ConvexHullIter};
+
+/// Number of directions in which the extreme points are searched, which is
+/// the maximum number of corners of the filter polygon.
+const DIRECTIONS: usize = 16;
+
+/// Number of invocations per workgroup of both shaders.
+const WORKGROUP_SIZE: usize = 256;
+
+/// Number of points handled by a workgroup of the extremes shader.
+const POINTS_PER_EXTREMES_GROUP: usize = WORKGROUP_SIZE * 16;
+
+/// Number of points handled by a workgroup of the filter shader (one bit per
+/// point in a 32 bit word per invocation).
+const POINTS_PER_FILTER_GROUP: usize = WORKGROUP_SIZE * 32;
+
+/// Upper limit for the number of points uploaded to the GPU at once (128 MiB
+/// of `f32` coordinates), which bounds the memory on both sides.
+const MAX_CHUNK_LEN: usize = 1 << 24;
+
+/**
+Finds the extreme points of each workgroup in all directions. Every invocation
+scans 16 points, then the workgroup reduces the results in shared memory and
+writes the index of the extreme point per direction.
+ */
+const EXTREMES_SHADER: &str = r"
+const WORKGROUP_SIZE: u32 = 256u;
+const POINTS_PER_INVOCATION: u32 = 16u;
+const DIRECTIONS: u32 = 16u;
+const TAU: f32 = 6.283185307179586;
+
+@group(0) @binding(0) var<storage, read> points: array<vec2<f32>>;
+@group(0) @binding(1) var<storage, read_write> extremes: array<u32>;
+@group(0) @binding(2) var<uniform> params: vec4<u32>;
+
+var<workgroup> values: array<f32, WORKGROUP_SIZE>;
+var<workgroup> indices: array<u32, WORKGROUP_SIZE>;
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn main(
+    @builtin(local_invocation_index) local: u32,
+    @builtin(workgroup_id) group: vec3<u32>,
+) {
+    let len = params.x;
+    let first = group.x * WORKGROUP_SIZE * POINTS_PER_INVOCATION;
+
+    var directions: array<vec2<f32>, DIRECTIONS>;
+    var best_values: array<f32, DIRECTIONS>;
+    var best_indices: array<u32, DIRECTIONS>;
+    for (var k = 0u; k < DIRECTIONS; k++) {
+        let angle = f32(k) * (TAU / f32(DIRECTIONS));
+        directions[k] = vec2<f32>(cos(angle), sin(angle));
+        best_values[k] = -3.4e38;
+        best_indices[k] = min(first, len - 1u);
+    }
+
+    for (var i = 0u; i < POINTS_PER_INVOCATION; i++) {
+        let index = first + i * WORKGROUP_SIZE + local;
+        if index < len {
+            let point = points[index];
+            for (var k = 0u; k < DIRECTIONS; k++) {
+                let value = dot(point, directions[k]);
+                if value > best_values[k] {
+                    best_values[k] = value;
+                    best_indices[k] = index;
+                }
+            }
+        }
+    }
+
+    for (var k = 0u; k < DIRECTIONS; k++) {
+        values[local] = best_values[k];
+        indices[local] = best_indices[k];
+        workgroupBarrier();
+        for (var stride = WORKGROUP_SIZE / 2u; stride > 0u; stride = stride / 2u) {
+            if local < stride && values[local + stride] > values[local] {
+                values[local] = values[local + stride];
+                indices[local] = indices[local + stride];
+            }
+            workgroupBarrier();
+        }
+        if local == 0u {
+            extremes[group.x * DIRECTIONS + k] = indices[0];
+        }
+        workgroupBarrier();
+    }
+}
+";
+
+/**
+Marks the points located strictly inside the filter polygon: Every invocation
+tests 32 consecutive points and writes one bit per point. A point is inside if
+it is located left of all edges (counter-clockwise) by more than the margin.
+ */
+const FILTER_SHADER: &str = r"
+const DIRECTIONS: u32 = 16u;
+
+struct Params {
+    len: u32,
+    edges: u32,
+    margin: f32,
+    padding: u32,
+}
+
+@group(0) @binding(0) var<storage, read> points: array<vec2<f32>>;
+@group(0) @binding(1) var<storage, read_write> inside: array<u32>;
+@group(0) @binding(2) var<uniform> params: Params;
+@group(0) @binding(3) var<uniform> polygon: array<vec4<f32>, DIRECTIONS>;
+
+@compute @workgroup_size(256)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let first = id.x * 32u;
+    if first >= params.len {
+        return;
+    }
+    var bits = 0u;
+    for (var bit = 0u; bit < 32u; bit++) {
+        let index = first + bit;
+        if index >= params.len {
+            break;
+        }
+        let point = points[index];
+        var strictly_inside = true;
+        for (var edge = 0u; edge < params.edges; edge++) {
+            let a = polygon[edge].xy;
+            let b = polygon[edge].zw;
+            let cross = (b.x - a.x) * (point.y - a.y) - (b.y - a.y) * (point.x - a.x);
+            if !(cross > params.margin) {
+                strictly_inside = false;
+                break;
+            }
+        }
+        if strictly_inside {
+            bits = bits | (1u << bit);
+        }
+    }
+    inside[id.x] = bits;
+}
+";
+
+/// Errors which can occur while running the GPU filter.
+#[derive(Debug)]
+pub enum GpuError {
+    /// No suitable GPU adapter was found.
+    Adapter(wgpu::RequestAdapterError),
+    /// The device could not be created.
+    Device(wgpu::RequestDeviceError),
+    /// Waiting for the device failed, e.g. because it was lost.
+    Poll(wgpu::PollError),
+    /// A buffer could not be mapped for reading back the results.
+    Map(wgpu::BufferAsyncError),
+    /// The mapped range of a buffer could not be accessed.
+    MapRange(wgpu::MapRangeError),
+}
+
+impl std::fmt::Display for GpuError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GpuError::Adapter(error) => write!(f, "no suitable GPU adapter found: {error}"),
+            GpuError::Device(error) => write!(f, "the GPU device could not be created: {error}"),
+            GpuError::Poll(error) => write!(f, "waiting for the GPU failed: {error}"),
+            GpuError::Map(error) => write!(f, "a GPU buffer could not be mapped: {error}"),
+            GpuError::MapRange(error) => {
+                write!(f, "a mapped GPU buffer could not be accessed: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GpuError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        return match self {
+            GpuError::Adapter(error) => Some(error),
+            GpuError::Device(error) => Some(error),
+            GpuError::Poll(error) => Some(error),
+            GpuError::Map(error) => Some(error),
+            GpuError::MapRange(error) => Some(error),
+        };
+    }
+}
+
+/// Real point of the input together with its position among the real points.
+type Entry = (usize, (usize, [f64; 2]));
+
+/// Returns the unit vector of the `k`-th direction of the extremes search.
+fn direction(k: usize) -> [f64; 2] {
+    let angle = k as f64 * (TAU / DIRECTIONS as f64);
+    return [angle.cos(), angle.sin()];
+}
+
+/**
+Calculates convex hulls of large point sets with a GPU prefilter, see the
+[module documentation](crate::gpu).
+
+The points are uploaded in chunks of up to 2^24 points. Each chunk is
+filtered with the polygon of the extremes of all chunks seen so far, the
+remaining points are collected and their hull is calculated with Andrew's
+monotone chain algorithm. Since the uploads and the readbacks of the results
+take time as well, the filter pays off for large point sets whose hull has
+few corners, e.g. the point clouds of lidar scans.
+
+# Examples
+
+```
+use planar_convex_hull::ConvexHull;
+use planar_convex_hull::generators::uniform_in_disk;
+use planar_convex_hull::gpu::GpuHull;
+
+let points = uniform_in_disk(100_000, [0.0, 0.0], 1.0, 1);
+// Not every machine has a GPU
+if let Ok(gpu) = GpuHull::new() {
+    let hull: Vec<_> = gpu.convex_hull(&points).unwrap().collect();
+    assert_eq!(hull, points.convex_hull_monotone_chain().collect::<Vec<_>>());
+}
+```
+ */
+#[derive(Debug)]
+pub struct GpuHull {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    extremes: wgpu::ComputePipeline,
+    filter: wgpu::ComputePipeline,
+}
+
+impl GpuHull {
+    /**
+    Creates a [`GpuHull`] on the default high-performance adapter, with the
+    limits of the adapter. The backends can be selected with the `WGPU_BACKEND`
+    environment variable.
+     */
+    pub fn new() -> Result<Self, GpuError> {
+        let instance =
+            wgpu::Instance::new(wgpu::InstanceDescriptor::new_without_display_handle_from_env());
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            ..Default::default()
+        }))
+        .map_err(GpuError::Adapter)?;
+        let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
+            label: Some("planar_convex_hull"),
+            required_limits: adapter.limits(),
+            ..Default::default()
+        }))
+        .map_err(GpuError::Device)?;
+        return Ok(Self::from_device(device, queue));
+    }
+
+    /**
+    Creates a [`GpuHull`] on an existing `device`, e.g. the one of a renderer.
+    The chunk size adapts to the limits of the device.
+     */
+    pub fn from_device(device: wgpu::Device, queue: wgpu::Queue) -> Self {
+        let pipeline = |label: &str, source: &str| {
+            let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some(label),
+                source: wgpu::ShaderSource::Wgsl(source.into()),
+            });
+            return device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some(label),
+                layout: None,
+                module: &module,
+                entry_point: Some("main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+        };
+        let extremes = pipeline("planar_convex_hull extremes", EXTREMES_SHADER);
+        let filter = pipeline("planar_convex_hull filter", FILTER_SHADER);
+        return Self {
+            device,
+            queue,
+            extremes,
+            filter,
+        };
+    }
+
+    /**
+    Calculates the convex hull of `collection` and returns its corners in the
+    same order as [`ConvexHull::convex_hull`]. Collinear points on the hull
+    edges and nonreal points are discarded, and of multiple identical points
+    the first one is kept (as for [`ConvexHull::convex_hull_monotone_chain`]).
+     */
+    pub fn convex_hull<T: ConvexHull + ?Sized>(
+        &self,
+        collection: &T,
+    ) -> Result<ConvexHullIter, GpuError> {
+        let limits = self.device.limits();
+        let binding_len = limits
+            .max_storage_buffer_binding_size
+            .min(limits.max_buffer_size)
+            / 8;
+        let dispatch_len =
+            limits.max_compute_workgroups_per_dimension as u64 * POINTS_PER_EXTREMES_GROUP as u64;
+        let chunk_len = (binding_len.min(dispatch_len) as usize).min(MAX_CHUNK_LEN)
+            / POINTS_PER_FILTER_GROUP
+            * POINTS_PER_FILTER_GROUP;
+        let chunk_len = chunk_len.max(POINTS_PER_FILTER_GROUP);
+
+        let mut origin: Option<[f64; 2]> = None;
+        let mut extremes: [Option<Entry>; DIRECTIONS] = [None; DIRECTIONS];
+        let mut candidates: Vec<Entry> = Vec::new();
+        let mut chunk: Vec<Entry> = Vec::new();
+        let real = collection
+            .convex_hull_iter()
+            .filter(|(_, point)| point[0].is_finite() && point[1].is_finite());
+        for (position, (key, point)) in real.enumerate() {
+            let origin = *origin.get_or_insert(point);
+            chunk.push((position, (key, point)));
+            if chunk.len() == chunk_len {
+                self.filter_chunk(&chunk, origin, &mut extremes, &mut candidates)?;
+                chunk.clear();
+            }
+        }
+        if let (Some(origin), false) = (origin, chunk.is_empty()) {
+            self.filter_chunk(&chunk, origin, &mut extremes, &mut candidates)?;
+        }
+
+        // The extremes are never discarded, but may have been found in a
+        // later chunk than the one they belong to.
+        candidates.extend(extremes.into_iter().flatten());
+        candidates.sort_unstable_by_key(|(position, _)| *position);
+        candidates.dedup_by_key(|(position, _)| *position);
+        let hull = monotone_chain(candidates.into_iter().map(|(_, point)| point).collect());
+        return Ok(ConvexHullIter::from_vec(hull));
+    }
+
+    /**
+    Updates the `extremes` with the ones of `chunk`, filters the chunk with the
+    polygon of the extremes and appends the remaining points to `candidates`.
+     */
+    fn filter_chunk(
+        &self,
+        chunk: &[Entry],
+        origin: [f64; 2],
+        extremes: &mut [Option<Entry>; DIRECTIONS],
+        candidates: &mut Vec<Entry>,
+    ) -> Result<(), GpuError> {
+        let relative = |point: [f64; 2]| [point[0] - origin[0], point[1] - origin[1]];
+
+        // Upload the coordinates relative to the origin. The largest absolute
+        // coordinate bounds the rounding errors of the filter.
+        let mut bytes: Vec<u8> = Vec::with_capacity(chunk.len() * 8);
+        let mut radius: f64 = 0.0;
+        for (_, (_, point)) in chunk.iter() {
+            let [x, y] = relative(*point);
+            radius = radius.max(x.abs()).max(y.abs());
+            bytes.extend_from_slice(&(x as f32).to_ne_bytes());
+            bytes.extend_from_slice(&(y as f32).to_ne_bytes());
+        }
+        let points = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("planar_convex_hull points"),
+                contents: &bytes,
+                usage: wgpu::BufferUsages::STORAGE,
+            });
+        let len = chunk.len() as u32;
+
+        // First pass: The extreme points per workgroup, of which the most
+        // extreme ones (evaluated exactly) are kept.
+        let groups = chunk.len().div_ceil(POINTS_PER_EXTREMES_GROUP);
+        let indices = self.run(
+            &self.extremes,
+            &points,
+            (groups * DIRECTIONS) as u64 * 4,
+            &[len, 0, 0, 0],
+            None,
+            groups as u32,
+        )?;
+        for (slot, index) in indices.into_iter().enumerate() {
+            let Some(entry) = chunk.get(index as usize) else {
+                continue;
+            };
+            let k = slot % DIRECTIONS;
+            let [dx, dy] = direction(k);
+            let value = |entry: &Entry| {
+                let [x, y] = relative(entry.1.1);
+                return x * dx + y * dy;
+            };
+            if extremes[k].is_none_or(|best| value(entry) > value(&best)) {
+                extremes[k] = Some(*entry);
+            }
+        }
+
+        let polygon = monotone_chain(
+            extremes
+                .iter()
+                .flatten()
+                .map(|(position, (_, point))| (*position, *point))
+                .collect(),
+        );
+        if polygon.len() < 3 {
+            candidates.extend_from_slice(chunk);
+            return Ok(());
+        }
+
+        // Second pass: Discard the points strictly inside the polygon. The
+        // margin exceeds the rounding errors of the cross products in f32.
+        let mut edges: Vec<u8> = Vec::with_capacity(DIRECTIONS * 16);
+        for (index, (_, a)) in polygon.iter().enumerate() {
+            let b = polygon[(index + 1) % polygon.len()].1;
+            for coordinate in relative(*a).into_iter().chain(relative(b)) {
+                radius = radius.max(coordinate.abs());
+                edges.extend_from_slice(&(coordinate as f32).to_ne_bytes());
+            }
+        }
+        edges.resize(DIRECTIONS * 16, 0);
+        let margin = (64.0 * radius * radius * f32::EPSILON as f64) as f32;
+        let words = self.run(
+            &self.filter,
+            &points,
+            chunk.len().div_ceil(32) as u64 * 4,
+            &[len, polygon.len() as u32, margin.to_bits(), 0],
+            Some(&edges),
+            chunk.len().div_ceil(POINTS_PER_FILTER_GROUP) as u32,
+        )?;
+        for (index, entry) in chunk.iter().enumerate() {
+            let inside = words
+                .get(index / 32)
+                .is_some_and(|word| word & (1 << (index % 32)) != 0);
+            if !inside {
+                candidates.push(*entry);
+            }
+        }
+        return Ok(());
+    }
+
+    /**
+    Runs `pipeline` on the `points` with the given uniform `params` (and the
+    optional second uniform buffer `polygon`) and returns the contents of its
+    output buffer of `size` bytes.
+     */
+    fn run(
+        &self,
+        pipeline: &wgpu::ComputePipeline,
+        points: &wgpu::Buffer,
+        size: u64,
+        params: &[u32; 4],
+        polygon: Option<&[u8]>,
+        groups: u32,
+    ) -> Result<Vec<u32>, GpuError> {
+        let output = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("planar_convex_hull output"),
+            size,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let staging = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("planar_convex_hull staging"),
+            size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let params: Vec<u8> = params
+            .iter()
+            .flat_map(|value| value.to_ne_bytes())
+            .collect();
+        let params = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("planar_convex_hull params"),
+                contents: &params,
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+        let polygon = polygon.map(|polygon| {
+            return self
+                .device
+                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("planar_convex_hull polygon"),
+                    contents: polygon,
+                    usage: wgpu::BufferUsages::UNIFORM,
+                });
+        });
+
+        let mut entries = vec![
+            wgpu::BindGroupEntry {
+                binding: 0,
+                resource: points.as_entire_binding(),
+            },
+            wgpu::BindGroupEntry {
+                binding: 1,
+                resource: output.as_entire_binding(),
+            },
+            wgpu::BindGroupEntry {
+                binding: 2,
+                resource: params.as_entire_binding(),
+            },
+        ];
+        if let Some(polygon) = polygon.as_ref() {
+            entries.push(wgpu::BindGroupEntry {
+                binding: 3,
+                resource: polygon.as_entire_binding(),
+            });
+        }
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &pipeline.get_bind_group_layout(0),
+            entries: &entries,
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(groups, 1, 1);
+        }
+        encoder.copy_buffer_to_buffer(&output, 0, &staging, 0, size);
+        self.queue.submit([encoder.finish()]);
+
+        let (sender, receiver) = mpsc::channel();
+        staging
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, move |result| {
+                let _ = sender.send(result);
+            });
+        self.device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .map_err(GpuError::Poll)?;
+        receiver
+            .recv()
+            .unwrap_or(Err(wgpu::BufferAsyncError))
+            .map_err(GpuError::Map)?;
+
+        let view = staging.get_mapped_range(..).map_err(GpuError::MapRange)?;
+        let words = view
+            .chunks_exact(4)
+            .map(|bytes| u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .collect();
+        drop(view);
+        staging.unmap();
+        return Ok(words);
+    }
+}
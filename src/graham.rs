@@ -0,0 +1,65 @@
+//! Graham's scan \[1\] with exact orientation predicates, see
+//! [`ConvexHull::convex_hull_graham`](crate::ConvexHull::convex_hull_graham).
+//!
+//! # Literature
+//!
+//! 1. Graham, R. L.: An efficient algorithm for determining the convex hull of
+//!    a finite planar set. Information Processing Letters 1, 132–133 (1972).
+//!    <https://doi.org/10.1016/0020-0190(72)90045-2>
+
+use std::cmp::Ordering;
+
+use crate::predicates::{compare_along_ray, orientation};
+
+/**
+Calculates the convex hull of `points` with Graham's scan and returns its
+corners in the same order as
+[`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull). Collinear points
+on the hull edges and nonreal points are discarded, and of multiple identical
+points the first one is kept.
+ */
+pub(crate) fn graham_scan(mut points: Vec<(usize, [f64; 2])>) -> Vec<(usize, [f64; 2])> {
+    points.retain(|(_, p)| p[0].is_finite() && p[1].is_finite());
+
+    // The pivot is the lowest point (and the leftmost of those), hence all
+    // other points are located in the half-plane above or right of it.
+    let Some(pivot) = points
+        .iter()
+        .map(|(_, p)| *p)
+        .min_by(|a, b| a[1].total_cmp(&b[1]).then(a[0].total_cmp(&b[0])))
+    else {
+        return points;
+    };
+
+    // Sort the points counter-clockwise around the pivot, where points in the
+    // same direction are sorted by their distance. Duplicates of the pivot
+    // come first. The sort is stable, hence identical points keep their order.
+    points.sort_by(|(_, a), (_, b)| match orientation(pivot, *a, *b) {
+        Ordering::Greater => Ordering::Less,
+        Ordering::Less => Ordering::Greater,
+        Ordering::Equal => compare_along_ray(pivot, *a, *b),
+    });
+    points.dedup_by(|(_, a), (_, b)| a == b);
+
+    let mut hull: Vec<(usize, [f64; 2])> = Vec::with_capacity(points.len());
+    for point in points {
+        while hull.len() >= 2
+            && orientation(hull[hull.len() - 2].1, hull[hull.len() - 1].1, point.1)
+                != Ordering::Greater
+        {
+            hull.pop();
+        }
+        hull.push(point);
+    }
+
+    // Start with the point with the largest x-value (and the largest y-value
+    // if there are multiple such points).
+    let first = hull
+        .iter()
+        .enumerate()
+        .max_by(|(_, (_, a)), (_, (_, b))| a[0].total_cmp(&b[0]).then(a[1].total_cmp(&b[1])))
+        .map(|(index, _)| index)
+        .unwrap_or(0);
+    hull.rotate_left(first);
+    return hull;
+}
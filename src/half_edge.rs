@@ -0,0 +1,135 @@
+//! This module contains the [`HalfEdges`] representation of a hull, which is
+//! returned by [`Hull::half_edges`].
+
+use crate::Hull;
+
+/// Corner of a hull in its [`HalfEdges`] representation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct HalfEdgeVertex {
+    /// Key of the corner.
+    pub key: usize,
+    /// Position of the corner.
+    pub point: [f64; 2],
+    /// Index of the previous corner in counter-clockwise order.
+    pub previous: usize,
+    /// Index of the next corner in counter-clockwise order.
+    pub next: usize,
+    /// Index of the edge ending at the corner, if any.
+    pub incoming: Option<usize>,
+    /// Index of the edge starting at the corner, if any.
+    pub outgoing: Option<usize>,
+}
+
+/// Edge of a hull in its [`HalfEdges`] representation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct HalfEdge {
+    /// Index of the corner at which the edge starts.
+    pub start: usize,
+    /// Index of the corner at which the edge ends.
+    pub end: usize,
+    /// Unit vector from the start to the end of the edge.
+    pub direction: [f64; 2],
+    /// Outward unit normal of the edge, i.e. the direction rotated clockwise
+    /// by a right angle.
+    pub normal: [f64; 2],
+    /// Length of the edge.
+    pub length: f64,
+}
+
+/**
+A hull in a half-edge representation, see [`Hull::half_edges`]: Each corner
+knows its neighbors and adjacent edges, and each edge knows its end corners,
+direction and outward normal. This allows algorithms such as rotating calipers
+or clipping to walk the hull without recomputing these relations.
+
+Corner `i` corresponds to the `i`-th point of [`Hull::points`] and edge `i`
+runs from corner `i` to its next corner, as in [`Hull::edges`]. Since the hull
+has a single face, there are no twin edges.
+ */
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct HalfEdges {
+    /// The corners in counter-clockwise order.
+    pub vertices: Vec<HalfEdgeVertex>,
+    /// The edges in counter-clockwise order.
+    pub edges: Vec<HalfEdge>,
+}
+
+impl HalfEdges {
+    /// Returns the edge following edge `index` in counter-clockwise order.
+    /// Panics if there are no edges.
+    pub fn next_edge(&self, index: usize) -> usize {
+        return (index + 1) % self.edges.len();
+    }
+
+    /// Returns the edge preceding edge `index` in counter-clockwise order.
+    /// Panics if there are no edges.
+    pub fn previous_edge(&self, index: usize) -> usize {
+        return (index + self.edges.len() - 1) % self.edges.len();
+    }
+}
+
+impl Hull {
+    /**
+    Returns the hull in a [`HalfEdges`] representation with neighbor links
+    for each corner and the direction and outward normal of each edge. A hull
+    with two corners has two edges (forth and back) and a hull with fewer
+    corners has none (see [`Hull::edges`]). Takes O(h) time.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 1.0], [0.0, 1.0]]);
+    let half_edges = hull.half_edges();
+
+    // Walk from the first corner along its outgoing edge
+    let corner = half_edges.vertices[0];
+    assert_eq!(corner.point, [2.0, 1.0]);
+    let edge = half_edges.edges[corner.outgoing.unwrap()];
+    assert_eq!(half_edges.vertices[edge.end].point, [0.0, 1.0]);
+    assert_eq!(edge.direction, [-1.0, 0.0]);
+    assert_eq!(edge.normal, [0.0, 1.0]);
+    assert_eq!(edge.length, 2.0);
+    assert_eq!(half_edges.vertices[corner.previous].point, [2.0, 0.0]);
+    ```
+     */
+    pub fn half_edges(&self) -> HalfEdges {
+        let n = self.len();
+        let has_edges = n >= 2;
+        let vertices = self
+            .iter()
+            .enumerate()
+            .map(|(i, (key, point))| HalfEdgeVertex {
+                key,
+                point,
+                previous: (i + n - 1) % n,
+                next: (i + 1) % n,
+                incoming: has_edges.then_some((i + n - 1) % n),
+                outgoing: has_edges.then_some(i),
+            })
+            .collect();
+        let edges = self
+            .edges()
+            .enumerate()
+            .map(|(i, (a, b))| {
+                let length = (b[0] - a[0]).hypot(b[1] - a[1]);
+                let direction = [(b[0] - a[0]) / length, (b[1] - a[1]) / length];
+                return HalfEdge {
+                    start: i,
+                    end: (i + 1) % n,
+                    direction,
+                    normal: [direction[1], -direction[0]],
+                    length,
+                };
+            })
+            .collect();
+        return HalfEdges { vertices, edges };
+    }
+}
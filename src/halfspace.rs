@@ -0,0 +1,214 @@
+//! This module contains the [`Halfspace`] type, which describes a hull by
+//! linear inequalities (its H-representation), see [`Hull::to_halfspaces`],
+//! and the polar dual of a hull, see [`Hull::polar_dual`].
+
+use std::cmp::Ordering;
+
+use crate::Hull;
+use crate::predicates::orientation;
+
+/// Reasons why the polar dual of a hull does not exist, see [`Hull::polar_dual`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolarDualError {
+    /// The hull has fewer than three corners, so it has no interior.
+    Degenerate,
+    /// The center is not located strictly inside the hull: It is on the line
+    /// through the given edge or beyond it. The dual of an edge through the
+    /// center is a point at infinity.
+    CenterNotInterior {
+        /// Index of the edge from corner `edge` to corner `edge + 1`.
+        edge: usize,
+    },
+}
+
+impl std::fmt::Display for PolarDualError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PolarDualError::Degenerate => write!(f, "the hull has fewer than three corners"),
+            PolarDualError::CenterNotInterior { edge } => write!(
+                f,
+                "the center is not located strictly inside the hull (edge {edge})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PolarDualError {}
+
+/**
+The closed halfspace `normal · x <= offset`.
+
+The normals returned by [`Hull::to_halfspaces`] are unit vectors pointing out
+of the hull, so `normal · x - offset` is the signed distance of `x` to the
+boundary line (negative inside).
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct Halfspace {
+    /// Outward normal vector `a` of the inequality `a · x <= b`.
+    pub normal: [f64; 2],
+    /// Right-hand side `b` of the inequality `a · x <= b`.
+    pub offset: f64,
+}
+
+impl Halfspace {
+    /// Returns `true` if `point` fulfills the inequality.
+    pub fn contains(&self, point: [f64; 2]) -> bool {
+        return self.normal[0] * point[0] + self.normal[1] * point[1] <= self.offset;
+    }
+}
+
+impl Hull {
+    /**
+    Returns the inequalities `a · x <= b` whose intersection is the hull (its
+    H-representation), e.g. as constraints of a linear program.
+
+    For a polygon, there is one inequality per edge in counter-clockwise order,
+    starting with the edge from the first to the second corner. The normals
+    have unit length and point outwards. Since normalizing a normal introduces
+    rounding errors, each offset is chosen as the maximum over both corners of
+    the edge, so that all corners fulfill all inequalities.
+
+    Degenerate hulls are described consistently as well: A single point
+    yields four axis-aligned inequalities, a segment yields two inequalities
+    for its supporting line and two for its end caps, and an empty hull yields
+    the infeasible inequality `0 · x <= -1`.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0], [1.0, 1.0]]);
+    let halfspaces = hull.to_halfspaces();
+    assert_eq!(halfspaces.len(), 4);
+
+    // The first corner is [2.0, 2.0], so the first edge is the top edge
+    assert_eq!(halfspaces[0].normal, [0.0, 1.0]);
+    assert_eq!(halfspaces[0].offset, 2.0);
+
+    assert!(halfspaces.iter().all(|halfspace| halfspace.contains([1.0, 1.5])));
+    assert!(!halfspaces.iter().all(|halfspace| halfspace.contains([1.0, 2.5])));
+    ```
+     */
+    pub fn to_halfspaces(&self) -> Vec<Halfspace> {
+        let points = self.points();
+        let dot = |a: [f64; 2], b: [f64; 2]| a[0] * b[0] + a[1] * b[1];
+        let unit = |v: [f64; 2]| {
+            let length = v[0].hypot(v[1]);
+            return [v[0] / length, v[1] / length];
+        };
+
+        match points {
+            [] => {
+                return vec![Halfspace {
+                    normal: [0.0, 0.0],
+                    offset: -1.0,
+                }];
+            }
+            [point] => {
+                return [[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0], [0.0, -1.0]]
+                    .into_iter()
+                    .map(|normal| Halfspace {
+                        normal,
+                        offset: dot(normal, *point),
+                    })
+                    .collect();
+            }
+            [a, b] => {
+                let direction = unit([b[0] - a[0], b[1] - a[1]]);
+                let side = [direction[1], -direction[0]];
+                return [
+                    (side, *a, *b),
+                    (direction, *b, *b),
+                    ([-side[0], -side[1]], *a, *b),
+                    ([-direction[0], -direction[1]], *a, *a),
+                ]
+                .into_iter()
+                .map(|(normal, p, q)| Halfspace {
+                    normal,
+                    offset: dot(normal, p).max(dot(normal, q)),
+                })
+                .collect();
+            }
+            _ => {
+                let n = points.len();
+                return (0..n)
+                    .map(|i| {
+                        let a = points[i];
+                        let b = points[(i + 1) % n];
+                        // Outward normal of a counter-clockwise edge
+                        let normal = unit([b[1] - a[1], a[0] - b[0]]);
+                        return Halfspace {
+                            normal,
+                            offset: dot(normal, a).max(dot(normal, b)),
+                        };
+                    })
+                    .collect();
+            }
+        }
+    }
+
+    /**
+    Calculates the polar dual of the hull about `center`, which must be located
+    strictly inside the hull.
+
+    Relative to `center`, the polar dual is the convex polygon
+    `{y : y · (x - center) <= 1 for all x in the hull}`. Each edge of the hull
+    with the inequality `a · (x - center) <= b` corresponds to the corner
+    `center + a / b` of the dual, and each corner of the hull to an edge of the
+    dual. The dual is returned translated by `center`, so that the dual of the
+    dual about the same center is the original hull (up to rounding). The key
+    of a dual corner is the index of its edge, i.e. the edge from
+    `self.points()[key]` to the next corner.
+
+    Whether the center is located strictly inside is decided with exact
+    predicates. If it lies on an edge (or on the line through it), the dual is
+    unbounded and an error is returned.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+    use planar_convex_hull::halfspace::PolarDualError;
+
+    let square = Hull::new(&[[-1.0, -1.0], [1.0, -1.0], [1.0, 1.0], [-1.0, 1.0]]);
+    let dual = square.polar_dual([0.0, 0.0]).unwrap();
+    assert_eq!(dual.points(), &[[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0], [0.0, -1.0]]);
+
+    let dual_of_dual = dual.polar_dual([0.0, 0.0]).unwrap();
+    assert_eq!(dual_of_dual.points(), square.points());
+
+    assert_eq!(
+        square.polar_dual([1.0, 0.0]),
+        Err(PolarDualError::CenterNotInterior { edge: 3 })
+    );
+    ```
+     */
+    pub fn polar_dual(&self, center: [f64; 2]) -> Result<Hull, PolarDualError> {
+        let points = self.points();
+        let n = points.len();
+        if n < 3 {
+            return Err(PolarDualError::Degenerate);
+        }
+
+        let mut dual = Vec::with_capacity(n);
+        for edge in 0..n {
+            let a = points[edge];
+            let b = points[(edge + 1) % n];
+            if orientation(a, b, center) != Ordering::Greater {
+                return Err(PolarDualError::CenterNotInterior { edge });
+            }
+            // Outward normal of a counter-clockwise edge and its distance
+            // from the center (scaled by the length of the normal)
+            let normal = [b[1] - a[1], a[0] - b[0]];
+            let offset = normal[0] * (a[0] - center[0]) + normal[1] * (a[1] - center[1]);
+            dual.push([
+                center[0] + normal[0] / offset,
+                center[1] + normal[1] / offset,
+            ]);
+        }
+        return Ok(Hull::new(&dual));
+    }
+}
@@ -0,0 +1,1972 @@
+//! This module contains the owned [`Hull`] type, the [`HullView`] borrowing
+//! the collection it was calculated from, the [`Degeneracy`] metadata
+//! attached to it, the [`PointLocation`] of query points relative to it and
+//! the errors returned when validating ([`HullError`]) or decoding
+//! (`DecodeError`, with the `postcard` or `bincode` feature flag enabled)
+//! hulls.
+//!
+//! With the `postcard` or `bincode` feature flag enabled, a [`Hull`] can be
+//! encoded into bytes, e.g. for caching hulls on disk between runs. The
+//! encoded hull starts with a header consisting of a magic number, a format
+//! version and the encoding, followed by the keys, the corners and the
+//! [`Degeneracy`] of the hull, encoded with [postcard](https://docs.rs/postcard)
+//! or [bincode](https://docs.rs/bincode). Both store the coordinates bit-exact,
+//! so decoding restores the hull without any loss. Besides the header, the
+//! decoded corners are validated like in [`Hull::from_vertices`], so corrupted
+//! data is rejected instead of producing a hull with unspecified behavior.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::f64::consts::TAU;
+
+use super::{ConvexHull, ConvexHullIter, calipers, geometry, monotone_chain, predicates};
+
+/// Reasons why a sequence of vertices is not a valid [`Hull`], see
+/// [`Hull::from_vertices`] and [`validate_hull`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HullError {
+    /// The vertex at the given position contains NaN or infinite values.
+    NonReal {
+        /// Position of the vertex in the sequence.
+        position: usize,
+    },
+    /// The vertex at the given position is identical to its predecessor.
+    Duplicate {
+        /// Position of the vertex in the sequence.
+        position: usize,
+    },
+    /// The boundary does not turn strictly left (counter-clockwise) at the
+    /// vertex at the given position, i.e. the vertex is reflex or collinear
+    /// with its neighbors.
+    NotConvex {
+        /// Position of the vertex in the sequence.
+        position: usize,
+    },
+    /// All turns are strictly left, but the boundary winds around more than
+    /// once (e.g. a pentagram).
+    SelfIntersecting,
+    /// The key at the given position does not exist in the collection (see
+    /// [`validate_hull`]).
+    UnknownKey {
+        /// Position of the key in the sequence.
+        position: usize,
+    },
+    /// The point with the given key is located outside of the hull (see
+    /// [`validate_hull`]).
+    Outside {
+        /// Key of the point.
+        key: usize,
+    },
+    /// The numbers of keys and points of a deserialized hull differ.
+    LengthMismatch {
+        /// Number of keys.
+        keys: usize,
+        /// Number of points.
+        points: usize,
+    },
+}
+
+impl std::fmt::Display for HullError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HullError::NonReal { position } => {
+                write!(f, "vertex {position} contains NaN or infinite values")
+            }
+            HullError::Duplicate { position } => {
+                write!(f, "vertex {position} is identical to its predecessor")
+            }
+            HullError::NotConvex { position } => write!(
+                f,
+                "the boundary does not turn strictly counter-clockwise at vertex {position}"
+            ),
+            HullError::SelfIntersecting => {
+                write!(f, "the boundary winds around more than once")
+            }
+            HullError::UnknownKey { position } => {
+                write!(
+                    f,
+                    "the key at position {position} does not exist in the collection"
+                )
+            }
+            HullError::Outside { key } => {
+                write!(f, "point {key} is located outside of the hull")
+            }
+            HullError::LengthMismatch { keys, points } => {
+                write!(f, "the hull has {keys} keys, but {points} points")
+            }
+        }
+    }
+}
+
+impl std::error::Error for HullError {}
+
+/**
+Checks with exact predicates that `keys` are the corners of the convex hull of
+`collection` in counter-clockwise order, e.g. to assert the correctness of
+results in debug builds:
+
+1. All keys must exist in `collection`.
+2. The corners must form a valid hull as checked by [`Hull::from_vertices`]:
+   They must be real and turn strictly counter-clockwise, winding around
+   exactly once.
+3. All real points of `collection` must be located inside the hull or on its
+   boundary.
+
+The first violation is returned. Nonreal points of `collection` are ignored,
+and the starting corner is not checked. Takes O(n log h) time. Unlike a
+[`Certificate`](crate::Certificate), which proves the hull to a third party
+with the help of additional data, this only needs the collection.
+
+# Examples
+
+```
+use planar_convex_hull::{ConvexHull, HullError, validate_hull};
+
+let slice = &[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0], [1.0, 1.0]];
+let keys: Vec<usize> = slice.convex_hull().map(|(key, _)| key).collect();
+debug_assert!(validate_hull(slice, &keys).is_ok());
+
+// Clockwise order
+assert_eq!(validate_hull(slice, &[0, 3, 2, 1]), Err(HullError::NotConvex { position: 0 }));
+
+// The point [0.0, 2.0] is missing
+assert_eq!(validate_hull(slice, &[2, 0, 1]), Err(HullError::Outside { key: 3 }));
+assert_eq!(validate_hull(slice, &[2, 3, 0, 7]), Err(HullError::UnknownKey { position: 3 }));
+```
+ */
+pub fn validate_hull<T: ConvexHull + ?Sized>(
+    collection: &T,
+    keys: &[usize],
+) -> Result<(), HullError> {
+    let points: HashMap<usize, [f64; 2]> = collection.convex_hull_iter().collect();
+    let mut vertices = Vec::with_capacity(keys.len());
+    for (position, key) in keys.iter().enumerate() {
+        let point = points.get(key).ok_or(HullError::UnknownKey { position })?;
+        vertices.push((*key, *point));
+    }
+    let hull = Hull::from_vertices(vertices)?;
+    for (key, point) in collection.convex_hull_iter() {
+        if point[0].is_finite() && point[1].is_finite() && !hull.contains(point) {
+            return Err(HullError::Outside { key });
+        }
+    }
+    return Ok(());
+}
+
+/**
+Reasons why bytes cannot be decoded into a [`Hull`], see
+[`Hull::from_postcard`] and [`Hull::from_bincode`]. Only available with the
+`postcard` or `bincode` feature flag enabled.
+ */
+#[cfg(any(feature = "postcard", feature = "bincode"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The bytes do not start with the magic number of the format.
+    InvalidMagic,
+    /// The bytes were written by an unknown (newer) version of the format.
+    UnsupportedVersion(u16),
+    /// The bytes were written with a different encoding (e.g. bincode
+    /// instead of postcard).
+    WrongEncoding,
+    /// The payload after the header could not be decoded. Contains the
+    /// message of the underlying encoding.
+    Malformed(String),
+    /// The decoded vertices do not form a valid hull.
+    InvalidHull(HullError),
+}
+
+#[cfg(any(feature = "postcard", feature = "bincode"))]
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecodeError::InvalidMagic => write!(f, "the data is not an encoded hull"),
+            DecodeError::UnsupportedVersion(version) => {
+                write!(f, "unsupported format version {version}")
+            }
+            DecodeError::WrongEncoding => {
+                write!(f, "the hull was encoded with a different encoding")
+            }
+            DecodeError::Malformed(message) => write!(f, "malformed payload: {message}"),
+            DecodeError::InvalidHull(error) => write!(f, "invalid hull: {error}"),
+        }
+    }
+}
+
+#[cfg(any(feature = "postcard", feature = "bincode"))]
+impl std::error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DecodeError::InvalidHull(error) => return Some(error),
+            _ => return None,
+        }
+    }
+}
+
+/// Magic number at the start of an encoded [`Hull`].
+#[cfg(any(feature = "postcard", feature = "bincode"))]
+const MAGIC: [u8; 4] = *b"PCHL";
+
+/// Current version of the binary format of [`Hull`].
+#[cfg(any(feature = "postcard", feature = "bincode"))]
+const FORMAT_VERSION: u16 = 1;
+
+/// Size of the header: Magic number, version and encoding.
+#[cfg(any(feature = "postcard", feature = "bincode"))]
+const HEADER_LEN: usize = 4 + 2 + 1;
+
+/// Encodings of the payload of an encoded [`Hull`], stored in its header.
+#[cfg(any(feature = "postcard", feature = "bincode"))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    #[cfg(feature = "postcard")]
+    Postcard = 0,
+    #[cfg(feature = "bincode")]
+    Bincode = 1,
+}
+
+/// Returns the header of a [`Hull`] whose payload is encoded with `encoding`.
+#[cfg(any(feature = "postcard", feature = "bincode"))]
+fn header(encoding: Encoding) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(HEADER_LEN);
+    bytes.extend_from_slice(&MAGIC);
+    bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+    bytes.push(encoding as u8);
+    return bytes;
+}
+
+/// Checks the header of `bytes` and returns the payload after it.
+#[cfg(any(feature = "postcard", feature = "bincode"))]
+fn payload(bytes: &[u8], encoding: Encoding) -> Result<&[u8], DecodeError> {
+    if bytes.len() < HEADER_LEN || bytes[0..4] != MAGIC {
+        return Err(DecodeError::InvalidMagic);
+    }
+    let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+    if version != FORMAT_VERSION {
+        return Err(DecodeError::UnsupportedVersion(version));
+    }
+    if bytes[6] != encoding as u8 {
+        return Err(DecodeError::WrongEncoding);
+    }
+    return Ok(&bytes[HEADER_LEN..]);
+}
+
+/**
+Degenerate configurations detected while calculating a [`Hull`].
+
+Downstream code usually has to treat these cases separately (e.g. a hull
+without area), so [`Hull::new`] reports them explicitly instead of leaving
+it to the caller to infer them from the number of corners or repeated
+coordinates.
+
+# Examples
+
+```
+use planar_convex_hull::Hull;
+
+let hull = Hull::new(&[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [2.0, 2.0]]);
+let degeneracy = hull.degeneracy();
+assert!(degeneracy.collinear);
+assert!(!degeneracy.coincident);
+assert!(!degeneracy.few_distinct_points);
+assert!(degeneracy.duplicates_on_hull);
+assert!(degeneracy.any());
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct Degeneracy {
+    /// All real points are located on a single line (but do not coincide).
+    /// The hull consists of the two end points of the line segment.
+    pub collinear: bool,
+    /// All real points coincide. The hull consists of a single point.
+    pub coincident: bool,
+    /// The collection has fewer than three distinct real points.
+    pub few_distinct_points: bool,
+    /// At least one hull corner occurs multiple times (with different keys)
+    /// in the collection. Only one of the keys is part of the hull.
+    pub duplicates_on_hull: bool,
+}
+
+impl Degeneracy {
+    /// Returns `true` if any degeneracy was detected.
+    pub fn any(&self) -> bool {
+        return self.collinear
+            || self.coincident
+            || self.few_distinct_points
+            || self.duplicates_on_hull;
+    }
+}
+
+/// Location of a point relative to a [`Hull`], see [`Hull::locate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum PointLocation {
+    /// The point is located strictly inside the hull.
+    Inside,
+    /// The point is located on an edge or is a corner of the hull.
+    OnBoundary,
+    /// The point is located outside of the hull (or is nonreal).
+    Outside,
+}
+
+/**
+An owned convex hull, consisting of the keys and points of its corners in
+counter-clockwise order.
+
+A [`Hull`] does not borrow the collection it was calculated from, which makes
+it possible to store it, send it to other threads or machines (with the `serde`
+feature flag enabled, it implements `Serialize` and `Deserialize`, where the
+deserialized corners are validated like in [`Hull::from_vertices`]) and to
+combine it with other hulls via [`Hull::combine`]. Since a [`Hull`] is itself a
+collection of keyed points, it implements [`ConvexHull`] as well.
+
+A hull created with [`Hull::new`] additionally reports the degenerate
+configurations of its input (see [`Degeneracy`]).
+
+# Examples
+
+```
+use planar_convex_hull::{ConvexHull, Hull};
+
+let points = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.2, 0.2]];
+let hull = Hull::from(points.convex_hull());
+assert_eq!(hull.keys(), &[1, 2, 0]);
+assert_eq!(hull.points(), &[[1.0, 0.0], [0.0, 1.0], [0.0, 0.0]]);
+```
+ */
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "HullData"))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct Hull {
+    keys: Vec<usize>,
+    points: Vec<[f64; 2]>,
+    degeneracy: Degeneracy,
+    /// Positions of the leftmost, bottom, rightmost and top corner, if they
+    /// are known from the divide-and-conquer algorithm.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    extremes: Option<[usize; 4]>,
+}
+
+/// Unvalidated fields of a deserialized [`Hull`].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct HullData {
+    keys: Vec<usize>,
+    points: Vec<[f64; 2]>,
+    #[serde(default)]
+    degeneracy: Degeneracy,
+}
+
+/// Deserialized hulls are validated with [`Hull::from_vertices`]. The
+/// degeneracies which can be derived from the corners are recomputed, the
+/// others are taken from the data.
+#[cfg(feature = "serde")]
+impl TryFrom<HullData> for Hull {
+    type Error = HullError;
+
+    fn try_from(data: HullData) -> Result<Self, Self::Error> {
+        if data.keys.len() != data.points.len() {
+            return Err(HullError::LengthMismatch {
+                keys: data.keys.len(),
+                points: data.points.len(),
+            });
+        }
+        let mut hull = Hull::from_vertices(data.keys.into_iter().zip(data.points))?;
+        hull.degeneracy.few_distinct_points |= data.degeneracy.few_distinct_points;
+        hull.degeneracy.duplicates_on_hull = data.degeneracy.duplicates_on_hull;
+        return Ok(hull);
+    }
+}
+
+/// Returns the bit pattern of `point`, treating `-0.0` and `0.0` as equal.
+fn bits(point: [f64; 2]) -> [u64; 2] {
+    return [(point[0] + 0.0).to_bits(), (point[1] + 0.0).to_bits()];
+}
+
+impl Hull {
+    /**
+    Calculates the convex hull of `collection` with
+    [`ConvexHull::convex_hull_exact`] and detects its degeneracies (see
+    [`Degeneracy`]) in a second pass over the collection.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.2, 0.2]]);
+    assert_eq!(hull.keys(), &[1, 2, 0]);
+    assert!(!hull.degeneracy().any());
+
+    let hull = Hull::new(&[[1.0, 1.0], [1.0, 1.0]]);
+    assert_eq!(hull.len(), 1);
+    assert!(hull.degeneracy().coincident);
+    assert!(hull.degeneracy().few_distinct_points);
+    assert!(hull.degeneracy().duplicates_on_hull);
+    ```
+     */
+    pub fn new<T: ConvexHull + ?Sized>(collection: &T) -> Self {
+        return Hull::from(collection.convex_hull_exact())
+            .with_input_degeneracies(collection.convex_hull_iter());
+    }
+
+    /// Derives the degeneracies which depend on the whole input (and not only
+    /// on the hull corners) from `input`, see [`Hull::degeneracy`].
+    fn with_input_degeneracies(mut self, input: impl Iterator<Item = (usize, [f64; 2])>) -> Self {
+        let corners: HashMap<[u64; 2], usize> =
+            self.iter().map(|(key, point)| (bits(point), key)).collect();
+        let mut distinct: Vec<[u64; 2]> = Vec::with_capacity(3);
+        let mut duplicates_on_hull = false;
+        for (key, point) in input {
+            if !point[0].is_finite() || !point[1].is_finite() {
+                continue;
+            }
+            let point_bits = bits(point);
+            if distinct.len() < 3 && !distinct.contains(&point_bits) {
+                distinct.push(point_bits);
+            }
+            if let Some(corner_key) = corners.get(&point_bits) {
+                duplicates_on_hull |= *corner_key != key;
+            }
+        }
+
+        self.degeneracy.few_distinct_points = distinct.len() < 3;
+        self.degeneracy.duplicates_on_hull = duplicates_on_hull;
+        return self;
+    }
+
+    /// Returns the number of corners of the hull.
+    pub fn len(&self) -> usize {
+        return self.points.len();
+    }
+
+    /// Returns `true` if the hull has no corners (i.e. it was calculated from
+    /// a collection without real points).
+    pub fn is_empty(&self) -> bool {
+        return self.points.is_empty();
+    }
+
+    /// Returns the keys of the hull corners in counter-clockwise order.
+    pub fn keys(&self) -> &[usize] {
+        return &self.keys;
+    }
+
+    /// Returns the hull corners in counter-clockwise order.
+    pub fn points(&self) -> &[[f64; 2]] {
+        return &self.points;
+    }
+
+    /**
+    Wraps vertices obtained elsewhere (e.g. from a cache, the network or
+    another library) into a [`Hull`] without any validation.
+
+    The vertices must be the corners of a convex polygon in counter-clockwise
+    order without collinear or duplicate points (which is what
+    [`Hull::from_vertices`] checks). Otherwise, the results of the methods of
+    [`Hull`] are unspecified. The first vertex does not need to follow the
+    starting point convention of [`ConvexHull::convex_hull`]. The degeneracies
+    are derived from the vertices as described in [`Hull::degeneracy`].
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::from_vertices_unchecked([(7, [0.0, 0.0]), (3, [1.0, 0.0]), (5, [0.0, 1.0])]);
+    assert_eq!(hull.keys(), &[7, 3, 5]);
+    ```
+     */
+    pub fn from_vertices_unchecked(vertices: impl IntoIterator<Item = (usize, [f64; 2])>) -> Self {
+        let (keys, points): (Vec<usize>, Vec<[f64; 2]>) = vertices.into_iter().unzip();
+        return Self::from_corners(keys, points);
+    }
+
+    /**
+    Wraps vertices obtained elsewhere into a [`Hull`] like
+    [`Hull::from_vertices_unchecked`], but validates them first with exact
+    predicates: All vertices must be real, consecutive vertices must differ,
+    the boundary must turn strictly counter-clockwise at every vertex and it
+    must wind around exactly once. Hulls with fewer than three vertices only
+    need to fulfill the first two conditions.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::{Hull, HullError};
+
+    let square = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+    assert!(Hull::from_vertices(square.into_iter().enumerate()).is_ok());
+
+    // Clockwise order
+    let clockwise = square.into_iter().rev().enumerate();
+    assert_eq!(
+        Hull::from_vertices(clockwise),
+        Err(HullError::NotConvex { position: 0 })
+    );
+
+    // The point [0.5, 0.0] is located on the edge between its neighbors
+    let collinear = [[0.0, 0.0], [0.5, 0.0], [1.0, 0.0], [1.0, 1.0]];
+    assert_eq!(
+        Hull::from_vertices(collinear.into_iter().enumerate()),
+        Err(HullError::NotConvex { position: 1 })
+    );
+    ```
+     */
+    pub fn from_vertices(
+        vertices: impl IntoIterator<Item = (usize, [f64; 2])>,
+    ) -> Result<Self, HullError> {
+        let hull = Self::from_vertices_unchecked(vertices);
+        let points = &hull.points;
+        let n = points.len();
+
+        if let Some(position) = points
+            .iter()
+            .position(|point| !point[0].is_finite() || !point[1].is_finite())
+        {
+            return Err(HullError::NonReal { position });
+        }
+        if let Some(position) = (0..n).find(|i| n > 1 && points[*i] == points[(i + n - 1) % n]) {
+            return Err(HullError::Duplicate { position });
+        }
+        if n < 3 {
+            return Ok(hull);
+        }
+
+        let mut total_turn = 0.0;
+        for position in 0..n {
+            let a = points[(position + n - 1) % n];
+            let b = points[position];
+            let c = points[(position + 1) % n];
+            if predicates::orientation(a, b, c) != Ordering::Greater {
+                return Err(HullError::NotConvex { position });
+            }
+            let ab = [b[0] - a[0], b[1] - a[1]];
+            let bc = [c[0] - b[0], c[1] - b[1]];
+            total_turn += (ab[0] * bc[1] - ab[1] * bc[0]).atan2(ab[0] * bc[0] + ab[1] * bc[1]);
+        }
+
+        // All turns are in (0, pi), so the total turn is a multiple of 2 pi
+        if (total_turn / TAU).round() != 1.0 {
+            return Err(HullError::SelfIntersecting);
+        }
+        return Ok(hull);
+    }
+
+    /// Creates a hull from its corners and derives the degeneracies from them.
+    fn from_corners(keys: Vec<usize>, points: Vec<[f64; 2]>) -> Self {
+        let degeneracy = Degeneracy {
+            collinear: points.len() == 2,
+            coincident: points.len() == 1,
+            few_distinct_points: points.len() < 3,
+            duplicates_on_hull: false,
+        };
+        return Self {
+            keys,
+            points,
+            degeneracy,
+            extremes: None,
+        };
+    }
+
+    /**
+    Returns the degenerate configurations detected while calculating the hull.
+
+    For a hull created via [`Hull::new`], all flags are derived from the
+    whole input collection. For a hull converted from a [`ConvexHullIter`],
+    only the corners are known: `collinear` and `coincident` are still
+    exact, while `few_distinct_points` refers to the corners and
+    `duplicates_on_hull` is always `false`.
+     */
+    pub fn degeneracy(&self) -> Degeneracy {
+        return self.degeneracy;
+    }
+
+    /// Iterates over the `(key, point)` pairs of the hull corners in
+    /// counter-clockwise order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (usize, [f64; 2])> + '_ {
+        return self.keys.iter().copied().zip(self.points.iter().copied());
+    }
+
+    /**
+    Resolves the keys of the hull corners into the items of `collection`, in
+    counter-clockwise order. This gives access to the original items (e.g.
+    points carrying additional data) without zipping the keys with the
+    collection manually. The collection must be the one the hull was
+    calculated from (or one using the same keys) and can be anything indexable
+    by the keys, such as a vector, a slice or a slab. A [`HullView`] keeps the
+    reference to the collection instead, so it does not need to be passed
+    again.
+
+    # Panics
+
+    Panics if a key is not a valid index of `collection`.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::{ConvexHull, Hull, Point2D, Points};
+
+    struct City {
+        name: &'static str,
+        position: [f64; 2],
+    }
+
+    impl Point2D for City {
+        fn x(&self) -> f64 {
+            return self.position[0];
+        }
+
+        fn y(&self) -> f64 {
+            return self.position[1];
+        }
+    }
+
+    let cities = vec![
+        City { name: "A", position: [0.0, 0.0] },
+        City { name: "B", position: [4.0, 0.0] },
+        City { name: "C", position: [1.0, 1.0] },
+        City { name: "D", position: [2.0, 3.0] },
+    ];
+    let hull = Hull::new(&Points(&cities));
+    let names: Vec<&str> = hull.resolve(&cities).map(|city| city.name).collect();
+    assert_eq!(names, vec!["B", "D", "A"]);
+    ```
+     */
+    pub fn resolve<'a, C: std::ops::Index<usize> + ?Sized>(
+        &'a self,
+        collection: &'a C,
+    ) -> impl ExactSizeIterator<Item = &'a C::Output> + 'a {
+        return self.keys.iter().map(move |key| &collection[*key]);
+    }
+
+    /**
+    Combines `self` and `other` into the convex hull of their union.
+
+    This is the reduce step for calculating a hull in a distributed fashion:
+    Each shard of a point set computes its partial hull, the partial hulls are
+    sent to a single place (see the `serde` feature flag) and then combined
+    pairwise in any order. Since only hull corners can be corners of the
+    combined hull, this is much cheaper than computing the hull of the union
+    of the shards. The corners are merged with [`Hull::merge`] in O(h1 + h2)
+    time. The keys of the corners are kept as they are, so they should be
+    unique across all shards (e.g. global point IDs).
+
+    The degeneracies of the combined hull are derived from the corners of
+    both hulls (see [`Hull::degeneracy`]).
+
+    # Examples
+
+    ```
+    use planar_convex_hull::{ConvexHull, Hull};
+
+    // Two shards of a point set, keyed by global IDs
+    let shard_a = std::collections::HashMap::from([
+        (0, [0.0, 0.0]),
+        (1, [2.0, 0.0]),
+        (2, [1.0, 0.5]),
+    ]);
+    let shard_b = std::collections::HashMap::from([
+        (3, [2.0, 2.0]),
+        (4, [0.0, 2.0]),
+        (5, [1.0, 1.5]),
+    ]);
+
+    let hull_a = Hull::from(shard_a.convex_hull());
+    let hull_b = Hull::from(shard_b.convex_hull());
+    let hull = hull_a.combine(&hull_b);
+    assert_eq!(hull.keys(), &[3, 4, 0, 1]);
+    ```
+     */
+    pub fn combine(&self, other: &Hull) -> Hull {
+        return self
+            .merge(other)
+            .with_input_degeneracies(self.iter().chain(other.iter()));
+    }
+
+    /**
+    Merges `self` and `other` into the convex hull of their union in
+    O(h1 + h2) time, without running a hull algorithm on the corners of both
+    hulls: Both hulls are split at their lexicographically smallest corner
+    into two sorted chains, so their corners are sorted in linear time and
+    Andrew's monotone chain algorithm (with exact predicates) finishes the
+    merge. This makes it suitable e.g. for hierarchical workflows which merge
+    many hulls bottom-up. Both hulls should have been calculated from the same
+    collection (or from collections with unique keys), of identical corners
+    the one of `self` is kept.
+
+    Unlike [`Hull::combine`], which additionally detects duplicate corners
+    with different keys, only the corners of the result are used to derive
+    its degeneracies (as for [`Hull::from_vertices_unchecked`]).
+
+    # Examples
+
+    ```
+    use std::collections::HashMap;
+    use planar_convex_hull::{ConvexHull, Hull};
+
+    let points = [[0.0, 0.0], [2.0, 0.0], [1.0, 0.5], [2.0, 2.0], [0.0, 2.0], [1.0, 1.5]];
+    let lower: HashMap<usize, [f64; 2]> = (0..3).map(|i| (i, points[i])).collect();
+    let upper: HashMap<usize, [f64; 2]> = (3..6).map(|i| (i, points[i])).collect();
+    let hull = Hull::new(&lower).merge(&Hull::new(&upper));
+    assert_eq!(hull.keys(), &[3, 4, 0, 1]);
+    assert_eq!(hull, Hull::from(points.convex_hull()));
+    ```
+     */
+    pub fn merge(&self, other: &Hull) -> Hull {
+        let corners = monotone_chain::merge(
+            &self.iter().collect::<Vec<_>>(),
+            &other.iter().collect::<Vec<_>>(),
+        );
+        return Hull::from_vertices_unchecked(corners);
+    }
+
+    /**
+    Combines any number of partial hulls into the convex hull of their union,
+    e.g. the hulls of all shards of a point set in a map-reduce pipeline (see
+    [`Hull::combine`]). The hulls are merged pairwise like a balanced tree,
+    which takes O(H log k) time for k hulls with H corners in total. The order
+    of the hulls only matters for the choice among identical corners with
+    different keys, of which the one of the first hull is kept.
+
+    The degeneracies of the result are derived from the corners of all
+    partial hulls (see [`Hull::degeneracy`]).
+
+    # Examples
+
+    ```
+    use planar_convex_hull::{ConvexHull, Hull};
+
+    let points = [[0.0, 0.0], [2.0, 0.0], [1.0, 0.5], [2.0, 2.0], [0.0, 2.0], [1.0, 1.5]];
+
+    // Each shard owns its points and offsets the keys of its hull by the
+    // global index of its first point
+    let partials = points.chunks(2).enumerate().map(|(shard, chunk)| {
+        return Hull::new(&chunk.to_vec()).map_keys(|key| 2 * shard + key);
+    });
+    let hull = Hull::combine_all(partials);
+    assert_eq!(hull.keys(), &[3, 4, 0, 1]);
+    assert_eq!(hull, Hull::from(points.convex_hull()));
+    ```
+     */
+    pub fn combine_all(hulls: impl IntoIterator<Item = Hull>) -> Hull {
+        let mut hulls: Vec<Hull> = hulls.into_iter().collect();
+        let input: Vec<(usize, [f64; 2])> = hulls.iter().flat_map(Hull::iter).collect();
+        while hulls.len() > 1 {
+            hulls = hulls
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => a.merge(b),
+                    _ => pair[0].clone(),
+                })
+                .collect();
+        }
+        return hulls
+            .pop()
+            .unwrap_or_default()
+            .with_input_degeneracies(input.into_iter());
+    }
+
+    /**
+    Replaces the key of each corner by `f(key)`, keeping the points and the
+    degeneracies. This converts e.g. the keys of a hull of a shard (its local
+    indices) into global point IDs before combining it with other hulls.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]).map_keys(|key| key + 10);
+    assert_eq!(hull.keys(), &[11, 12, 10]);
+    ```
+     */
+    pub fn map_keys(mut self, f: impl FnMut(usize) -> usize) -> Hull {
+        self.keys = self.keys.into_iter().map(f).collect();
+        return self;
+    }
+
+    /**
+    Returns the exterior turn angle at each corner together with the
+    cumulative turning up to and including that corner, in the order of
+    [`Hull::points`].
+
+    The exterior angle at a corner is the angle between the incoming and the
+    outgoing edge, in radians. For a valid hull with at least three corners,
+    each angle is in (0, π) and the angles sum up to 2π (up to rounding), so
+    the last cumulative value is approximately 2π. A very small angle marks a
+    nearly collinear corner. A segment turns by π at both ends, while a hull
+    with fewer than two corners has no turn angles.
+
+    # Examples
+
+    ```
+    use std::f64::consts::{FRAC_PI_2, PI, TAU};
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+    let turns = hull.turn_angles();
+    assert_eq!(turns.len(), 4);
+    assert_eq!(turns[0], (FRAC_PI_2, FRAC_PI_2));
+    assert_eq!(turns[3], (FRAC_PI_2, TAU));
+
+    let segment = Hull::new(&[[0.0, 0.0], [2.0, 1.0]]);
+    assert_eq!(segment.turn_angles(), vec![(PI, PI), (PI, TAU)]);
+    ```
+     */
+    pub fn turn_angles(&self) -> Vec<(f64, f64)> {
+        let points = &self.points;
+        let n = points.len();
+        if n < 2 {
+            return Vec::new();
+        }
+
+        let mut cumulative = 0.0;
+        return (0..n)
+            .map(|i| {
+                let a = points[(i + n - 1) % n];
+                let b = points[i];
+                let c = points[(i + 1) % n];
+                let ab = [b[0] - a[0], b[1] - a[1]];
+                let bc = [c[0] - b[0], c[1] - b[1]];
+                let angle = (ab[0] * bc[1] - ab[1] * bc[0]).atan2(ab[0] * bc[0] + ab[1] * bc[1]);
+                // A segment reverses its direction at both ends
+                let angle = if n == 2 { angle.abs() } else { angle };
+                cumulative += angle;
+                return (angle, cumulative);
+            })
+            .collect();
+    }
+
+    /**
+    Iterates over the interior angles at the corners of the hull in radians,
+    in the order of [`Hull::points`]. The interior angle is π minus the
+    exterior turn angle (see [`Hull::turn_angles`]), so for a valid hull with
+    n ≥ 3 corners each angle is in (0, π) and the angles sum up to (n - 2)π.
+    A segment has an interior angle of zero at both ends, while a hull with
+    fewer than two corners has no angles.
+
+    # Examples
+
+    ```
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]);
+    let angles: Vec<f64> = hull.interior_angles().collect();
+    assert_eq!(angles, vec![FRAC_PI_4, FRAC_PI_4, FRAC_PI_2]);
+    ```
+     */
+    pub fn interior_angles(&self) -> impl ExactSizeIterator<Item = f64> + '_ {
+        let points = &self.points;
+        let n = if points.len() < 2 { 0 } else { points.len() };
+        return (0..n).map(move |i| {
+            let a = points[(i + n - 1) % n];
+            let b = points[i];
+            let c = points[(i + 1) % n];
+            let ba = [a[0] - b[0], a[1] - b[1]];
+            let bc = [c[0] - b[0], c[1] - b[1]];
+            return (ba[0] * bc[1] - ba[1] * bc[0])
+                .abs()
+                .atan2(ba[0] * bc[0] + ba[1] * bc[1]);
+        });
+    }
+
+    /**
+    Iterates over the outward unit normals of the edges of the hull, in the
+    order of [`Hull::edges`]. The normal of an edge is its direction rotated
+    clockwise by a right angle. The two edges of a segment have opposite
+    normals.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    let normals: Vec<[f64; 2]> = hull.edge_normals().collect();
+    assert_eq!(normals, vec![[0.0, 1.0], [-1.0, 0.0], [0.0, -1.0], [1.0, 0.0]]);
+    ```
+     */
+    pub fn edge_normals(&self) -> impl ExactSizeIterator<Item = [f64; 2]> + '_ {
+        return self.edges().map(|(a, b)| {
+            let length = (b[0] - a[0]).hypot(b[1] - a[1]);
+            return [(b[1] - a[1]) / length, (a[0] - b[0]) / length];
+        });
+    }
+
+    /**
+    Returns the signed area of the hull polygon, calculated with the shoelace
+    formula relative to the first corner (which reduces cancellation for
+    points far away from the origin). Since the corners are in
+    counter-clockwise order, the area of a valid hull is positive; hulls with
+    fewer than three corners have zero area. Takes O(h) time.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 1.0], [0.0, 1.0], [1.0, 0.5]]);
+    assert_eq!(hull.area(), 2.0);
+
+    let segment = Hull::new(&[[0.0, 0.0], [2.0, 1.0]]);
+    assert_eq!(segment.area(), 0.0);
+    ```
+     */
+    pub fn area(&self) -> f64 {
+        return geometry::signed_area(&self.points);
+    }
+
+    /**
+    Iterates over the edges of the hull as pairs of consecutive corners in
+    counter-clockwise order, including the closing edge from the last to the
+    first corner. A hull with two corners has two edges (forth and back) and a
+    hull with fewer corners has none.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]);
+    let edges: Vec<([f64; 2], [f64; 2])> = hull.edges().collect();
+    assert_eq!(
+        edges,
+        vec![
+            ([1.0, 0.0], [0.0, 1.0]),
+            ([0.0, 1.0], [0.0, 0.0]),
+            ([0.0, 0.0], [1.0, 0.0]),
+        ]
+    );
+    ```
+     */
+    pub fn edges(&self) -> impl ExactSizeIterator<Item = ([f64; 2], [f64; 2])> + '_ {
+        let points = &self.points;
+        let n = if points.len() < 2 { 0 } else { points.len() };
+        return (0..n).map(move |i| (points[i], points[(i + 1) % n]));
+    }
+
+    /**
+    Triangulates the hull as a fan from its first corner and returns the
+    triangles as indices into [`Hull::points`] in counter-clockwise order,
+    ready to be uploaded as the index buffer of a mesh (with the hull points as
+    its vertex buffer). A hull with n ≥ 3 corners yields n - 2 triangles, a
+    hull with fewer corners none. Since the hull is convex and has no
+    collinear corners, no triangle is degenerate.
+
+    # Panics
+
+    Panics if the hull has more than `u32::MAX` corners.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+    assert_eq!(hull.triangulate(), vec![[0, 1, 2], [0, 2, 3]]);
+    ```
+     */
+    pub fn triangulate(&self) -> Vec<[u32; 3]> {
+        let n = u32::try_from(self.points.len()).expect("too many corners for u32 indices");
+        return (1..n.saturating_sub(1)).map(|i| [0, i, i + 1]).collect();
+    }
+
+    /**
+    Returns the length of the boundary of the hull, i.e. the sum of the
+    lengths of [`Hull::edges`] including the closing edge. For a hull with two
+    corners, this is twice the distance between them. Takes O(h) time.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [3.0, 0.0], [3.0, 4.0], [1.0, 1.0]]);
+    assert_eq!(hull.perimeter(), 12.0);
+    ```
+     */
+    pub fn perimeter(&self) -> f64 {
+        return self
+            .edges()
+            .map(|(a, b)| (b[0] - a[0]).hypot(b[1] - a[1]))
+            .sum();
+    }
+
+    /**
+    Returns the point on the boundary of the hull at the arc length `s`,
+    measured counter-clockwise from the first corner along [`Hull::edges`],
+    e.g. to place evenly spaced markers or to resample the boundary. The arc
+    length wraps around, so `s` may be negative or exceed the perimeter (see
+    [`Hull::perimeter`]). Returns `None` for an empty hull or a nonreal `s`;
+    a hull with a single corner always returns this corner. Takes O(h) time.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    assert_eq!(hull.points()[0], [2.0, 2.0]);
+    assert_eq!(hull.point_at_perimeter(1.0), Some([1.0, 2.0]));
+    assert_eq!(hull.point_at_perimeter(3.0), Some([0.0, 1.0]));
+    assert_eq!(hull.point_at_perimeter(9.0), Some([1.0, 2.0]));
+    assert_eq!(hull.point_at_perimeter(-1.0), Some([2.0, 1.0]));
+    ```
+     */
+    pub fn point_at_perimeter(&self, s: f64) -> Option<[f64; 2]> {
+        let first = *self.points.first()?;
+        if !s.is_finite() {
+            return None;
+        }
+        let perimeter = self.perimeter();
+        if perimeter == 0.0 {
+            return Some(first);
+        }
+        let mut remaining = s.rem_euclid(perimeter);
+        for (a, b) in self.edges() {
+            let length = (b[0] - a[0]).hypot(b[1] - a[1]);
+            if remaining <= length {
+                let t = remaining / length;
+                return Some([a[0] + t * (b[0] - a[0]), a[1] + t * (b[1] - a[1])]);
+            }
+            remaining -= length;
+        }
+        // Only reached due to rounding errors at the end of the last edge
+        return Some(first);
+    }
+
+    /**
+    Returns the centroid (center of mass) of the hull polygon, or `None` for
+    an empty hull.
+
+    The centroid of a hull with area is area-weighted, i.e. it does not depend
+    on how the corners are distributed along the boundary. The degenerate
+    hulls of [`Degeneracy`] are treated as the shapes they describe: The
+    centroid of a segment (two corners) is its midpoint and the centroid of a
+    single point is the point itself. Takes O(h) time.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [3.0, 0.0], [0.0, 3.0], [1.0, 0.0], [0.5, 0.5]]);
+    assert_eq!(hull.centroid(), Some([1.0, 1.0]));
+
+    let segment = Hull::new(&[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]);
+    assert_eq!(segment.centroid(), Some([1.0, 1.0]));
+    assert_eq!(Hull::new(&Vec::<[f64; 2]>::new()).centroid(), None);
+    ```
+     */
+    pub fn centroid(&self) -> Option<[f64; 2]> {
+        let points = &self.points;
+        let n = points.len();
+        let origin = *points.first()?;
+        let area = self.area();
+        if n < 3 || area == 0.0 {
+            // Mean of the corners, which is the midpoint of a segment
+            let sum = points.iter().fold([0.0, 0.0], |sum, point| {
+                [sum[0] + point[0], sum[1] + point[1]]
+            });
+            return Some([sum[0] / n as f64, sum[1] / n as f64]);
+        }
+
+        // Relative to the first corner to reduce cancellation
+        let (mut cx, mut cy) = (0.0, 0.0);
+        for (a, b) in self.edges() {
+            let (x0, y0) = (a[0] - origin[0], a[1] - origin[1]);
+            let (x1, y1) = (b[0] - origin[0], b[1] - origin[1]);
+            let cross = x0 * y1 - x1 * y0;
+            cx += (x0 + x1) * cross;
+            cy += (y0 + y1) * cross;
+        }
+        return Some([origin[0] + cx / (6.0 * area), origin[1] + cy / (6.0 * area)]);
+    }
+
+    /**
+    Determines whether `point` is located inside, on the boundary of or
+    outside of the hull using exact predicates, so points on an edge are
+    always classified as [`PointLocation::OnBoundary`]. A hull with two
+    corners is a segment without interior and a hull with a single corner is a
+    point. Takes O(log h) time via a binary search over the wedges spanned by
+    the first corner.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::{Hull, PointLocation};
+
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    assert_eq!(hull.locate([1.0, 1.0]), PointLocation::Inside);
+    assert_eq!(hull.locate([2.0, 0.5]), PointLocation::OnBoundary);
+    assert_eq!(hull.locate([0.0, 0.0]), PointLocation::OnBoundary);
+    assert_eq!(hull.locate([3.0, 1.0]), PointLocation::Outside);
+    ```
+     */
+    pub fn locate(&self, point: [f64; 2]) -> PointLocation {
+        let corners = &self.points;
+        let n = corners.len();
+        if n == 0 || !point[0].is_finite() || !point[1].is_finite() {
+            return PointLocation::Outside;
+        }
+        let on_boundary = |a: [f64; 2], b: [f64; 2]| {
+            if predicates::on_segment(a, b, point) {
+                return PointLocation::OnBoundary;
+            }
+            return PointLocation::Outside;
+        };
+        if n < 3 {
+            return on_boundary(corners[0], corners[n - 1]);
+        }
+
+        let origin = corners[0];
+        match (
+            predicates::orientation(origin, corners[1], point),
+            predicates::orientation(origin, corners[n - 1], point),
+        ) {
+            (Ordering::Less, _) | (_, Ordering::Greater) => return PointLocation::Outside,
+            (Ordering::Equal, _) => return on_boundary(origin, corners[1]),
+            (_, Ordering::Equal) => return on_boundary(corners[n - 1], origin),
+            _ => (),
+        }
+
+        // Invariant: point is left of origin -> corners[lo] and right of
+        // origin -> corners[hi] (or on the ray).
+        let (mut lo, mut hi) = (1, n - 1);
+        while hi - lo > 1 {
+            let mid = (lo + hi) / 2;
+            if predicates::orientation(origin, corners[mid], point) == Ordering::Greater {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        match predicates::orientation(corners[lo], corners[hi], point) {
+            Ordering::Greater => return PointLocation::Inside,
+            Ordering::Equal => return PointLocation::OnBoundary,
+            Ordering::Less => return PointLocation::Outside,
+        }
+    }
+
+    /**
+    Returns `true` if `point` is located inside the hull or on its boundary,
+    see [`Hull::locate`].
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]);
+    assert!(hull.contains([0.25, 0.25]));
+    assert!(hull.contains([0.5, 0.5]));
+    assert!(!hull.contains([1.0, 1.0]));
+    ```
+     */
+    pub fn contains(&self, point: [f64; 2]) -> bool {
+        return self.locate(point) != PointLocation::Outside;
+    }
+
+    /**
+    Returns `true` if `point` is located strictly inside the hull, i.e. not on
+    its boundary, see [`Hull::locate`]. Hulls with fewer than three corners
+    contain no point strictly.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]);
+    assert!(hull.contains_strictly([0.25, 0.25]));
+    assert!(!hull.contains_strictly([0.5, 0.5]));
+    ```
+     */
+    pub fn contains_strictly(&self, point: [f64; 2]) -> bool {
+        return self.locate(point) == PointLocation::Inside;
+    }
+
+    /**
+    Returns `true` if `other` is located inside the hull, where its corners
+    may lie on the boundary of the hull, e.g. to check that a simplified hull
+    still covers the original one. Since both hulls are convex, it suffices to
+    locate the corners of `other` (see [`Hull::locate`]), which takes
+    O(m log n) time. An empty hull is contained in every hull.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    let inner = Hull::new(&[[0.5, 0.5], [1.5, 0.5], [1.0, 1.5]]);
+    let touching = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]]);
+    let crossing = Hull::new(&[[1.0, 1.0], [3.0, 1.0], [1.0, 1.5]]);
+    assert!(hull.contains_hull(&inner));
+    assert!(hull.contains_hull(&touching));
+    assert!(!hull.contains_hull(&crossing));
+    ```
+     */
+    pub fn contains_hull(&self, other: &Hull) -> bool {
+        return other.points.iter().all(|point| self.contains(*point));
+    }
+
+    /**
+    Returns `true` if `other` is located strictly inside the hull, i.e. none
+    of its corners lies on the boundary of the hull, see
+    [`Hull::contains_hull`]. Hulls with fewer than three corners contain no
+    hull strictly except the empty one.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    let inner = Hull::new(&[[0.5, 0.5], [1.5, 0.5], [1.0, 1.5]]);
+    let touching = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]]);
+    assert!(hull.contains_hull_strictly(&inner));
+    assert!(!hull.contains_hull_strictly(&touching));
+    ```
+     */
+    pub fn contains_hull_strictly(&self, other: &Hull) -> bool {
+        return other
+            .points
+            .iter()
+            .all(|point| self.contains_strictly(*point));
+    }
+
+    /**
+    Returns the point of the hull (including its interior) which is closest to
+    `query` together with the distance between them, or `None` for an empty
+    hull. A query inside the hull or on its boundary is its own closest point
+    at distance zero, so this also clamps points into the hull. For points
+    outside, the query is projected onto the closest edge or corner. Takes
+    O(h) time.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    assert_eq!(hull.closest_point([3.0, 1.0]), Some(([2.0, 1.0], 1.0)));
+    assert_eq!(hull.closest_point([5.0, 6.0]), Some(([2.0, 2.0], 5.0)));
+    assert_eq!(hull.closest_point([1.0, 1.5]), Some(([1.0, 1.5], 0.0)));
+    ```
+     */
+    pub fn closest_point(&self, query: [f64; 2]) -> Option<([f64; 2], f64)> {
+        let first = *self.points.first()?;
+        if self.contains(query) {
+            return Some((query, 0.0));
+        }
+        let distance = |point: [f64; 2]| (point[0] - query[0]).hypot(point[1] - query[1]);
+
+        let mut closest = (first, distance(first));
+        for (a, b) in self.edges() {
+            let edge = [b[0] - a[0], b[1] - a[1]];
+            let t = ((query[0] - a[0]) * edge[0] + (query[1] - a[1]) * edge[1])
+                / (edge[0] * edge[0] + edge[1] * edge[1]);
+            let point = if t <= 0.0 {
+                a
+            } else if t >= 1.0 {
+                b
+            } else {
+                [a[0] + t * edge[0], a[1] + t * edge[1]]
+            };
+            let candidate = distance(point);
+            if candidate < closest.1 {
+                closest = (point, candidate);
+            }
+        }
+        return Some(closest);
+    }
+
+    /**
+    Returns the signed distance of `point` to the boundary of the hull:
+    negative inside, zero on the boundary and positive outside, so the hull
+    can be used directly as a signed distance function (SDF). Outside, the
+    value is the distance returned by [`Hull::closest_point`]; inside, it is
+    the negated distance to the closest edge. Hulls with fewer than three
+    corners have no interior, so their distances are never negative, and the
+    distance to an empty hull is infinite. Takes O(h) time.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]]);
+    assert_eq!(hull.signed_distance([1.0, 2.0]), -1.0);
+    assert_eq!(hull.signed_distance([4.0, 2.0]), 0.0);
+    assert_eq!(hull.signed_distance([7.0, 8.0]), 5.0);
+    ```
+     */
+    pub fn signed_distance(&self, point: [f64; 2]) -> f64 {
+        if self.locate(point) != PointLocation::Inside {
+            return self
+                .closest_point(point)
+                .map_or(f64::INFINITY, |(_, distance)| distance);
+        }
+        // The closest boundary point of an interior point lies on the edge
+        // whose supporting line is closest.
+        return -self
+            .edges()
+            .map(|(a, b)| {
+                let edge = [b[0] - a[0], b[1] - a[1]];
+                let cross = edge[0] * (point[1] - a[1]) - edge[1] * (point[0] - a[0]);
+                return cross / edge[0].hypot(edge[1]);
+            })
+            .fold(f64::INFINITY, f64::min);
+    }
+
+    /**
+    Returns the key and the position of the hull corner which is farthest in
+    the given `direction` (i.e. which maximizes the dot product with it), also
+    known as the support point. If an edge is perpendicular to `direction`,
+    the corner at which this edge starts (in counter-clockwise order) is
+    returned; a zero direction returns the first corner. Returns `None` for an
+    empty hull or a nonreal direction.
+
+    The edge directions of a convex polygon are sorted by their angle, so the
+    corner is found by a binary search in O(log h) time without any
+    preprocessing. For many queries on the same hull, see
+    [`SupportTable`](crate::support::SupportTable).
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 1.0], [0.0, 1.0], [1.0, 0.5]]);
+    assert_eq!(hull.extreme_point_in_direction([1.0, 1.0]), Some((2, [2.0, 1.0])));
+    assert_eq!(hull.extreme_point_in_direction([-1.0, -0.1]), Some((0, [0.0, 0.0])));
+
+    // The top edge is perpendicular to the direction and starts at corner 2
+    assert_eq!(hull.extreme_point_in_direction([0.0, 1.0]), Some((2, [2.0, 1.0])));
+    ```
+     */
+    pub fn extreme_point_in_direction(&self, direction: [f64; 2]) -> Option<(usize, [f64; 2])> {
+        let index = self.extreme_index(direction)?;
+        return Some((self.keys[index], self.points[index]));
+    }
+
+    /**
+    Returns the axis-aligned bounding box of the hull as `[min, max]`, i.e.
+    the smallest and the largest coordinates of its corners, or `None` for an
+    empty hull. The bounding box is spanned by the extreme corners (see
+    [`Hull::leftmost`] and its siblings).
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 1.0], [2.0, 0.0], [3.0, 2.0], [1.0, 3.0], [1.0, 1.0]]);
+    assert_eq!(hull.aabb(), Some([[0.0, 0.0], [3.0, 3.0]]));
+    ```
+     */
+    pub fn aabb(&self) -> Option<[[f64; 2]; 2]> {
+        let points = &self.points;
+        return Some([
+            [points[self.leftmost()?][0], points[self.bottom()?][1]],
+            [points[self.rightmost()?][0], points[self.top()?][1]],
+        ]);
+    }
+
+    /**
+    Returns the position (in [`Hull::points`]) of the corner with the
+    smallest x-value, or `None` for an empty hull. Of two corners on a
+    vertical edge, the lower one is returned.
+
+    The four extreme corners [`Hull::leftmost`], [`Hull::bottom`],
+    [`Hull::rightmost`] and [`Hull::top`] follow each other in
+    counter-clockwise order. At an axis-parallel edge, each of them is the
+    corner at which the edge ends, so the rightmost corner is the first corner
+    of a hull calculated by [`ConvexHull::convex_hull`].
+
+    The divide-and-conquer algorithm behind [`ConvexHull::convex_hull`],
+    [`ConvexHull::convex_hull_exact`] and [`Hull::new`] finds these corners in
+    its first step. They are kept when converting its result into a [`Hull`],
+    so they are returned in O(1) time. For other hulls (e.g. created with
+    [`Hull::from_vertices`] or [`Hull::merge`]), each of them is found by a
+    binary search in O(log h) time (see [`Hull::extreme_point_in_direction`]).
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 1.0], [0.0, 1.0]]);
+    assert_eq!(hull.points(), &[[2.0, 1.0], [0.0, 1.0], [0.0, 0.0], [2.0, 0.0]]);
+    assert_eq!(hull.leftmost(), Some(2));
+    assert_eq!(hull.bottom(), Some(3));
+    assert_eq!(hull.rightmost(), Some(0));
+    assert_eq!(hull.top(), Some(1));
+    ```
+     */
+    pub fn leftmost(&self) -> Option<usize> {
+        if let Some([leftmost, ..]) = self.extremes {
+            return Some(leftmost);
+        }
+        return self.axis_extreme([-1.0, 0.0], [0.0, -1.0]);
+    }
+
+    /// Returns the position (in [`Hull::points`]) of the corner with the
+    /// largest x-value, or `None` for an empty hull. Of two corners on a
+    /// vertical edge, the upper one is returned. See [`Hull::leftmost`].
+    pub fn rightmost(&self) -> Option<usize> {
+        if let Some([_, _, rightmost, _]) = self.extremes {
+            return Some(rightmost);
+        }
+        return self.axis_extreme([1.0, 0.0], [0.0, 1.0]);
+    }
+
+    /// Returns the position (in [`Hull::points`]) of the corner with the
+    /// largest y-value, or `None` for an empty hull. Of two corners on a
+    /// horizontal edge, the left one is returned. See [`Hull::leftmost`].
+    pub fn top(&self) -> Option<usize> {
+        if let Some([_, _, _, top]) = self.extremes {
+            return Some(top);
+        }
+        return self.axis_extreme([0.0, 1.0], [-1.0, 0.0]);
+    }
+
+    /// Returns the position (in [`Hull::points`]) of the corner with the
+    /// smallest y-value, or `None` for an empty hull. Of two corners on a
+    /// horizontal edge, the right one is returned. See [`Hull::leftmost`].
+    pub fn bottom(&self) -> Option<usize> {
+        if let Some([_, bottom, _, _]) = self.extremes {
+            return Some(bottom);
+        }
+        return self.axis_extreme([0.0, -1.0], [1.0, 0.0]);
+    }
+
+    /// Position of the corner farthest in the axis `direction`, where ties
+    /// are broken by the perpendicular axis `tie`.
+    fn axis_extreme(&self, direction: [f64; 2], tie: [f64; 2]) -> Option<usize> {
+        let index = self.extreme_index(direction)?;
+        let n = self.points.len();
+        let key = |i: &usize| {
+            let p = self.points[*i];
+            return (
+                p[0] * direction[0] + p[1] * direction[1],
+                p[0] * tie[0] + p[1] * tie[1],
+            );
+        };
+        // The support corner is the start of an edge perpendicular to the
+        // axis, so only its neighbors can tie with it.
+        return [(index + n - 1) % n, index, (index + 1) % n]
+            .into_iter()
+            .max_by(|a, b| key(a).partial_cmp(&key(b)).unwrap_or(Ordering::Equal));
+    }
+
+    /// Index of the corner returned by [`Hull::extreme_point_in_direction`].
+    fn extreme_index(&self, direction: [f64; 2]) -> Option<usize> {
+        let points = &self.points;
+        let n = points.len();
+        if n == 0 || !direction[0].is_finite() || !direction[1].is_finite() {
+            return None;
+        }
+        let dot = |point: [f64; 2]| point[0] * direction[0] + point[1] * direction[1];
+        if n < 3 {
+            let index = if dot(points[n - 1]) > dot(points[0]) {
+                n - 1
+            } else {
+                0
+            };
+            return Some(index);
+        }
+
+        let edge = |i: usize| {
+            let (a, b) = (points[i], points[(i + 1) % n]);
+            return [b[0] - a[0], b[1] - a[1]];
+        };
+        let cross = |u: [f64; 2], v: [f64; 2]| u[0] * v[1] - u[1] * v[0];
+
+        // Angles are measured counter-clockwise from the first edge. Vectors
+        // in the upper half plane (relative to the first edge) come first and
+        // are ordered by cross products within their half.
+        let reference = edge(0);
+        let half = |v: [f64; 2]| {
+            let c = cross(reference, v);
+            if c > 0.0 || (c == 0.0 && reference[0] * v[0] + reference[1] * v[1] >= 0.0) {
+                return 0;
+            }
+            return 1;
+        };
+        let less = |u: [f64; 2], v: [f64; 2]| {
+            return half(u) < half(v) || (half(u) == half(v) && cross(u, v) > 0.0);
+        };
+
+        // The support corner is the start of the first edge whose direction
+        // is not smaller than the direction rotated by 90°.
+        let target = [-direction[1], direction[0]];
+        let (mut lo, mut hi) = (0, n);
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if less(edge(mid), target) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        return Some(lo % n);
+    }
+
+    /**
+    Returns the two tangent corners of the hull as seen from the exterior
+    `point`, i.e. the corners at which the lines through `point` touch the
+    hull, as `[left, right]` with their keys. Looking from `point` towards the
+    hull, the left tangent is the left end of the visible part of the boundary
+    and the right tangent its right end: The whole hull lies to the right of
+    the ray from `point` through the left tangent and to the left of the ray
+    through the right tangent. The visible edges run counter-clockwise from
+    the left to the right tangent.
+
+    If `point` is collinear with an edge through a tangent corner, the corner
+    closer to `point` is returned. For a hull with a single corner, both
+    tangents are this corner. Returns `None` if `point` is not located
+    strictly outside the hull (see [`Hull::locate`]).
+
+    The visible edges form a contiguous range of the boundary. After finding
+    one visible and one invisible edge via binary searches, the ends of this
+    range are found by binary searches as well, so a query takes O(log h)
+    time.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    assert_eq!(hull.keys(), &[2, 3, 0, 1]);
+
+    // Looking west from the east, the south-east corner is on the left
+    let [left, right] = hull.tangents_from([4.0, 1.0]).unwrap();
+    assert_eq!((left.0, right.0), (1, 2));
+
+    // Looking south-west from the north-east
+    let [left, right] = hull.tangents_from([3.0, 3.0]).unwrap();
+    assert_eq!((left.0, right.0), (1, 3));
+    assert_eq!(hull.tangents_from([1.0, 1.0]), None);
+    ```
+     */
+    pub fn tangents_from(&self, point: [f64; 2]) -> Option<[(usize, [f64; 2]); 2]> {
+        if self.locate(point) != PointLocation::Outside
+            || !point[0].is_finite()
+            || !point[1].is_finite()
+        {
+            return None;
+        }
+        let points = &self.points;
+        let n = points.len();
+        let corner = |index: usize| (self.keys[index], points[index]);
+        let squared_distance = |p: [f64; 2]| (p[0] - point[0]).powi(2) + (p[1] - point[1]).powi(2);
+        match n {
+            0 => return None,
+            1 => return Some([corner(0), corner(0)]),
+            2 => match predicates::orientation(points[0], points[1], point) {
+                Ordering::Less => return Some([corner(0), corner(1)]),
+                Ordering::Greater => return Some([corner(1), corner(0)]),
+                Ordering::Equal => {
+                    let nearer = if squared_distance(points[1]) < squared_distance(points[0]) {
+                        1
+                    } else {
+                        0
+                    };
+                    return Some([corner(nearer), corner(nearer)]);
+                }
+            },
+            _ => (),
+        }
+
+        // Edge i runs from corner i to corner i + 1 and is visible if the
+        // point is strictly right of it.
+        let visible = |edge: usize| {
+            return predicates::orientation(points[edge % n], points[(edge + 1) % n], point)
+                == Ordering::Less;
+        };
+
+        // A visible edge: The point is located outside of the fan of
+        // triangles spanned by the first corner or beyond the outer edge of
+        // the triangle whose wedge contains it.
+        let origin = points[0];
+        let beyond = |a: [f64; 2], b: [f64; 2]| {
+            return (point[0] - a[0]) * (b[0] - a[0]) + (point[1] - a[1]) * (b[1] - a[1]) > 0.0;
+        };
+        let first_visible = match (
+            predicates::orientation(origin, points[1], point),
+            predicates::orientation(origin, points[n - 1], point),
+        ) {
+            (Ordering::Less, _) => 0,
+            (_, Ordering::Greater) => n - 1,
+            // On the line through an edge at the first corner, but outside
+            // of the edge
+            (Ordering::Equal, _) if beyond(origin, points[1]) => 1,
+            (Ordering::Equal, _) => n - 1,
+            (_, Ordering::Equal) if beyond(origin, points[n - 1]) => n - 2,
+            (_, Ordering::Equal) => 0,
+            _ => {
+                let (mut lo, mut hi) = (1, n - 1);
+                while hi - lo > 1 {
+                    let mid = (lo + hi) / 2;
+                    if predicates::orientation(origin, points[mid], point) == Ordering::Greater {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                lo
+            }
+        };
+
+        // An invisible edge: One of the edges at the corner which is extreme
+        // in the direction from the point towards an interior point.
+        let interior = [
+            (points[0][0] + points[1][0] + points[2][0]) / 3.0,
+            (points[0][1] + points[1][1] + points[2][1]) / 3.0,
+        ];
+        let far = self
+            .extreme_index([interior[0] - point[0], interior[1] - point[1]])
+            .unwrap_or(0);
+        let first_invisible = [far, far + n - 1]
+            .into_iter()
+            .find(|edge| !visible(*edge))
+            .or_else(|| (0..n).find(|edge| !visible(*edge)))?
+            % n;
+
+        // Number of edges from `start` on for which `predicate` holds,
+        // assuming that it holds for a prefix of the `count` edges.
+        let prefix = |start: usize, count: usize, predicate: &dyn Fn(usize) -> bool| {
+            let (mut lo, mut hi) = (0, count);
+            while lo < hi {
+                let mid = (lo + hi) / 2;
+                if predicate(start + mid) {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            return lo;
+        };
+        let to_invisible = (first_invisible + n - first_visible) % n;
+        let to_visible = (first_visible + n - first_invisible) % n;
+        let right = (first_visible + prefix(first_visible, to_invisible, &visible)) % n;
+        let left =
+            (first_invisible + prefix(first_invisible, to_visible, &|edge| !visible(edge))) % n;
+        return Some([corner(left), corner(right)]);
+    }
+
+    /**
+    Returns the two corners of the hull which are farthest apart together with
+    their distance (the diameter of the point set), or `None` for an empty
+    hull. The corners are in counter-clockwise order of the hull; for a hull
+    with a single corner, both are this corner.
+
+    The farthest pair admits two parallel supporting lines (it is an antipodal
+    pair). These pairs are enumerated by rotating calipers in O(h) time instead
+    of checking all O(h²) pairs of corners.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [4.0, 0.0], [4.0, 3.0], [1.0, 2.0], [0.0, 1.0]]);
+    let ([a, b], distance) = hull.diameter().unwrap();
+    assert_eq!((a.1, b.1), ([4.0, 3.0], [0.0, 0.0]));
+    assert_eq!(distance, 5.0);
+    ```
+     */
+    #[allow(clippy::type_complexity)]
+    pub fn diameter(&self) -> Option<([(usize, [f64; 2]); 2], f64)> {
+        let points = &self.points;
+        let distance = |[i, j]: [usize; 2]| {
+            return (points[j][0] - points[i][0]).hypot(points[j][1] - points[i][1]);
+        };
+        let [i, j] = calipers::antipodal_pairs(points)
+            .into_iter()
+            .max_by(|a, b| distance(*a).total_cmp(&distance(*b)))
+            .or_else(|| (!points.is_empty()).then_some([0, 0]))?;
+        return Some((
+            [(self.keys[i], points[i]), (self.keys[j], points[j])],
+            distance([i, j]),
+        ));
+    }
+
+    /**
+    Classifies every point of `points` with [`Hull::locate`] and returns the
+    locations in the same order, e.g. for testing millions of samples against
+    a hull per frame. Nonreal points are classified as
+    [`PointLocation::Outside`].
+
+    When the `rayon` feature is enabled, the points are classified in
+    parallel. Since the classification of each point is exact and independent
+    of the others, the result is identical in both cases.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::{Hull, PointLocation};
+
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    let samples = [[1.0, 1.0], [2.0, 1.0], [3.0, 1.0]];
+    assert_eq!(
+        hull.locate_all(&samples),
+        vec![PointLocation::Inside, PointLocation::OnBoundary, PointLocation::Outside]
+    );
+    ```
+     */
+    pub fn locate_all(&self, points: &[[f64; 2]]) -> Vec<PointLocation> {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            return points.par_iter().map(|point| self.locate(*point)).collect();
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            return points.iter().map(|point| self.locate(*point)).collect();
+        }
+    }
+}
+
+impl Hull {
+    /**
+    Encodes the hull with [postcard](https://docs.rs/postcard), which stores
+    keys as variable-length integers. Only available with the `postcard`
+    feature flag enabled.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+    use planar_convex_hull::hull::DecodeError;
+
+    let hull = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.1, 0.1]]);
+    let bytes = hull.to_postcard();
+    assert_eq!(Hull::from_postcard(&bytes), Ok(hull));
+    assert_eq!(Hull::from_postcard(b"not a hull"), Err(DecodeError::InvalidMagic));
+    ```
+     */
+    #[cfg(feature = "postcard")]
+    pub fn to_postcard(&self) -> Vec<u8> {
+        let bytes = header(Encoding::Postcard);
+        return postcard::to_extend(self, bytes).expect("encoding into a Vec cannot fail");
+    }
+
+    /**
+    Decodes a hull encoded by [`Hull::to_postcard`]. Only available with the
+    `postcard` feature flag enabled.
+     */
+    #[cfg(feature = "postcard")]
+    pub fn from_postcard(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let payload = payload(bytes, Encoding::Postcard)?;
+        let (data, rest): (HullData, &[u8]) = postcard::take_from_bytes(payload)
+            .map_err(|error| DecodeError::Malformed(error.to_string()))?;
+        if !rest.is_empty() {
+            return Err(DecodeError::Malformed(format!(
+                "{} trailing bytes",
+                rest.len()
+            )));
+        }
+        return Hull::try_from(data).map_err(DecodeError::InvalidHull);
+    }
+
+    /**
+    Encodes the hull with [bincode](https://docs.rs/bincode) in its standard
+    configuration. Only available with the `bincode` feature flag enabled.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+    use planar_convex_hull::hull::DecodeError;
+
+    let hull = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.1, 0.1]]);
+    let bytes = hull.to_bincode();
+    assert_eq!(Hull::from_bincode(&bytes), Ok(hull));
+    ```
+     */
+    #[cfg(feature = "bincode")]
+    pub fn to_bincode(&self) -> Vec<u8> {
+        let mut bytes = header(Encoding::Bincode);
+        bincode::serde::encode_into_std_write(self, &mut bytes, bincode::config::standard())
+            .expect("encoding into a Vec cannot fail");
+        return bytes;
+    }
+
+    /**
+    Decodes a hull encoded by [`Hull::to_bincode`]. Only available with the
+    `bincode` feature flag enabled.
+     */
+    #[cfg(feature = "bincode")]
+    pub fn from_bincode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let payload = payload(bytes, Encoding::Bincode)?;
+        let (data, read): (HullData, usize) =
+            bincode::serde::decode_from_slice(payload, bincode::config::standard())
+                .map_err(|error| DecodeError::Malformed(error.to_string()))?;
+        if read != payload.len() {
+            return Err(DecodeError::Malformed(format!(
+                "{} trailing bytes",
+                payload.len() - read
+            )));
+        }
+        return Hull::try_from(data).map_err(DecodeError::InvalidHull);
+    }
+}
+
+/// Two hulls are equal if they have the same corners (keys and points) in the
+/// same order. The [`Degeneracy`] of their inputs is not compared, so hulls of
+/// different collections with the same corners are equal.
+impl PartialEq for Hull {
+    fn eq(&self, other: &Self) -> bool {
+        return self.keys == other.keys && self.points == other.points;
+    }
+}
+
+impl Default for Hull {
+    /// Returns an empty hull (the hull of a collection without real points).
+    fn default() -> Self {
+        return Self::from_corners(Vec::new(), Vec::new());
+    }
+}
+
+/// The extreme corners found in step 1 of the divide-and-conquer algorithm
+/// (see [`Hull::leftmost`]) are kept, so that they are available in O(1).
+impl From<ConvexHullIter> for Hull {
+    fn from(value: ConvexHullIter) -> Self {
+        let extremes = value.extremes();
+        let (keys, points): (Vec<usize>, Vec<[f64; 2]>) = value.unzip();
+        let mut hull = Self::from_corners(keys, points);
+        if let Some(extremes) = extremes {
+            let mut positions = [None; 4];
+            for (position, point) in hull.points.iter().enumerate() {
+                for (extreme, extreme_position) in extremes.iter().zip(positions.iter_mut()) {
+                    if bits(*extreme) == bits(*point) {
+                        *extreme_position = Some(position);
+                    }
+                }
+            }
+            if let [Some(leftmost), Some(bottom), Some(rightmost), Some(top)] = positions {
+                hull.extremes = Some([leftmost, bottom, rightmost, top]);
+            }
+        }
+        return hull;
+    }
+}
+
+impl ConvexHull for Hull {
+    fn convex_hull_iter(&self) -> impl Iterator<Item = (usize, [f64; 2])> {
+        return self.iter();
+    }
+}
+
+/**
+The convex hull of a collection together with a reference to the collection,
+so that its corners can be resolved to the items of the collection directly.
+
+The owned [`Hull`] only stores the keys and points of its corners and can
+outlive the collection (e.g. for serialization or for combining hulls of
+shards). A [`HullView`] borrows the collection instead, which guarantees that
+the keys refer to it. All geometry operations of [`Hull`] are available
+through [`Deref`](std::ops::Deref).
+
+# Examples
+
+```
+use planar_convex_hull::{HullView, Point2D, Points};
+
+struct City {
+    name: &'static str,
+    position: [f64; 2],
+}
+
+impl Point2D for City {
+    fn x(&self) -> f64 {
+        return self.position[0];
+    }
+
+    fn y(&self) -> f64 {
+        return self.position[1];
+    }
+}
+
+let cities = vec![
+    City { name: "A", position: [0.0, 0.0] },
+    City { name: "B", position: [4.0, 0.0] },
+    City { name: "C", position: [1.0, 1.0] },
+    City { name: "D", position: [2.0, 3.0] },
+];
+let points = Points(&cities);
+let hull = HullView::new(&points);
+let names: Vec<&str> = hull.items().map(|city| city.name).collect();
+assert_eq!(names, vec!["B", "D", "A"]);
+assert_eq!(hull.item(1).name, "D");
+
+// Geometry operations of the owned hull
+assert_eq!(hull.area(), 6.0);
+```
+ */
+#[derive(Debug, Clone)]
+pub struct HullView<'a, T: ?Sized> {
+    collection: &'a T,
+    hull: Hull,
+}
+
+impl<'a, T: ConvexHull + ?Sized> HullView<'a, T> {
+    /// Calculates the hull of `collection` like [`Hull::new`] and keeps a
+    /// reference to `collection`.
+    pub fn new(collection: &'a T) -> Self {
+        return Self {
+            collection,
+            hull: Hull::new(collection),
+        };
+    }
+
+    /// Returns the collection the hull was calculated from.
+    pub fn collection(&self) -> &'a T {
+        return self.collection;
+    }
+
+    /// Returns the owned hull.
+    pub fn hull(&self) -> &Hull {
+        return &self.hull;
+    }
+
+    /// Returns the owned hull, which no longer borrows the collection.
+    pub fn into_hull(self) -> Hull {
+        return self.hull;
+    }
+}
+
+impl<'a, T: ConvexHull + std::ops::Index<usize> + ?Sized> HullView<'a, T> {
+    /// Returns the item of the collection at the corner with the given
+    /// `position` in counter-clockwise order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` is not smaller than the number of corners.
+    pub fn item(&self, position: usize) -> &'a T::Output {
+        return &self.collection[self.hull.keys()[position]];
+    }
+
+    /// Returns the items of the collection at the corners of the hull in
+    /// counter-clockwise order.
+    pub fn items(&self) -> impl ExactSizeIterator<Item = &'a T::Output> + '_ {
+        let collection = self.collection;
+        return self.hull.keys().iter().map(move |key| &collection[*key]);
+    }
+}
+
+impl<'a, T: ?Sized> std::ops::Deref for HullView<'a, T> {
+    type Target = Hull;
+
+    fn deref(&self) -> &Hull {
+        return &self.hull;
+    }
+}
@@ -0,0 +1,286 @@
+//! Intersection of two hulls, see [`Hull::intersection`].
+//!
+//! Both hulls are split into an upper and a lower chain, which are concave
+//! and convex functions of x. The intersection is the region between the
+//! lower envelope of the upper chains and the upper envelope of the lower
+//! chains. All chains are sorted by x, so they are evaluated by merging their
+//! breakpoints in linear time \[1\].
+//!
+//! # Literature
+//!
+//! 1. Shamos, M. I., Hoey, D.: Geometric intersection problems. 17th Annual
+//!    Symposium on Foundations of Computer Science, 208–215 (1976).
+//!    <https://doi.org/10.1109/SFCS.1976.16>
+
+use std::cmp::Ordering;
+
+use crate::Hull;
+use crate::predicates::orientation;
+
+/**
+Upper and lower chain of the hull corners `points` (counter-clockwise), both
+sorted by increasing x. Vertical edges at the left and right end are omitted,
+so both chains are functions of x on the same range.
+ */
+fn chains(points: &[[f64; 2]]) -> [Vec<[f64; 2]>; 2] {
+    // Start at the rightmost corner with the largest y-value
+    let n = points.len();
+    let start = (0..n)
+        .max_by(|a, b| {
+            let (p, q) = (points[*a], points[*b]);
+            return p[0].total_cmp(&q[0]).then(p[1].total_cmp(&q[1]));
+        })
+        .unwrap_or(0);
+    let mut points = points.to_vec();
+    points.rotate_left(start);
+
+    let min = points.iter().map(|p| p[0]).fold(f64::INFINITY, f64::min);
+    let max = points[0][0];
+    let top_left = points.iter().position(|p| p[0] == min).unwrap_or(0);
+    let bottom_left = points.iter().rposition(|p| p[0] == min).unwrap_or(0);
+
+    let mut upper = points[..=top_left].to_vec();
+    upper.reverse();
+    let mut lower = points[bottom_left..].to_vec();
+    if points[n - 1][0] != max {
+        lower.push(points[0]);
+    }
+    return [upper, lower];
+}
+
+/// Merges two vectors sorted by `total_cmp` into one.
+fn merge(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if j == b.len() || (i < a.len() && a[i] <= b[j]) {
+            merged.push(a[i]);
+            i += 1;
+        } else {
+            merged.push(b[j]);
+            j += 1;
+        }
+    }
+    return merged;
+}
+
+/// Piecewise linear function given by a chain, evaluated at nondecreasing
+/// positions.
+struct Cursor<'a> {
+    chain: &'a [[f64; 2]],
+    index: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(chain: &'a [[f64; 2]]) -> Self {
+        return Self { chain, index: 0 };
+    }
+
+    fn at(&mut self, x: f64) -> f64 {
+        let chain = self.chain;
+        if chain.len() == 1 {
+            return chain[0][1];
+        }
+        while self.index + 2 < chain.len() && chain[self.index + 1][0] <= x {
+            self.index += 1;
+        }
+        let (a, b) = (chain[self.index], chain[self.index + 1]);
+        if x <= a[0] {
+            return a[1];
+        }
+        if x >= b[0] {
+            return b[1];
+        }
+        return a[1] + (b[1] - a[1]) * (x - a[0]) / (b[0] - a[0]);
+    }
+}
+
+/// Relative position in `[0, 1]` where the linear function with the values
+/// `start` and `end` crosses zero, if it changes its sign in between.
+fn crossing(start: f64, end: f64) -> Option<f64> {
+    if (start < 0.0 && end > 0.0) || (start > 0.0 && end < 0.0) {
+        return Some(start / (start - end));
+    }
+    return None;
+}
+
+/**
+Turns the counter-clockwise boundary `points` of a convex polygon, which
+starts at a corner and may contain duplicate or collinear points, into a hull
+in O(k). The corners are rotated to the starting point convention of
+[`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull) and their
+positions are used as keys.
+ */
+fn from_boundary(points: Vec<[f64; 2]>) -> Hull {
+    let mut corners: Vec<[f64; 2]> = Vec::with_capacity(points.len());
+    for point in points {
+        while corners.len() >= 2
+            && orientation(
+                corners[corners.len() - 2],
+                corners[corners.len() - 1],
+                point,
+            ) != Ordering::Greater
+        {
+            corners.pop();
+        }
+        if corners.last() != Some(&point) {
+            corners.push(point);
+        }
+    }
+    while corners.len() >= 3
+        && orientation(
+            corners[corners.len() - 2],
+            corners[corners.len() - 1],
+            corners[0],
+        ) != Ordering::Greater
+    {
+        corners.pop();
+    }
+    if corners.len() == 2 && corners[0] == corners[1] {
+        corners.pop();
+    }
+
+    let start = (0..corners.len())
+        .max_by(|a, b| {
+            let (p, q) = (corners[*a], corners[*b]);
+            return p[0].total_cmp(&q[0]).then(p[1].total_cmp(&q[1]));
+        })
+        .unwrap_or(0);
+    corners.rotate_left(start);
+    return Hull::from_vertices_unchecked(corners.into_iter().enumerate());
+}
+
+/**
+Calculates the intersection of the convex polygons `a` and `b` (corner points
+in counter-clockwise order, at least three of them each) in O(n + m), see
+[`Hull::intersection`]. It is also used by [`iou`](crate::polygon::iou).
+ */
+pub(crate) fn convex_intersection(a: &[[f64; 2]], b: &[[f64; 2]]) -> Hull {
+    let [upper_a, lower_a] = chains(a);
+    let [upper_b, lower_b] = chains(b);
+    let lo = upper_a[0][0].max(upper_b[0][0]);
+    let hi = upper_a[upper_a.len() - 1][0].min(upper_b[upper_b.len() - 1][0]);
+    if lo > hi {
+        return from_boundary(Vec::new());
+    }
+
+    // Breakpoints of all chains within the common range
+    let xs = |chain: &[[f64; 2]]| -> Vec<f64> {
+        return chain
+            .iter()
+            .map(|p| p[0])
+            .filter(|x| *x > lo && *x < hi)
+            .collect();
+    };
+    let mut breakpoints = merge(
+        &merge(&xs(&upper_a), &xs(&upper_b)),
+        &merge(&xs(&lower_a), &xs(&lower_b)),
+    );
+    breakpoints.insert(0, lo);
+    breakpoints.push(hi);
+    breakpoints.dedup();
+
+    // Where the upper or the lower chains cross each other, the
+    // envelopes have additional breakpoints
+    let mut cursors = [&upper_a, &upper_b, &lower_a, &lower_b].map(|c| Cursor::new(c));
+    let mut values = |x: f64| cursors.each_mut().map(|cursor| cursor.at(x));
+    let mut refined = vec![lo];
+    let mut previous = values(lo);
+    for window in breakpoints.windows(2) {
+        let (x0, x1) = (window[0], window[1]);
+        let current = values(x1);
+        let mut extra: Vec<f64> = [(0, 1), (2, 3)]
+            .into_iter()
+            .filter_map(|(i, j)| {
+                return crossing(previous[i] - previous[j], current[i] - current[j]);
+            })
+            .map(|t| x0 + t * (x1 - x0))
+            .filter(|x| *x > x0 && *x < x1)
+            .collect();
+        extra.sort_by(f64::total_cmp);
+        refined.extend(extra);
+        refined.push(x1);
+        previous = current;
+    }
+    refined.dedup();
+
+    // The envelopes are linear between the refined breakpoints, so the
+    // intersection ends where their difference changes its sign.
+    let mut cursors = [&upper_a, &upper_b, &lower_a, &lower_b].map(|c| Cursor::new(c));
+    let mut envelopes = |x: f64| {
+        let [ua, ub, la, lb] = cursors.each_mut().map(|cursor| cursor.at(x));
+        return [ua.min(ub), la.max(lb)];
+    };
+    let mut lower = Vec::new();
+    let mut upper = Vec::new();
+    let mut previous: Option<(f64, [f64; 2])> = None;
+    for x in refined {
+        let [top, bottom] = envelopes(x);
+        if let Some((x0, [top0, bottom0])) = previous
+            && let Some(t) = crossing(top0 - bottom0, top - bottom)
+        {
+            let y = bottom0 + t * (bottom - bottom0);
+            let crossing = [x0 + t * (x - x0), y];
+            lower.push(crossing);
+            upper.push(crossing);
+        }
+        if top >= bottom {
+            lower.push([x, bottom]);
+            upper.push([x, top]);
+        }
+        previous = Some((x, [top, bottom]));
+    }
+
+    upper.reverse();
+    lower.extend(upper);
+    return from_boundary(lower);
+}
+
+impl Hull {
+    /**
+    Calculates the intersection of the hull and `other`, i.e. the convex
+    polygon of all points contained in both hulls. If the hulls only touch,
+    the intersection degenerates to a segment or a point, and if they are
+    disjoint, it is empty.
+
+    Both hulls are split into their upper and lower chains, whose breakpoints
+    are merged by x-coordinate, so the intersection is calculated in
+    O(n + m) time. Since most corners of the result are new points, the keys
+    of the result are the positions of its corners, starting at zero.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let square = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    let diamond = Hull::new(&[[2.0, 0.0], [3.0, 1.0], [2.0, 2.0], [1.0, 1.0]]);
+    let intersection = square.intersection(&diamond);
+    assert_eq!(intersection.points(), &[[2.0, 2.0], [1.0, 1.0], [2.0, 0.0]]);
+
+    let far_away = Hull::new(&[[5.0, 5.0], [6.0, 5.0], [5.0, 6.0]]);
+    assert!(square.intersection(&far_away).is_empty());
+    ```
+     */
+    pub fn intersection(&self, other: &Hull) -> Hull {
+        let (a, b) = (self.points(), other.points());
+        if a.is_empty() || b.is_empty() {
+            return from_boundary(Vec::new());
+        }
+        if a.len() < 3 || b.len() < 3 {
+            // Clip the segment (or point) to the other hull, which is more
+            // robust than the chains of a polygon without area
+            let (clipped, hull) = if a.len() <= b.len() {
+                (a, other)
+            } else {
+                (b, self)
+            };
+            let (p, q) = (clipped[0], clipped[clipped.len() - 1]);
+            return match hull.clip_segment(p, q) {
+                Some([start, end]) => from_boundary(vec![start, end]),
+                None => from_boundary(Vec::new()),
+            };
+        }
+        return convex_intersection(a, b);
+    }
+}
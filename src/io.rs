@@ -0,0 +1,134 @@
+//! This module contains helpers for reading point sets from files. It is only
+//! available with the `csv` feature flag enabled.
+
+use std::io::Read;
+
+/// Errors which can occur in [`from_csv_reader`].
+#[derive(Debug)]
+pub enum CsvError {
+    /// The underlying reader failed or the data is not valid CSV.
+    Csv(csv::Error),
+    /// A row has fewer columns than required.
+    MissingColumn {
+        /// One-based line number of the row.
+        line: u64,
+        /// Zero-based index of the missing column.
+        column: usize,
+        /// Number of columns of the row.
+        len: usize,
+    },
+    /// A field could not be parsed as a floating-point number.
+    InvalidNumber {
+        /// One-based line number of the row.
+        line: u64,
+        /// Zero-based index of the column.
+        column: usize,
+        /// Content of the field.
+        field: String,
+    },
+}
+
+impl std::fmt::Display for CsvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CsvError::Csv(error) => write!(f, "{error}"),
+            CsvError::MissingColumn { line, column, len } => write!(
+                f,
+                "line {line}: expected at least {} columns to read column {column}, found {len}",
+                column + 1
+            ),
+            CsvError::InvalidNumber {
+                line,
+                column,
+                field,
+            } => write!(f, "line {line}, column {column}: {field:?} is not a number"),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CsvError::Csv(error) => return Some(error),
+            _ => return None,
+        }
+    }
+}
+
+impl From<csv::Error> for CsvError {
+    fn from(value: csv::Error) -> Self {
+        return CsvError::Csv(value);
+    }
+}
+
+/**
+Reads points from comma-separated values, taking the x-coordinates from the
+column `x_col` and the y-coordinates from the column `y_col` (zero-based
+indices). Other columns are ignored, and rows may have different lengths.
+
+If neither field of the first row can be parsed as a number, the row is
+treated as a header and skipped. Empty lines are skipped as well. Leading and
+trailing whitespace of the fields is ignored. Any other row which does not
+contain two valid numbers results in an error which names the offending line
+and column.
+
+# Examples
+
+```
+use planar_convex_hull::ConvexHull;
+use planar_convex_hull::io::from_csv_reader;
+
+let data = "id,x,y\n0,0.0,0.0\n1,1.0,0.0\n2, 0.0, 1.0\n3,0.2,0.2\n";
+let points = from_csv_reader(data.as_bytes(), 1, 2).unwrap();
+assert_eq!(points, vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.2, 0.2]]);
+assert_eq!(points.convex_hull().count(), 3);
+
+let error = from_csv_reader("x,y\n1.0,2.0\n1.0,a\n".as_bytes(), 0, 1).unwrap_err();
+assert_eq!(error.to_string(), "line 3, column 1: \"a\" is not a number");
+```
+ */
+pub fn from_csv_reader(
+    reader: impl Read,
+    x_col: usize,
+    y_col: usize,
+) -> Result<Vec<[f64; 2]>, CsvError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(reader);
+
+    let mut points = Vec::new();
+    for (row, record) in reader.records().enumerate() {
+        let record = record?;
+        let line = record
+            .position()
+            .map(|position| position.line())
+            .unwrap_or(row as u64 + 1);
+
+        let parse = |column: usize| -> Result<f64, CsvError> {
+            let field = record.get(column).ok_or(CsvError::MissingColumn {
+                line,
+                column,
+                len: record.len(),
+            })?;
+            return field.parse::<f64>().map_err(|_| CsvError::InvalidNumber {
+                line,
+                column,
+                field: field.to_string(),
+            });
+        };
+
+        match (parse(x_col), parse(y_col)) {
+            (Ok(x), Ok(y)) => points.push([x, y]),
+            (Err(CsvError::InvalidNumber { .. }), Err(CsvError::InvalidNumber { .. }))
+                if row == 0 =>
+            {
+                // Header (a first row with only one number is an error)
+                continue;
+            }
+            (Err(error), _) | (_, Err(error)) => return Err(error),
+        }
+    }
+    return Ok(points);
+}
@@ -0,0 +1,140 @@
+//! This module contains Jarvis' march (gift wrapping) \[1\], an
+//! output-sensitive convex hull algorithm which runs in O(nh) and does not
+//! allocate any memory. It is used by
+//! [`ConvexHull::convex_hull_into`](crate::ConvexHull::convex_hull_into) and
+//! [`ConvexHull::convex_hull_jarvis`](crate::ConvexHull::convex_hull_jarvis).
+//!
+//! # Literature
+//!
+//! 1. Jarvis, R. A.: On the identification of the convex hull of a finite set
+//!    of points in the plane. Information Processing Letters 2, 18–21 (1973).
+//!    <https://doi.org/10.1016/0020-0190(73)90020-3>
+
+use std::cmp::Ordering;
+
+use crate::ConvexHull;
+use crate::predicates::{compare_along_ray, orientation};
+
+/**
+Error returned by
+[`ConvexHull::convex_hull_into`](crate::ConvexHull::convex_hull_into) if the
+provided buffer is too small for the hull.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError {
+    /// Number of hull points, i.e. the required buffer length.
+    pub required: usize,
+    /// Length of the provided buffer.
+    pub capacity: usize,
+}
+
+impl std::fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the hull has {} points, but the buffer only holds {}",
+            self.required, self.capacity
+        )
+    }
+}
+
+impl std::error::Error for CapacityError {}
+
+/**
+Wraps the points of `collection` with Jarvis' march using exact orientation
+predicates and calls `visit` with each hull point in the order of
+[`ConvexHull::convex_hull`]. Of multiple identical points, the first one is
+visited.
+
+The march takes at most as many steps as there are finite points, so it
+terminates even if the predicates cannot be evaluated exactly (see the
+[`predicates`](crate::predicates) module), e.g. if they would close a cycle
+which does not contain the start point.
+ */
+fn march<T: ConvexHull + ?Sized>(collection: &T, mut visit: impl FnMut((usize, [f64; 2]))) {
+    let finite = |point: &[f64; 2]| point[0].is_finite() && point[1].is_finite();
+
+    // Start with the largest x-value (and the largest y-value for ties)
+    let mut start: Option<(usize, [f64; 2])> = None;
+    let mut finite_points = 0;
+    for (key, point) in collection.convex_hull_iter() {
+        if !finite(&point) {
+            continue;
+        }
+        finite_points += 1;
+        match start {
+            Some((_, start_point)) if (point[0], point[1]) <= (start_point[0], start_point[1]) => {}
+            _ => start = Some((key, point)),
+        }
+    }
+    let Some(start) = start else {
+        return;
+    };
+
+    let mut current = start;
+    for _ in 0..finite_points {
+        visit(current);
+
+        // The next hull point is the one which has all other points on its
+        // left. Of multiple collinear candidates, the farthest one is taken.
+        let mut next: Option<(usize, [f64; 2])> = None;
+        for (key, point) in collection.convex_hull_iter() {
+            if !finite(&point) || point == current.1 {
+                continue;
+            }
+            match next {
+                None => next = Some((key, point)),
+                Some((_, next_point)) => match orientation(current.1, next_point, point) {
+                    Ordering::Less => next = Some((key, point)),
+                    Ordering::Equal => {
+                        if compare_along_ray(current.1, point, next_point) == Ordering::Greater {
+                            next = Some((key, point));
+                        }
+                    }
+                    Ordering::Greater => (),
+                },
+            }
+        }
+
+        match next {
+            Some(next) if next.1 != start.1 => current = next,
+            _ => break,
+        }
+    }
+}
+
+/**
+Calculates the convex hull of `collection` with Jarvis' march using exact
+orientation predicates and writes the keys of the hull points into `keys`. The
+hull follows the conventions of [`ConvexHull::convex_hull`]. Returns the number
+of hull points, or an error with the required length if `keys` is too short.
+ */
+pub(crate) fn jarvis_into<T: ConvexHull + ?Sized>(
+    collection: &T,
+    keys: &mut [usize],
+) -> Result<usize, CapacityError> {
+    let mut count = 0;
+    march(collection, |(key, _)| {
+        if count < keys.len() {
+            keys[count] = key;
+        }
+        count += 1;
+    });
+    if count > keys.len() {
+        return Err(CapacityError {
+            required: count,
+            capacity: keys.len(),
+        });
+    }
+    return Ok(count);
+}
+
+/**
+Calculates the convex hull of `collection` with Jarvis' march, see
+[`ConvexHull::convex_hull_jarvis`].
+ */
+pub(crate) fn jarvis<T: ConvexHull + ?Sized>(collection: &T) -> Vec<(usize, [f64; 2])> {
+    let mut hull = Vec::new();
+    march(collection, |corner| hull.push(corner));
+    return hull;
+}
@@ -0,0 +1,211 @@
+//! The marriage-before-conquest algorithm of Kirkpatrick and Seidel \[1\], see
+//! [`ConvexHull::convex_hull_kirkpatrick_seidel`](crate::ConvexHull::convex_hull_kirkpatrick_seidel).
+//! The bridges are found as the solutions of two-dimensional linear programs
+//! with the randomized incremental algorithm of Seidel \[2\].
+//!
+//! # Literature
+//!
+//! 1. Kirkpatrick, D. G., Seidel, R.: The ultimate planar convex hull
+//!    algorithm? SIAM Journal on Computing 15, 287–299 (1986).
+//!    <https://doi.org/10.1137/0215021>
+//! 2. Seidel, R.: Small-dimensional linear programming and convex hulls made
+//!    easy. Discrete & Computational Geometry 6, 423–434 (1991).
+//!    <https://doi.org/10.1007/BF02574699>
+
+use std::cmp::Ordering;
+
+use crate::generators::Rng;
+use crate::monotone_chain::monotone_chain;
+use crate::predicates::orientation;
+
+/**
+Returns the corner of `candidates` which has all `candidates` on the right of
+the line through it and `point` (or on the line): If `towards` is `true`, the
+line is directed towards the corner, otherwise it is directed towards `point`.
+ */
+fn tangent(
+    points: &[(usize, [f64; 2])],
+    point: usize,
+    candidates: &[usize],
+    towards: bool,
+) -> usize {
+    let mut best = candidates[0];
+    for candidate in candidates.iter().copied() {
+        let above = if towards {
+            orientation(points[point].1, points[best].1, points[candidate].1)
+        } else {
+            orientation(points[best].1, points[point].1, points[candidate].1)
+        };
+        if above == Ordering::Greater {
+            best = candidate;
+        }
+    }
+    return best;
+}
+
+/**
+Finds the bridge over the gap between `left` and `right`, i.e. the edge of
+the upper hull of both sets which connects them. All points of `left` must have
+smaller x-values than all points of `right`. If the bridge contains further
+points, its ends are the leftmost point of `left` and the rightmost point of
+`right` on it, and of identical points the one with the smallest index is
+returned.
+
+The bridge is the line above all points whose height at the gap is minimal.
+The points are added in random order and whenever a point is located above
+the current bridge, the new bridge runs through it, which takes expected
+O(n) time in total.
+ */
+fn bridge(
+    points: &[(usize, [f64; 2])],
+    left: &[usize],
+    right: &[usize],
+    rng: &mut Rng,
+) -> (usize, usize) {
+    let point = |index: usize| points[index].1;
+    let mut order: Vec<(bool, usize)> = left
+        .iter()
+        .map(|index| (true, *index))
+        .chain(right.iter().map(|index| (false, *index)))
+        .collect();
+    for i in (1..order.len()).rev() {
+        order.swap(i, rng.index(i + 1));
+    }
+
+    let (mut p, mut q) = (left[0], right[0]);
+    let (mut seen_left, mut seen_right) = (vec![p], vec![q]);
+    for (is_left, r) in order {
+        if r == left[0] || r == right[0] {
+            continue;
+        }
+        if orientation(point(p), point(q), point(r)) == Ordering::Greater {
+            if is_left {
+                p = r;
+                q = tangent(points, r, &seen_right, true);
+            } else {
+                q = r;
+                p = tangent(points, r, &seen_left, false);
+            }
+        }
+        if is_left {
+            seen_left.push(r);
+        } else {
+            seen_right.push(r);
+        }
+    }
+
+    // Extend the bridge to the outermost points on it
+    let (a, b) = (point(p), point(q));
+    let outermost = |candidates: &[usize], outer: Ordering| {
+        return candidates
+            .iter()
+            .copied()
+            .filter(|index| orientation(a, b, point(*index)) == Ordering::Equal)
+            .min_by(|i, j| {
+                let order = point(*i)[0].total_cmp(&point(*j)[0]);
+                let order = if outer == Ordering::Less {
+                    order
+                } else {
+                    order.reverse()
+                };
+                return order.then(i.cmp(j));
+            });
+    };
+    return (
+        outermost(left, Ordering::Less).unwrap_or(p),
+        outermost(right, Ordering::Greater).unwrap_or(q),
+    );
+}
+
+/**
+Appends the corners of the upper hull between the corners `lo` and `hi` to
+`hull` (from left to right), where `candidates` contains all points with
+x-values between the ones of `lo` and `hi`.
+ */
+fn upper_hull(
+    points: &[(usize, [f64; 2])],
+    lo: usize,
+    hi: usize,
+    mut candidates: Vec<usize>,
+    rng: &mut Rng,
+    hull: &mut Vec<usize>,
+) {
+    let (a, b) = (points[lo].1, points[hi].1);
+    candidates.retain(|index| orientation(a, b, points[*index].1) == Ordering::Greater);
+    if candidates.is_empty() {
+        return;
+    }
+
+    // Split at the median x-value and connect both halves with their bridge
+    let median = candidates.len() / 2;
+    candidates.select_nth_unstable_by(median, |i, j| points[*i].1[0].total_cmp(&points[*j].1[0]));
+    let x = points[candidates[median]].1[0];
+    let (mut left, mut right): (Vec<usize>, Vec<usize>) = candidates
+        .into_iter()
+        .partition(|index| points[*index].1[0] <= x);
+    left.push(lo);
+    right.push(hi);
+    let (p, q) = bridge(points, &left, &right, rng);
+
+    // Only the points outside the bridge remain
+    let (px, qx) = (points[p].1[0], points[q].1[0]);
+    left.retain(|index| *index != lo && points[*index].1[0] < px);
+    right.retain(|index| *index != hi && points[*index].1[0] > qx);
+    upper_hull(points, lo, p, left, rng, hull);
+    if p != lo {
+        hull.push(p);
+    }
+    if q != hi {
+        hull.push(q);
+    }
+    upper_hull(points, q, hi, right, rng, hull);
+}
+
+/// Returns the indices of the corners of the upper hull of `points`.
+fn upper(points: &[(usize, [f64; 2])]) -> Vec<usize> {
+    // The upper hull runs from the topmost of the leftmost points to the
+    // topmost of the rightmost points.
+    let n = points.len();
+    let (Some(lo), Some(hi)) = (
+        (0..n).min_by(|i, j| {
+            let (a, b) = (points[*i].1, points[*j].1);
+            return a[0].total_cmp(&b[0]).then(b[1].total_cmp(&a[1]));
+        }),
+        (0..n).min_by(|i, j| {
+            let (a, b) = (points[*i].1, points[*j].1);
+            return b[0].total_cmp(&a[0]).then(b[1].total_cmp(&a[1]));
+        }),
+    ) else {
+        return Vec::new();
+    };
+
+    // A fixed seed keeps the result reproducible
+    let mut rng = Rng::new(0);
+    let mut hull = vec![lo, hi];
+    upper_hull(points, lo, hi, (0..n).collect(), &mut rng, &mut hull);
+    return hull;
+}
+
+/**
+Calculates the convex hull of `points` with the algorithm of Kirkpatrick and
+Seidel and returns its corners in the same order as
+[`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull). Collinear points
+on the hull edges and nonreal points are discarded, and of multiple identical
+points the first one is kept.
+
+The upper and the lower hull are calculated separately, where the lower hull
+is the upper hull of the points mirrored at the origin. Their corners are
+brought into order with the monotone chain algorithm.
+ */
+pub(crate) fn kirkpatrick_seidel(mut points: Vec<(usize, [f64; 2])>) -> Vec<(usize, [f64; 2])> {
+    points.retain(|(_, p)| p[0].is_finite() && p[1].is_finite());
+    let mirrored: Vec<(usize, [f64; 2])> = points
+        .iter()
+        .map(|(key, p)| (*key, [-p[0], -p[1]]))
+        .collect();
+    let mut corners = upper(&points);
+    corners.extend(upper(&mirrored));
+    corners.sort_unstable();
+    corners.dedup();
+    return monotone_chain(corners.into_iter().map(|index| points[index]).collect());
+}
@@ -0,0 +1,85 @@
+//! Onion peeling, see
+//! [`ConvexHull::convex_depth`](crate::ConvexHull::convex_depth).
+
+use std::collections::HashMap;
+
+use crate::ConvexHull;
+use crate::monotone_chain::monotone_chain;
+use crate::predicates::{on_segment, strictly_inside_convex};
+
+/// Returns the bit pattern of `point`, treating `-0.0` and `0.0` as equal.
+fn bits(point: [f64; 2]) -> [u64; 2] {
+    return [(point[0] + 0.0).to_bits(), (point[1] + 0.0).to_bits()];
+}
+
+fn squared_distance(a: [f64; 2], b: [f64; 2]) -> f64 {
+    return (b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2);
+}
+
+/**
+Peels the real points of `this` layer by layer and calls `visit` with the
+depth and the points of each layer.
+
+A layer consists of all remaining points on the boundary of the hull of the
+remaining points, including duplicates of the corners and points located on
+the edges. The points of a layer are in counter-clockwise order along the
+boundary, starting with the first corner according to the conventions of
+[`ConvexHull::convex_hull`]; duplicates of a corner follow the corner. The
+tests use exact predicates. Each layer takes O(n log n) time for the n
+remaining points.
+ */
+pub(crate) fn peel<T: ConvexHull + ?Sized>(
+    this: &T,
+    mut visit: impl FnMut(usize, Vec<(usize, [f64; 2])>),
+) {
+    let mut remaining: Vec<(usize, [f64; 2])> = this
+        .convex_hull_iter()
+        .filter(|(_, point)| point[0].is_finite() && point[1].is_finite())
+        .collect();
+
+    let mut depth = 0;
+    while !remaining.is_empty() {
+        let corners = monotone_chain(remaining.clone());
+        let n = corners.len();
+        let points: Vec<[f64; 2]> = corners.iter().map(|(_, point)| *point).collect();
+        let position: HashMap<[u64; 2], usize> = points
+            .iter()
+            .enumerate()
+            .map(|(i, point)| (bits(*point), i))
+            .collect();
+
+        // Points on each edge (from corner i to corner i + 1), including the
+        // corner i itself and its duplicates
+        let mut edges: Vec<Vec<(usize, [f64; 2])>> = vec![Vec::new(); n];
+        let mut inner = Vec::with_capacity(remaining.len());
+        for (key, point) in remaining {
+            if let Some(corner) = position.get(&bits(point)) {
+                edges[*corner].push((key, point));
+            } else if n < 3 {
+                // The remaining points are located on a segment
+                edges[0].push((key, point));
+            } else if strictly_inside_convex(&points, point) {
+                inner.push((key, point));
+            } else {
+                let edge = (0..n)
+                    .find(|i| on_segment(points[*i], points[(i + 1) % n], point))
+                    .unwrap_or(0);
+                edges[edge].push((key, point));
+            }
+        }
+
+        let mut layer = Vec::new();
+        for ((corner_key, corner), mut edge) in corners.into_iter().zip(edges) {
+            // The corner comes first, followed by its duplicates
+            edge.sort_by(|a, b| {
+                return squared_distance(corner, a.1)
+                    .total_cmp(&squared_distance(corner, b.1))
+                    .then((a.0 != corner_key).cmp(&(b.0 != corner_key)));
+            });
+            layer.extend(edge);
+        }
+        visit(depth, layer);
+        remaining = inner;
+        depth += 1;
+    }
+}
@@ -11,16 +11,99 @@ planar convex hull algorithm for your own datatype.
 
 use ordered_float::OrderedFloat;
 use std::cmp::Ordering;
-use std::collections::{BTreeMap, btree_map::IntoIter};
-use std::f64::INFINITY;
-use std::f64::NEG_INFINITY;
+use std::collections::{BTreeMap, HashMap};
 use std::ops::Bound::Excluded;
 use std::ops::Bound::Unbounded;
+use std::time::{Duration, Instant};
 
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
 
+pub mod anchors;
+pub mod annulus;
+mod approximate;
+pub mod batch;
+#[cfg(feature = "bevy")]
+pub mod bevy;
+pub mod calipers;
+pub mod certificate;
+mod chan;
+pub mod circles;
+mod clip;
 pub mod convex_hull_impl;
+pub mod descriptors;
+mod directional;
+pub mod discs;
+pub mod dynamic;
+pub mod external;
+pub mod generators;
+mod geometry;
+#[cfg(feature = "gpu")]
+pub mod gpu;
+mod graham;
+pub mod half_edge;
+pub mod halfspace;
+pub mod hull;
+mod intersection;
+#[cfg(feature = "csv")]
+pub mod io;
+pub mod jarvis;
+mod kirkpatrick_seidel;
+mod layers;
+pub mod locator;
+mod melkman;
+mod minkowski;
+mod monotone_chain;
+pub mod offset;
+pub mod online;
+pub mod options;
+pub mod orthogonal;
+#[cfg(feature = "parry2d")]
+pub mod parry;
+pub mod point2d;
+pub mod polygon;
+pub mod predicates;
+mod prefilter;
+pub mod prefix;
+pub mod primitives;
+mod proximity;
+mod quickhull;
+pub mod range;
+mod raster;
+#[cfg(feature = "proj")]
+pub mod reproject;
+mod simplify;
+pub mod stats;
+pub mod support;
+pub mod tangents;
+pub mod trace;
+#[cfg(feature = "cross-validation")]
+pub mod validation;
+mod warm_start;
+pub mod window;
+
+pub use anchors::AnchorError;
+pub use batch::convex_hulls;
+pub use calipers::{OrientedRectangle, Width};
+pub use certificate::{Certificate, CertificateError};
+pub use circles::Circle;
+pub use descriptors::{SecondMoments, ShapeDescriptors};
+pub use dynamic::DynConvexHull;
+pub use half_edge::{HalfEdge, HalfEdgeVertex, HalfEdges};
+pub use halfspace::Halfspace;
+pub use hull::{Degeneracy, Hull, HullError, HullView, PointLocation, validate_hull};
+pub use jarvis::CapacityError;
+pub use offset::Join;
+pub use options::{ConvexHullOptions, Normalization};
+pub use orthogonal::Quadrant;
+pub use point2d::{Point2D, Points};
+pub use stats::ConvexHullStats;
+pub use tangents::CommonTangents;
+pub use trace::{Trace, TraceEvent};
+
+/// Partial hull of a single quadrant, sorted by the (possibly sign-flipped)
+/// x-value of its points.
+type QuadrantHull = BTreeMap<OrderedFloat<f64>, (usize, [f64; 2])>;
 
 /**
 A trait for implementing a planar convex hull algorithm for a collection type.
@@ -156,14 +239,17 @@ pub trait ConvexHull: std::marker::Sync {
     below).
 
     When the `rayon` feature is enabled, the divide-and-conquer part of the
-    algorithm is parallelized.
+    algorithm is parallelized: The four quadrants are processed in parallel,
+    and within each quadrant, the points which can possibly become hull points
+    are filtered out of the input in parallel before the sequential hull
+    construction runs over them.
 
     # Literature
 
     1. Liu, Gh., Chen, Cb: A new algorithm for computing the convex hull of a planar point set.
-    J. Zhejiang Univ. - Sci. A 8, 1210–1217 (2007). [https://doi.org/10.1631/jzus.2007.A1210](https://doi.org/10.1631/jzus.2007.A1210)
+       J. Zhejiang Univ. - Sci. A 8, 1210–1217 (2007). [https://doi.org/10.1631/jzus.2007.A1210](https://doi.org/10.1631/jzus.2007.A1210)
     2. Saad, Omar: A Convex Hull Algorithm and its implementation in O(n log h) (2017).
-    See docs/convex_hull_algorithm.html.
+       See docs/convex_hull_algorithm.html.
 
     # Examples
     ```
@@ -230,502 +316,1656 @@ pub trait ConvexHull: std::marker::Sync {
     ```
      */
     fn convex_hull(&self) -> ConvexHullIter {
-        /*
-        Step 1: Identify the four point-pairs defining each quadrant.
-
-        We search for the points containing one extremum x- or y-value. The
-        quadrant borders are defined by the other value of the point. For
-        example, if the point with the largest x-value is [2, 1] and that with
-        the largest y-value is [1, 3], all points where x >= 1 and y >= 1 belong
-        to the q1 quadrant. Similarily, the q2 quadrant is defined by x <= 1 and
-        y >= 1, the q3 quadrant by x <= 1 and y <= 3, and the q4 quadrant by
-        x >= 1 and y <= 3.
-         */
-        let mut q1x: usize = usize::MAX;
-        let mut q1y: usize = usize::MAX;
-        let mut q2x: usize = usize::MAX;
-        let mut q2y: usize = usize::MAX;
-        let mut q3x: usize = usize::MAX;
-        let mut q3y: usize = usize::MAX;
-        let mut q4x: usize = usize::MAX;
-        let mut q4y: usize = usize::MAX;
-        let mut q1x_pt: [f64; 2] = [NEG_INFINITY, NEG_INFINITY];
-        let mut q1y_pt: [f64; 2] = [NEG_INFINITY, NEG_INFINITY];
-        let mut q2x_pt: [f64; 2] = [INFINITY, NEG_INFINITY];
-        let mut q2y_pt: [f64; 2] = [INFINITY, NEG_INFINITY];
-        let mut q3x_pt: [f64; 2] = [INFINITY, INFINITY];
-        let mut q3y_pt: [f64; 2] = [INFINITY, INFINITY];
-        let mut q4x_pt: [f64; 2] = [NEG_INFINITY, INFINITY];
-        let mut q4y_pt: [f64; 2] = [NEG_INFINITY, INFINITY];
-
-        // This variable is used to catch the special case of a collection
-        // having only one real point.
-        let mut num_real_points = 0;
-
-        for (idx, point) in self.convex_hull_iter() {
-            // Skip any non-real points
+        return divide_and_conquer(self, None, None);
+    }
+
+    /**
+    Calculates the convex hull for `self` like [`ConvexHull::convex_hull`] and
+    additionally returns the durations of the individual phases of the
+    algorithm (see [`ConvexHullStats`]).
+
+    This is meant for tuning (e.g. comparing builds with and without the
+    `rayon` feature on production data) without attaching a profiler. The hull
+    itself is identical to the one returned by [`ConvexHull::convex_hull`].
+
+    # Examples
+    ```
+    use planar_convex_hull::ConvexHull;
+
+    let slice = &[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0], [0.5, 0.5]];
+    let (hull, stats) = slice.convex_hull_with_stats();
+    assert_eq!(hull.count(), 4);
+    assert!(stats.extremes <= stats.total);
+    assert!(stats.quadrants.iter().all(|duration| *duration <= stats.total));
+    ```
+     */
+    fn convex_hull_with_stats(&self) -> (ConvexHullIter, ConvexHullStats) {
+        let mut stats = ConvexHullStats::default();
+        let hull = divide_and_conquer(self, Some(&mut stats), None);
+        return (hull, stats);
+    }
+
+    /**
+    Calculates the convex hull for `self` like [`ConvexHull::convex_hull`] and
+    additionally records every step of the algorithm (extremum points,
+    accepted and rejected candidates with their bracketing points and removed
+    points) in a [`Trace`].
+
+    This is meant for teaching the algorithm and for attaching the trace to
+    bug reports. Recording costs additional time and memory. If the `rayon`
+    feature is enabled, the parallel candidate filtering is skipped, so that
+    the trace contains every candidate. The hull itself is identical to the
+    one returned by [`ConvexHull::convex_hull`].
+
+    # Examples
+    ```
+    use planar_convex_hull::ConvexHull;
+    use planar_convex_hull::trace::TraceEvent;
+
+    let slice = &[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0], [0.5, 0.5]];
+    let (hull, trace) = slice.convex_hull_with_trace();
+    assert_eq!(hull.count(), 4);
+
+    // The extremum points of the four quadrants are the corners of the square
+    assert_eq!(
+        trace.events(),
+        &[
+            TraceEvent::Extreme { quadrant: 0, key: 3, point: [1.0, 1.0] },
+            TraceEvent::Extreme { quadrant: 1, key: 2, point: [0.0, 1.0] },
+            TraceEvent::Extreme { quadrant: 2, key: 0, point: [0.0, 0.0] },
+            TraceEvent::Extreme { quadrant: 3, key: 1, point: [1.0, 0.0] },
+        ]
+    );
+    assert_eq!(trace.to_string().lines().next(), Some("q1: extreme 3 [1.0, 1.0]"));
+    ```
+     */
+    fn convex_hull_with_trace(&self) -> (ConvexHullIter, Trace) {
+        let mut trace = Trace::default();
+        let hull = divide_and_conquer(self, None, Some(&mut trace));
+        return (hull, trace);
+    }
+
+    /**
+    Calculates the convex hull for `self` like [`ConvexHull::convex_hull`], but
+    guarantees that the result is exact.
+
+    The divide-and-conquer algorithm uses floating-point arithmetic for its
+    orientation tests, which can give wrong results for nearly collinear
+    points (e.g. a hull point very close to a hull edge might be discarded or a
+    slightly concave point might be kept). This function runs the fast
+    floating-point algorithm first and then validates its result with the
+    exact predicates of the [`predicates`] module:
+
+    1. The hull must be strictly convex.
+    2. All other points must be located strictly inside the hull (or coincide
+       with a hull point).
+
+    If the hull is not convex, it is recomputed from scratch with exact
+    arithmetic. Otherwise, only the points violating the second condition are
+    suspicious: The hull is then recomputed with exact arithmetic from the
+    floating-point hull points and the suspicious points, which is usually a
+    tiny subset of the input. Since the predicates themselves only fall back to
+    exact arithmetic for nearly degenerate configurations, the overall cost is
+    close to that of [`ConvexHull::convex_hull`] plus one validation pass over
+    the input.
+
+    The output follows the same conventions as [`ConvexHull::convex_hull`].
+
+    # Examples
+    ```
+    use planar_convex_hull::ConvexHull;
+
+    // The first point is located very slightly below the line y = x, hence
+    // the second point is part of the convex hull. The floating-point
+    // algorithm misses it.
+    let slice = &[[0.5000000000000001, 0.5], [12.0, 12.0], [24.0, 24.0], [18.0, -5.0]];
+    assert_eq!(slice.convex_hull().count(), 3);
+
+    let mut hull = slice.convex_hull_exact();
+    assert_eq!(hull.next(), Some((2, [24.0, 24.0])));
+    assert_eq!(hull.next(), Some((1, [12.0, 12.0])));
+    assert_eq!(hull.next(), Some((0, [0.5000000000000001, 0.5])));
+    assert_eq!(hull.next(), Some((3, [18.0, -5.0])));
+    assert_eq!(hull.next(), None);
+    ```
+     */
+    fn convex_hull_exact(&self) -> ConvexHullIter {
+        // The extremum points of the divide-and-conquer algorithm are found by
+        // exact comparisons, so they are corners of the exact hull as well.
+        let hull = self.convex_hull();
+        let extremes = hull.extremes();
+        let hull: Vec<(usize, [f64; 2])> = hull.collect();
+        let corners: Vec<[f64; 2]> = hull.iter().map(|(_, point)| *point).collect();
+        let n = corners.len();
+
+        // Step 1: If the floating-point hull is not strictly convex, it cannot
+        // be trusted at all.
+        let convex = n < 3
+            || (0..n).all(|i| {
+                predicates::orientation(corners[i], corners[(i + 1) % n], corners[(i + 2) % n])
+                    == Ordering::Greater
+            });
+        if !convex {
+            return ConvexHullIter::from_vec(monotone_chain::monotone_chain(
+                self.convex_hull_iter().collect(),
+            ))
+            .with_extremes(extremes);
+        }
+
+        // Step 2: Collect all points which are not confirmed to be inside the
+        // floating-point hull. Adding zero treats -0.0 and 0.0 as identical.
+        let bits = |point: &[f64; 2]| [(point[0] + 0.0).to_bits(), (point[1] + 0.0).to_bits()];
+        let corner_bits: std::collections::HashSet<[u64; 2]> = corners.iter().map(bits).collect();
+        let mut suspicious: Vec<(usize, [f64; 2])> = Vec::new();
+        for (key, point) in self.convex_hull_iter() {
             if !point[0].is_finite() || !point[1].is_finite() {
                 continue;
             }
-            num_real_points += 1;
+            if corner_bits.contains(&bits(&point)) {
+                continue;
+            }
+            let confirmed = match n {
+                0 | 1 => false,
+                2 => {
+                    // The point must be located on the line segment
+                    let [a, b] = [corners[0], corners[1]];
+                    predicates::orientation(a, b, point) == Ordering::Equal
+                        && a[0].min(b[0]) <= point[0]
+                        && point[0] <= a[0].max(b[0])
+                        && a[1].min(b[1]) <= point[1]
+                        && point[1] <= a[1].max(b[1])
+                }
+                _ => predicates::strictly_inside_convex(&corners, point),
+            };
+            if !confirmed {
+                suspicious.push((key, point));
+            }
+        }
 
-            // q1x
-            if let Some(cmp) = q1x_pt[0].partial_cmp(&point[0]) {
-                match cmp {
-                    Ordering::Less => {
-                        q1x_pt = point.clone();
+        if suspicious.is_empty() {
+            return ConvexHullIter::from_vec(hull).with_extremes(extremes);
+        }
+
+        // Step 3: Recompute the hull exactly from the floating-point hull and
+        // the suspicious points.
+        suspicious.extend(hull);
+        return ConvexHullIter::from_vec(monotone_chain::monotone_chain(suspicious))
+            .with_extremes(extremes);
+    }
+
+    /**
+    Calculates the convex hull for `self` with Andrew's monotone chain
+    algorithm instead of the divide-and-conquer algorithm of
+    [`ConvexHull::convex_hull`].
+
+    The points are sorted lexicographically and the upper and lower chains of
+    the hull are built in a single pass each, using exact orientation
+    predicates. This takes O(n log n) time and is usually slower than
+    [`ConvexHull::convex_hull`], but the algorithm is simple and exact, which
+    makes it a trustworthy fallback and a reference for testing other
+    algorithms.
+
+    The output follows the same conventions as [`ConvexHull::convex_hull`].
+    Of multiple identical points, the one which is returned first by
+    [`ConvexHull::convex_hull_iter`] is kept.
+
+    # Examples
+    ```
+    use planar_convex_hull::ConvexHull;
+
+    let slice = &[[0.0, 0.0], [2.0, 0.0], [1.0, 0.5], [2.0, 2.0], [1.0, 1.0], [0.0, 2.0]];
+    let mut hull = slice.convex_hull_monotone_chain();
+    assert_eq!(hull.next(), Some((3, [2.0, 2.0])));
+    assert_eq!(hull.next(), Some((5, [0.0, 2.0])));
+    assert_eq!(hull.next(), Some((0, [0.0, 0.0])));
+    assert_eq!(hull.next(), Some((1, [2.0, 0.0])));
+    assert_eq!(hull.next(), None);
+
+    // Same result as the default algorithm
+    assert!(slice.convex_hull().eq(slice.convex_hull_monotone_chain()));
+    ```
+     */
+    fn convex_hull_monotone_chain(&self) -> ConvexHullIter {
+        return ConvexHullIter::from_vec(monotone_chain::monotone_chain(
+            self.convex_hull_iter().collect(),
+        ));
+    }
+
+    /**
+    Calculates the convex hull for `self` in O(n) time if
+    [`ConvexHull::convex_hull_iter`] returns the points sorted by their
+    x-values and points with equal x-values by their y-values, e.g. because
+    they come from a sorted database query.
+
+    The hull is calculated with a single pass of the monotone chain algorithm
+    (see [`ConvexHull::convex_hull_monotone_chain`]), skipping all sorting and
+    searching. Whether the points are sorted is checked on the fly; if they
+    are not, they are sorted first and the hull is still correct, but the
+    calculation takes O(n log n) time. Nonreal points are ignored for the
+    order. All predicates are exact.
+
+    The output follows the same conventions as [`ConvexHull::convex_hull`].
+    Of multiple identical points, the one which is returned first by
+    [`ConvexHull::convex_hull_iter`] is kept.
+
+    # Examples
+    ```
+    use planar_convex_hull::ConvexHull;
+
+    let sorted = &[[0.0, 0.0], [0.0, 2.0], [1.0, 0.5], [1.0, 1.0], [2.0, 0.0], [2.0, 2.0]];
+    let mut hull = sorted.convex_hull_sorted();
+    assert_eq!(hull.next(), Some((5, [2.0, 2.0])));
+    assert_eq!(hull.next(), Some((1, [0.0, 2.0])));
+    assert_eq!(hull.next(), Some((0, [0.0, 0.0])));
+    assert_eq!(hull.next(), Some((4, [2.0, 0.0])));
+    assert_eq!(hull.next(), None);
+    ```
+     */
+    fn convex_hull_sorted(&self) -> ConvexHullIter {
+        return ConvexHullIter::from_vec(monotone_chain::monotone_chain_presorted(
+            self.convex_hull_iter().collect(),
+        ));
+    }
+
+    /**
+    Calculates the convex hull for `self` with Graham's scan instead of the
+    divide-and-conquer algorithm of [`ConvexHull::convex_hull`].
+
+    The points are sorted by their angle around the lowest point and the hull
+    is built in a single pass, using exact orientation predicates. Points in
+    the same direction are sorted by their distance, so collinear and
+    identical points are handled without special cases. This takes
+    O(n log n) time.
+
+    The output follows the same conventions as [`ConvexHull::convex_hull`].
+    Of multiple identical points, the one which is returned first by
+    [`ConvexHull::convex_hull_iter`] is kept.
+
+    # Examples
+    ```
+    use planar_convex_hull::ConvexHull;
+
+    let slice = &[[0.0, 0.0], [2.0, 0.0], [1.0, 0.0], [2.0, 2.0], [1.0, 1.0], [0.0, 2.0]];
+    let mut hull = slice.convex_hull_graham();
+    assert_eq!(hull.next(), Some((3, [2.0, 2.0])));
+    assert_eq!(hull.next(), Some((5, [0.0, 2.0])));
+    assert_eq!(hull.next(), Some((0, [0.0, 0.0])));
+    assert_eq!(hull.next(), Some((1, [2.0, 0.0])));
+    assert_eq!(hull.next(), None);
+    ```
+     */
+    fn convex_hull_graham(&self) -> ConvexHullIter {
+        return ConvexHullIter::from_vec(graham::graham_scan(self.convex_hull_iter().collect()));
+    }
+
+    /**
+    Calculates the convex hull for `self` with QuickHull instead of the
+    divide-and-conquer algorithm of [`ConvexHull::convex_hull`].
+
+    The points are split at the line through the smallest and the largest
+    point (lexicographically). On each side, the point farthest from the line
+    is a hull corner, and all points inside the triangle it spans with the line
+    are discarded. The points outside the two new edges are processed
+    recursively. This takes O(n log n) time on average and is fast if most
+    points are discarded early, e.g. for uniformly distributed points with
+    small hulls. The points are partitioned with exact predicates and the
+    remaining candidates are finalized with the monotone chain algorithm (see
+    [`ConvexHull::convex_hull_monotone_chain`]), so the result is exact.
+
+    When the `rayon` feature is enabled, large subproblems are processed in
+    parallel. Unlike [`ConvexHull::convex_hull`], which runs one task per
+    quadrant, the recursion provides as many tasks as there are threads.
+
+    The output follows the same conventions as [`ConvexHull::convex_hull`].
+    Of multiple identical points, the one which is returned first by
+    [`ConvexHull::convex_hull_iter`] is kept.
+
+    # Examples
+    ```
+    use planar_convex_hull::ConvexHull;
+
+    let slice = &[[0.0, 0.0], [2.0, 0.0], [1.0, 0.5], [2.0, 2.0], [1.0, 1.0], [0.0, 2.0]];
+    let mut hull = slice.convex_hull_quickhull();
+    assert_eq!(hull.next(), Some((3, [2.0, 2.0])));
+    assert_eq!(hull.next(), Some((5, [0.0, 2.0])));
+    assert_eq!(hull.next(), Some((0, [0.0, 0.0])));
+    assert_eq!(hull.next(), Some((1, [2.0, 0.0])));
+    assert_eq!(hull.next(), None);
+    ```
+     */
+    fn convex_hull_quickhull(&self) -> ConvexHullIter {
+        return ConvexHullIter::from_vec(quickhull::quickhull(self.convex_hull_iter().collect()));
+    }
+
+    /**
+    Calculates the convex hull for `self` with Chan's output-sensitive
+    algorithm instead of the divide-and-conquer algorithm of
+    [`ConvexHull::convex_hull`].
+
+    The points are split into groups of m points and the hull of each group is
+    calculated. The hull of all points is then wrapped around the group hulls
+    like in Jarvis' march, where the next corner of each group hull is found
+    in O(log m) time (see [`Hull::tangents_from`]). If the wrapping does not
+    close after m steps, m was too small and the calculation is repeated with
+    m squared. This takes O(n log h) time for h hull corners, so it is
+    well suited for very large inputs with small hulls. All predicates are
+    exact.
+
+    The output follows the same conventions as [`ConvexHull::convex_hull`].
+    Of multiple identical points, the one which is returned first by
+    [`ConvexHull::convex_hull_iter`] is kept.
+
+    # Examples
+    ```
+    use planar_convex_hull::ConvexHull;
+
+    let slice = &[[0.0, 0.0], [2.0, 0.0], [1.0, 0.5], [2.0, 2.0], [1.0, 1.0], [0.0, 2.0]];
+    let mut hull = slice.convex_hull_chan();
+    assert_eq!(hull.next(), Some((3, [2.0, 2.0])));
+    assert_eq!(hull.next(), Some((5, [0.0, 2.0])));
+    assert_eq!(hull.next(), Some((0, [0.0, 0.0])));
+    assert_eq!(hull.next(), Some((1, [2.0, 0.0])));
+    assert_eq!(hull.next(), None);
+    ```
+     */
+    fn convex_hull_chan(&self) -> ConvexHullIter {
+        return ConvexHullIter::from_vec(chan::chan(self.convex_hull_iter().collect()));
+    }
+
+    /**
+    Calculates the convex hull for `self` with Jarvis' march (gift wrapping)
+    instead of the divide-and-conquer algorithm of [`ConvexHull::convex_hull`].
+
+    Starting at the first hull point, the next one is found by iterating over
+    all points and taking the one which has all others on its left. This
+    takes O(nh) time for h hull points, which beats the O(n log n) algorithms
+    if the hull is known to be very small, e.g. for points inside a triangle
+    or rectangle. Since the algorithm is simple and uses exact predicates, it
+    is also a useful reference for testing. See [`ConvexHull::convex_hull_into`]
+    for a variant which does not allocate any memory.
+
+    The output follows the same conventions as [`ConvexHull::convex_hull`].
+    Of multiple identical points, the one which is returned first by
+    [`ConvexHull::convex_hull_iter`] is kept.
+
+    # Examples
+    ```
+    use planar_convex_hull::ConvexHull;
+
+    let slice = &[[0.0, 0.0], [2.0, 0.0], [1.0, 0.5], [2.0, 2.0], [1.0, 1.0], [0.0, 2.0]];
+    let mut hull = slice.convex_hull_jarvis();
+    assert_eq!(hull.next(), Some((3, [2.0, 2.0])));
+    assert_eq!(hull.next(), Some((5, [0.0, 2.0])));
+    assert_eq!(hull.next(), Some((0, [0.0, 0.0])));
+    assert_eq!(hull.next(), Some((1, [2.0, 0.0])));
+    assert_eq!(hull.next(), None);
+    ```
+     */
+    fn convex_hull_jarvis(&self) -> ConvexHullIter {
+        return ConvexHullIter::from_vec(jarvis::jarvis(self));
+    }
+
+    /**
+    Calculates the convex hull for `self` with the "ultimate" algorithm of
+    Kirkpatrick and Seidel instead of the divide-and-conquer algorithm of
+    [`ConvexHull::convex_hull`].
+
+    Instead of calculating the hulls of two halves and merging them, the
+    points are split at their median x-value and the hull edge crossing the
+    split (the bridge) is found first. All points below the bridge are
+    discarded and the algorithm continues on the remaining points on both
+    sides ("marriage before conquest"). The upper and lower hull are
+    calculated separately. Each bridge is found in expected linear time with a
+    randomized linear program, so the hull is calculated in expected
+    O(n log h) time for h hull corners. Unlike for
+    [`ConvexHull::convex_hull_quickhull`], this bound holds for every
+    distribution of the points (the expectation only refers to the internal
+    random choices, which are seeded, so the result is reproducible). All
+    predicates are exact.
+
+    The output follows the same conventions as [`ConvexHull::convex_hull`].
+    Of multiple identical points, the one which is returned first by
+    [`ConvexHull::convex_hull_iter`] is kept.
+
+    # Examples
+    ```
+    use planar_convex_hull::ConvexHull;
+
+    let slice = &[[0.0, 0.0], [2.0, 0.0], [1.0, 0.5], [2.0, 2.0], [1.0, 1.0], [0.0, 2.0]];
+    let mut hull = slice.convex_hull_kirkpatrick_seidel();
+    assert_eq!(hull.next(), Some((3, [2.0, 2.0])));
+    assert_eq!(hull.next(), Some((5, [0.0, 2.0])));
+    assert_eq!(hull.next(), Some((0, [0.0, 0.0])));
+    assert_eq!(hull.next(), Some((1, [2.0, 0.0])));
+    assert_eq!(hull.next(), None);
+    ```
+     */
+    fn convex_hull_kirkpatrick_seidel(&self) -> ConvexHullIter {
+        return ConvexHullIter::from_vec(kirkpatrick_seidel::kirkpatrick_seidel(
+            self.convex_hull_iter().collect(),
+        ));
+    }
+
+    /**
+    Calculates the convex hull for `self` in O(n) time with Melkman's
+    algorithm, assuming that [`ConvexHull::convex_hull_iter`] returns the
+    vertices of a simple (i.e. not self-intersecting) polyline in their order
+    along it, such as a GIS boundary or a robot trajectory stored in a slice
+    or vector. The polyline can be open or closed, where a closed polyline may
+    repeat its first vertex at the end or not.
+
+    The algorithm walks along the polyline and keeps the hull of the visited
+    vertices in a double-ended queue. Since the polyline does not intersect
+    itself, it can only leave the hull next to its last vertex, so each new
+    vertex is checked against the two hull edges at the last vertex and the
+    hull is updated at both ends of the queue. All predicates are exact.
+
+    If the polyline intersects itself, the result is not necessarily the
+    convex hull. Nonreal vertices are skipped. Otherwise, the output follows
+    the same conventions as [`ConvexHull::convex_hull`] and of multiple
+    identical vertices, the first one is kept.
+
+    # Examples
+    ```
+    use planar_convex_hull::ConvexHull;
+
+    // A closed, L-shaped polygon
+    let polyline = &[
+        [0.0, 0.0],
+        [2.0, 0.0],
+        [2.0, 1.0],
+        [1.0, 1.0],
+        [1.0, 2.0],
+        [0.0, 2.0],
+        [0.0, 0.0],
+    ];
+    let mut hull = polyline.convex_hull_of_polyline();
+    assert_eq!(hull.next(), Some((2, [2.0, 1.0])));
+    assert_eq!(hull.next(), Some((4, [1.0, 2.0])));
+    assert_eq!(hull.next(), Some((5, [0.0, 2.0])));
+    assert_eq!(hull.next(), Some((0, [0.0, 0.0])));
+    assert_eq!(hull.next(), Some((1, [2.0, 0.0])));
+    assert_eq!(hull.next(), None);
+    ```
+     */
+    fn convex_hull_of_polyline(&self) -> ConvexHullIter {
+        return ConvexHullIter::from_vec(melkman::melkman(self.convex_hull_iter()));
+    }
+
+    /**
+    Calculates an approximation of the convex hull for `self` in a single
+    pass, which is guaranteed to deviate from the exact hull by less than
+    `tolerance`. This is meant for huge inputs such as telemetry streams, for
+    which an exact hull is not needed.
+
+    The plane is divided into vertical strips of width `tolerance` and only
+    the lowest and the highest point of each strip are kept (Bentley, Faust
+    and Preparata). The returned hull is the exact hull of these points, so
+    its corners are points of `self` and it is located inside the exact hull.
+    Each point of `self` lies on a vertical segment between two kept points of
+    its strip, which is less than `tolerance` away from the point. Therefore,
+    every point of the exact hull is located less than `tolerance` away from
+    the approximation. This takes O(n + s log s) time and O(s) memory for s
+    occupied strips.
+
+    The output follows the same conventions as [`ConvexHull::convex_hull`].
+
+    # Panics
+
+    Panics if `tolerance` is not positive and finite.
+
+    # Examples
+    ```
+    use planar_convex_hull::{ConvexHull, Hull, generators};
+
+    let points = generators::uniform_in_disk(10_000, [0.0, 0.0], 1.0, 3);
+    let approximation = Hull::from_vertices_unchecked(points.convex_hull_approximate(0.05));
+
+    // All points are within the tolerance of the approximation
+    assert!(points.iter().all(|point| approximation.signed_distance(*point) < 0.05));
+    assert!(approximation.len() <= 2 * 41);
+    ```
+     */
+    fn convex_hull_approximate(&self, tolerance: f64) -> ConvexHullIter {
+        return ConvexHullIter::from_vec(approximate::approximate(self, tolerance));
+    }
+
+    /**
+    Calculates the convex hull for `self` and returns its edges as
+    `(start key, end key)` pairs.
+
+    The edges follow the counter-clockwise order of [`ConvexHull::convex_hull`],
+    and the last edge closes the loop by connecting the last hull point with the
+    first one. If the hull consists of a single point (or no point at all), no
+    edges are returned. If all points are located on a single line, the hull
+    consists of two points and the two returned edges run back and forth
+    between them.
+
+    # Examples
+    ```
+    use planar_convex_hull::ConvexHull;
+
+    // Square with a point in its middle
+    let slice = &[
+        [0.0, 0.0],
+        [1.0, 0.0],
+        [0.0, 1.0],
+        [1.0, 1.0],
+        [0.5, 0.5], // Not part of the convex hull
+    ];
+    assert_eq!(
+        slice.convex_hull_edges(),
+        vec![(3, 2), (2, 0), (0, 1), (1, 3)]
+    );
+
+    // All points on a single line
+    let slice = &[[0.0, 0.0], [1.0, 0.0], [2.0, 0.0]];
+    assert_eq!(slice.convex_hull_edges(), vec![(2, 0), (0, 2)]);
+
+    // Single point
+    let slice = &[[0.0, 0.0]];
+    assert_eq!(slice.convex_hull_edges(), vec![]);
+    ```
+     */
+    fn convex_hull_edges(&self) -> Vec<(usize, usize)> {
+        let keys: Vec<usize> = self.convex_hull().map(|(key, _)| key).collect();
+        if keys.len() < 2 {
+            return Vec::new();
+        }
+        return keys
+            .iter()
+            .zip(keys.iter().cycle().skip(1))
+            .map(|(start, end)| (*start, *end))
+            .collect();
+    }
+
+    /**
+    Calculates the convex hull for `self` like [`ConvexHull::convex_hull`], but
+    applies the opt-in preprocessing steps selected in `options` to the
+    coordinates first (see [`ConvexHullOptions`] for details).
+
+    The preprocessing only affects the internal computation: The returned
+    points are the original points of `self` and the output follows the same
+    conventions as [`ConvexHull::convex_hull`]. The [`Normalization`] which
+    was applied to the coordinates is returned alongside the hull.
+
+    # Examples
+    ```
+    use planar_convex_hull::{ConvexHull, ConvexHullOptions};
+
+    let options = ConvexHullOptions {
+        normalize: true,
+        ..Default::default()
+    };
+
+    // The products of these coordinates underflow, so the plain algorithm
+    // misses the hull point with the key 4.
+    let slice = &[[1e-170, 0.0], [0.0, 1e-170], [-1e-170, 0.0], [0.0, -1e-170], [5e-171, 5.001e-171]];
+    assert_eq!(slice.convex_hull().count(), 4);
+
+    let (mut hull, normalization) = slice.convex_hull_with_options(&options);
+    assert_eq!(normalization.scale, 2.0f64.powi(564));
+    assert_eq!(hull.next(), Some((0, [1e-170, 0.0])));
+    assert_eq!(hull.next(), Some((4, [5e-171, 5.001e-171])));
+    assert_eq!(hull.next(), Some((1, [0.0, 1e-170])));
+    assert_eq!(hull.next(), Some((2, [-1e-170, 0.0])));
+    assert_eq!(hull.next(), Some((3, [0.0, -1e-170])));
+    assert_eq!(hull.next(), None);
+    ```
+     */
+    fn convex_hull_with_options(
+        &self,
+        options: &ConvexHullOptions,
+    ) -> (ConvexHullIter, Normalization) {
+        return options::convex_hull_with_options(self, options);
+    }
+
+    /**
+    Calculates the convex hull for `self` without allocating any memory and
+    writes the keys of the hull points into `keys`. Returns the number of hull
+    points, i.e. `keys[..count]` contains the hull.
+
+    This is meant for allocation-free real-time loops where the maximum hull
+    size is known, e.g. hulls of small sensor frames. Instead of the
+    divide-and-conquer algorithm, Jarvis' march (see the [`jarvis`] module) is
+    used, which iterates over `self` once per hull point and therefore runs in
+    O(nh). It uses exact predicates, and its output follows the conventions of
+    [`ConvexHull::convex_hull`] (only the choice between duplicate points may
+    differ).
+
+    If `keys` is too short, its content is unspecified and a
+    [`CapacityError`] with the required length is returned.
+
+    # Examples
+    ```
+    use planar_convex_hull::{CapacityError, ConvexHull};
+
+    let slice = &[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0], [0.5, 0.5]];
+
+    let mut keys = [0usize; 8];
+    let count = slice.convex_hull_into(&mut keys).unwrap();
+    assert_eq!(&keys[..count], &[3, 2, 0, 1]);
+
+    let mut keys = [0usize; 3];
+    assert_eq!(
+        slice.convex_hull_into(&mut keys),
+        Err(CapacityError { required: 4, capacity: 3 })
+    );
+    ```
+     */
+    fn convex_hull_into(&self, keys: &mut [usize]) -> Result<usize, CapacityError> {
+        return jarvis::jarvis_into(self, keys);
+    }
+
+    /**
+    Calculates the convex hull for `self` like [`ConvexHull::convex_hull_exact`],
+    but additionally includes the points with the keys in `anchors` in the
+    boundary (e.g. calibration markers which must always be part of the
+    reported outline).
+
+    An anchor located on a hull edge is inserted between the corners of that
+    edge, even though it is collinear with them. An anchor with the same
+    coordinates as a hull corner replaces the key of that corner. Since a
+    boundary through an anchor strictly inside the hull would not be convex,
+    such an anchor results in an [`AnchorError::Interior`]. The positions of
+    the anchors are tested with exact predicates. The returned points start
+    with the first hull corner according to the conventions of
+    [`ConvexHull::convex_hull`].
+
+    # Examples
+    ```
+    use planar_convex_hull::{AnchorError, ConvexHull};
+
+    let slice = &[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0], [1.0, 0.0], [1.0, 1.0]];
+    assert_eq!(slice.convex_hull().map(|(key, _)| key).collect::<Vec<_>>(), vec![2, 3, 0, 1]);
+
+    // Point 4 is located on the lower edge
+    let hull = slice.convex_hull_with_anchors(&[4]).unwrap();
+    assert_eq!(hull.map(|(key, _)| key).collect::<Vec<_>>(), vec![2, 3, 0, 4, 1]);
+
+    // Point 5 is located inside the hull
+    assert_eq!(
+        slice.convex_hull_with_anchors(&[4, 5]).unwrap_err(),
+        AnchorError::Interior { key: 5 }
+    );
+    ```
+     */
+    fn convex_hull_with_anchors(&self, anchors: &[usize]) -> Result<ConvexHullIter, AnchorError> {
+        return anchors::convex_hull_with_anchors(self, anchors);
+    }
+
+    /**
+    Calculates the part of the convex hull for `self` whose outward normals
+    point into the angular range from `start` to `end` (in radians, measured
+    counter-clockwise from the positive x-axis), e.g. the north-facing
+    boundary for `start = π/4` and `end = 3π/4`. Since the directions are
+    evaluated in floating-point arithmetic, an edge whose normal is very close
+    to `start` or `end` may or may not be included.
+
+    The returned chain of hull corners is in counter-clockwise order. It
+    starts with the corner which is extreme in the `start` direction and ends
+    with the corner which is extreme in the `end` direction. If `end - start`
+    is at least 2π, the whole hull is returned (starting with the corner which
+    is extreme in the `start` direction). A range of zero width returns the
+    corner which is extreme in this direction.
+
+    For ranges below π, only the points outside the line between the first
+    and the last corner of the chain are processed further, so a narrow range
+    is much cheaper than calculating the whole hull. Collinear points are
+    excluded as in [`ConvexHull::convex_hull`], and the orientation tests use
+    exact predicates.
+
+    # Examples
+    ```
+    use planar_convex_hull::ConvexHull;
+
+    let slice = &[[0.0, 0.0], [2.0, 0.0], [3.0, 1.0], [1.0, 3.0], [-1.0, 1.0], [1.0, 1.0]];
+
+    // Upward-facing boundary
+    let north: Vec<usize> = slice
+        .convex_hull_facing(0.5, 2.6)
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect();
+    assert_eq!(north, vec![2, 3, 4]);
+
+    // The bottom edge faces straight down
+    let south: Vec<usize> = slice
+        .convex_hull_facing(-2.0, -1.2)
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect();
+    assert_eq!(south, vec![0, 1]);
+    ```
+     */
+    fn convex_hull_facing(&self, start: f64, end: f64) -> Vec<(usize, [f64; 2])> {
+        return directional::convex_hull_facing(self, start, end);
+    }
+
+    /**
+    Calculates the convex hull for `self` like [`ConvexHull::convex_hull_exact`]
+    and a [`Certificate`] which proves that all other points are located
+    inside the hull. The certificate assigns a witness edge to each real point
+    which is not a hull corner (including duplicates of corners and points on
+    edges). Anyone holding the collection, the hull and the certificate can
+    verify the result in O(n) time with [`Certificate::verify`], independently
+    of the algorithm which produced it.
+
+    Finding the witnesses takes an O(log h) search per point.
+
+    # Examples
+    ```
+    use planar_convex_hull::ConvexHull;
+
+    let points = [[0.0, 0.0], [2.0, 0.0], [0.0, 2.0], [0.5, 0.5], [1.0, 0.0]];
+    let (hull, certificate) = points.convex_hull_with_certificate();
+    let hull: Vec<(usize, [f64; 2])> = hull.collect();
+    assert_eq!(certificate.witnesses().len(), 2);
+    assert_eq!(certificate.verify(&points, &hull), Ok(()));
+    ```
+     */
+    fn convex_hull_with_certificate(&self) -> (ConvexHullIter, Certificate) {
+        return certificate::convex_hull_with_certificate(self);
+    }
+
+    /**
+    Calculates the convex hull for `self` using the hull of a previous frame
+    as a hint, e.g. in physics or tracking loops where the points only move
+    slightly between frames and a few points are added or removed.
+
+    The points of `self` with the keys of the `hint` corners are looked up
+    (keys which no longer exist are skipped) and their hull is calculated.
+    All points strictly inside this polygon are discarded with an O(log h)
+    test, and only the remaining points near the boundary are passed to
+    Andrew's monotone chain algorithm. The result is therefore always the
+    exact hull of `self`, no matter how much the points moved: A bad hint only
+    makes the calculation slower. If fewer than three hint points are left, all
+    points are passed to the monotone chain algorithm.
+
+    The result follows the conventions of [`ConvexHull::convex_hull`] and uses
+    exact predicates like [`ConvexHull::convex_hull_exact`]. Of multiple
+    identical points, the chosen key may differ from the one chosen by
+    [`ConvexHull::convex_hull`].
+
+    # Examples
+    ```
+    use planar_convex_hull::{ConvexHull, Hull};
+
+    let mut points = vec![[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0], [1.0, 1.0]];
+    let hull = Hull::new(&points);
+
+    // Next frame: the points moved slightly
+    for point in points.iter_mut() {
+        point[0] += 0.01;
+    }
+    points[4] = [1.0, 2.5];
+    let next: Vec<usize> = points.convex_hull_warm_start(&hull).map(|(key, _)| key).collect();
+    assert_eq!(next, vec![2, 4, 3, 0, 1]);
+    ```
+     */
+    fn convex_hull_warm_start(&self, hint: &Hull) -> ConvexHullIter {
+        return warm_start::convex_hull_warm_start(self, hint);
+    }
+
+    /**
+    Calculates the onion-peeling depth of each real point of `self`: The
+    points on the boundary of the convex hull have depth 0, the points on the
+    boundary of the hull of the remaining points have depth 1 and so on. The
+    returned map assigns the depth to the key of each point.
+
+    Unlike [`ConvexHull::convex_hull`], a layer includes all points on the
+    boundary, i.e. duplicates of the corners and points located on the edges,
+    so duplicate points always have the same depth. The layers are determined
+    with exact predicates. Each layer takes O(n log n) time for the n points
+    remaining at its depth.
+
+    # Examples
+    ```
+    use planar_convex_hull::ConvexHull;
+
+    let slice = &[
+        [0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0], // Outer square
+        [2.0, 0.0], // Located on an edge of the outer square
+        [1.0, 1.0], [3.0, 1.0], [2.0, 3.0], // Inner triangle
+        [2.0, 2.0], // Center
+    ];
+    let depth = slice.convex_depth();
+    assert_eq!(depth[&0], 0);
+    assert_eq!(depth[&4], 0);
+    assert_eq!(depth[&5], 1);
+    assert_eq!(depth[&8], 2);
+    ```
+     */
+    fn convex_depth(&self) -> HashMap<usize, usize> {
+        let mut depths = HashMap::new();
+        layers::peel(self, |depth, layer| {
+            depths.extend(layer.into_iter().map(|(key, _)| (key, depth)));
+        });
+        return depths;
+    }
+
+    /**
+    Peels the real points of `self` like an onion and returns the keys of the
+    convex layers: The first layer consists of the points on the boundary of
+    the convex hull, the second layer of the points on the boundary of the
+    hull of the remaining points and so on. The position of a point's layer
+    is its depth according to [`ConvexHull::convex_depth`].
+
+    Each layer includes all points on the boundary, i.e. duplicates of the
+    corners and points located on the edges, so that every real point is
+    part of exactly one layer. Within a layer, the points are in
+    counter-clockwise order along the boundary, starting with the first
+    corner according to the conventions of [`ConvexHull::convex_hull`]. The
+    duplicates of a corner follow the corner. The layers are determined with
+    exact predicates. Each layer takes O(n log n) time for the n points
+    remaining at its depth.
+
+    # Examples
+    ```
+    use planar_convex_hull::ConvexHull;
+
+    let slice = &[
+        [0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0], // Outer square
+        [2.0, 0.0], // Located on an edge of the outer square
+        [1.0, 1.0], [3.0, 1.0], [2.0, 3.0], // Inner triangle
+        [2.0, 2.0], // Center
+    ];
+    let layers = slice.convex_layers();
+    assert_eq!(layers, vec![vec![2, 3, 0, 4, 1], vec![6, 7, 5], vec![8]]);
+    ```
+     */
+    fn convex_layers(&self) -> Vec<Vec<usize>> {
+        let mut layers = Vec::new();
+        layers::peel(self, |_, layer| {
+            layers.push(layer.into_iter().map(|(key, _)| key).collect());
+        });
+        return layers;
+    }
+
+    /**
+    Returns `true` if `self` is in convex position, i.e. if every real point
+    is a corner of the convex hull. Duplicates of a corner are allowed, while
+    points located on a hull edge are not corners. Nonreal points are ignored,
+    and collections with fewer than three distinct real points are always in
+    convex position.
+
+    The points are sorted and split into an upper and a lower chain, which
+    must turn strictly at every point. This is checked with exact predicates
+    and ends at the first violation, without building the hull. Takes
+    O(n log n) time.
+
+    # Examples
+    ```
+    use planar_convex_hull::ConvexHull;
+
+    let square = vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0], [0.0, 0.0]];
+    assert!(square.is_in_convex_position());
+
+    let mut with_center = square.clone();
+    with_center.push([0.5, 0.5]);
+    assert!(!with_center.is_in_convex_position());
+
+    let mut with_edge_point = square.clone();
+    with_edge_point.push([0.5, 0.0]);
+    assert!(!with_edge_point.is_in_convex_position());
+    ```
+     */
+    fn is_in_convex_position(&self) -> bool {
+        return monotone_chain::in_convex_position(
+            self.convex_hull_iter().map(|(_, point)| point).collect(),
+        );
+    }
+
+    /**
+    Calculates the orthogonal (rectilinear) convex hull of `self`: the
+    smallest polygon with only horizontal and vertical edges which contains
+    all points and whose intersection with every horizontal and vertical line
+    is connected. Its boundary consists of four staircases between the
+    extreme points in x- and y-direction, whose corners are the points which
+    are not dominated by another point towards the respective quadrant (see
+    [`ConvexHull::pareto_front`]).
+
+    The returned points are the corners located on the boundary, in
+    counter-clockwise order starting with the same point as
+    [`ConvexHull::convex_hull`]. Two consecutive points are connected by a
+    horizontal and a vertical segment which bend away from the interior.
+    Points located on the boundary without being a corner of it and nonreal
+    points are discarded, and of multiple identical points only the first is
+    kept. If the hull degenerates (e.g. for points on a descending line), the
+    boundary passes the points between the extreme points twice. Takes
+    O(n log n) time.
+
+    # Examples
+    ```
+    use planar_convex_hull::ConvexHull;
+
+    let slice = &[[0.0, 0.0], [4.0, 0.0], [4.0, 1.0], [2.0, 3.0], [0.0, 2.0], [3.0, 1.5], [1.0, 1.0]];
+
+    // Point 5 is inside the convex hull, but a corner of the orthogonal hull
+    let hull: Vec<usize> = slice.orthogonal_convex_hull().map(|(key, _)| key).collect();
+    assert_eq!(hull, vec![2, 5, 3, 4, 0, 1]);
+    ```
+     */
+    fn orthogonal_convex_hull(&self) -> ConvexHullIter {
+        return orthogonal::orthogonal_convex_hull(self);
+    }
+
+    /**
+    Extracts the maximal (non-dominated) points of `self` towards `quadrant`:
+    A point is part of the [Pareto front](https://en.wikipedia.org/wiki/Pareto_front)
+    if no other point is located at least as far in both coordinate
+    directions of the quadrant. For [`Quadrant::NorthEast`], these are the
+    points which cannot be improved in x without getting worse in y.
+
+    The front is a staircase, the rectilinear counterpart of the part of the
+    convex hull facing the quadrant: every hull corner between the two
+    extreme points of the quadrant is part of the front. The points are returned in counter-clockwise
+    order around the point set (e.g. from the rightmost to the topmost point
+    for [`Quadrant::NorthEast`]). Nonreal points are ignored and of multiple
+    identical points only the first is kept. Takes O(n log n) time.
+
+    # Examples
+    ```
+    use planar_convex_hull::{ConvexHull, Quadrant};
+
+    // Cost (x) and latency (y) of some configurations, both to be minimized
+    let slice = &[[1.0, 9.0], [2.0, 4.0], [3.0, 5.0], [4.0, 2.0], [6.0, 1.0], [7.0, 3.0]];
+    let front: Vec<usize> = slice
+        .pareto_front(Quadrant::SouthWest)
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect();
+    assert_eq!(front, vec![0, 1, 3, 4]);
+    ```
+     */
+    fn pareto_front(&self, quadrant: Quadrant) -> Vec<(usize, [f64; 2])> {
+        return orthogonal::pareto_front(self, quadrant);
+    }
+}
+
+/**
+Divide-and-conquer algorithm behind [`ConvexHull::convex_hull`]. If `stats` is
+given, the durations of the individual phases are measured and written into it.
+If `trace` is given, the individual steps are recorded into it.
+ */
+fn divide_and_conquer<T: ConvexHull + ?Sized>(
+    this: &T,
+    mut stats: Option<&mut ConvexHullStats>,
+    trace: Option<&mut Trace>,
+) -> ConvexHullIter {
+    // Timings are only taken if requested, since reading the clock is not
+    // free (and not even available on all platforms).
+    let measure = stats.is_some();
+    let start = measure.then(Instant::now);
+
+    /*
+    Step 1: Identify the four point-pairs defining each quadrant.
+
+    We search for the points containing one extremum x- or y-value. The
+    quadrant borders are defined by the other value of the point. For
+    example, if the point with the largest x-value is [2, 1] and that with
+    the largest y-value is [1, 3], all points where x >= 1 and y >= 1 belong
+    to the q1 quadrant. Similarily, the q2 quadrant is defined by x <= 1 and
+    y >= 1, the q3 quadrant by x <= 1 and y <= 3, and the q4 quadrant by
+    x >= 1 and y <= 3.
+     */
+    let mut q1x: usize = usize::MAX;
+    let mut q1y: usize = usize::MAX;
+    let mut q2x: usize = usize::MAX;
+    let mut q2y: usize = usize::MAX;
+    let mut q3x: usize = usize::MAX;
+    let mut q3y: usize = usize::MAX;
+    let mut q4x: usize = usize::MAX;
+    let mut q4y: usize = usize::MAX;
+    let mut q1x_pt: [f64; 2] = [f64::NEG_INFINITY, f64::NEG_INFINITY];
+    let mut q1y_pt: [f64; 2] = [f64::NEG_INFINITY, f64::NEG_INFINITY];
+    let mut q2x_pt: [f64; 2] = [f64::INFINITY, f64::NEG_INFINITY];
+    let mut q2y_pt: [f64; 2] = [f64::INFINITY, f64::NEG_INFINITY];
+    let mut q3x_pt: [f64; 2] = [f64::INFINITY, f64::INFINITY];
+    let mut q3y_pt: [f64; 2] = [f64::INFINITY, f64::INFINITY];
+    let mut q4x_pt: [f64; 2] = [f64::NEG_INFINITY, f64::INFINITY];
+    let mut q4y_pt: [f64; 2] = [f64::NEG_INFINITY, f64::INFINITY];
+
+    // This variable is used to catch the special case of a collection
+    // having only one real point.
+    let mut num_real_points = 0;
+
+    for (idx, point) in this.convex_hull_iter() {
+        // Skip any non-real points
+        if !point[0].is_finite() || !point[1].is_finite() {
+            continue;
+        }
+        num_real_points += 1;
+
+        // q1x
+        if let Some(cmp) = q1x_pt[0].partial_cmp(&point[0]) {
+            match cmp {
+                Ordering::Less => {
+                    q1x_pt = point;
+                    q1x = idx;
+                }
+                Ordering::Equal => {
+                    if point[1] > q1x_pt[1] {
+                        q1x_pt = point;
                         q1x = idx;
                     }
-                    Ordering::Equal => {
-                        if point[1] > q1x_pt[1] {
-                            q1x_pt = point.clone();
-                            q1x = idx;
-                        }
-                    }
-                    Ordering::Greater => (),
                 }
+                Ordering::Greater => (),
             }
+        }
 
-            // q1y
-            if let Some(cmp) = q1y_pt[1].partial_cmp(&point[1]) {
-                match cmp {
-                    Ordering::Less => {
-                        q1y_pt = point.clone();
+        // q1y
+        if let Some(cmp) = q1y_pt[1].partial_cmp(&point[1]) {
+            match cmp {
+                Ordering::Less => {
+                    q1y_pt = point;
+                    q1y = idx;
+                }
+                Ordering::Equal => {
+                    if point[0] > q1y_pt[0] {
+                        q1y_pt = point;
                         q1y = idx;
                     }
-                    Ordering::Equal => {
-                        if point[0] > q1y_pt[0] {
-                            q1y_pt = point.clone();
-                            q1y = idx;
-                        }
-                    }
-                    Ordering::Greater => (),
                 }
+                Ordering::Greater => (),
             }
+        }
 
-            // q2x
-            if let Some(cmp) = q2x_pt[0].partial_cmp(&point[0]) {
-                match cmp {
-                    Ordering::Greater => {
-                        q2x_pt = point.clone();
+        // q2x
+        if let Some(cmp) = q2x_pt[0].partial_cmp(&point[0]) {
+            match cmp {
+                Ordering::Greater => {
+                    q2x_pt = point;
+                    q2x = idx;
+                }
+                Ordering::Equal => {
+                    if point[1] > q2x_pt[1] {
+                        q2x_pt = point;
                         q2x = idx;
                     }
-                    Ordering::Equal => {
-                        if point[1] > q2x_pt[1] {
-                            q2x_pt = point.clone();
-                            q2x = idx;
-                        }
-                    }
-                    Ordering::Less => (),
                 }
+                Ordering::Less => (),
             }
+        }
 
-            // q2y
-            if let Some(cmp) = q2y_pt[1].partial_cmp(&point[1]) {
-                match cmp {
-                    Ordering::Less => {
-                        q2y_pt = point.clone();
+        // q2y
+        if let Some(cmp) = q2y_pt[1].partial_cmp(&point[1]) {
+            match cmp {
+                Ordering::Less => {
+                    q2y_pt = point;
+                    q2y = idx;
+                }
+                Ordering::Equal => {
+                    if point[0] < q2y_pt[0] {
+                        q2y_pt = point;
                         q2y = idx;
                     }
-                    Ordering::Equal => {
-                        if point[0] < q2y_pt[0] {
-                            q2y_pt = point.clone();
-                            q2y = idx;
-                        }
-                    }
-                    Ordering::Greater => (),
                 }
+                Ordering::Greater => (),
             }
+        }
 
-            // q3x
-            if let Some(cmp) = q3x_pt[0].partial_cmp(&point[0]) {
-                match cmp {
-                    Ordering::Greater => {
-                        q3x_pt = point.clone();
+        // q3x
+        if let Some(cmp) = q3x_pt[0].partial_cmp(&point[0]) {
+            match cmp {
+                Ordering::Greater => {
+                    q3x_pt = point;
+                    q3x = idx;
+                }
+                Ordering::Equal => {
+                    if point[1] < q3x_pt[1] {
+                        q3x_pt = point;
                         q3x = idx;
                     }
-                    Ordering::Equal => {
-                        if point[1] < q3x_pt[1] {
-                            q3x_pt = point.clone();
-                            q3x = idx;
-                        }
-                    }
-                    Ordering::Less => (),
                 }
+                Ordering::Less => (),
             }
+        }
 
-            // q3y
-            if let Some(cmp) = q3y_pt[1].partial_cmp(&point[1]) {
-                match cmp {
-                    Ordering::Greater => {
-                        q3y_pt = point.clone();
+        // q3y
+        if let Some(cmp) = q3y_pt[1].partial_cmp(&point[1]) {
+            match cmp {
+                Ordering::Greater => {
+                    q3y_pt = point;
+                    q3y = idx;
+                }
+                Ordering::Equal => {
+                    if point[0] < q3y_pt[0] {
+                        q3y_pt = point;
                         q3y = idx;
                     }
-                    Ordering::Equal => {
-                        if point[0] < q3y_pt[0] {
-                            q3y_pt = point.clone();
-                            q3y = idx;
-                        }
-                    }
-                    Ordering::Less => (),
                 }
+                Ordering::Less => (),
             }
+        }
 
-            // q4x
-            if let Some(cmp) = q4x_pt[0].partial_cmp(&point[0]) {
-                match cmp {
-                    Ordering::Less => {
-                        q4x_pt = point.clone();
+        // q4x
+        if let Some(cmp) = q4x_pt[0].partial_cmp(&point[0]) {
+            match cmp {
+                Ordering::Less => {
+                    q4x_pt = point;
+                    q4x = idx;
+                }
+                Ordering::Equal => {
+                    if point[1] < q4x_pt[1] {
+                        q4x_pt = point;
                         q4x = idx;
                     }
-                    Ordering::Equal => {
-                        if point[1] < q4x_pt[1] {
-                            q4x_pt = point.clone();
-                            q4x = idx;
-                        }
-                    }
-                    Ordering::Greater => (),
                 }
+                Ordering::Greater => (),
             }
+        }
 
-            // q4y
-            if let Some(cmp) = q4y_pt[1].partial_cmp(&point[1]) {
-                match cmp {
-                    Ordering::Greater => {
-                        q4y_pt = point.clone();
+        // q4y
+        if let Some(cmp) = q4y_pt[1].partial_cmp(&point[1]) {
+            match cmp {
+                Ordering::Greater => {
+                    q4y_pt = point;
+                    q4y = idx;
+                }
+                Ordering::Equal => {
+                    if point[0] > q4y_pt[0] {
+                        q4y_pt = point;
                         q4y = idx;
                     }
-                    Ordering::Equal => {
-                        if point[0] > q4y_pt[0] {
-                            q4y_pt = point.clone();
-                            q4y = idx;
-                        }
-                    }
-                    Ordering::Less => (),
                 }
+                Ordering::Less => (),
             }
         }
+    }
 
-        // Cover the special case of a collection having only one point
-        if num_real_points == 1 {
-            let mut q1 = BTreeMap::new();
-            q1.insert(OrderedFloat(0.0), (q1x, q1x_pt));
+    if let (Some(stats), Some(start)) = (stats.as_deref_mut(), start) {
+        stats.extremes = start.elapsed();
+    }
 
-            let q2 = BTreeMap::new();
-            let q3 = BTreeMap::new();
-            let q4 = BTreeMap::new();
-            return ConvexHullIter::new([q1, q2, q3, q4]);
+    // The extremum points are the leftmost, bottom, rightmost and top corners
+    // of the hull (with the tie-breaking of `Hull::leftmost` and its
+    // siblings), which are kept for the conversion into a `Hull`.
+    let extremes = (num_real_points > 0).then_some([q3x_pt, q4y_pt, q1x_pt, q2y_pt]);
+
+    // Cover the special case of a collection having only one point
+    if num_real_points == 1 {
+        let mut q1 = BTreeMap::new();
+        q1.insert(OrderedFloat(0.0), (q1x, q1x_pt));
+        if let Some(trace) = trace {
+            *trace = Trace::from_events(vec![TraceEvent::Extreme {
+                quadrant: 0,
+                key: q1x,
+                point: q1x_pt,
+            }]);
         }
 
-        // Step 2: Insert the found extremum points into the quadrant hulls. If
-        // a quadrant has less than two points, it is considered degenerate and
-        // will be ignored in the next step.
-        //
-        // The hulls are represented by BTreeMaps, where the key is the x-value
-        // of the point and the value is a tuple containing the index and the
-        // point itself. The BTreeMap is used to keep the points sorted by their
-        // x-values, which is necessary for the next step of the algorithm.
-        let mut partial_hull_q1: BTreeMap<OrderedFloat<f64>, (usize, [f64; 2])> = BTreeMap::new();
-        if q1x != usize::MAX {
-            partial_hull_q1.insert(OrderedFloat(-q1x_pt[0]), (q1x, q1x_pt));
-        }
-        if q1y != usize::MAX {
-            partial_hull_q1.insert(OrderedFloat(-q1y_pt[0]), (q1y, q1y_pt));
+        let q2 = BTreeMap::new();
+        let q3 = BTreeMap::new();
+        let q4 = BTreeMap::new();
+        let hull = ConvexHullIter::new([q1, q2, q3, q4], extremes);
+        if let (Some(stats), Some(start)) = (stats, start) {
+            stats.total = start.elapsed();
         }
+        return hull;
+    }
 
-        let mut partial_hull_q2: BTreeMap<OrderedFloat<f64>, (usize, [f64; 2])> = BTreeMap::new();
-        if q2x != usize::MAX {
-            partial_hull_q2.insert(OrderedFloat(-q2x_pt[0]), (q2x, q2x_pt));
-        }
-        if q2y != usize::MAX {
-            partial_hull_q2.insert(OrderedFloat(-q2y_pt[0]), (q2y, q2y_pt));
-        }
+    // Step 2: Insert the found extremum points into the quadrant hulls. If
+    // a quadrant has less than two points, it is considered degenerate and
+    // will be ignored in the next step.
+    //
+    // The hulls are represented by BTreeMaps, where the key is the x-value
+    // of the point and the value is a tuple containing the index and the
+    // point itself. The BTreeMap is used to keep the points sorted by their
+    // x-values, which is necessary for the next step of the algorithm.
+    let mut partial_hull_q1: BTreeMap<OrderedFloat<f64>, (usize, [f64; 2])> = BTreeMap::new();
+    if q1x != usize::MAX {
+        partial_hull_q1.insert(OrderedFloat(-q1x_pt[0]), (q1x, q1x_pt));
+    }
+    if q1y != usize::MAX {
+        partial_hull_q1.insert(OrderedFloat(-q1y_pt[0]), (q1y, q1y_pt));
+    }
+
+    let mut partial_hull_q2: BTreeMap<OrderedFloat<f64>, (usize, [f64; 2])> = BTreeMap::new();
+    if q2x != usize::MAX {
+        partial_hull_q2.insert(OrderedFloat(-q2x_pt[0]), (q2x, q2x_pt));
+    }
+    if q2y != usize::MAX {
+        partial_hull_q2.insert(OrderedFloat(-q2y_pt[0]), (q2y, q2y_pt));
+    }
 
-        let mut partial_hull_q3: BTreeMap<OrderedFloat<f64>, (usize, [f64; 2])> = BTreeMap::new();
-        if q3x != usize::MAX {
-            partial_hull_q3.insert(OrderedFloat(q3x_pt[0]), (q3x, q3x_pt));
+    let mut partial_hull_q3: BTreeMap<OrderedFloat<f64>, (usize, [f64; 2])> = BTreeMap::new();
+    if q3x != usize::MAX {
+        partial_hull_q3.insert(OrderedFloat(q3x_pt[0]), (q3x, q3x_pt));
+    }
+    if q3y != usize::MAX {
+        partial_hull_q3.insert(OrderedFloat(q3y_pt[0]), (q3y, q3y_pt));
+    }
+
+    let mut partial_hull_q4: BTreeMap<OrderedFloat<f64>, (usize, [f64; 2])> = BTreeMap::new();
+    if q4x != usize::MAX {
+        partial_hull_q4.insert(OrderedFloat(q4x_pt[0]), (q4x, q4x_pt));
+    }
+    if q4y != usize::MAX {
+        partial_hull_q4.insert(OrderedFloat(q4y_pt[0]), (q4y, q4y_pt));
+    }
+
+    let mut partial_hulls = [
+        partial_hull_q1,
+        partial_hull_q2,
+        partial_hull_q3,
+        partial_hull_q4,
+    ];
+
+    let degenerate_quadrant = [
+        partial_hulls[0].len() < 2,
+        partial_hulls[1].len() < 2,
+        partial_hulls[2].len() < 2,
+        partial_hulls[3].len() < 2,
+    ];
+
+    let end_points = [q1x, q1y, q2x, q2y, q3x, q3y, q4x, q4y];
+
+    // Events of each quadrant, which are only recorded if a trace is
+    // requested. The extremum points are recorded up front.
+    let tracing = trace.is_some();
+    let mut extreme_events = Vec::new();
+    if tracing {
+        for (quadrant, partial_hull) in partial_hulls.iter().enumerate() {
+            for (key, point) in partial_hull.values().copied() {
+                extreme_events.push(TraceEvent::Extreme {
+                    quadrant,
+                    key,
+                    point,
+                });
+            }
         }
-        if q3y != usize::MAX {
-            partial_hull_q3.insert(OrderedFloat(q3y_pt[0]), (q3y, q3y_pt));
+    }
+    let mut quadrant_events: [Vec<TraceEvent>; 4] = Default::default();
+
+    /// Records a point which was removed from (or replaced inside) a partial hull.
+    fn record_removed(
+        events: &mut Option<&mut Vec<TraceEvent>>,
+        quadrant: usize,
+        removed: Option<(usize, [f64; 2])>,
+    ) {
+        if let (Some(events), Some((key, point))) = (events.as_deref_mut(), removed) {
+            events.push(TraceEvent::Removed {
+                quadrant,
+                key,
+                point,
+            });
         }
+    }
 
-        let mut partial_hull_q4: BTreeMap<OrderedFloat<f64>, (usize, [f64; 2])> = BTreeMap::new();
-        if q4x != usize::MAX {
-            partial_hull_q4.insert(OrderedFloat(q4x_pt[0]), (q4x, q4x_pt));
-        }
-        if q4y != usize::MAX {
-            partial_hull_q4.insert(OrderedFloat(q4y_pt[0]), (q4y, q4y_pt));
+    /// Records a point which was added to a partial hull on a quadrant boundary.
+    fn record_boundary(
+        events: &mut Option<&mut Vec<TraceEvent>>,
+        quadrant: usize,
+        (key, point): (usize, [f64; 2]),
+        replaced: Option<(usize, [f64; 2])>,
+    ) {
+        record_removed(events, quadrant, replaced);
+        if let Some(events) = events.as_deref_mut() {
+            events.push(TraceEvent::Boundary {
+                quadrant,
+                key,
+                point,
+            });
         }
+    }
 
-        let mut partial_hulls = [
-            partial_hull_q1,
-            partial_hull_q2,
-            partial_hull_q3,
-            partial_hull_q4,
-        ];
-
-        let degenerate_quadrant = [
-            partial_hulls[0].len() < 2,
-            partial_hulls[1].len() < 2,
-            partial_hulls[2].len() < 2,
-            partial_hulls[3].len() < 2,
-        ];
-
-        let end_points = [q1x, q1y, q2x, q2y, q3x, q3y, q4x, q4y];
-
-        fn loop_body<T: ConvexHull + ?Sized>(
-            this: &T,
-            partial_hull: &mut BTreeMap<OrderedFloat<f64>, (usize, [f64; 2])>,
-            quadrant: usize,
-            is_degenerate: bool,
-            end_points: [usize; 8],
-            q1y_pt: [f64; 2],
-            q2x_pt: [f64; 2],
-            q3y_pt: [f64; 2],
-            q4x_pt: [f64; 2],
-        ) {
-            // In q1 and q2, the search for new convex hull points starts with
-            // the largest x-value and stops with the smallest x-value of the
-            // quadrant (counter-clockwise search along the point set). In q3
-            // and q4, the search starts with the smallest x-value and ends with
-            // the largest. To use the same code inside the loop, the signs of
-            // the x-values in q1 and q2 are flipped. The orientation variable
-            // is used to flip the signs of the x-values in q1 and q2.
-            let orientation = 1.0 - (2.0 * (quadrant < 2) as i32 as f64);
-
-            for (c, pt_c) in this.convex_hull_iter() {
-                // Exclude all degenerate partial hulls. A partial hull is one
-                // which only has one entry.
-                if is_degenerate {
-                    continue;
-                }
+    #[allow(clippy::too_many_arguments)]
+    fn loop_body(
+        candidates: impl Iterator<Item = (usize, [f64; 2])>,
+        partial_hull: &mut BTreeMap<OrderedFloat<f64>, (usize, [f64; 2])>,
+        quadrant: usize,
+        is_degenerate: bool,
+        end_points: [usize; 8],
+        q1y_pt: [f64; 2],
+        q2x_pt: [f64; 2],
+        q3y_pt: [f64; 2],
+        q4x_pt: [f64; 2],
+        mut events: Option<&mut Vec<TraceEvent>>,
+    ) {
+        // In q1 and q2, the search for new convex hull points starts with
+        // the largest x-value and stops with the smallest x-value of the
+        // quadrant (counter-clockwise search along the point set). In q3
+        // and q4, the search starts with the smallest x-value and ends with
+        // the largest. To use the same code inside the loop, the signs of
+        // the x-values in q1 and q2 are flipped. The orientation variable
+        // is used to flip the signs of the x-values in q1 and q2.
+        let orientation = 1.0 - (2.0 * (quadrant < 2) as i32 as f64);
+
+        for (c, pt_c) in candidates {
+            // Exclude all degenerate partial hulls. A partial hull is one
+            // which only has one entry.
+            if is_degenerate {
+                continue;
+            }
 
-                // Skip any non-real points. Inverting "is_finite" also catches
-                // NaN (is_infinite only catches infinite values, not NaN).
-                if !pt_c[0].is_finite() || !pt_c[1].is_finite() {
-                    continue;
-                }
+            // Skip any non-real points. Inverting "is_finite" also catches
+            // NaN (is_infinite only catches infinite values, not NaN).
+            if !pt_c[0].is_finite() || !pt_c[1].is_finite() {
+                continue;
+            }
 
-                match quadrant {
-                    0 => {
-                        // Skip test if c == a or c == b
-                        if end_points.contains(&c) {
-                            continue;
-                        }
+            match quadrant {
+                0 => {
+                    // Skip test if c == a or c == b
+                    if end_points.contains(&c) {
+                        continue;
+                    }
 
-                        // Quadrant 1 -> 2
-                        if q1y_pt[1] == pt_c[1] {
+                    // Quadrant 1 -> 2
+                    if q1y_pt[1] == pt_c[1] {
+                        let replaced =
                             partial_hull.insert(OrderedFloat(pt_c[0] * orientation), (c, pt_c));
-                            continue;
-                        }
+                        record_boundary(&mut events, quadrant, (c, pt_c), replaced);
+                        continue;
+                    }
+                }
+                1 => {
+                    // Skip test if c == a or c == b
+                    if end_points.contains(&c) {
+                        continue;
                     }
-                    1 => {
-                        // Skip test if c == a or c == b
-                        if end_points.contains(&c) {
-                            continue;
-                        }
 
-                        // Quadrant 2 -> 3
-                        if q2x_pt[0] == pt_c[0] {
-                            partial_hull.insert(
-                                OrderedFloat((pt_c[0] + pt_c[1] - q2x_pt[1]) * orientation),
-                                (c, pt_c),
-                            );
-                            continue;
-                        }
+                    // Quadrant 2 -> 3
+                    if q2x_pt[0] == pt_c[0] {
+                        let replaced = partial_hull.insert(
+                            OrderedFloat((pt_c[0] + pt_c[1] - q2x_pt[1]) * orientation),
+                            (c, pt_c),
+                        );
+                        record_boundary(&mut events, quadrant, (c, pt_c), replaced);
+                        continue;
+                    }
+                }
+                2 => {
+                    // Skip test if c == a or c == b
+                    if end_points.contains(&c) {
+                        continue;
                     }
-                    2 => {
-                        // Skip test if c == a or c == b
-                        if end_points.contains(&c) {
-                            continue;
-                        }
 
-                        // Quadrant 3 -> 4
-                        if q3y_pt[1] == pt_c[1] {
+                    // Quadrant 3 -> 4
+                    if q3y_pt[1] == pt_c[1] {
+                        let replaced =
                             partial_hull.insert(OrderedFloat(pt_c[0] * orientation), (c, pt_c));
-                            continue;
-                        }
+                        record_boundary(&mut events, quadrant, (c, pt_c), replaced);
+                        continue;
+                    }
+                }
+                3 => {
+                    // Skip test if c == a or c == b
+                    if end_points.contains(&c) {
+                        continue;
                     }
-                    3 => {
-                        // Skip test if c == a or c == b
-                        if end_points.contains(&c) {
-                            continue;
-                        }
 
-                        // Quadrant 4 -> 1
-                        if q4x_pt[0] == pt_c[0] {
-                            partial_hull.insert(
-                                OrderedFloat((pt_c[0] + pt_c[1] - q4x_pt[1]) * orientation),
-                                (c, pt_c),
-                            );
-                            continue;
-                        }
+                    // Quadrant 4 -> 1
+                    if q4x_pt[0] == pt_c[0] {
+                        let replaced = partial_hull.insert(
+                            OrderedFloat((pt_c[0] + pt_c[1] - q4x_pt[1]) * orientation),
+                            (c, pt_c),
+                        );
+                        record_boundary(&mut events, quadrant, (c, pt_c), replaced);
+                        continue;
                     }
-                    _ => unreachable!(),
                 }
+                _ => unreachable!(),
+            }
 
-                let x = OrderedFloat(orientation * pt_c[0]);
-
-                /*
-                Find the two points inside the current partial hull whose
-                x-values form the closest bracket around the x-value of pt_c,
-                using the fact that the BTreeMap is sorted by x-value. The two
-                points are called A and B. If C is located to the right of the
-                line AB, C is part of the convex hull and possibly invalidates
-                A and/or B as well as neighboring points of A and B. If C is
-                located to the left of the line AB or directly on it, C is not
-                part of the convex hull and can be discarded. The cross product
-                of the vectors AB and AC is used to determine the relative
-                position of C to the line AB. The cross product is positive if C
-                is to the left of AB, negative if C is to the right, and zero if
-                C is on the line AB. The cross product is calculated as follows:
-                cross_prod_abc = (B.x - A.x) * (C.y - A.y) - (B.y - A.y) * (C.x - A.x)
-                 */
-                let mut pt_a = match partial_hull.range((Unbounded, Excluded(x))).last() {
-                    Some(lower_clamp) => lower_clamp.1.1,
-                    None => continue,
-                };
-                let mut pt_b = match partial_hull.range((Excluded(x), Unbounded)).next() {
-                    Some(upper_clamp) => upper_clamp.1.1,
-                    None => continue,
+            let x = OrderedFloat(orientation * pt_c[0]);
+
+            /*
+            Find the two points inside the current partial hull whose
+            x-values form the closest bracket around the x-value of pt_c,
+            using the fact that the BTreeMap is sorted by x-value. The two
+            points are called A and B. If C is located to the right of the
+            line AB, C is part of the convex hull and possibly invalidates
+            A and/or B as well as neighboring points of A and B. If C is
+            located to the left of the line AB or directly on it, C is not
+            part of the convex hull and can be discarded. The cross product
+            of the vectors AB and AC is used to determine the relative
+            position of C to the line AB. The cross product is positive if C
+            is to the left of AB, negative if C is to the right, and zero if
+            C is on the line AB. The cross product is calculated as follows:
+            cross_prod_abc = (B.x - A.x) * (C.y - A.y) - (B.y - A.y) * (C.x - A.x)
+             */
+            let (a, mut pt_a) = match partial_hull.range((Unbounded, Excluded(x))).last() {
+                Some(lower_clamp) => *lower_clamp.1,
+                None => continue,
+            };
+            let (b, mut pt_b) = match partial_hull.range((Excluded(x), Unbounded)).next() {
+                Some(upper_clamp) => *upper_clamp.1,
+                None => continue,
+            };
+
+            /*
+            Calculate the cross product which tells us whether C is on the
+            left of the line AB, directly on the line or right of it:
+            If (cross_prod > 0) then C is to the left => C can be discarded.
+            If (cross_prod = 0) then C is on the line => C is collinear
+            to A and B and can be discarded.
+            If (cross_prod < 0) then C is to the right => C is part of the
+            convex hull and possibly invalidates A and/or B as well as
+            neighboring points of A and B.
+
+            The last step is done by repeatedly reading the left / right
+            neighbor of A / B (called D) from here on. If A / B is located
+            on the left of DC / CD, A / B is discarded and D is assigned as
+            the next A / B. If A / B has no neighbors or if A / B is not
+            located on the left of DC / CD, the main loop continues.
+             */
+            let cross_prod_abc = (pt_b[0] - pt_a[0]) * (pt_c[1] - pt_a[1])
+                - (pt_b[1] - pt_a[1]) * (pt_c[0] - pt_a[0]);
+
+            if let Some(events) = events.as_deref_mut() {
+                let event = if cross_prod_abc < 0.0 {
+                    TraceEvent::Accepted {
+                        quadrant,
+                        key: c,
+                        point: pt_c,
+                        bracket: [a, b],
+                    }
+                } else {
+                    TraceEvent::Rejected {
+                        quadrant,
+                        key: c,
+                        point: pt_c,
+                        bracket: [a, b],
+                    }
                 };
+                events.push(event);
+            }
 
-                /*
-                Calculate the cross product which tells us whether C is on the
-                left of the line AB, directly on the line or right of it:
-                If (cross_prod > 0) then C is to the left => C can be discarded.
-                If (cross_prod = 0) then C is on the line => C is collinear
-                to A and B and can be discarded.
-                If (cross_prod < 0) then C is to the right => C is part of the
-                convex hull and possibly invalidates A and/or B as well as
-                neighboring points of A and B.
-
-                The last step is done by repeatedly reading the left / right
-                neighbor of A / B (called D) from here on. If A / B is located
-                on the left of DC / CD, A / B is discarded and D is assigned as
-                the next A / B. If A / B has no neighbors or if A / B is not
-                located on the left of DC / CD, the main loop continues.
-                 */
-                let cross_prod_abc = (pt_b[0] - pt_a[0]) * (pt_c[1] - pt_a[1])
-                    - (pt_b[1] - pt_a[1]) * (pt_c[0] - pt_a[0]);
-
-                if let Some(ordering) = cross_prod_abc.partial_cmp(&0.0) {
-                    match ordering {
-                        Ordering::Less => {
-                            // Check all neighbors on the left of A: [-INF, A)
-                            loop {
-                                let (_, pt_d) = match partial_hull
-                                    .range((
-                                        Unbounded,
-                                        Excluded(OrderedFloat(pt_a[0] * orientation)),
-                                    ))
-                                    .last()
-                                {
-                                    Some(val) => *val.1,
-                                    None => break, // A has no neighbor in search direction
-                                };
-
-                                // Line DC with A
-                                let cross_prod = (pt_c[0] - pt_d[0]) * (pt_a[1] - pt_d[1])
-                                    - (pt_c[1] - pt_d[1]) * (pt_a[0] - pt_d[0]);
-
-                                // If true, A is on the left of DC and is therefore discarded.
-                                if cross_prod >= 0.0 {
+            if let Some(ordering) = cross_prod_abc.partial_cmp(&0.0) {
+                match ordering {
+                    Ordering::Less => {
+                        // Check all neighbors on the left of A: [-INF, A)
+                        // The loop stops if A has no neighbor in search direction
+                        while let Some((_, pt_d)) = partial_hull
+                            .range((Unbounded, Excluded(OrderedFloat(pt_a[0] * orientation))))
+                            .last()
+                            .map(|val| *val.1)
+                        {
+                            // Line DC with A
+                            let cross_prod = (pt_c[0] - pt_d[0]) * (pt_a[1] - pt_d[1])
+                                - (pt_c[1] - pt_d[1]) * (pt_a[0] - pt_d[0]);
+
+                            // If true, A is on the left of DC and is therefore discarded.
+                            if cross_prod >= 0.0 {
+                                let removed =
                                     partial_hull.remove(&OrderedFloat(pt_a[0] * orientation));
+                                record_removed(&mut events, quadrant, removed);
 
-                                    // Replace A with D.
-                                    pt_a = pt_d;
-                                } else {
-                                    break;
-                                }
+                                // Replace A with D.
+                                pt_a = pt_d;
+                            } else {
+                                break;
                             }
+                        }
 
-                            // Check all neighbors on the right of B
-                            loop {
-                                let (_, pt_d) = match partial_hull
-                                    .range((
-                                        Excluded(OrderedFloat(pt_b[0] * orientation)),
-                                        Unbounded,
-                                    ))
-                                    .next()
-                                {
-                                    Some(val) => *val.1,
-                                    None => break, // B has no neighbor in search direction
-                                };
-
-                                // Line CD with B
-                                let cross_prod = (pt_d[0] - pt_c[0]) * (pt_b[1] - pt_c[1])
-                                    - (pt_d[1] - pt_c[1]) * (pt_b[0] - pt_c[0]);
-
-                                // If true, B is on the left of CD and is therefore discarded.
-                                if cross_prod >= 0.0 {
+                        // Check all neighbors on the right of B
+                        // The loop stops if B has no neighbor in search direction
+                        while let Some((_, pt_d)) = partial_hull
+                            .range((Excluded(OrderedFloat(pt_b[0] * orientation)), Unbounded))
+                            .next()
+                            .map(|val| *val.1)
+                        {
+                            // Line CD with B
+                            let cross_prod = (pt_d[0] - pt_c[0]) * (pt_b[1] - pt_c[1])
+                                - (pt_d[1] - pt_c[1]) * (pt_b[0] - pt_c[0]);
+
+                            // If true, B is on the left of CD and is therefore discarded.
+                            if cross_prod >= 0.0 {
+                                let removed =
                                     partial_hull.remove(&OrderedFloat(pt_b[0] * orientation));
+                                record_removed(&mut events, quadrant, removed);
 
-                                    // Replace B with D
-                                    pt_b = pt_d;
-                                } else {
-                                    break;
-                                }
+                                // Replace B with D
+                                pt_b = pt_d;
+                            } else {
+                                break;
                             }
+                        }
 
-                            // Add C to the partial hull
+                        // Add C to the partial hull
+                        let replaced =
                             partial_hull.insert(OrderedFloat(pt_c[0] * orientation), (c, pt_c));
-                        }
-                        _ => continue,
+                        record_removed(&mut events, quadrant, replaced);
                     }
+                    _ => continue,
                 }
             }
         }
+    }
 
-        /*
-        Loop for hull construction
-         */
-        #[cfg(not(feature = "rayon"))]
-        {
-            partial_hulls
-                .iter_mut()
-                .zip(degenerate_quadrant.into_iter())
-                .enumerate()
-                .for_each(|(quadrant, (partial_hull, is_degenerate))| {
-                    loop_body(
-                        self,
-                        partial_hull,
-                        quadrant,
-                        is_degenerate,
-                        end_points.clone(),
-                        q1y_pt,
-                        q2x_pt,
-                        q3y_pt,
-                        q4x_pt,
-                    );
-                });
-        }
-        #[cfg(feature = "rayon")]
-        {
-            partial_hulls
-                .par_iter_mut()
-                .zip(degenerate_quadrant.into_par_iter())
-                .enumerate()
-                .for_each(|(quadrant, (partial_hull, is_degenerate))| {
-                    loop_body(
-                        self,
-                        partial_hull,
-                        quadrant,
-                        is_degenerate,
-                        end_points.clone(),
-                        q1y_pt,
-                        q2x_pt,
-                        q3y_pt,
-                        q4x_pt,
-                    );
-                });
+    /*
+    Loop for hull construction
+     */
+    #[cfg(not(feature = "rayon"))]
+    let quadrant_durations: Vec<Option<Duration>> = {
+        partial_hulls
+            .iter_mut()
+            .zip(degenerate_quadrant)
+            .zip(quadrant_events.iter_mut())
+            .enumerate()
+            .map(|(quadrant, ((partial_hull, is_degenerate), events))| {
+                let quadrant_start = measure.then(Instant::now);
+                loop_body(
+                    this.convex_hull_iter(),
+                    partial_hull,
+                    quadrant,
+                    is_degenerate,
+                    end_points,
+                    q1y_pt,
+                    q2x_pt,
+                    q3y_pt,
+                    q4x_pt,
+                    tracing.then_some(events),
+                );
+                quadrant_start.map(|quadrant_start| quadrant_start.elapsed())
+            })
+            .collect()
+    };
+    #[cfg(feature = "rayon")]
+    let quadrant_durations: Vec<Option<Duration>> = {
+        // The points are collected once so that the candidate search
+        // inside each quadrant can be parallelized as well.
+        let preparation_start = measure.then(Instant::now);
+        let points: Vec<(usize, [f64; 2])> = this
+            .convex_hull_iter()
+            .filter(|(_, pt)| pt[0].is_finite() && pt[1].is_finite())
+            .collect();
+
+        if let (Some(stats), Some(preparation_start)) = (stats.as_deref_mut(), preparation_start) {
+            stats.preparation = preparation_start.elapsed();
         }
 
-        // Step 3: Combine the hulls inside an iterator which goes over the four
-        // quadrants in counter-clockwise order. The iterator also filters out
-        // duplicate points at the boundaries of the quadrants.
-        return ConvexHullIter::new(partial_hulls);
+        partial_hulls
+            .par_iter_mut()
+            .zip(degenerate_quadrant.into_par_iter())
+            .zip(quadrant_events.par_iter_mut())
+            .enumerate()
+            .map(|(quadrant, ((partial_hull, is_degenerate), events))| {
+                let quadrant_start = measure.then(Instant::now);
+                let elapsed = || quadrant_start.map(|quadrant_start| quadrant_start.elapsed());
+                if is_degenerate {
+                    return elapsed();
+                }
+
+                /*
+                Parallel partition of the candidates: The partial hull of
+                the quadrant only grows outwards from the line between its
+                two extremum points A and B. Therefore, only points on the
+                right of AB (and points on the quadrant boundary, which are
+                treated separately inside the loop body) can become hull
+                points. The sequential refinement in the loop body then only
+                needs to run over these survivors, which are usually a small
+                fraction of all points. If a trace is recorded, all points
+                are kept so that the trace contains every candidate.
+                 */
+                let (pt_a, pt_b) = match (
+                    partial_hull.first_key_value(),
+                    partial_hull.last_key_value(),
+                ) {
+                    (Some(first), Some(last)) => (first.1.1, last.1.1),
+                    _ => return elapsed(),
+                };
+                let candidates: Vec<(usize, [f64; 2])> = points
+                    .par_iter()
+                    .filter(|(c, pt_c)| {
+                        if end_points.contains(c) {
+                            return false;
+                        }
+                        let on_boundary = match quadrant {
+                            0 => q1y_pt[1] == pt_c[1],
+                            1 => q2x_pt[0] == pt_c[0],
+                            2 => q3y_pt[1] == pt_c[1],
+                            _ => q4x_pt[0] == pt_c[0],
+                        };
+                        let cross_prod_abc = (pt_b[0] - pt_a[0]) * (pt_c[1] - pt_a[1])
+                            - (pt_b[1] - pt_a[1]) * (pt_c[0] - pt_a[0]);
+                        tracing || on_boundary || cross_prod_abc < 0.0
+                    })
+                    .copied()
+                    .collect();
+
+                loop_body(
+                    candidates.into_iter(),
+                    partial_hull,
+                    quadrant,
+                    is_degenerate,
+                    end_points,
+                    q1y_pt,
+                    q2x_pt,
+                    q3y_pt,
+                    q4x_pt,
+                    tracing.then_some(events),
+                );
+                elapsed()
+            })
+            .collect()
+    };
+
+    // Step 3: Combine the hulls inside an iterator which goes over the four
+    // quadrants in counter-clockwise order. The iterator also filters out
+    // duplicate points at the boundaries of the quadrants.
+    let merge_start = measure.then(Instant::now);
+    let hull = ConvexHullIter::new(partial_hulls, extremes);
+
+    if let Some(trace) = trace {
+        extreme_events.extend(quadrant_events.into_iter().flatten());
+        *trace = Trace::from_events(extreme_events);
     }
+
+    if let Some(stats) = stats {
+        for (duration, quadrant_duration) in stats.quadrants.iter_mut().zip(quadrant_durations) {
+            *duration = quadrant_duration.unwrap_or_default();
+        }
+        if let Some(merge_start) = merge_start {
+            stats.merge = merge_start.elapsed();
+        }
+        if let Some(start) = start {
+            stats.total = start.elapsed();
+        }
+    }
+    return hull;
 }
 
 /**
@@ -736,54 +1976,121 @@ for more.
  */
 #[derive(Debug)]
 pub struct ConvexHullIter {
-    quadrant_iterators: [IntoIter<OrderedFloat<f64>, (usize, [f64; 2])>; 4],
-    hull_idx: usize,
-    first_returned_idx: Option<usize>,
-    last_returned_idx: Option<usize>,
+    source: HullSource,
+    /// Leftmost, bottom, rightmost and top corner, if known from step 1 of
+    /// the divide-and-conquer algorithm.
+    extremes: Option<[[f64; 2]; 4]>,
+}
+
+/// The hull points behind a [`ConvexHullIter`]. The lazy variant is kept
+/// inline, so that the divide-and-conquer hull does not allocate.
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+enum HullSource {
+    /// The partial hulls of the divide-and-conquer algorithm, which are
+    /// merged lazily while iterating.
+    Quadrants {
+        quadrant_iterators: [<QuadrantHull as IntoIterator>::IntoIter; 4],
+        hull_idx: usize,
+        first_returned: Option<(usize, [f64; 2])>,
+        last_returned: Option<(usize, [f64; 2])>,
+    },
+    /// Hull points which are already in counter-clockwise order, e.g. the
+    /// result of [`ConvexHull::convex_hull_exact`].
+    Points(std::vec::IntoIter<(usize, [f64; 2])>),
 }
 
 impl ConvexHullIter {
-    fn new(quadrants: [BTreeMap<OrderedFloat<f64>, (usize, [f64; 2])>; 4]) -> Self {
-        let quadrant_iterators = quadrants.map(|q| q.into_iter());
+    fn new(quadrants: [QuadrantHull; 4], extremes: Option<[[f64; 2]; 4]>) -> Self {
+        return Self {
+            source: HullSource::Quadrants {
+                quadrant_iterators: quadrants.map(|q| q.into_iter()),
+                hull_idx: 0,
+                first_returned: None,
+                last_returned: None,
+            },
+            extremes,
+        };
+    }
 
+    /**
+    Creates an iterator from hull points which are already in
+    counter-clockwise order.
+     */
+    pub(crate) fn from_vec(points: Vec<(usize, [f64; 2])>) -> Self {
         return Self {
-            quadrant_iterators,
-            hull_idx: 0,
-            first_returned_idx: None,
-            last_returned_idx: None,
+            source: HullSource::Points(points.into_iter()),
+            extremes: None,
         };
     }
+
+    /// Attaches the leftmost, bottom, rightmost and top corner of the hull.
+    pub(crate) fn with_extremes(mut self, extremes: Option<[[f64; 2]; 4]>) -> Self {
+        self.extremes = extremes;
+        return self;
+    }
+
+    /// Returns the leftmost, bottom, rightmost and top corner of the hull, if
+    /// they are known.
+    pub(crate) fn extremes(&self) -> Option<[[f64; 2]; 4]> {
+        return self.extremes;
+    }
 }
 
 impl Iterator for ConvexHullIter {
     type Item = (usize, [f64; 2]);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.hull_idx >= 4 {
-            return None;
-        }
-
-        let current_hull = &mut self.quadrant_iterators[self.hull_idx];
-        match current_hull.next() {
-            Some(item) => {
-                // This check prevents that points are returned twice at the
-                // boundary of two hull iterators.
-                let idx = Some(item.1.0);
-                if idx == self.last_returned_idx || idx == self.first_returned_idx {
-                    return self.next();
-                }
-                self.last_returned_idx = idx;
-
-                if self.first_returned_idx.is_none() {
-                    self.first_returned_idx = idx;
+        match &mut self.source {
+            HullSource::Quadrants {
+                quadrant_iterators,
+                hull_idx,
+                first_returned,
+                last_returned,
+            } => {
+                while let Some(current_hull) = quadrant_iterators.get_mut(*hull_idx) {
+                    let Some((_, item)) = current_hull.next() else {
+                        *hull_idx += 1;
+                        continue;
+                    };
+
+                    // This check prevents that points are returned twice at
+                    // the boundary of two quadrant hulls. Duplicate points
+                    // with different keys can end up in neighboring
+                    // quadrants, hence the coordinates are compared as well.
+                    let is_same = |p: &(usize, [f64; 2])| p.0 == item.0 || p.1 == item.1;
+                    if last_returned.as_ref().is_some_and(is_same)
+                        || first_returned.as_ref().is_some_and(is_same)
+                    {
+                        continue;
+                    }
+                    *last_returned = Some(item);
+                    if first_returned.is_none() {
+                        *first_returned = Some(item);
+                    }
+                    return Some(item);
                 }
-
-                return Some(item.1);
+                return None;
             }
-            None => {
-                self.hull_idx += 1;
-                return self.next();
+            HullSource::Points(points) => return points.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.source {
+            HullSource::Quadrants {
+                quadrant_iterators,
+                hull_idx,
+                ..
+            } => {
+                let remaining = quadrant_iterators
+                    .iter()
+                    .skip(*hull_idx)
+                    .map(|iter| iter.len())
+                    .sum();
+                return (0, Some(remaining));
             }
+            HullSource::Points(points) => return points.size_hint(),
         }
     }
 }
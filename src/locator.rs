@@ -0,0 +1,118 @@
+//! This module contains the [`HullLocator`] for answering many point location
+//! queries on the same hull.
+
+use std::cmp::Ordering;
+
+use crate::Hull;
+use crate::hull::PointLocation;
+use crate::predicates::{on_segment, orientation};
+
+/**
+Precomputed structure for repeated point location queries on a [`Hull`].
+
+The corners of the hull are sorted by their angle around an interior anchor
+point, which divides the hull into triangular wedges. A query finds the wedge
+of the point by a binary search over the precomputed angles, which is cheaper
+than the binary search with orientation predicates of [`Hull::locate`]. The
+point is then located relative to the outer edge of the wedge and its two
+neighbors with exact predicates. Checking the neighbors guards against
+rounding errors of the angles, so the results agree with [`Hull::locate`].
+After the O(h) setup, each query takes O(log h) time.
+
+# Examples
+
+```
+use planar_convex_hull::{Hull, PointLocation, locator::HullLocator};
+
+let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+let locator = HullLocator::new(&hull);
+assert_eq!(locator.locate([1.0, 1.0]), PointLocation::Inside);
+assert_eq!(locator.locate([2.0, 0.5]), PointLocation::OnBoundary);
+assert_eq!(locator.locate([3.0, 1.0]), PointLocation::Outside);
+assert!(locator.contains([0.0, 0.0]));
+```
+ */
+#[derive(Debug, Clone)]
+pub struct HullLocator {
+    /// Hull corners, rotated so that their angles around the anchor are
+    /// ascending.
+    corners: Vec<[f64; 2]>,
+    /// Angle of each corner around the anchor in (-π, π], ascending.
+    angles: Vec<f64>,
+    /// Point strictly inside the hull.
+    anchor: [f64; 2],
+    /// Hulls without interior are located directly.
+    degenerate: Option<Hull>,
+}
+
+impl HullLocator {
+    /// Precomputes the locator for `hull` in O(h).
+    pub fn new(hull: &Hull) -> Self {
+        let points = hull.points();
+        let n = points.len();
+        if n < 3 {
+            return Self {
+                corners: Vec::new(),
+                angles: Vec::new(),
+                anchor: [0.0; 2],
+                degenerate: Some(hull.clone()),
+            };
+        }
+
+        // The centroid of a triangle spanned by corners which are spread over
+        // the boundary is well inside the hull.
+        let [a, b, c] = [points[0], points[n / 3], points[2 * n / 3]];
+        let anchor = [(a[0] + b[0] + c[0]) / 3.0, (a[1] + b[1] + c[1]) / 3.0];
+        let angle = |p: [f64; 2]| (p[1] - anchor[1]).atan2(p[0] - anchor[0]);
+
+        // Rotate the corners so that the one with the smallest angle comes
+        // first. For a convex polygon, the angles are then ascending.
+        let first = (0..n)
+            .min_by(|i, j| angle(points[*i]).total_cmp(&angle(points[*j])))
+            .unwrap_or(0);
+        let mut corners = points.to_vec();
+        corners.rotate_left(first);
+        let angles = corners.iter().map(|p| angle(*p)).collect();
+        return Self {
+            corners,
+            angles,
+            anchor,
+            degenerate: None,
+        };
+    }
+
+    /// Returns the location of `point` relative to the hull, see
+    /// [`Hull::locate`].
+    pub fn locate(&self, point: [f64; 2]) -> PointLocation {
+        if let Some(hull) = &self.degenerate {
+            return hull.locate(point);
+        }
+        if !point[0].is_finite() || !point[1].is_finite() {
+            return PointLocation::Outside;
+        }
+        let corners = &self.corners;
+        let n = corners.len();
+
+        // The wedge between the corners `wedge - 1` and `wedge`
+        let angle = (point[1] - self.anchor[1]).atan2(point[0] - self.anchor[0]);
+        let wedge = self.angles.partition_point(|a| *a <= angle);
+
+        let mut location = PointLocation::Inside;
+        for edge in [wedge + n - 2, wedge + n - 1, wedge + n] {
+            let (a, b) = (corners[edge % n], corners[(edge + 1) % n]);
+            match orientation(a, b, point) {
+                Ordering::Less => return PointLocation::Outside,
+                Ordering::Equal if on_segment(a, b, point) => location = PointLocation::OnBoundary,
+                Ordering::Equal => return PointLocation::Outside,
+                Ordering::Greater => (),
+            }
+        }
+        return location;
+    }
+
+    /// Returns `true` if `point` is located inside the hull or on its
+    /// boundary, see [`Hull::contains`].
+    pub fn contains(&self, point: [f64; 2]) -> bool {
+        return self.locate(point) != PointLocation::Outside;
+    }
+}
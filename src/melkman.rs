@@ -0,0 +1,98 @@
+//! Melkman's linear-time algorithm for the convex hull of a simple polyline
+//! \[1\], see
+//! [`ConvexHull::convex_hull_of_polyline`](crate::ConvexHull::convex_hull_of_polyline).
+//!
+//! # Literature
+//!
+//! 1. Melkman, A. A.: On-line construction of the convex hull of a simple
+//!    polyline. Information Processing Letters 25, 11–12 (1987).
+//!    <https://doi.org/10.1016/0020-0190(87)90086-X>
+
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+use crate::monotone_chain::monotone_chain;
+use crate::predicates::{on_segment, orientation};
+
+/// Returns `true` if `point` is located left of the line from `a` to `b` or on
+/// the segment between them.
+fn inside(a: [f64; 2], b: [f64; 2], point: [f64; 2]) -> bool {
+    return match orientation(a, b, point) {
+        Ordering::Greater => true,
+        Ordering::Equal => on_segment(a, b, point),
+        Ordering::Less => false,
+    };
+}
+
+/**
+Calculates the convex hull of the simple polyline through `vertices` with
+Melkman's algorithm and returns its corners in the same order as
+[`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull). Nonreal vertices
+are skipped.
+ */
+pub(crate) fn melkman(vertices: impl Iterator<Item = (usize, [f64; 2])>) -> Vec<(usize, [f64; 2])> {
+    let mut vertices = vertices.filter(|(_, p)| p[0].is_finite() && p[1].is_finite());
+
+    // The polyline may start with a straight section, which is collapsed to
+    // its ends (the lexicographically smallest and largest vertex).
+    let mut line: Vec<(usize, [f64; 2])> = Vec::new();
+    let mut deque: VecDeque<(usize, [f64; 2])> = VecDeque::new();
+    for vertex in vertices.by_ref() {
+        if let [b, a] = line.as_slice() {
+            match orientation(a.1, b.1, vertex.1) {
+                Ordering::Equal => (),
+                Ordering::Greater => {
+                    deque.extend([vertex, *a, *b, vertex]);
+                    break;
+                }
+                Ordering::Less => {
+                    deque.extend([vertex, *b, *a, vertex]);
+                    break;
+                }
+            }
+        }
+        line.push(vertex);
+        line = monotone_chain(line);
+    }
+    if deque.is_empty() {
+        // All vertices are collinear
+        return line;
+    }
+
+    // The deque holds the hull in counter-clockwise order, starting and ending
+    // with the last hull vertex of the polyline. A vertex which is located
+    // inside the hull can only leave it through the edges at this vertex.
+    for vertex in vertices {
+        let n = deque.len();
+        if inside(deque[n - 2].1, deque[n - 1].1, vertex.1)
+            && inside(deque[0].1, deque[1].1, vertex.1)
+        {
+            continue;
+        }
+        while deque.len() > 2 && orientation(deque[0].1, deque[1].1, vertex.1) != Ordering::Greater
+        {
+            deque.pop_front();
+        }
+        deque.push_front(vertex);
+        while deque.len() > 2 && {
+            let n = deque.len();
+            orientation(deque[n - 2].1, deque[n - 1].1, vertex.1) != Ordering::Greater
+        } {
+            deque.pop_back();
+        }
+        deque.push_back(vertex);
+    }
+
+    // Start with the point with the largest x-value (and the largest y-value
+    // if there are multiple such points).
+    deque.pop_back();
+    let mut hull: Vec<(usize, [f64; 2])> = deque.into();
+    let first = hull
+        .iter()
+        .enumerate()
+        .max_by(|(_, (_, a)), (_, (_, b))| a[0].total_cmp(&b[0]).then(a[1].total_cmp(&b[1])))
+        .map(|(index, _)| index)
+        .unwrap_or(0);
+    hull.rotate_left(first);
+    return hull;
+}
@@ -0,0 +1,103 @@
+//! Minkowski sums of hulls, see [`Hull::minkowski_sum`] and
+//! [`Hull::interpolate`].
+
+use crate::Hull;
+use crate::polygon::minkowski_sum;
+
+/// Creates a hull from the corners of a convex polygon, using their positions
+/// as keys.
+fn renumbered(points: Vec<[f64; 2]>) -> Hull {
+    return Hull::from_vertices_unchecked(points.into_iter().enumerate());
+}
+
+/// Returns the hull scaled by the positive `factor` about the origin.
+fn scaled(hull: &Hull, factor: f64) -> Vec<[f64; 2]> {
+    return hull
+        .points()
+        .iter()
+        .map(|p| [factor * p[0], factor * p[1]])
+        .collect();
+}
+
+impl Hull {
+    /**
+    Calculates the Minkowski sum of the hull and `other`, i.e. the convex
+    polygon of all sums `a + b` of a point `a` of the hull and a point `b` of
+    `other`. In motion planning, the sum of an obstacle and the reflected
+    shape of a robot is the obstacle in configuration space. If one of the
+    hulls is empty, the result is empty.
+
+    Both hulls are traversed once, starting at their lowest corners and
+    merging their edges by angle (like
+    [`minkowski_difference`](crate::polygon::minkowski_difference)), which
+    takes O(n + m) time. Since the
+    corners of the result are new points, the keys of the result are the
+    positions of its corners, starting at zero.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let square = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+    let triangle = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [0.0, 2.0]]);
+    let sum = square.minkowski_sum(&triangle);
+    assert_eq!(
+        sum.points(),
+        &[[3.0, 1.0], [1.0, 3.0], [0.0, 3.0], [0.0, 0.0], [3.0, 0.0]]
+    );
+    assert_eq!(sum.area(), 1.0 + 2.0 + 4.0);
+    ```
+     */
+    pub fn minkowski_sum(&self, other: &Hull) -> Hull {
+        return renumbered(minkowski_sum(self.points(), other.points()));
+    }
+
+    /**
+    Interpolates between the hull (at `t = 0`) and `other` (at `t = 1`) via
+    the Minkowski combination `(1 - t) A + t B` of both hulls, e.g. to animate
+    a tracked region between two frames. The intermediate polygons are convex
+    and change continuously with `t`: Their support functions (and therefore
+    their widths, perimeters and centroids) interpolate linearly, and every
+    edge of the result is parallel to an edge of one of the hulls. If one of
+    the hulls is empty, the result is empty.
+
+    The Minkowski sum of the scaled hulls is calculated in O(n + m) time (see
+    [`Hull::minkowski_sum`]). The keys of the result are the positions of its
+    corners, starting at zero.
+
+    # Panics
+
+    Panics if `t` is not within [0, 1].
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let square = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    let shifted = Hull::new(&[[4.0, 0.0], [6.0, 0.0], [6.0, 2.0], [4.0, 2.0]]);
+    let halfway = square.interpolate(&shifted, 0.5);
+    assert_eq!(halfway.points(), &[[4.0, 2.0], [2.0, 2.0], [2.0, 0.0], [4.0, 0.0]]);
+
+    // The perimeter interpolates linearly
+    let diamond = Hull::new(&[[1.0, 0.0], [2.0, 1.0], [1.0, 2.0], [0.0, 1.0]]);
+    let morph = square.interpolate(&diamond, 0.25);
+    let expected = 0.75 * square.perimeter() + 0.25 * diamond.perimeter();
+    assert!((morph.perimeter() - expected).abs() < 1e-12);
+    ```
+     */
+    pub fn interpolate(&self, other: &Hull, t: f64) -> Hull {
+        assert!((0.0..=1.0).contains(&t), "t must be within [0, 1]");
+        if self.is_empty() || other.is_empty() {
+            return Hull::default();
+        }
+        if t == 0.0 {
+            return renumbered(self.points().to_vec());
+        }
+        if t == 1.0 {
+            return renumbered(other.points().to_vec());
+        }
+        return renumbered(minkowski_sum(&scaled(self, 1.0 - t), &scaled(other, t)));
+    }
+}
@@ -0,0 +1,159 @@
+//! Andrew's monotone chain algorithm with exact orientation predicates. It is
+//! used as a robust fallback and reference implementation for the
+//! divide-and-conquer algorithm of [`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull).
+
+use std::cmp::Ordering;
+
+use crate::predicates::orientation;
+
+/**
+Calculates the convex hull of `points` and returns its corners in the same
+order as [`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull):
+Counter-clockwise, starting with the point with the largest x-value (and the
+largest y-value if there are multiple such points). Collinear points on the
+hull edges and nonreal points are discarded, and of multiple identical points
+only one is kept.
+ */
+pub(crate) fn monotone_chain(mut points: Vec<(usize, [f64; 2])>) -> Vec<(usize, [f64; 2])> {
+    points.retain(|(_, p)| p[0].is_finite() && p[1].is_finite());
+    points.sort_by(|(_, a), (_, b)| lexicographic(a, b));
+    return from_sorted(points);
+}
+
+/**
+Like [`monotone_chain`], but skips the sorting if the real `points` are
+already sorted lexicographically, which is checked in O(n).
+ */
+pub(crate) fn monotone_chain_presorted(
+    mut points: Vec<(usize, [f64; 2])>,
+) -> Vec<(usize, [f64; 2])> {
+    points.retain(|(_, p)| p[0].is_finite() && p[1].is_finite());
+    if !points.is_sorted_by(|(_, a), (_, b)| lexicographic(a, b) != Ordering::Greater) {
+        points.sort_by(|(_, a), (_, b)| lexicographic(a, b));
+    }
+    return from_sorted(points);
+}
+
+/// Compares the real points `a` and `b` lexicographically, treating `-0.0` and
+/// `0.0` as equal.
+pub(crate) fn lexicographic(a: &[f64; 2], b: &[f64; 2]) -> Ordering {
+    return (a[0] + 0.0)
+        .total_cmp(&(b[0] + 0.0))
+        .then((a[1] + 0.0).total_cmp(&(b[1] + 0.0)));
+}
+
+/// Runs the monotone chain algorithm on real `points` which are sorted
+/// lexicographically.
+fn from_sorted(mut points: Vec<(usize, [f64; 2])>) -> Vec<(usize, [f64; 2])> {
+    points.dedup_by(|(_, a), (_, b)| a == b);
+
+    if points.len() < 3 {
+        // Zero or one point or a line from the largest to the smallest point
+        points.reverse();
+        return points;
+    }
+
+    fn chain<'a>(points: impl Iterator<Item = &'a (usize, [f64; 2])>) -> Vec<(usize, [f64; 2])> {
+        let mut chain: Vec<(usize, [f64; 2])> = Vec::new();
+        for point in points {
+            while chain.len() >= 2
+                && orientation(chain[chain.len() - 2].1, chain[chain.len() - 1].1, point.1)
+                    != Ordering::Greater
+            {
+                chain.pop();
+            }
+            chain.push(*point);
+        }
+
+        // The last point of a chain is the first point of the next one
+        chain.pop();
+        return chain;
+    }
+
+    // The upper chain starts with the largest point and ends with the smallest
+    let mut hull = chain(points.iter().rev());
+    hull.extend(chain(points.iter()));
+    return hull;
+}
+
+/// Merges the lexicographically sorted points `a` and `b`, where points of `a`
+/// come first among identical points.
+fn merge_sorted(a: &[(usize, [f64; 2])], b: &[(usize, [f64; 2])]) -> Vec<(usize, [f64; 2])> {
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if j == b.len() || (i < a.len() && lexicographic(&a[i].1, &b[j].1) != Ordering::Greater) {
+            merged.push(a[i]);
+            i += 1;
+        } else {
+            merged.push(b[j]);
+            j += 1;
+        }
+    }
+    return merged;
+}
+
+/**
+Returns the corners of a hull (in the order of
+[`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull)) sorted
+lexicographically in O(h): The corners up to the lexicographically smallest one
+are sorted in decreasing order, the remaining ones in increasing order.
+ */
+fn sorted_corners(corners: &[(usize, [f64; 2])]) -> Vec<(usize, [f64; 2])> {
+    let Some(smallest) =
+        (0..corners.len()).min_by(|a, b| lexicographic(&corners[*a].1, &corners[*b].1))
+    else {
+        return Vec::new();
+    };
+    let decreasing: Vec<(usize, [f64; 2])> = corners[..=smallest].iter().rev().copied().collect();
+    return merge_sorted(&decreasing, &corners[smallest + 1..]);
+}
+
+/**
+Calculates the convex hull of the union of the hulls `a` and `b` (both in the
+order of [`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull)) in
+O(h1 + h2): The corners of both hulls are sorted lexicographically in linear
+time, so the sorting step of the monotone chain algorithm reduces to merging
+them. Of identical corners, the one of `a` is kept.
+ */
+pub(crate) fn merge(a: &[(usize, [f64; 2])], b: &[(usize, [f64; 2])]) -> Vec<(usize, [f64; 2])> {
+    return from_sorted(merge_sorted(&sorted_corners(a), &sorted_corners(b)));
+}
+
+/**
+Returns `true` if every distinct real point of `points` is a corner of their
+convex hull. The points are sorted and split by the line through the smallest
+and the largest point into an upper and a lower chain, which must turn
+strictly clockwise and counter-clockwise at every point. The first violation
+ends the check early.
+ */
+pub(crate) fn in_convex_position(mut points: Vec<[f64; 2]>) -> bool {
+    points.retain(|p| p[0].is_finite() && p[1].is_finite());
+    points.sort_by(lexicographic);
+    points.dedup();
+    let (Some(first), Some(last)) = (points.first().copied(), points.last().copied()) else {
+        return true;
+    };
+    if points.len() < 3 {
+        return true;
+    }
+
+    let (mut upper, mut lower) = (vec![first], vec![first]);
+    for point in &points[1..points.len() - 1] {
+        match orientation(first, last, *point) {
+            Ordering::Greater => upper.push(*point),
+            Ordering::Less => lower.push(*point),
+            // Located on the segment between the smallest and the largest
+            // point
+            Ordering::Equal => return false,
+        }
+    }
+    upper.push(last);
+    lower.push(last);
+    let turns = |chain: &[[f64; 2]], turn: Ordering| {
+        return chain
+            .windows(3)
+            .all(|window| orientation(window[0], window[1], window[2]) == turn);
+    };
+    return turns(&upper, Ordering::Less) && turns(&lower, Ordering::Greater);
+}
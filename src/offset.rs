@@ -0,0 +1,295 @@
+//! This module contains the [`Join`] type, which selects how the corners of a
+//! dilated hull are formed, see [`Hull::offset`].
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::f64::consts::TAU;
+
+use ordered_float::OrderedFloat;
+
+use crate::{ConvexHull, Hull};
+
+/**
+Shape of the corners of a hull dilated by [`Hull::offset`].
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum Join {
+    /// The shifted edges are extended until they meet, so the result has as
+    /// many corners as the hull. Points and segments get square caps.
+    Miter,
+    /// The shifted edges are connected by circular arcs around the hull
+    /// corners (the exact dilation). The arcs are approximated by tangent
+    /// segments which deviate at most `tolerance` from them, so the result
+    /// contains the exact dilation.
+    Round {
+        /// Largest distance between an arc and its approximation.
+        tolerance: f64,
+    },
+}
+
+/// Line of a hull edge, shifted inwards by the offset `t` as the polygon
+/// shrinks: The points `x` with `normal · x = offset + t`.
+struct OffsetLine {
+    direction: [f64; 2],
+    normal: [f64; 2],
+    offset: f64,
+}
+
+/**
+Intersection of the shifted lines `a` and `b`, where `b` follows `a` in
+counter-clockwise order. The intersection is `position + t * velocity` for the
+shift `t`. Returns `None` if the lines do not turn left.
+ */
+fn offset_vertex(a: &OffsetLine, b: &OffsetLine) -> Option<([f64; 2], [f64; 2])> {
+    let [p, q] = [a.normal, b.normal];
+    let det = p[0] * q[1] - p[1] * q[0];
+    if det <= 0.0 {
+        return None;
+    }
+    let solve = |u: f64, v: f64| [(u * q[1] - v * p[1]) / det, (v * p[0] - u * q[0]) / det];
+    let (position, velocity) = (solve(a.offset, b.offset), solve(1.0, 1.0));
+    if position
+        .iter()
+        .chain(&velocity)
+        .any(|value| !value.is_finite())
+    {
+        return None;
+    }
+    return Some((position, velocity));
+}
+
+/// Result of [`shrink`].
+pub(crate) enum Shrunk {
+    /// Corners of the polygon after shifting all edges inwards.
+    Polygon(Vec<[f64; 2]>),
+    /// The polygon vanished in the point `center` before the shift was
+    /// reached.
+    Vanished { center: [f64; 2] },
+}
+
+/**
+Shrinks the convex polygon `points` (counter-clockwise, at least three corners,
+no collinear corners) by moving all edge lines inwards by `shift` at the same
+speed.
+
+Whenever an edge shrinks to a point, its line no longer bounds the shrunk
+polygon and is removed. With a priority queue of the edge collapses, this takes
+O(h log h) time. If the polygon vanishes before `shift` is reached, the point
+where it vanished (the root of its straight skeleton) is returned.
+ */
+pub(crate) fn shrink(points: &[[f64; 2]], shift: f64) -> Shrunk {
+    let n = points.len();
+    let lines: Vec<OffsetLine> = (0..n)
+        .map(|i| {
+            let (a, b) = (points[i], points[(i + 1) % n]);
+            let length = (b[0] - a[0]).hypot(b[1] - a[1]);
+            let direction = [(b[0] - a[0]) / length, (b[1] - a[1]) / length];
+            let normal = [-direction[1], direction[0]];
+            return OffsetLine {
+                direction,
+                normal,
+                offset: normal[0] * a[0] + normal[1] * a[1],
+            };
+        })
+        .collect();
+
+    // Doubly linked list of the remaining edges
+    let mut previous: Vec<usize> = (0..n).map(|i| (i + n - 1) % n).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| (i + 1) % n).collect();
+    let mut version = vec![0usize; n];
+
+    // Shift at which edge `i` collapses and the point it collapses to
+    let collapse = |i: usize, previous: usize, next: usize| {
+        let (start, start_velocity) = offset_vertex(&lines[previous], &lines[i])?;
+        let (end, end_velocity) = offset_vertex(&lines[i], &lines[next])?;
+        let d = lines[i].direction;
+        let length = d[0] * (end[0] - start[0]) + d[1] * (end[1] - start[1]);
+        let shrinking = d[0] * (start_velocity[0] - end_velocity[0])
+            + d[1] * (start_velocity[1] - end_velocity[1]);
+        if shrinking <= 0.0 {
+            return None;
+        }
+        let t = length / shrinking;
+        return Some((
+            t,
+            [
+                start[0] + t * start_velocity[0],
+                start[1] + t * start_velocity[1],
+            ],
+        ));
+    };
+
+    let mut queue = BinaryHeap::new();
+    for i in 0..n {
+        if let Some((t, _)) = collapse(i, previous[i], next[i]) {
+            queue.push(Reverse((OrderedFloat(t), i, 0usize)));
+        }
+    }
+
+    let mut remaining = n;
+    let mut start = 0;
+    while let Some(Reverse((OrderedFloat(t), i, event_version))) = queue.peek().copied() {
+        if t > shift {
+            break;
+        }
+        queue.pop();
+        if event_version != version[i] {
+            continue;
+        }
+        let (p, q) = (previous[i], next[i]);
+        if remaining == 3
+            && let Some((_, center)) = collapse(i, p, q)
+        {
+            return Shrunk::Vanished { center };
+        }
+        next[p] = q;
+        previous[q] = p;
+        remaining -= 1;
+        start = q;
+        for j in [p, q] {
+            version[j] += 1;
+            if let Some((t, _)) = collapse(j, previous[j], next[j]) {
+                queue.push(Reverse((OrderedFloat(t), j, version[j])));
+            }
+        }
+    }
+
+    let mut polygon = Vec::with_capacity(remaining);
+    let mut line = start;
+    for _ in 0..remaining {
+        if let Some((position, velocity)) = offset_vertex(&lines[previous[line]], &lines[line]) {
+            polygon.push([
+                position[0] + shift * velocity[0],
+                position[1] + shift * velocity[1],
+            ]);
+        }
+        line = next[line];
+    }
+    return Shrunk::Polygon(polygon);
+}
+
+/// Returns the hull of `points` with the positions of its corners as keys.
+/// Many of the points are nearly collinear, so exact predicates are used.
+pub(crate) fn renumber(points: Vec<[f64; 2]>) -> Hull {
+    let hull = Hull::from(points.convex_hull_exact());
+    return Hull::from_vertices_unchecked(hull.points().iter().copied().enumerate());
+}
+
+impl Hull {
+    /**
+    Dilates the hull by `distance` (or erodes it, if `distance` is negative):
+    The result contains all points whose distance to the hull is at most
+    `distance`, e.g. the clearance zone around an obstacle. The corners are
+    formed according to `join`. For erosion, the result contains all points of
+    the hull whose distance to its boundary is at least `-distance` and `join`
+    has no effect. Erosion can make the hull vanish, in which case an empty
+    hull is returned.
+
+    Since the corners of the result are new points, the keys of the result are
+    the positions of its corners, starting at zero. Dilation takes O(h) time
+    (plus the corners of the arcs), erosion takes O(h log h) time.
+
+    # Panics
+
+    Panics if `join` is [`Join::Round`] with a tolerance which is not
+    positive.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::{Hull, Join};
+
+    let square = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+
+    let dilated = square.offset(1.0, Join::Miter);
+    assert_eq!(dilated.points(), &[[3.0, 3.0], [-1.0, 3.0], [-1.0, -1.0], [3.0, -1.0]]);
+
+    let rounded = square.offset(1.0, Join::Round { tolerance: 0.01 });
+    assert!(rounded.area() > 12.0 + std::f64::consts::PI);
+    assert!(rounded.area() < 12.0 + std::f64::consts::PI + 0.1);
+
+    let eroded = square.offset(-0.5, Join::Miter);
+    assert_eq!(eroded.points(), &[[1.5, 1.5], [0.5, 1.5], [0.5, 0.5], [1.5, 0.5]]);
+    assert!(square.offset(-1.5, Join::Miter).is_empty());
+    ```
+     */
+    pub fn offset(&self, distance: f64, join: Join) -> Hull {
+        if let Join::Round { tolerance } = join {
+            assert!(tolerance > 0.0, "the tolerance must be positive");
+        }
+        let points = self.points();
+        if points.is_empty() || distance == 0.0 {
+            return renumber(points.to_vec());
+        }
+
+        if distance < 0.0 {
+            if points.len() < 3 {
+                return renumber(Vec::new());
+            }
+            match shrink(points, -distance) {
+                Shrunk::Polygon(polygon) => return renumber(polygon),
+                Shrunk::Vanished { .. } => return renumber(Vec::new()),
+            }
+        }
+
+        let corners = match join {
+            Join::Miter => {
+                // Intersections of consecutive shifted halfspaces
+                let halfspaces = self.to_halfspaces();
+                let m = halfspaces.len();
+                (0..m)
+                    .filter_map(|i| {
+                        let (a, b) = (halfspaces[i], halfspaces[(i + 1) % m]);
+                        let (p, q) = (a.normal, b.normal);
+                        let (u, v) = (a.offset + distance, b.offset + distance);
+                        let det = p[0] * q[1] - p[1] * q[0];
+                        if det == 0.0 {
+                            return None;
+                        }
+                        return Some([(u * q[1] - v * p[1]) / det, (v * p[0] - u * q[0]) / det]);
+                    })
+                    .collect()
+            }
+            Join::Round { tolerance } => {
+                // Angular step of the tangent segments, whose corners are at
+                // most `tolerance` further away from the hull corner than the
+                // arc
+                let step = 2.0 * (distance / (distance + tolerance)).acos();
+                let n = points.len();
+                let mut corners = Vec::new();
+                for i in 0..n {
+                    let (previous, corner, next) =
+                        (points[(i + n - 1) % n], points[i], points[(i + 1) % n]);
+                    // Angles of the outward normals of the adjacent edges
+                    let (start, span) = if n == 1 {
+                        (0.0, TAU)
+                    } else {
+                        let start = (previous[0] - corner[0]).atan2(corner[1] - previous[1]);
+                        let end = (corner[0] - next[0]).atan2(next[1] - corner[1]);
+                        let span = (end - start).rem_euclid(TAU);
+                        // A segment turns around at its ends
+                        (start, if n == 2 { TAU / 2.0 } else { span })
+                    };
+                    let steps = (span / step).ceil().max(1.0) as usize;
+                    let delta = span / steps as f64;
+                    let radius = distance / (0.5 * delta).cos();
+                    let at = |angle: f64, radius: f64| {
+                        return [
+                            corner[0] + radius * angle.cos(),
+                            corner[1] + radius * angle.sin(),
+                        ];
+                    };
+                    corners.push(at(start, distance));
+                    for k in 0..steps {
+                        corners.push(at(start + (k as f64 + 0.5) * delta, radius));
+                    }
+                    corners.push(at(start + span, distance));
+                }
+                corners
+            }
+        };
+        return renumber(corners);
+    }
+}
@@ -0,0 +1,423 @@
+//! This module contains the [`OnlineHull`], a convex hull which is updated
+//! point by point and maintains its area and perimeter incrementally, and the
+//! [`StreamingHull`], which assigns the keys itself and provides the current
+//! corners as a slice.
+
+use std::cell::OnceCell;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::ops::Bound::{Excluded, Unbounded};
+
+use ordered_float::OrderedFloat;
+
+use crate::Hull;
+use crate::geometry::{cross, difference};
+use crate::predicates::orientation;
+
+/**
+One monotone chain of the hull, ordered by the x-coordinate. The lower chain
+stores the points as they are, the upper chain stores them mirrored at the
+x-axis, so that both chains can be updated as lower chains.
+ */
+#[derive(Debug, Clone, Default)]
+struct Chain {
+    points: BTreeMap<OrderedFloat<f64>, (usize, f64)>,
+    /// Sum of the cross products of consecutive points (relative to the origin
+    /// of the hull), from left to right.
+    cross: f64,
+    /// Sum of the lengths of the segments between consecutive points.
+    length: f64,
+}
+
+impl Chain {
+    fn point(entry: (&OrderedFloat<f64>, &(usize, f64))) -> (usize, [f64; 2]) {
+        return (entry.1.0, [entry.0.0, entry.1.1]);
+    }
+
+    fn before(&self, x: f64) -> Option<(usize, [f64; 2])> {
+        return self
+            .points
+            .range((Unbounded, Excluded(OrderedFloat(x))))
+            .next_back()
+            .map(Self::point);
+    }
+
+    fn after(&self, x: f64) -> Option<(usize, [f64; 2])> {
+        return self
+            .points
+            .range((Excluded(OrderedFloat(x)), Unbounded))
+            .next()
+            .map(Self::point);
+    }
+
+    fn first(&self) -> Option<[f64; 2]> {
+        return self
+            .points
+            .first_key_value()
+            .map(|entry| Self::point(entry).1);
+    }
+
+    fn last(&self) -> Option<[f64; 2]> {
+        return self
+            .points
+            .last_key_value()
+            .map(|entry| Self::point(entry).1);
+    }
+
+    /// Adds (`sign = 1.0`) or removes (`sign = -1.0`) the contribution of the
+    /// segment from `a` to `b`.
+    fn segment(&mut self, a: [f64; 2], b: [f64; 2], origin: [f64; 2], sign: f64) {
+        self.cross += sign * cross(difference(a, origin), difference(b, origin));
+        self.length += sign * (b[0] - a[0]).hypot(b[1] - a[1]);
+    }
+
+    /// Inserts `point` if it is located strictly below the chain and removes
+    /// the points which are no longer convex (their keys are appended to
+    /// `removed`). Returns `true` if the chain changed.
+    fn insert(
+        &mut self,
+        key: usize,
+        point: [f64; 2],
+        origin: [f64; 2],
+        removed: &mut Vec<usize>,
+    ) -> bool {
+        let x = point[0];
+        let existing = self.points.get(&OrderedFloat(x)).copied();
+        let before = self.before(x);
+        let after = self.after(x);
+
+        match existing {
+            Some((_, y)) if point[1] >= y => return false,
+            Some((existing_key, y)) => {
+                removed.push(existing_key);
+                let existing = [x, y];
+                if let Some((_, a)) = before {
+                    self.segment(a, existing, origin, -1.0);
+                }
+                if let Some((_, b)) = after {
+                    self.segment(existing, b, origin, -1.0);
+                }
+            }
+            None => {
+                if let (Some((_, a)), Some((_, b))) = (before, after) {
+                    if orientation(a, b, point) != Ordering::Less {
+                        return false;
+                    }
+                    self.segment(a, b, origin, -1.0);
+                }
+            }
+        }
+
+        self.points.insert(OrderedFloat(x), (key, point[1]));
+        if let Some((_, a)) = before {
+            self.segment(a, point, origin, 1.0);
+        }
+        if let Some((_, b)) = after {
+            self.segment(point, b, origin, 1.0);
+        }
+
+        // Remove points to the right which are no longer convex
+        while let Some((b_key, b)) = self.after(x) {
+            let Some((_, c)) = self.after(b[0]) else {
+                break;
+            };
+            if orientation(point, b, c) == Ordering::Greater {
+                break;
+            }
+            self.points.remove(&OrderedFloat(b[0]));
+            removed.push(b_key);
+            self.segment(point, b, origin, -1.0);
+            self.segment(b, c, origin, -1.0);
+            self.segment(point, c, origin, 1.0);
+        }
+
+        // Remove points to the left which are no longer convex
+        while let Some((a_key, a)) = self.before(x) {
+            let Some((_, z)) = self.before(a[0]) else {
+                break;
+            };
+            if orientation(z, a, point) == Ordering::Greater {
+                break;
+            }
+            self.points.remove(&OrderedFloat(a[0]));
+            removed.push(a_key);
+            self.segment(z, a, origin, -1.0);
+            self.segment(a, point, origin, -1.0);
+            self.segment(z, point, origin, 1.0);
+        }
+        return true;
+    }
+}
+
+/// Mirrors `point` at the x-axis.
+fn mirror(point: [f64; 2]) -> [f64; 2] {
+    return [point[0], -point[1]];
+}
+
+/**
+A convex hull which is built by inserting points one at a time.
+
+The hull is stored as its lower and upper chain, each ordered by the
+x-coordinate. Inserting a point takes O(log h) time plus the time to remove
+the corners which are no longer part of the hull, i.e. O(log h) amortized.
+Points located inside the hull (or on its boundary) are discarded right away.
+The orientation tests use exact predicates (see the
+[`predicates`](crate::predicates) module), so the result is the same as
+[`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull) of all inserted
+points (up to the choice of the key among duplicate points).
+
+The area and perimeter are updated with each change of the hull, so
+[`OnlineHull::area`] and [`OnlineHull::perimeter`] take O(1) time. Since they
+are sums of the contributions of all edges which were ever added or removed,
+they can deviate from a recomputation from scratch by accumulated rounding
+errors. The contributions are calculated relative to the first inserted point
+to keep these errors small for data far away from the origin.
+
+# Examples
+
+```
+use planar_convex_hull::online::OnlineHull;
+
+let mut hull = OnlineHull::new();
+assert!(hull.insert(0, [0.0, 0.0]));
+assert!(hull.insert(1, [2.0, 0.0]));
+assert!(hull.insert(2, [2.0, 2.0]));
+assert_eq!(hull.area(), 2.0);
+
+assert!(hull.insert(3, [0.0, 2.0]));
+assert!(!hull.insert(4, [1.0, 1.0])); // Inside
+assert_eq!(hull.area(), 4.0);
+assert_eq!(hull.perimeter(), 8.0);
+assert_eq!(hull.hull().keys(), &[2, 3, 0, 1]);
+```
+ */
+#[derive(Debug, Clone, Default)]
+pub struct OnlineHull {
+    origin: Option<[f64; 2]>,
+    lower: Chain,
+    /// Upper chain, mirrored at the x-axis.
+    upper: Chain,
+    area: f64,
+    perimeter: f64,
+}
+
+impl OnlineHull {
+    /// Creates an empty [`OnlineHull`].
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /**
+    Inserts the point with the given `key` and returns `true` if the hull
+    changed. Nonreal points are ignored, as are points located inside the hull
+    or on its boundary.
+     */
+    pub fn insert(&mut self, key: usize, point: [f64; 2]) -> bool {
+        let [lower, upper] = self.insert_tracked(key, point, &mut Vec::new());
+        return lower || upper;
+    }
+
+    /**
+    Inserts the point like [`OnlineHull::insert`] and returns whether it was
+    added to the lower and the upper chain. The keys of the points removed
+    from either chain are appended to `removed` (once per chain).
+     */
+    pub(crate) fn insert_tracked(
+        &mut self,
+        key: usize,
+        point: [f64; 2],
+        removed: &mut Vec<usize>,
+    ) -> [bool; 2] {
+        if !point[0].is_finite() || !point[1].is_finite() {
+            return [false; 2];
+        }
+        let origin = *self.origin.get_or_insert(point);
+
+        let lower = self.lower.insert(key, point, origin, removed);
+        let upper = self
+            .upper
+            .insert(key, mirror(point), mirror(origin), removed);
+        if !lower && !upper {
+            return [false; 2];
+        }
+
+        // Close the polygon with the (possibly degenerate) vertical edges at
+        // the leftmost and rightmost x-coordinate. The cross products of the
+        // mirrored upper chain from left to right equal those of the actual
+        // upper chain from right to left.
+        let (Some(lower_first), Some(lower_last), Some(upper_first), Some(upper_last)) = (
+            self.lower.first(),
+            self.lower.last(),
+            self.upper.first().map(mirror),
+            self.upper.last().map(mirror),
+        ) else {
+            return [lower, upper];
+        };
+        let doubled_area = self.lower.cross
+            + self.upper.cross
+            + cross(
+                difference(lower_last, origin),
+                difference(upper_last, origin),
+            )
+            + cross(
+                difference(upper_first, origin),
+                difference(lower_first, origin),
+            );
+        self.area = 0.5 * doubled_area;
+        self.perimeter = self.lower.length
+            + self.upper.length
+            + (upper_last[1] - lower_last[1])
+            + (upper_first[1] - lower_first[1]);
+        return [lower, upper];
+    }
+
+    /**
+    Returns the area of the hull in O(1). The area of a hull with fewer than
+    three corners is zero.
+     */
+    pub fn area(&self) -> f64 {
+        return self.area;
+    }
+
+    /**
+    Returns the perimeter of the hull in O(1). The perimeter of a segment is
+    twice its length, since its boundary runs along it back and forth.
+     */
+    pub fn perimeter(&self) -> f64 {
+        return self.perimeter;
+    }
+
+    /// Returns `true` if no real point has been inserted yet.
+    pub fn is_empty(&self) -> bool {
+        return self.origin.is_none();
+    }
+
+    /**
+    Returns the current hull, following the conventions of
+    [`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull): the corners
+    are in counter-clockwise order, starting with the point with the largest
+    x-value (and the largest y-value of those).
+     */
+    pub fn hull(&self) -> Hull {
+        let mut corners: Vec<(usize, [f64; 2])> =
+            Vec::with_capacity(self.lower.points.len() + self.upper.points.len());
+        let upper = self
+            .upper
+            .points
+            .iter()
+            .rev()
+            .map(Chain::point)
+            .map(|(key, point)| (key, mirror(point)));
+        let lower = self.lower.points.iter().map(Chain::point);
+        for (key, point) in upper.chain(lower) {
+            if corners.last().is_some_and(|last| last.1 == point) {
+                continue;
+            }
+            corners.push((key, point));
+        }
+        if corners.len() > 1
+            && corners.first().map(|first| first.1) == corners.last().map(|last| last.1)
+        {
+            corners.pop();
+        }
+        return Hull::from_vertices_unchecked(corners);
+    }
+}
+
+/**
+A convex hull of a stream of points, built on top of [`OnlineHull`].
+
+Each point passed to [`StreamingHull::push`] is given the next key (starting at
+zero), i.e. its index in the stream. Pushing a point takes O(log h) amortized
+time. The corners returned by [`StreamingHull::hull`] are cached until the hull
+changes, so querying the hull after a change takes O(h) time and O(1)
+otherwise.
+
+# Examples
+
+```
+use planar_convex_hull::online::StreamingHull;
+
+let mut hull = StreamingHull::new();
+assert_eq!(hull.push([0.0, 0.0]), 0);
+assert_eq!(hull.push([2.0, 0.0]), 1);
+assert_eq!(hull.push([2.0, 2.0]), 2);
+assert_eq!(hull.hull(), &[(2, [2.0, 2.0]), (0, [0.0, 0.0]), (1, [2.0, 0.0])]);
+
+assert_eq!(hull.push([1.0, 0.5]), 3); // Inside
+assert_eq!(hull.push([0.0, 2.0]), 4);
+assert_eq!(hull.len(), 5);
+assert_eq!(hull.area(), 4.0);
+assert_eq!(
+    hull.hull(),
+    &[(2, [2.0, 2.0]), (4, [0.0, 2.0]), (0, [0.0, 0.0]), (1, [2.0, 0.0])]
+);
+```
+ */
+#[derive(Debug, Clone, Default)]
+pub struct StreamingHull {
+    online: OnlineHull,
+    len: usize,
+    corners: OnceCell<Vec<(usize, [f64; 2])>>,
+}
+
+impl StreamingHull {
+    /// Creates an empty [`StreamingHull`].
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /**
+    Adds `point` to the hull and returns its key, which is the number of
+    points pushed before. Nonreal points are counted, but ignored otherwise.
+     */
+    pub fn push(&mut self, point: [f64; 2]) -> usize {
+        let key = self.len;
+        self.len += 1;
+        if self.online.insert(key, point) {
+            self.corners.take();
+        }
+        return key;
+    }
+
+    /**
+    Returns the corners of the current hull together with their keys,
+    following the conventions of
+    [`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull): the corners
+    are in counter-clockwise order, starting with the point with the largest
+    x-value (and the largest y-value of those).
+     */
+    pub fn hull(&self) -> &[(usize, [f64; 2])] {
+        return self
+            .corners
+            .get_or_init(|| self.online.hull().iter().collect());
+    }
+
+    /// Returns the number of pushed points (including the nonreal ones).
+    pub fn len(&self) -> usize {
+        return self.len;
+    }
+
+    /// Returns `true` if no point has been pushed yet.
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
+    /// Returns the area of the current hull in O(1), see [`OnlineHull::area`].
+    pub fn area(&self) -> f64 {
+        return self.online.area();
+    }
+
+    /**
+    Returns the perimeter of the current hull in O(1), see
+    [`OnlineHull::perimeter`].
+     */
+    pub fn perimeter(&self) -> f64 {
+        return self.online.perimeter();
+    }
+
+    /// Returns the underlying [`OnlineHull`].
+    pub fn online_hull(&self) -> &OnlineHull {
+        return &self.online;
+    }
+}
@@ -0,0 +1,300 @@
+//! This module contains the [`ConvexHullOptions`] used by
+//! [`ConvexHull::convex_hull_with_options`] and the [`Normalization`] it
+//! applied to the coordinates.
+
+use std::collections::HashMap;
+
+use super::{ConvexHull, ConvexHullIter, prefilter};
+
+/**
+Opt-in preprocessing steps for [`ConvexHull::convex_hull_with_options`].
+
+All options are disabled by default, in which case
+[`ConvexHull::convex_hull_with_options`] is equivalent to
+[`ConvexHull::convex_hull`]. Regardless of the options, the returned points
+are always the original points of the collection.
+
+# Examples
+
+```
+use planar_convex_hull::{ConvexHull, ConvexHullOptions};
+
+let options = ConvexHullOptions {
+    normalize: true,
+    ..Default::default()
+};
+
+// The products of these coordinates overflow, so the plain algorithm misses
+// the hull point with the key 4.
+let slice = &[[1e200, 0.0], [0.0, 1e200], [-1e200, 0.0], [0.0, -1e200], [5e199, 5.001e199]];
+assert_eq!(slice.convex_hull().count(), 4);
+
+let (hull, normalization) = slice.convex_hull_with_options(&options);
+let hull: Vec<usize> = hull.map(|(key, _)| key).collect();
+assert_eq!(hull, vec![0, 4, 1, 2, 3]);
+assert_eq!(normalization.scale, 2.0f64.powi(-665));
+```
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ConvexHullOptions {
+    /**
+    Scales all coordinates by a power of two so that the largest absolute
+    coordinate value is in the range \[0.5, 1) before computing the hull.
+
+    Scaling by a power of two does not introduce any rounding error, but it
+    keeps the products inside the orientation tests away from overflow (for
+    very large coordinates) and from underflow (for very small coordinates).
+    The scale factor only depends on the input and is undone when the results
+    are reported.
+
+    Normalization only guards the exponent range: Since the scaling is exact,
+    the relative precision of the coordinates stays the same, so it does not
+    help against the cancellation for data located far away from the origin
+    (e.g. coordinates in the millions). Use [`ConvexHullOptions::recenter`]
+    for such data.
+     */
+    pub normalize: bool,
+
+    /**
+    Translates all points by their centroid (the mean of all real points)
+    before computing the hull, so that the coordinates are centered around
+    the origin.
+
+    This reduces the magnitude of the coordinates for data located far away
+    from the origin (e.g. UTM coordinates with offsets of six or seven
+    digits), so less precision is lost to cancellation inside the orientation
+    tests. For such data, the translation itself is usually exact: The
+    difference of two floating-point numbers within a factor of two of each
+    other is always representable. If combined with
+    [`ConvexHullOptions::normalize`], the points are translated first and
+    scaled afterwards.
+     */
+    pub recenter: bool,
+
+    /**
+    Discards points which cannot be part of the hull with a grid prefilter
+    before running the hull algorithm. The value is the number of grid cells
+    along each axis of the bounding box of the points. It is clamped to 1024
+    and to the square root of the number of real points, so the grid never
+    allocates more cells than there are points.
+
+    The bounding box and the axis-extreme points of each occupied cell are
+    recorded. The exact hull of these extreme points is an inner
+    approximation of the actual hull, and all cells whose bounding boxes are
+    located strictly inside it are discarded as a whole. For heavily
+    clustered data (e.g. millions of points in a few blobs), this removes
+    almost all points with three cheap passes over the collection, and the
+    hull algorithm only runs over the survivors. The result is the same as
+    without the prefilter. A resolution of about 64 cells per axis is a
+    reasonable starting point.
+     */
+    pub grid_prefilter: Option<usize>,
+
+    /**
+    Discards points which are located strictly inside the polygon spanned by
+    the extreme points of the collection (Akl–Toussaint heuristic) before
+    running the hull algorithm.
+
+    The extreme points are the points with the smallest and largest values of
+    x, y, x + y and x - y, which span a polygon with up to eight corners. It
+    is located inside the hull, so no point strictly inside of it can be a
+    hull corner. For dense, blob-like point clouds, this polygon covers most of
+    the hull and the hull algorithm only runs over the few remaining points
+    close to the boundary. The filter costs two passes over the collection
+    and the result is the same as without it. If combined with
+    [`ConvexHullOptions::grid_prefilter`], this filter runs first.
+     */
+    pub akl_toussaint: bool,
+}
+
+/**
+The transformation `(point - offset) * scale` which
+[`ConvexHull::convex_hull_with_options`] applied to the coordinates before
+computing the hull (see [`ConvexHullOptions::recenter`] and
+[`ConvexHullOptions::normalize`]).
+
+The returned hull always consists of the original points. This transformation
+is needed to relate them to the conditioned coordinates, e.g. to compute
+derived quantities in the same frame. If neither option is enabled, it is the
+identity (`offset` is zero and `scale` is one).
+
+# Examples
+
+```
+use planar_convex_hull::{ConvexHull, ConvexHullOptions};
+
+let options = ConvexHullOptions {
+    normalize: true,
+    recenter: true,
+    ..Default::default()
+};
+let slice = &[[1000.0, 1000.0], [1004.0, 1000.0], [1000.0, 1004.0], [1004.0, 1004.0]];
+let (hull, normalization) = slice.convex_hull_with_options(&options);
+assert_eq!(hull.count(), 4);
+assert_eq!(normalization.offset, [1002.0, 1002.0]);
+assert_eq!(normalization.scale, 0.25);
+assert_eq!(normalization.apply([1004.0, 1000.0]), [0.5, -0.5]);
+assert_eq!(normalization.revert([0.5, -0.5]), [1004.0, 1000.0]);
+```
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normalization {
+    /// Offset subtracted from the points (the centroid if
+    /// [`ConvexHullOptions::recenter`] is enabled).
+    pub offset: [f64; 2],
+    /// Power of two the translated points are multiplied with (if
+    /// [`ConvexHullOptions::normalize`] is enabled).
+    pub scale: f64,
+}
+
+impl Default for Normalization {
+    fn default() -> Self {
+        return Self {
+            offset: [0.0, 0.0],
+            scale: 1.0,
+        };
+    }
+}
+
+impl Normalization {
+    /// Transforms `point` into the conditioned coordinates.
+    pub fn apply(&self, point: [f64; 2]) -> [f64; 2] {
+        return [
+            (point[0] - self.offset[0]) * self.scale,
+            (point[1] - self.offset[1]) * self.scale,
+        ];
+    }
+
+    /// Transforms `point` from the conditioned coordinates back into the
+    /// coordinates of the collection.
+    pub fn revert(&self, point: [f64; 2]) -> [f64; 2] {
+        return [
+            point[0] / self.scale + self.offset[0],
+            point[1] / self.scale + self.offset[1],
+        ];
+    }
+}
+
+/// A collection of keyed points, e.g. the survivors of a prefilter.
+pub(crate) struct Keyed(pub(crate) Vec<(usize, [f64; 2])>);
+
+impl ConvexHull for Keyed {
+    fn convex_hull_iter(&self) -> impl Iterator<Item = (usize, [f64; 2])> {
+        return self.0.iter().copied();
+    }
+}
+
+/// A view on a collection whose points are transformed by a [`Normalization`]
+/// before they are handed to the hull algorithm.
+struct Transformed<'a, T: ?Sized> {
+    inner: &'a T,
+    normalization: Normalization,
+}
+
+impl<'a, T: ConvexHull + ?Sized> ConvexHull for Transformed<'a, T> {
+    fn convex_hull_iter(&self) -> impl Iterator<Item = (usize, [f64; 2])> {
+        return self
+            .inner
+            .convex_hull_iter()
+            .map(|(key, point)| (key, self.normalization.apply(point)));
+    }
+}
+
+/// Returns the power of two which scales `max_abs` into the range [0.5, 1).
+/// The scaling is exact and therefore only moves the exponents, it does not
+/// change the relative precision of the coordinates.
+fn power_of_two_scale(max_abs: f64) -> f64 {
+    if max_abs == 0.0 || !max_abs.is_finite() {
+        return 1.0;
+    }
+    let exponent = (max_abs.log2().floor() as i32 + 1).clamp(-1000, 1000);
+    return 2.0f64.powi(-exponent);
+}
+
+/// Runs the prefilters selected in `options` and calculates the hull of the
+/// remaining points.
+fn prefiltered_hull<T: ConvexHull + ?Sized>(
+    collection: &T,
+    options: &ConvexHullOptions,
+) -> ConvexHullIter {
+    if options.akl_toussaint {
+        let survivors = Keyed(prefilter::akl_toussaint(collection));
+        return grid_prefiltered_hull(&survivors, options);
+    }
+    return grid_prefiltered_hull(collection, options);
+}
+
+/// Runs the grid prefilter if it is selected in `options` and calculates the
+/// hull of the remaining points.
+fn grid_prefiltered_hull<T: ConvexHull + ?Sized>(
+    collection: &T,
+    options: &ConvexHullOptions,
+) -> ConvexHullIter {
+    match options.grid_prefilter {
+        Some(cells) => return Keyed(prefilter::grid(collection, cells)).convex_hull(),
+        None => return collection.convex_hull(),
+    }
+}
+
+pub(crate) fn convex_hull_with_options<T: ConvexHull + ?Sized>(
+    this: &T,
+    options: &ConvexHullOptions,
+) -> (ConvexHullIter, Normalization) {
+    if !options.normalize && !options.recenter {
+        return (prefiltered_hull(this, options), Normalization::default());
+    }
+
+    let finite = |point: &[f64; 2]| point[0].is_finite() && point[1].is_finite();
+
+    let mut offset = [0.0, 0.0];
+    if options.recenter {
+        let mut count = 0usize;
+        let mut sum = [0.0, 0.0];
+        for (_, point) in this.convex_hull_iter().filter(|(_, point)| finite(point)) {
+            count += 1;
+            sum[0] += point[0];
+            sum[1] += point[1];
+        }
+        if count > 0 {
+            offset = [sum[0] / count as f64, sum[1] / count as f64];
+        }
+        // The sum might overflow even though the points do not
+        if !finite(&offset) {
+            offset = [0.0, 0.0];
+        }
+    }
+
+    let mut scale = 1.0;
+    if options.normalize {
+        let max_abs = this
+            .convex_hull_iter()
+            .filter(|(_, point)| finite(point))
+            .map(|(_, point)| {
+                (point[0] - offset[0])
+                    .abs()
+                    .max((point[1] - offset[1]).abs())
+            })
+            .fold(0.0, f64::max);
+        scale = power_of_two_scale(max_abs);
+    }
+
+    let normalization = Normalization { offset, scale };
+    let transformed = Transformed {
+        inner: this,
+        normalization,
+    };
+    let mut hull: Vec<(usize, [f64; 2])> = prefiltered_hull(&transformed, options).collect();
+
+    // Report the original points of the hull corners
+    let positions: HashMap<usize, usize> = hull
+        .iter()
+        .enumerate()
+        .map(|(position, (key, _))| (*key, position))
+        .collect();
+    for (key, point) in this.convex_hull_iter() {
+        if let Some(position) = positions.get(&key) {
+            hull[*position].1 = point;
+        }
+    }
+    return (ConvexHullIter::from_vec(hull), normalization);
+}
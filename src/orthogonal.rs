@@ -0,0 +1,97 @@
+//! This module contains the [`Quadrant`] type, which selects the orientation
+//! of [`ConvexHull::pareto_front`], and the staircases behind
+//! [`ConvexHull::orthogonal_convex_hull`].
+
+use crate::{ConvexHull, ConvexHullIter};
+
+/**
+Quadrant towards which points are compared in [`ConvexHull::pareto_front`]:
+A point dominates another point if it is located at least as far in both
+coordinate directions of the quadrant (e.g. for [`Quadrant::NorthEast`], at
+least as large x- and y-values).
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum Quadrant {
+    /// Maximize x and y.
+    NorthEast,
+    /// Minimize x and maximize y.
+    NorthWest,
+    /// Minimize x and y.
+    SouthWest,
+    /// Maximize x and minimize y.
+    SouthEast,
+}
+
+impl Quadrant {
+    /// All quadrants in counter-clockwise order.
+    pub const ALL: [Quadrant; 4] = [
+        Quadrant::NorthEast,
+        Quadrant::NorthWest,
+        Quadrant::SouthWest,
+        Quadrant::SouthEast,
+    ];
+
+    /// Returns the signs of the x- and y-direction of the quadrant.
+    pub fn signs(&self) -> [f64; 2] {
+        match self {
+            Quadrant::NorthEast => return [1.0, 1.0],
+            Quadrant::NorthWest => return [-1.0, 1.0],
+            Quadrant::SouthWest => return [-1.0, -1.0],
+            Quadrant::SouthEast => return [1.0, -1.0],
+        }
+    }
+}
+
+pub(crate) fn pareto_front<T: ConvexHull + ?Sized>(
+    this: &T,
+    quadrant: Quadrant,
+) -> Vec<(usize, [f64; 2])> {
+    let sign = quadrant.signs();
+    let mut points: Vec<(usize, [f64; 2])> = this
+        .convex_hull_iter()
+        .filter(|(_, point)| point[0].is_finite() && point[1].is_finite())
+        .collect();
+    points.sort_by(|(_, a), (_, b)| {
+        return (sign[0] * b[0])
+            .total_cmp(&(sign[0] * a[0]))
+            .then((sign[1] * b[1]).total_cmp(&(sign[1] * a[1])));
+    });
+
+    // Sweep along decreasing x (in the direction of the quadrant) and keep
+    // the points which exceed all previous ones in y. Of multiple identical
+    // points, the stable sort keeps the first one in front.
+    let mut front: Vec<(usize, [f64; 2])> = Vec::new();
+    for (key, point) in points {
+        if front
+            .last()
+            .is_none_or(|(_, last)| sign[1] * point[1] > sign[1] * last[1])
+        {
+            front.push((key, point));
+        }
+    }
+
+    // Counter-clockwise order around the point set
+    if sign[0] * sign[1] < 0.0 {
+        front.reverse();
+    }
+    return front;
+}
+
+pub(crate) fn orthogonal_convex_hull<T: ConvexHull + ?Sized>(this: &T) -> ConvexHullIter {
+    // The four staircases in counter-clockwise order, starting with the one
+    // from the rightmost to the topmost point. Adjacent staircases share their
+    // extreme points.
+    let mut boundary: Vec<(usize, [f64; 2])> = Quadrant::ALL
+        .iter()
+        .flat_map(|quadrant| pareto_front(this, *quadrant))
+        .collect();
+    boundary.dedup_by_key(|(key, _)| *key);
+    while boundary.len() > 1
+        && boundary.first().map(|(key, _)| key) == boundary.last().map(|(key, _)| key)
+    {
+        boundary.pop();
+    }
+    return ConvexHullIter::from_vec(boundary);
+}
@@ -0,0 +1,130 @@
+//! This module implements the shape traits of
+//! [parry2d-f64](https://docs.rs/parry2d-f64) for [`Hull`], so that a hull can
+//! be used directly as a convex shape in parry's GJK / EPA based queries
+//! without converting it into a `ConvexPolygon` first. It is only available
+//! with the `parry2d` feature flag enabled.
+//!
+//! An empty hull behaves like a single point at the origin. The feature ids
+//! reported by [`PointQuery::project_local_point_and_get_feature`] follow the
+//! order of [`Hull::points`]: [`FeatureId::Vertex`]`(i)` is the `i`-th corner
+//! and [`FeatureId::Face`]`(i)` is the edge from corner `i` to corner `i + 1`.
+//!
+//! # Examples
+//!
+//! ```
+//! use planar_convex_hull::Hull;
+//! use parry2d_f64::math::{Isometry, Point, Vector};
+//! use parry2d_f64::query::PointQuery;
+//! use parry2d_f64::query::details::distance_support_map_support_map;
+//! use parry2d_f64::shape::SupportMap;
+//!
+//! let square = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0], [0.5, 0.5]]);
+//!
+//! assert_eq!(
+//!     square.local_support_point(&Vector::new(1.0, 0.1)),
+//!     Point::new(1.0, 1.0)
+//! );
+//! assert!(square.contains_local_point(&Point::new(0.5, 0.25)));
+//! assert_eq!(square.distance_to_local_point(&Point::new(3.0, 0.5), true), 2.0);
+//!
+//! // GJK distance between the square and a copy shifted by 3 along the x-axis
+//! let shift = Isometry::translation(3.0, 0.0);
+//! let distance = distance_support_map_support_map(&shift, &square, &square);
+//! assert!((distance - 2.0).abs() < 1e-12);
+//! ```
+
+use std::cmp::Ordering;
+
+use parry2d_f64::math::{Point, Real, Vector};
+use parry2d_f64::query::{PointProjection, PointQuery};
+use parry2d_f64::shape::{FeatureId, SupportMap};
+
+use crate::Hull;
+use crate::predicates::orientation;
+
+impl SupportMap for Hull {
+    fn local_support_point(&self, dir: &Vector<Real>) -> Point<Real> {
+        let dot = |point: &[f64; 2]| point[0] * dir.x + point[1] * dir.y;
+        let point = self
+            .points()
+            .iter()
+            .max_by(|a, b| dot(a).total_cmp(&dot(b)))
+            .copied()
+            .unwrap_or([0.0; 2]);
+        return Point::new(point[0], point[1]);
+    }
+}
+
+/**
+Returns the point on the boundary of `hull` which is closest to `point`
+together with the feature it lies on, and whether `point` is located inside
+the hull (including its boundary).
+ */
+fn project_on_boundary(hull: &Hull, point: [f64; 2]) -> ([f64; 2], FeatureId, bool) {
+    let corners = hull.points();
+    let n = corners.len();
+    if n == 0 {
+        return ([0.0; 2], FeatureId::Unknown, point == [0.0; 2]);
+    }
+
+    let mut closest = (corners[0], FeatureId::Vertex(0));
+    let mut closest_distance = f64::INFINITY;
+    for i in 0..n {
+        let a = corners[i];
+        let b = corners[(i + 1) % n];
+        let edge = [b[0] - a[0], b[1] - a[1]];
+        let length_squared = edge[0] * edge[0] + edge[1] * edge[1];
+        let t = if length_squared > 0.0 {
+            (((point[0] - a[0]) * edge[0] + (point[1] - a[1]) * edge[1]) / length_squared)
+                .clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let (candidate, feature) = if t == 0.0 {
+            (a, FeatureId::Vertex(i as u32))
+        } else if t == 1.0 {
+            (b, FeatureId::Vertex(((i + 1) % n) as u32))
+        } else {
+            (
+                [a[0] + t * edge[0], a[1] + t * edge[1]],
+                FeatureId::Face(i as u32),
+            )
+        };
+        let distance = (point[0] - candidate[0]).powi(2) + (point[1] - candidate[1]).powi(2);
+        if distance < closest_distance {
+            closest = (candidate, feature);
+            closest_distance = distance;
+        }
+        if n == 1 {
+            break;
+        }
+    }
+
+    let inside = if n < 3 {
+        closest_distance == 0.0
+    } else {
+        (0..n).all(|i| orientation(corners[i], corners[(i + 1) % n], point) != Ordering::Less)
+    };
+    return (closest.0, closest.1, inside);
+}
+
+impl PointQuery for Hull {
+    fn project_local_point(&self, pt: &Point<Real>, solid: bool) -> PointProjection {
+        let (projection, _, inside) = project_on_boundary(self, [pt.x, pt.y]);
+        if inside && solid {
+            return PointProjection::new(true, *pt);
+        }
+        return PointProjection::new(inside, Point::new(projection[0], projection[1]));
+    }
+
+    fn project_local_point_and_get_feature(
+        &self,
+        pt: &Point<Real>,
+    ) -> (PointProjection, FeatureId) {
+        let (projection, feature, inside) = project_on_boundary(self, [pt.x, pt.y]);
+        return (
+            PointProjection::new(inside, Point::new(projection[0], projection[1])),
+            feature,
+        );
+    }
+}
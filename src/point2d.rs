@@ -0,0 +1,209 @@
+//! This module contains the [`Point2D`] accessor trait, which is an alternative
+//! to the `Into<[f64; 2]>` bound used by the implementations in
+//! [`convex_hull_impl`](crate::convex_hull_impl), and the [`Points`] adaptor
+//! which implements [`ConvexHull`] for slices of such points.
+
+use super::ConvexHull;
+
+/**
+Accessor trait for a type representing a point in 2-dimensional cartesian
+coordinates.
+
+Implementing this trait for a local point type is always possible, whereas
+`Into<[f64; 2]>` can only be implemented if the point type itself is local. In
+combination with [`Points`], any slice of `Point2D` implementors can be used as
+a [`ConvexHull`] collection.
+
+Implementations are provided for `[f64; 2]`, `[f32; 2]`, `(f64, f64)`,
+`(f32, f32)` and references to implementors. With the `nalgebra` feature flag
+enabled, `Point2` and `Vector2` from [nalgebra](https://docs.rs/nalgebra) (with
+`f32` or `f64` scalars) implement the trait as well, and so do `Vec2` and
+`DVec2` from [bevy_math](https://docs.rs/bevy_math) with the `bevy` feature
+flag enabled.
+
+# Examples
+
+```
+use planar_convex_hull::{ConvexHull, Point2D, Points};
+
+struct Measurement {
+    east: f64,
+    north: f64,
+}
+
+impl Point2D for Measurement {
+    fn x(&self) -> f64 {
+        return self.east;
+    }
+
+    fn y(&self) -> f64 {
+        return self.north;
+    }
+}
+
+let measurements = vec![
+    Measurement { east: 0.0, north: 0.0 },
+    Measurement { east: 1.0, north: 0.0 },
+    Measurement { east: 0.0, north: 1.0 },
+    Measurement { east: 0.2, north: 0.2 }, // Not part of the convex hull
+];
+let mut hull = Points(&measurements).convex_hull();
+assert_eq!(hull.next(), Some((1, [1.0, 0.0])));
+assert_eq!(hull.next(), Some((2, [0.0, 1.0])));
+assert_eq!(hull.next(), Some((0, [0.0, 0.0])));
+assert_eq!(hull.next(), None);
+```
+ */
+pub trait Point2D {
+    /// Returns the x-coordinate of the point.
+    fn x(&self) -> f64;
+
+    /// Returns the y-coordinate of the point.
+    fn y(&self) -> f64;
+
+    /// Returns the point as `[x, y]` array.
+    fn to_array(&self) -> [f64; 2] {
+        return [self.x(), self.y()];
+    }
+}
+
+impl<P: Point2D + ?Sized> Point2D for &P {
+    fn x(&self) -> f64 {
+        return (**self).x();
+    }
+
+    fn y(&self) -> f64 {
+        return (**self).y();
+    }
+}
+
+impl Point2D for [f64; 2] {
+    fn x(&self) -> f64 {
+        return self[0];
+    }
+
+    fn y(&self) -> f64 {
+        return self[1];
+    }
+}
+
+impl Point2D for [f32; 2] {
+    fn x(&self) -> f64 {
+        return self[0].into();
+    }
+
+    fn y(&self) -> f64 {
+        return self[1].into();
+    }
+}
+
+impl Point2D for (f64, f64) {
+    fn x(&self) -> f64 {
+        return self.0;
+    }
+
+    fn y(&self) -> f64 {
+        return self.1;
+    }
+}
+
+impl Point2D for (f32, f32) {
+    fn x(&self) -> f64 {
+        return self.0.into();
+    }
+
+    fn y(&self) -> f64 {
+        return self.1.into();
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl Point2D for nalgebra::Point2<f64> {
+    fn x(&self) -> f64 {
+        return self.x;
+    }
+
+    fn y(&self) -> f64 {
+        return self.y;
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl Point2D for nalgebra::Point2<f32> {
+    fn x(&self) -> f64 {
+        return self.x.into();
+    }
+
+    fn y(&self) -> f64 {
+        return self.y.into();
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl Point2D for nalgebra::Vector2<f64> {
+    fn x(&self) -> f64 {
+        return self.x;
+    }
+
+    fn y(&self) -> f64 {
+        return self.y;
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl Point2D for nalgebra::Vector2<f32> {
+    fn x(&self) -> f64 {
+        return self.x.into();
+    }
+
+    fn y(&self) -> f64 {
+        return self.y.into();
+    }
+}
+
+#[cfg(feature = "bevy")]
+impl Point2D for bevy_math::Vec2 {
+    fn x(&self) -> f64 {
+        return self.x.into();
+    }
+
+    fn y(&self) -> f64 {
+        return self.y.into();
+    }
+}
+
+#[cfg(feature = "bevy")]
+impl Point2D for bevy_math::DVec2 {
+    fn x(&self) -> f64 {
+        return self.x;
+    }
+
+    fn y(&self) -> f64 {
+        return self.y;
+    }
+}
+
+/**
+Adaptor which implements [`ConvexHull`] for a slice of [`Point2D`]
+implementors. The keys are the element indices.
+
+See the documentation of [`Point2D`] for an example.
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct Points<'a, P>(pub &'a [P]);
+
+impl<'a, P: Point2D + std::marker::Sync> ConvexHull for Points<'a, P> {
+    fn convex_hull_iter(&self) -> impl Iterator<Item = (usize, [f64; 2])> {
+        return self.0.iter().map(Point2D::to_array).enumerate();
+    }
+}
+
+/// Returns the element with the given key, e.g. to resolve the corners of a
+/// [`HullView`](crate::HullView).
+impl<'a, P> std::ops::Index<usize> for Points<'a, P> {
+    type Output = P;
+
+    fn index(&self, key: usize) -> &P {
+        return &self.0[key];
+    }
+}
@@ -0,0 +1,418 @@
+//! This module contains free functions operating on convex polygons which are
+//! given as slices of their corner points in counter-clockwise order, e.g. the
+//! points returned by [`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull).
+//!
+//! [`signed_area`] and [`winding`] (as well as their counterparts for loops of
+//! keys over a [`ConvexHull`] collection) accept arbitrary simple polygons.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::intersection::convex_intersection;
+use crate::predicates::orientation;
+use crate::{ConvexHull, geometry};
+
+/**
+Returns the edge lengths and the exterior turn angles of a polygon.
+
+The i-th entry contains the length of the edge from corner i to corner i + 1
+and the turn angle (in radians) at corner i + 1. If `normalize` is true, the
+edge lengths are divided by the perimeter.
+ */
+fn edge_signature(polygon: &[[f64; 2]], normalize: bool) -> Vec<[f64; 2]> {
+    let n = polygon.len();
+    let mut signature: Vec<[f64; 2]> = (0..n)
+        .map(|i| {
+            let a = polygon[i];
+            let b = polygon[(i + 1) % n];
+            let c = polygon[(i + 2) % n];
+            let ab = [b[0] - a[0], b[1] - a[1]];
+            let bc = [c[0] - b[0], c[1] - b[1]];
+            let length = ab[0].hypot(ab[1]);
+            let cross = ab[0] * bc[1] - ab[1] * bc[0];
+            let dot = ab[0] * bc[0] + ab[1] * bc[1];
+            [length, cross.atan2(dot)]
+        })
+        .collect();
+
+    if normalize {
+        let perimeter: f64 = signature.iter().map(|[length, _]| length).sum();
+        if perimeter > 0.0 {
+            for entry in signature.iter_mut() {
+                entry[0] /= perimeter;
+            }
+        }
+    }
+    return signature;
+}
+
+/**
+Checks whether the cyclic sequence `b` is equal to `a` up to a rotation of its
+start index, within the given tolerance.
+ */
+fn cyclic_match(a: &[[f64; 2]], b: &[[f64; 2]], tolerance: f64) -> bool {
+    let n = a.len();
+    return (0..n).any(|shift| {
+        (0..n).all(|i| {
+            let x = a[i];
+            let y = b[(i + shift) % n];
+            (x[0] - y[0]).abs() <= tolerance && (x[1] - y[1]).abs() <= tolerance
+        })
+    });
+}
+
+/**
+Checks whether the two convex polygons `a` and `b` are congruent within the
+given `tolerance`.
+
+Both polygons are given as their corner points in counter-clockwise order. They
+are considered congruent if one can be transformed into the other by a rotation
+and a translation. If `reflection` is true, mirroring is allowed as well. If
+`scale` is true, the polygons only need to be similar (i.e. uniform scaling is
+allowed).
+
+The comparison is based on the cyclic sequences of edge lengths and turn angles
+of the polygons, hence the result does not depend on which corner point is the
+first one. The `tolerance` is applied to the turn angles (in radians) and to the
+edge lengths. If `scale` is true, the edge lengths are measured relative to the
+perimeter of their polygon.
+
+Polygons with a different number of corner points are never congruent, so
+nearly-collinear corner points should be removed beforehand.
+
+# Examples
+
+```
+use planar_convex_hull::{ConvexHull, polygon::congruent};
+
+let square = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+
+// Rotated by 45° and shifted, starting with a different corner point
+let s = std::f64::consts::FRAC_1_SQRT_2;
+let rotated = [[5.0, s], [5.0 - s, 0.0], [5.0, -s], [5.0 + s, 0.0]];
+assert!(congruent(&square, &rotated, 1e-9, false, false));
+
+// Twice as large
+let larger = [[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]];
+assert!(!congruent(&square, &larger, 1e-9, false, false));
+assert!(congruent(&square, &larger, 1e-9, false, true));
+
+// Hulls can be compared directly
+let points = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0], [0.5, 0.5]];
+let hull: Vec<[f64; 2]> = points.convex_hull().map(|(_, p)| p).collect();
+assert!(congruent(&square, &hull, 1e-9, false, false));
+```
+ */
+pub fn congruent(
+    a: &[[f64; 2]],
+    b: &[[f64; 2]],
+    tolerance: f64,
+    reflection: bool,
+    scale: bool,
+) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    if a.len() < 2 {
+        return true;
+    }
+
+    let signature_a = edge_signature(a, scale);
+    if cyclic_match(&signature_a, &edge_signature(b, scale), tolerance) {
+        return true;
+    }
+
+    if reflection {
+        // Mirror b at the x-axis and reverse the order of its points so that
+        // the mirrored polygon is counter-clockwise again.
+        let mirrored: Vec<[f64; 2]> = b.iter().rev().map(|p| [p[0], -p[1]]).collect();
+        return cyclic_match(&signature_a, &edge_signature(&mirrored, scale), tolerance);
+    }
+
+    return false;
+}
+
+/**
+Returns the signed area of a simple polygon (not necessarily convex) using the
+shoelace formula. The area is positive for counter-clockwise polygons and
+negative for clockwise ones. Polygons with fewer than three corners have zero
+area.
+
+The corners are taken relative to the first corner, which reduces the
+cancellation for polygons located far away from the origin.
+
+# Examples
+
+```
+use planar_convex_hull::polygon::signed_area;
+
+let square = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+assert_eq!(signed_area(&square), 1.0);
+
+let clockwise = [[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]];
+assert_eq!(signed_area(&clockwise), -1.0);
+```
+ */
+pub fn signed_area(polygon: &[[f64; 2]]) -> f64 {
+    return geometry::signed_area(polygon);
+}
+
+/// Winding direction of a polygon, see [`winding`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Winding {
+    /// The polygon has a positive signed area.
+    CounterClockwise,
+    /// The polygon has a negative signed area.
+    Clockwise,
+    /// The polygon has no area (e.g. fewer than three corners or all corners
+    /// on a single line) or its area is not a real number.
+    Degenerate,
+}
+
+/**
+Returns the winding direction of a simple polygon, derived from the sign of its
+[`signed_area`].
+
+# Examples
+
+```
+use planar_convex_hull::polygon::{Winding, winding};
+
+assert_eq!(winding(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]), Winding::CounterClockwise);
+assert_eq!(winding(&[[0.0, 0.0], [0.0, 1.0], [1.0, 0.0]]), Winding::Clockwise);
+assert_eq!(winding(&[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]), Winding::Degenerate);
+```
+ */
+pub fn winding(polygon: &[[f64; 2]]) -> Winding {
+    let area = signed_area(polygon);
+    if area > 0.0 {
+        return Winding::CounterClockwise;
+    } else if area < 0.0 {
+        return Winding::Clockwise;
+    }
+    return Winding::Degenerate;
+}
+
+/**
+Resolves the `keys` of a polygon to the points of `collection` with a single
+pass over the collection. Returns `None` if a key is not part of the collection.
+ */
+fn resolve_keys<T: ConvexHull + ?Sized>(collection: &T, keys: &[usize]) -> Option<Vec<[f64; 2]>> {
+    let mut points: HashMap<usize, Option<[f64; 2]>> =
+        keys.iter().map(|key| (*key, None)).collect();
+    for (key, point) in collection.convex_hull_iter() {
+        if let Some(entry) = points.get_mut(&key) {
+            *entry = Some(point);
+        }
+    }
+    return keys.iter().map(|key| points[key]).collect();
+}
+
+/**
+Returns the [`signed_area`] of the polygon formed by the loop of `keys` over
+the points of `collection`. The loop is closed implicitly, i.e. the last key
+should not repeat the first one.
+
+This works for any loop of keys, not only for hulls calculated by this crate,
+e.g. for polygons from other sources sharing the same point storage. Returns
+`None` if a key is not part of the collection.
+
+# Examples
+
+```
+use planar_convex_hull::polygon::signed_area_of_loop;
+
+let points = vec![[0.0, 0.0], [2.0, 0.0], [1.0, 1.0], [2.0, 2.0], [0.0, 2.0]];
+assert_eq!(signed_area_of_loop(&points, &[0, 1, 3, 4]), Some(4.0));
+assert_eq!(signed_area_of_loop(&points, &[0, 4, 3, 2, 1]), Some(-3.0));
+assert_eq!(signed_area_of_loop(&points, &[0, 1, 5]), None);
+```
+ */
+pub fn signed_area_of_loop<T: ConvexHull + ?Sized>(collection: &T, keys: &[usize]) -> Option<f64> {
+    return resolve_keys(collection, keys).map(|polygon| signed_area(&polygon));
+}
+
+/**
+Returns the [`winding`] direction of the polygon formed by the loop of `keys`
+over the points of `collection` (see [`signed_area_of_loop`]). Returns `None` if
+a key is not part of the collection.
+
+# Examples
+
+```
+use planar_convex_hull::ConvexHull;
+use planar_convex_hull::polygon::{Winding, winding_of_loop};
+
+let points = vec![[0.0, 0.0], [2.0, 0.0], [1.0, 1.0], [2.0, 2.0], [0.0, 2.0]];
+let hull: Vec<usize> = points.convex_hull().map(|(key, _)| key).collect();
+assert_eq!(winding_of_loop(&points, &hull), Some(Winding::CounterClockwise));
+```
+ */
+pub fn winding_of_loop<T: ConvexHull + ?Sized>(collection: &T, keys: &[usize]) -> Option<Winding> {
+    return resolve_keys(collection, keys).map(|polygon| winding(&polygon));
+}
+
+/**
+Calculates the intersection over union (the area of the intersection divided by
+the area of the union) of the convex polygons `a` and `b`.
+
+Both polygons are given as their corner points in counter-clockwise order. The
+returned value is between 0 (disjoint polygons) and 1 (identical polygons). If
+the union of both polygons has no area (e.g. because both polygons are
+degenerated to lines or points), 0 is returned. The intersection is calculated
+in O(n + m) like [`Hull::intersection`](crate::Hull::intersection).
+
+# Examples
+
+```
+use planar_convex_hull::polygon::iou;
+
+let a = [[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]];
+let b = [[1.0, 0.0], [3.0, 0.0], [3.0, 2.0], [1.0, 2.0]];
+
+// Intersection area is 2, union area is 6
+assert!((iou(&a, &b) - 1.0 / 3.0).abs() < 1e-12);
+assert_eq!(iou(&a, &a), 1.0);
+```
+ */
+pub fn iou(a: &[[f64; 2]], b: &[[f64; 2]]) -> f64 {
+    let area_a = signed_area(a).abs();
+    let area_b = signed_area(b).abs();
+    let area_intersection = if area_a > 0.0 && area_b > 0.0 {
+        convex_intersection(a, b).area()
+    } else {
+        0.0
+    };
+    let area_union = area_a + area_b - area_intersection;
+    if area_union <= 0.0 {
+        return 0.0;
+    }
+    return (area_intersection / area_union).clamp(0.0, 1.0);
+}
+
+/**
+Brings a convex polygon into the form returned by
+[`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull): Duplicate and
+collinear corners are removed and the first corner is the one with the largest
+x-value (and the largest y-value if there are multiple such corners).
+ */
+pub(crate) fn canonicalize(mut polygon: Vec<[f64; 2]>) -> Vec<[f64; 2]> {
+    polygon.dedup();
+    while polygon.len() > 1 && polygon.first() == polygon.last() {
+        polygon.pop();
+    }
+    let n = polygon.len();
+    if n >= 3 {
+        let corners: Vec<[f64; 2]> = (0..n)
+            .filter(|i| {
+                orientation(polygon[(i + n - 1) % n], polygon[*i], polygon[(i + 1) % n])
+                    != Ordering::Equal
+            })
+            .map(|i| polygon[i])
+            .collect();
+        if corners.len() >= 3 {
+            polygon = corners;
+        } else {
+            // All corners are collinear, so the polygon is a segment between
+            // its lexicographically smallest and largest corner
+            let compare = |a: &&[f64; 2], b: &&[f64; 2]| {
+                return a[0].total_cmp(&b[0]).then(a[1].total_cmp(&b[1]));
+            };
+            let min = *polygon.iter().min_by(compare).unwrap_or(&polygon[0]);
+            let max = *polygon.iter().max_by(compare).unwrap_or(&polygon[0]);
+            polygon = vec![max, min];
+        }
+    }
+    if let Some(start) = (0..polygon.len()).max_by(|i, j| {
+        polygon[*i][0]
+            .total_cmp(&polygon[*j][0])
+            .then(polygon[*i][1].total_cmp(&polygon[*j][1]))
+    }) {
+        polygon.rotate_left(start);
+    }
+    return polygon;
+}
+
+/**
+Calculates the Minkowski sum of the convex polygons `a` and `b` (in
+counter-clockwise order) by merging their edges by angle in O(n + m). Points
+and segments are polygons without edges and with two opposite edges,
+respectively. The result follows the conventions of
+[`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull).
+ */
+pub(crate) fn minkowski_sum(a: &[[f64; 2]], b: &[[f64; 2]]) -> Vec<[f64; 2]> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    // Start both polygons at their lowest corner (smallest y, then smallest x)
+    let lowest = |polygon: &[[f64; 2]]| {
+        return (0..polygon.len())
+            .min_by(|i, j| {
+                polygon[*i][1]
+                    .total_cmp(&polygon[*j][1])
+                    .then(polygon[*i][0].total_cmp(&polygon[*j][0]))
+            })
+            .unwrap_or(0);
+    };
+    let (n, m) = (a.len(), b.len());
+    let (start_a, start_b) = (lowest(a), lowest(b));
+    let corner_a = |i: usize| a[(start_a + i) % n];
+    let corner_b = |j: usize| b[(start_b + j) % m];
+    let edges = |count: usize| if count < 2 { 0 } else { count };
+    let (edges_a, edges_b) = (edges(n), edges(m));
+
+    let mut sum = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    loop {
+        let (p, q) = (corner_a(i), corner_b(j));
+        sum.push([p[0] + q[0], p[1] + q[1]]);
+        if i == edges_a && j == edges_b {
+            break;
+        }
+        let edge_a = [corner_a(i + 1)[0] - p[0], corner_a(i + 1)[1] - p[1]];
+        let edge_b = [corner_b(j + 1)[0] - q[0], corner_b(j + 1)[1] - q[1]];
+        let turn = orientation([0.0, 0.0], edge_a, edge_b);
+        let advance_a = (turn != Ordering::Less || j == edges_b) && i < edges_a;
+        let advance_b = (turn != Ordering::Greater || i == edges_a) && j < edges_b;
+        i += advance_a as usize;
+        j += advance_b as usize;
+    }
+    return canonicalize(sum);
+}
+
+/**
+Calculates the Minkowski difference `a ⊖ b` in the sense used for motion
+planning, i.e. the Minkowski sum of `a` and the point reflection `-b`. Both
+polygons need to be convex and counter-clockwise (e.g. the output of
+[`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull)).
+
+The result is the configuration-space obstacle of `a` for a robot with the
+shape `b`: If the reference point of the robot (the origin of the coordinate
+system `b` is given in) is located inside the result, the translated robot
+overlaps `a`. The result is again a convex polygon in counter-clockwise order
+following the conventions of
+[`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull) and is calculated in
+O(n + m). It is empty if either polygon is empty.
+
+# Examples
+
+```
+use planar_convex_hull::polygon::minkowski_difference;
+
+// Unit square obstacle and a robot which is a triangle around its reference point
+let obstacle = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+let robot = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]];
+
+let c_obstacle = minkowski_difference(&obstacle, &robot);
+assert_eq!(
+    c_obstacle,
+    vec![[1.0, 1.0], [-1.0, 1.0], [-1.0, 0.0], [0.0, -1.0], [1.0, -1.0]]
+);
+```
+ */
+pub fn minkowski_difference(a: &[[f64; 2]], b: &[[f64; 2]]) -> Vec<[f64; 2]> {
+    // The point reflection keeps the counter-clockwise order.
+    let reflected: Vec<[f64; 2]> = b.iter().map(|point| [-point[0], -point[1]]).collect();
+    return minkowski_sum(a, &reflected);
+}
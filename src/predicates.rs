@@ -0,0 +1,264 @@
+//! This module contains geometric predicates which are evaluated exactly for
+//! (practically) all finite inputs. They are used by
+//! [`ConvexHull::convex_hull_exact`](crate::ConvexHull::convex_hull_exact) to
+//! validate and repair the result of the floating-point algorithm.
+//!
+//! The predicates first evaluate the floating-point approximation and only fall
+//! back to exact expansion arithmetic \[1\] if the approximation is too close to
+//! zero to determine its sign reliably, or if its intermediate results overflow
+//! or underflow. In the latter case, the coordinates are scaled by a power of
+//! two before the exact evaluation, which does not change the sign of the
+//! result. The only inputs for which the result might not be exact combine
+//! such an overflow or underflow with coordinates of vastly different
+//! magnitude: the ratio between the largest and the smallest nonzero
+//! coordinate magnitude of the involved points must exceed 2^900, so that the
+//! smaller coordinates are lost when scaling the larger ones into range.
+//!
+//! # Literature
+//!
+//! 1. Shewchuk, J. R.: Adaptive Precision Floating-Point Arithmetic and Fast
+//!    Robust Geometric Predicates. Discrete & Computational Geometry 18,
+//!    305–363 (1997). <https://doi.org/10.1007/PL00009321>
+
+use std::cmp::Ordering;
+
+/// Relative error bound of the floating-point approximation of [`orientation`].
+const ORIENTATION_ERROR_BOUND: f64 = (3.0 + 16.0 * f64::EPSILON) * f64::EPSILON;
+
+/// Returns `2^exponent` for exponents of normal floating-point numbers.
+const fn power_of_two(exponent: i32) -> f64 {
+    return f64::from_bits(((exponent + 1023) as u64) << 52);
+}
+
+/// Nonzero products of the floating-point approximation of [`orientation`]
+/// must lie between `2^-960` and `2^1000`, otherwise the approximation or its
+/// error bound might have underflowed or overflowed.
+const MIN_PRODUCT: f64 = power_of_two(-960);
+const MAX_PRODUCT: f64 = power_of_two(1000);
+
+/// Exponent of the largest coordinate magnitude after scaling the points for
+/// the exact evaluation of [`orientation`]. The products of the scaled
+/// coordinates and their sums cannot overflow.
+const SCALED_EXPONENT: i32 = 500;
+
+/// Returns the sum of `a` and `b` and the rounding error of the sum.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    return (sum, (a - a_virtual) + (b - b_virtual));
+}
+
+/// Returns the product of `a` and `b` and the rounding error of the product.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+    return (product, a.mul_add(b, -product));
+}
+
+/// Maximum number of components of the expansions used in this module (two
+/// per product of [`orientation`]).
+const MAX_COMPONENTS: usize = 12;
+
+/// Nonoverlapping expansion whose components are sorted by increasing
+/// magnitude. It is stored inline, so the predicates never allocate.
+struct Expansion {
+    components: [f64; MAX_COMPONENTS],
+    len: usize,
+}
+
+impl Expansion {
+    /// Adds `value` to the expansion. The result is again nonoverlapping and
+    /// sorted.
+    fn grow(&mut self, value: f64) {
+        let mut q = value;
+        for component in self.components[..self.len].iter_mut() {
+            let (sum, error) = two_sum(q, *component);
+            *component = error;
+            q = sum;
+        }
+        self.components[self.len] = q;
+        self.len += 1;
+    }
+}
+
+/// Evaluates the sign of the sum of the given `(sign, x, y)` products
+/// `sign * x * y` exactly.
+fn exact_sign_of_products(products: &[(f64, f64, f64); MAX_COMPONENTS / 2]) -> Ordering {
+    let mut expansion = Expansion {
+        components: [0.0; MAX_COMPONENTS],
+        len: 0,
+    };
+    for (sign, x, y) in products.iter() {
+        let (product, error) = two_product(*x, *y);
+        expansion.grow(sign * product);
+        expansion.grow(sign * error);
+    }
+
+    // The largest nonzero component determines the sign of the expansion.
+    return expansion.components[..expansion.len]
+        .iter()
+        .rev()
+        .find(|component| **component != 0.0)
+        .and_then(|component| component.partial_cmp(&0.0))
+        .unwrap_or(Ordering::Equal);
+}
+
+/**
+Determines the orientation of the point triplet `a`, `b`, `c`.
+
+Returns [`Ordering::Greater`] if `c` is located on the left of the directed line
+from `a` to `b` (the triplet is counter-clockwise), [`Ordering::Less`] if it is
+located on the right (clockwise) and [`Ordering::Equal`] if the three points are
+collinear. The result is exact for all finite inputs (see the
+[module documentation](self) for the single exception). The function never
+allocates.
+
+# Examples
+
+```
+use std::cmp::Ordering;
+use planar_convex_hull::predicates::orientation;
+
+assert_eq!(orientation([0.0, 0.0], [1.0, 0.0], [0.0, 1.0]), Ordering::Greater);
+assert_eq!(orientation([0.0, 0.0], [1.0, 0.0], [0.0, -1.0]), Ordering::Less);
+assert_eq!(orientation([0.0, 0.0], [1.0, 1.0], [3.0, 3.0]), Ordering::Equal);
+
+// The naive floating-point evaluation of this triplet yields zero, even
+// though c is located slightly above the line through a and b.
+let a = [0.5, 0.5];
+let b = [12.0, 12.0];
+let c = [24.0, 24.000000000000004];
+assert_eq!(orientation(a, b, c), Ordering::Greater);
+```
+ */
+pub fn orientation(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> Ordering {
+    let left = (a[0] - c[0]) * (b[1] - c[1]);
+    let right = (a[1] - c[1]) * (b[0] - c[0]);
+    if !in_filter_range(left, a[0] - c[0], b[1] - c[1])
+        || !in_filter_range(right, a[1] - c[1], b[0] - c[0])
+    {
+        return exact_orientation(a, b, c);
+    }
+    let det = left - right;
+
+    let det_sum = if left > 0.0 {
+        if right <= 0.0 {
+            return det.partial_cmp(&0.0).unwrap_or(Ordering::Equal);
+        }
+        left + right
+    } else if left < 0.0 {
+        if right >= 0.0 {
+            return det.partial_cmp(&0.0).unwrap_or(Ordering::Equal);
+        }
+        -left - right
+    } else {
+        return det.partial_cmp(&0.0).unwrap_or(Ordering::Equal);
+    };
+
+    if det.abs() > ORIENTATION_ERROR_BOUND * det_sum {
+        return det.partial_cmp(&0.0).unwrap_or(Ordering::Equal);
+    }
+    return exact_orientation(a, b, c);
+}
+
+/// Checks whether the `product` of `x` and `y` neither overflowed nor
+/// underflowed.
+fn in_filter_range(product: f64, x: f64, y: f64) -> bool {
+    return product.abs() <= MAX_PRODUCT && (product.abs() >= MIN_PRODUCT || x == 0.0 || y == 0.0);
+}
+
+/// Multiplies `value` by `2^exponent`, which is exact unless the result is
+/// subnormal.
+fn scale(value: f64, exponent: i32) -> f64 {
+    let half = exponent / 2;
+    return value * power_of_two(half) * power_of_two(exponent - half);
+}
+
+/**
+Evaluates the orientation of the point triplet `a`, `b`, `c` with exact
+expansion arithmetic. The points are scaled by a power of two beforehand so
+that the products of their coordinates neither overflow nor (for coordinates of
+similar magnitude) underflow.
+ */
+fn exact_orientation(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> Ordering {
+    let max = [a, b, c]
+        .iter()
+        .flatten()
+        .fold(0.0_f64, |max, value| max.max(value.abs()));
+    if max == 0.0 || !max.is_finite() {
+        return Ordering::Equal;
+    }
+
+    // Exponent of the largest magnitude. Subnormal numbers (whose biased
+    // exponent is zero) are treated like the smallest normal one.
+    let exponent = (((max.to_bits() >> 52) & 0x7ff) as i32).max(1) - 1023;
+    let shift = SCALED_EXPONENT - exponent;
+    let [a, b, c] = [a, b, c].map(|point| point.map(|value| scale(value, shift)));
+
+    // Exact evaluation of the expanded determinant
+    // ax * by - ax * cy - ay * bx + ay * cx + bx * cy - by * cx
+    return exact_sign_of_products(&[
+        (1.0, a[0], b[1]),
+        (-1.0, a[0], c[1]),
+        (-1.0, a[1], b[0]),
+        (1.0, a[1], c[0]),
+        (1.0, b[0], c[1]),
+        (-1.0, b[1], c[0]),
+    ]);
+}
+
+/**
+Compares the points `a` and `b`, which are located on the same ray starting at
+`origin`, by their distance to `origin`. The coordinates are compared directly
+instead of calculating the distances, which could be rounded to the same value
+for distinct points.
+ */
+pub(crate) fn compare_along_ray(origin: [f64; 2], a: [f64; 2], b: [f64; 2]) -> Ordering {
+    // Along the ray, the coordinates grow (or shrink) monotonically
+    let axis = if a[0] != b[0] { 0 } else { 1 };
+    if a[axis] > origin[axis] || b[axis] > origin[axis] {
+        return a[axis].total_cmp(&b[axis]);
+    }
+    return b[axis].total_cmp(&a[axis]);
+}
+
+/// Returns `true` if `point` is located on the closed segment from `a` to `b`.
+pub(crate) fn on_segment(a: [f64; 2], b: [f64; 2], point: [f64; 2]) -> bool {
+    return orientation(a, b, point) == Ordering::Equal
+        && point[0] >= a[0].min(b[0])
+        && point[0] <= a[0].max(b[0])
+        && point[1] >= a[1].min(b[1])
+        && point[1] <= a[1].max(b[1]);
+}
+
+/**
+Checks whether `point` is located strictly inside the convex polygon `hull`
+(corner points in counter-clockwise order, no three of them collinear) using a
+binary search over the wedges spanned by the first corner point.
+ */
+pub(crate) fn strictly_inside_convex(hull: &[[f64; 2]], point: [f64; 2]) -> bool {
+    let n = hull.len();
+    if n < 3 {
+        return false;
+    }
+    let origin = hull[0];
+    if orientation(origin, hull[1], point) != Ordering::Greater
+        || orientation(origin, hull[n - 1], point) != Ordering::Less
+    {
+        return false;
+    }
+
+    // Invariant: point is left of origin -> hull[lo] and not left of
+    // origin -> hull[hi].
+    let mut lo = 1;
+    let mut hi = n - 1;
+    while hi - lo > 1 {
+        let mid = (lo + hi) / 2;
+        if orientation(origin, hull[mid], point) == Ordering::Greater {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    return orientation(hull[lo], hull[hi], point) == Ordering::Greater;
+}
@@ -0,0 +1,195 @@
+//! Prefilters which discard points that cannot be part of the convex hull
+//! before the actual hull algorithm runs. They are selected via
+//! [`ConvexHullOptions`](crate::ConvexHullOptions).
+
+use crate::ConvexHull;
+use crate::monotone_chain::monotone_chain;
+use crate::predicates::strictly_inside_convex;
+
+/// Upper limit for the number of grid cells along each axis, which bounds the
+/// memory of the grid.
+const MAX_CELLS: usize = 1024;
+
+/// Bounding box and axis-extreme points of the points inside a grid cell.
+struct Cell {
+    min: [f64; 2],
+    max: [f64; 2],
+    /// Points with the smallest x, largest x, smallest y and largest y-value.
+    extremes: [(usize, [f64; 2]); 4],
+}
+
+impl Cell {
+    fn new(key: usize, point: [f64; 2]) -> Self {
+        return Self {
+            min: point,
+            max: point,
+            extremes: [(key, point); 4],
+        };
+    }
+
+    fn insert(&mut self, key: usize, point: [f64; 2]) {
+        if point[0] < self.min[0] {
+            self.min[0] = point[0];
+            self.extremes[0] = (key, point);
+        }
+        if point[0] > self.max[0] {
+            self.max[0] = point[0];
+            self.extremes[1] = (key, point);
+        }
+        if point[1] < self.min[1] {
+            self.min[1] = point[1];
+            self.extremes[2] = (key, point);
+        }
+        if point[1] > self.max[1] {
+            self.max[1] = point[1];
+            self.extremes[3] = (key, point);
+        }
+    }
+}
+
+/**
+Grid prefilter: The bounding box of all real points is divided into `cells` x
+`cells` grid cells, and the bounding boxes and axis-extreme points of the
+occupied cells are recorded. The number of cells along each axis is limited to
+[`MAX_CELLS`] and to the square root of the number of real points, so the grid
+never has more cells than there are points. The exact convex hull of all
+axis-extreme points is a (usually very good) inner approximation of the actual
+hull. Every cell whose bounding box is located strictly inside
+this approximation cannot contain a hull point, so all of its points are
+discarded.
+
+For clustered data, most points are located in such interior cells. The
+returned points are a subset of the real points of `collection` which contains
+all corners of its convex hull.
+ */
+pub(crate) fn grid<T: ConvexHull + ?Sized>(collection: &T, cells: usize) -> Vec<(usize, [f64; 2])> {
+    let finite = |point: &[f64; 2]| point[0].is_finite() && point[1].is_finite();
+
+    let mut min = [f64::INFINITY; 2];
+    let mut max = [f64::NEG_INFINITY; 2];
+    let mut count: usize = 0;
+    for (_, point) in collection.convex_hull_iter() {
+        if finite(&point) {
+            count += 1;
+            min = [min[0].min(point[0]), min[1].min(point[1])];
+            max = [max[0].max(point[0]), max[1].max(point[1])];
+        }
+    }
+
+    let cells = cells.min(count.isqrt()).clamp(1, MAX_CELLS);
+    let cell_index = |point: [f64; 2]| -> usize {
+        let index = |axis: usize| {
+            let extent = max[axis] - min[axis];
+            if extent > 0.0 && extent.is_finite() {
+                (((point[axis] - min[axis]) / extent * cells as f64) as usize).min(cells - 1)
+            } else {
+                0
+            }
+        };
+        return index(0) * cells + index(1);
+    };
+
+    let mut occupied: Vec<Option<Cell>> = (0..cells * cells).map(|_| None).collect();
+    for (key, point) in collection.convex_hull_iter() {
+        if !finite(&point) {
+            continue;
+        }
+        match &mut occupied[cell_index(point)] {
+            Some(cell) => cell.insert(key, point),
+            cell => *cell = Some(Cell::new(key, point)),
+        }
+    }
+
+    let inner: Vec<[f64; 2]> = monotone_chain(
+        occupied
+            .iter()
+            .flatten()
+            .flat_map(|cell| cell.extremes.iter().copied())
+            .collect(),
+    )
+    .into_iter()
+    .map(|(_, point)| point)
+    .collect();
+
+    let interior = |cell: &Cell| {
+        return [
+            cell.min,
+            [cell.max[0], cell.min[1]],
+            cell.max,
+            [cell.min[0], cell.max[1]],
+        ]
+        .into_iter()
+        .all(|corner| strictly_inside_convex(&inner, corner));
+    };
+    let keep: Vec<bool> = occupied
+        .iter()
+        .map(|cell| cell.as_ref().is_some_and(|cell| !interior(cell)))
+        .collect();
+
+    return collection
+        .convex_hull_iter()
+        .filter(|(_, point)| finite(point) && keep[cell_index(*point)])
+        .collect();
+}
+
+/**
+Akl–Toussaint prefilter \[1\]: The points with the smallest and largest values
+of x, y, x + y and x - y are corners of the convex hull. All points located
+strictly inside their exact convex hull are discarded.
+
+The returned points are a subset of the real points of `collection` which
+contains all corners of its convex hull.
+
+# Literature
+
+1. Akl, S. G., Toussaint, G. T.: A fast convex hull algorithm. Information
+   Processing Letters 7, 219–222 (1978).
+   <https://doi.org/10.1016/0020-0190(78)90003-0>
+ */
+pub(crate) fn akl_toussaint<T: ConvexHull + ?Sized>(collection: &T) -> Vec<(usize, [f64; 2])> {
+    let finite = |point: &[f64; 2]| point[0].is_finite() && point[1].is_finite();
+
+    // Smallest and largest value of each linear function and the associated
+    // point. Rounding errors of the sums only affect which points are chosen.
+    // Since they are points of the collection, their hull is located inside
+    // the hull of the collection in any case.
+    let functions = [
+        |p: [f64; 2]| p[0],
+        |p: [f64; 2]| p[1],
+        |p: [f64; 2]| p[0] + p[1],
+        |p: [f64; 2]| p[0] - p[1],
+    ];
+    let mut extremes = None;
+    for (_, point) in collection.convex_hull_iter() {
+        if !finite(&point) {
+            continue;
+        }
+        let extremes =
+            extremes.get_or_insert(functions.map(|function| [(function(point), point); 2]));
+        for (function, [min, max]) in functions.iter().zip(extremes.iter_mut()) {
+            let value = function(point);
+            if value < min.0 {
+                *min = (value, point);
+            }
+            if value > max.0 {
+                *max = (value, point);
+            }
+        }
+    }
+
+    let inner: Vec<[f64; 2]> = monotone_chain(
+        extremes
+            .iter()
+            .flatten()
+            .flat_map(|[min, max]| [(0, min.1), (0, max.1)])
+            .collect(),
+    )
+    .into_iter()
+    .map(|(_, point)| point)
+    .collect();
+
+    return collection
+        .convex_hull_iter()
+        .filter(|(_, point)| finite(point) && !strictly_inside_convex(&inner, *point))
+        .collect();
+}
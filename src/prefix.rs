@@ -0,0 +1,140 @@
+//! This module contains [`PrefixHulls`], the hulls of all prefixes of an
+//! ordered collection.
+
+use std::ops::Range;
+
+use crate::monotone_chain::monotone_chain;
+use crate::online::OnlineHull;
+use crate::{ConvexHull, Hull};
+
+/**
+Convex hulls of all prefixes of a collection, i.e. of its first `len` points
+for every `len`, in the iteration order of
+[`ConvexHull::convex_hull_iter`] (the index order for slices and vectors).
+
+Since the hull of a prefix only grows when points are added, each point is a
+hull corner for a single contiguous range of prefix lengths: from the prefix
+which added it until the prefix which added a point that covered it. The
+hulls are therefore stored compactly as these lifetimes, which need O(n)
+memory for all n prefixes. They are calculated by inserting the points one by
+one into an [`OnlineHull`], which takes O(n log n) time in total.
+
+Points located inside the hull of the preceding points (or on its boundary)
+never become corners and have no lifetime; the same applies to nonreal
+points.
+
+# Examples
+
+```
+use planar_convex_hull::prefix::PrefixHulls;
+
+let trajectory = [[0.0, 0.0], [2.0, 0.0], [1.0, 1.0], [1.0, 3.0], [3.0, 1.0]];
+let prefixes = PrefixHulls::new(&trajectory);
+assert_eq!(prefixes.len(), 5);
+
+assert_eq!(prefixes.hull(3).keys(), &[1, 2, 0]);
+assert_eq!(prefixes.hull(4).keys(), &[1, 3, 0]);
+assert_eq!(prefixes.hull(5).keys(), &[4, 3, 0, 1]);
+
+// Point 2 is a corner of the prefixes with three points only
+assert_eq!(prefixes.lifetime(2), Some(3..4));
+assert_eq!(prefixes.lifetime(0), Some(1..6));
+```
+ */
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct PrefixHulls {
+    /// Key and point of each corner together with the range of prefix lengths
+    /// for which it is a corner, ordered by the start of the range.
+    lifetimes: Vec<(usize, [f64; 2], Range<usize>)>,
+    len: usize,
+}
+
+impl PrefixHulls {
+    /// Calculates the hulls of all prefixes of `collection`.
+    pub fn new<T: ConvexHull + ?Sized>(collection: &T) -> Self {
+        let mut online = OnlineHull::new();
+        // Index into lifetimes and number of chains containing the point, per
+        // position in the collection
+        let mut alive: Vec<(usize, u8)> = Vec::new();
+        let mut lifetimes: Vec<(usize, [f64; 2], Range<usize>)> = Vec::new();
+        let mut removed = Vec::new();
+
+        let mut len = 0;
+        for (position, (key, point)) in collection.convex_hull_iter().enumerate() {
+            len = position + 1;
+            removed.clear();
+            let chains = online.insert_tracked(position, point, &mut removed);
+            let count = chains.iter().filter(|chain| **chain).count() as u8;
+            alive.push((lifetimes.len(), count));
+            if count > 0 {
+                lifetimes.push((key, point, len..usize::MAX));
+            }
+            for other in removed.iter() {
+                let (index, count) = &mut alive[*other];
+                *count -= 1;
+                if *count == 0 {
+                    lifetimes[*index].2.end = len;
+                }
+            }
+        }
+        for lifetime in lifetimes.iter_mut() {
+            lifetime.2.end = lifetime.2.end.min(len + 1);
+        }
+        return Self { lifetimes, len };
+    }
+
+    /// Returns the number of points of the collection, which is the length of
+    /// the longest prefix.
+    pub fn len(&self) -> usize {
+        return self.len;
+    }
+
+    /// Returns `true` if the collection is empty.
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
+    /**
+    Returns the range of prefix lengths for which the point at `position` (in
+    the iteration order) is a hull corner, or `None` if it never is one.
+     */
+    pub fn lifetime(&self, position: usize) -> Option<Range<usize>> {
+        let index = self
+            .lifetimes
+            .partition_point(|(_, _, range)| range.start <= position);
+        return self
+            .lifetimes
+            .get(index)
+            .filter(|(_, _, range)| range.start == position + 1)
+            .map(|(_, _, range)| range.clone());
+    }
+
+    /**
+    Returns the keys and points of all points which are a hull corner for at
+    least one prefix, together with the range of prefix lengths for which
+    they are corners. The entries are ordered by the position of the point
+    in the collection.
+     */
+    pub fn lifetimes(&self) -> &[(usize, [f64; 2], Range<usize>)] {
+        return &self.lifetimes;
+    }
+
+    /**
+    Returns the hull of the first `len` points, following the conventions of
+    [`ConvexHull::convex_hull`]. This takes O(m + h log h) time, where m is
+    the number of lifetimes and h the number of corners. A `len` larger than
+    the collection returns the hull of the whole collection.
+     */
+    pub fn hull(&self, len: usize) -> Hull {
+        let corners: Vec<(usize, [f64; 2])> = self
+            .lifetimes
+            .iter()
+            .take_while(|(_, _, range)| range.start <= len)
+            .filter(|(_, _, range)| range.contains(&len.min(self.len)))
+            .map(|(key, point, _)| (*key, *point))
+            .collect();
+        return Hull::from_vertices_unchecked(monotone_chain(corners));
+    }
+}
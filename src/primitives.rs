@@ -0,0 +1,138 @@
+//! This module contains the [`Primitives`] collection, which calculates the
+//! hull of line segments and polygons while keeping track of the primitive
+//! each hull corner came from.
+
+use crate::ConvexHull;
+
+/**
+Origin of a point of a [`Primitives`] collection, see
+[`Primitives::provenance`].
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct Provenance {
+    /// Index of the primitive, in the order the primitives were added.
+    pub primitive: usize,
+    /// Index of the vertex within the primitive.
+    pub vertex: usize,
+}
+
+/**
+Collection of line segments and polygons (or polylines) whose hull is the hull
+of all their vertices.
+
+The keys of the [`ConvexHull`] implementation enumerate the vertices of all
+primitives in the order they were added; [`Primitives::provenance`] maps a key
+back to the primitive and the vertex within it.
+
+# Examples
+
+```
+use planar_convex_hull::ConvexHull;
+use planar_convex_hull::primitives::{Primitives, Provenance};
+
+let mut primitives = Primitives::new();
+let road = primitives.push_segment([0.0, 0.0], [4.0, 0.0]);
+let building = primitives.push_polygon([[1.0, 1.0], [2.0, 1.0], [2.0, 3.0], [1.0, 3.0]]);
+assert_eq!((road, building), (0, 1));
+
+let hull = primitives.convex_hull_with_provenance();
+assert_eq!(
+    hull,
+    vec![
+        (Provenance { primitive: 0, vertex: 1 }, [4.0, 0.0]),
+        (Provenance { primitive: 1, vertex: 2 }, [2.0, 3.0]),
+        (Provenance { primitive: 1, vertex: 3 }, [1.0, 3.0]),
+        (Provenance { primitive: 0, vertex: 0 }, [0.0, 0.0]),
+    ]
+);
+```
+ */
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct Primitives {
+    /// Vertices of all primitives.
+    points: Vec<[f64; 2]>,
+    /// Index of the first vertex of each primitive in `points`.
+    offsets: Vec<usize>,
+}
+
+impl Primitives {
+    /// Creates an empty collection.
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Adds the segment from `start` to `end` and returns its primitive index.
+    pub fn push_segment(&mut self, start: [f64; 2], end: [f64; 2]) -> usize {
+        return self.push_polygon([start, end]);
+    }
+
+    /**
+    Adds a polygon (or polyline) with the given vertices and returns its
+    primitive index. The vertices can be in any order, the polygon does not
+    need to be closed and it may be empty.
+     */
+    pub fn push_polygon(&mut self, vertices: impl IntoIterator<Item = [f64; 2]>) -> usize {
+        self.offsets.push(self.points.len());
+        self.points.extend(vertices);
+        return self.offsets.len() - 1;
+    }
+
+    /// Returns the number of primitives.
+    pub fn len(&self) -> usize {
+        return self.offsets.len();
+    }
+
+    /// Returns `true` if no primitive has been added yet.
+    pub fn is_empty(&self) -> bool {
+        return self.offsets.is_empty();
+    }
+
+    /// Returns the vertices of the primitive with the given index.
+    pub fn primitive(&self, primitive: usize) -> Option<&[[f64; 2]]> {
+        let start = *self.offsets.get(primitive)?;
+        let end = self
+            .offsets
+            .get(primitive + 1)
+            .copied()
+            .unwrap_or(self.points.len());
+        return Some(&self.points[start..end]);
+    }
+
+    /**
+    Returns the primitive and the vertex within it which belong to `key`, or
+    `None` if the collection has no point with this key.
+     */
+    pub fn provenance(&self, key: usize) -> Option<Provenance> {
+        if key >= self.points.len() {
+            return None;
+        }
+        // The offsets are sorted, and empty primitives share their offset
+        // with the next primitive.
+        let primitive = self.offsets.partition_point(|offset| *offset <= key) - 1;
+        return Some(Provenance {
+            primitive,
+            vertex: key - self.offsets[primitive],
+        });
+    }
+
+    /**
+    Calculates the hull with [`ConvexHull::convex_hull`] and resolves the key
+    of each corner into its [`Provenance`].
+     */
+    pub fn convex_hull_with_provenance(&self) -> Vec<(Provenance, [f64; 2])> {
+        return self
+            .convex_hull()
+            .filter_map(|(key, point)| Some((self.provenance(key)?, point)))
+            .collect();
+    }
+}
+
+impl ConvexHull for Primitives {
+    fn convex_hull_iter(&self) -> impl Iterator<Item = (usize, [f64; 2])> {
+        return self.points.iter().copied().enumerate();
+    }
+}
@@ -0,0 +1,275 @@
+//! Proximity queries between two hulls, see [`Hull::distance_to`],
+//! [`Hull::overlaps`] and [`Hull::hausdorff_distance`].
+//!
+//! # Literature
+//!
+//! 1. Gilbert, E. G., Johnson, D. W., Keerthi, S. S.: A fast procedure for
+//!    computing the distance between complex objects in three-dimensional
+//!    space. IEEE Journal on Robotics and Automation 4, 193–203 (1988).
+//!    <https://doi.org/10.1109/56.2083>
+
+use crate::Hull;
+
+fn dot(u: [f64; 2], v: [f64; 2]) -> f64 {
+    return u[0] * v[0] + u[1] * v[1];
+}
+
+fn sub(u: [f64; 2], v: [f64; 2]) -> [f64; 2] {
+    return [u[0] - v[0], u[1] - v[1]];
+}
+
+/// Corner `a - b` of the Minkowski difference of two hulls, together with
+/// the corners `a` and `b` it is made of.
+#[derive(Clone, Copy)]
+struct Vertex {
+    w: [f64; 2],
+    a: [f64; 2],
+    b: [f64; 2],
+}
+
+/// Convex combination of simplex vertices closest to the origin.
+struct Closest {
+    weights: Vec<(Vertex, f64)>,
+}
+
+impl Closest {
+    fn point(&self, part: impl Fn(&Vertex) -> [f64; 2]) -> [f64; 2] {
+        return self
+            .weights
+            .iter()
+            .fold([0.0, 0.0], |sum, (vertex, weight)| {
+                let p = part(vertex);
+                return [sum[0] + weight * p[0], sum[1] + weight * p[1]];
+            });
+    }
+}
+
+/// Point of the segment `p`, `q` closest to the origin.
+fn closest_on_segment(p: Vertex, q: Vertex) -> Closest {
+    let edge = sub(q.w, p.w);
+    let length = dot(edge, edge);
+    let t = if length > 0.0 {
+        (-dot(p.w, edge) / length).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    if t == 0.0 {
+        return Closest {
+            weights: vec![(p, 1.0)],
+        };
+    }
+    if t == 1.0 {
+        return Closest {
+            weights: vec![(q, 1.0)],
+        };
+    }
+    return Closest {
+        weights: vec![(p, 1.0 - t), (q, t)],
+    };
+}
+
+/**
+Returns the point of the simplex closest to the origin, reduced to the
+vertices needed to express it. A triangle containing the origin is returned
+as a whole.
+ */
+fn closest_on_simplex(simplex: &[Vertex]) -> Closest {
+    match simplex {
+        [p] => {
+            return Closest {
+                weights: vec![(*p, 1.0)],
+            };
+        }
+        [p, q] => return closest_on_segment(*p, *q),
+        [p, q, r] => {
+            // Barycentric coordinates of the origin
+            let cross = |u: [f64; 2], v: [f64; 2]| u[0] * v[1] - u[1] * v[0];
+            let area = cross(sub(q.w, p.w), sub(r.w, p.w));
+            if area != 0.0 {
+                let u = cross(q.w, r.w) / area;
+                let v = cross(r.w, p.w) / area;
+                let w = cross(p.w, q.w) / area;
+                if u >= 0.0 && v >= 0.0 && w >= 0.0 {
+                    return Closest {
+                        weights: vec![(*p, u), (*q, v), (*r, w)],
+                    };
+                }
+            }
+            let norm = |closest: &Closest| {
+                let point = closest.point(|vertex| vertex.w);
+                return dot(point, point);
+            };
+            let mut best = closest_on_segment(*p, *q);
+            for candidate in [closest_on_segment(*q, *r), closest_on_segment(*r, *p)] {
+                if norm(&candidate) < norm(&best) {
+                    best = candidate;
+                }
+            }
+            return best;
+        }
+        _ => unreachable!("simplices in the plane have at most three vertices"),
+    }
+}
+
+impl Hull {
+    /**
+    Calculates the minimum distance between the hull and `other`, together
+    with the closest points of both hulls (in this order). If the hulls
+    intersect, the distance is zero and both points are the same point of the
+    intersection. Returns `None` if one of the hulls is empty.
+
+    The distance is calculated with the algorithm of Gilbert, Johnson and
+    Keerthi (GJK) \[1\], which iteratively approaches the point of the
+    Minkowski difference of both hulls which is closest to the origin. The
+    support points of the difference are found in O(log n + log m) (see
+    [`Hull::extreme_point_in_direction`]) and only few iterations are needed
+    in practice.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let square = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+    let triangle = Hull::new(&[[3.0, 0.5], [5.0, 0.0], [5.0, 1.0]]);
+    let (distance, [a, b]) = square.distance_to(&triangle).unwrap();
+    assert!((distance - 2.0).abs() < 1e-12);
+    assert!((a[0] - 1.0).abs() < 1e-12 && (0.0..=1.0).contains(&a[1]));
+    assert_eq!(b, [3.0, 0.5]);
+
+    let overlapping = Hull::new(&[[0.5, 0.5], [2.0, 0.5], [2.0, 2.0]]);
+    let (distance, [a, b]) = square.distance_to(&overlapping).unwrap();
+    assert_eq!(distance, 0.0);
+    assert_eq!(a, b);
+    ```
+     */
+    pub fn distance_to(&self, other: &Hull) -> Option<(f64, [[f64; 2]; 2])> {
+        let support = |direction: [f64; 2]| {
+            let (_, a) = self.extreme_point_in_direction(direction)?;
+            let (_, b) = other.extreme_point_in_direction([-direction[0], -direction[1]])?;
+            return Some(Vertex { w: sub(a, b), a, b });
+        };
+
+        let start = support([1.0, 0.0])?;
+        let mut simplex = vec![start];
+        let mut closest = closest_on_simplex(&simplex);
+        let mut v = start.w;
+
+        // Each iteration strictly decreases the distance and the Minkowski
+        // difference has at most n + m corners, so few iterations suffice.
+        // The limit only guards against cycles caused by rounding errors.
+        let limit = 4 * (self.len() + other.len()) + 16;
+        for _ in 0..limit {
+            let norm = dot(v, v);
+            if norm == 0.0 || closest.weights.len() == 3 {
+                break;
+            }
+            let Some(w) = support([-v[0], -v[1]]) else {
+                break;
+            };
+            if norm - dot(v, w.w) <= 1e-12 * norm || simplex.iter().any(|vertex| vertex.w == w.w) {
+                break;
+            }
+            simplex.push(w);
+            closest = closest_on_simplex(&simplex);
+            simplex = closest.weights.iter().map(|(vertex, _)| *vertex).collect();
+            v = closest.point(|vertex| vertex.w);
+        }
+
+        let (a, b) = (
+            closest.point(|vertex| vertex.a),
+            closest.point(|vertex| vertex.b),
+        );
+        if closest.weights.len() == 3 {
+            return Some((0.0, [a, a]));
+        }
+        let distance = v[0].hypot(v[1]);
+        if distance == 0.0 {
+            return Some((0.0, [a, a]));
+        }
+        return Some((distance, [a, b]));
+    }
+
+    /**
+    Returns `true` if the hull and `other` share at least one point (touching
+    hulls overlap). This is cheaper than [`Hull::intersection`] or
+    [`Hull::distance_to`] and sufficient e.g. for the broad phase of a
+    collision detection.
+
+    By the separating axis theorem, two convex polygons are disjoint if and
+    only if a line through an edge of one of them separates them. For each
+    edge, the corner of the other hull nearest to the edge line is its
+    support point against the edge normal, which is found in O(log h) (see
+    [`Hull::extreme_point_in_direction`]), so the test takes
+    O(n log m + m log n) time. For segments and points, the lines
+    perpendicular to them are tested as well (see [`Hull::to_halfspaces`]).
+    Empty hulls overlap nothing.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let square = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+    let triangle = Hull::new(&[[0.5, 0.5], [2.0, 0.5], [2.0, 2.0]]);
+    let touching = Hull::new(&[[1.0, 0.0], [2.0, 0.0], [2.0, 1.0]]);
+    let disjoint = Hull::new(&[[1.5, 0.0], [2.0, 0.0], [2.0, 1.0]]);
+    assert!(square.overlaps(&triangle));
+    assert!(square.overlaps(&touching));
+    assert!(!square.overlaps(&disjoint));
+    ```
+     */
+    pub fn overlaps(&self, other: &Hull) -> bool {
+        let separates = |hull: &Hull, other: &Hull| {
+            return hull.to_halfspaces().iter().any(|halfspace| {
+                let normal = halfspace.normal;
+                let Some((_, nearest)) = other.extreme_point_in_direction([-normal[0], -normal[1]])
+                else {
+                    return true;
+                };
+                return dot(normal, nearest) > halfspace.offset;
+            });
+        };
+        return !separates(self, other) && !separates(other, self);
+    }
+
+    /**
+    Calculates the Hausdorff distance between the boundaries of the hull and
+    `other`, i.e. the largest distance from a point on one boundary to the
+    nearest point on the other boundary. It is a symmetric measure of how
+    different two shapes are, e.g. the footprints of a tracked object in two
+    frames. Returns `None` if one of the hulls is empty.
+
+    For convex polygons, the Hausdorff distance between the boundaries equals
+    the one between the polygons including their interiors. The distance from
+    a point to a convex polygon is a convex function, so the largest distance
+    is attained at a corner, and the distance is calculated via
+    [`Hull::closest_point`] in O(n m) time.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let square = Hull::new(&[[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]]);
+    let shifted = Hull::new(&[[1.0, 0.0], [5.0, 0.0], [5.0, 4.0], [1.0, 4.0]]);
+    assert_eq!(square.hausdorff_distance(&shifted), Some(1.0));
+
+    // The center of the square is 2 away from the boundary
+    let inner = Hull::new(&[[2.0, 2.0]]);
+    assert_eq!(square.hausdorff_distance(&inner), Some(2.0f64.hypot(2.0)));
+    ```
+     */
+    pub fn hausdorff_distance(&self, other: &Hull) -> Option<f64> {
+        if self.is_empty() || other.is_empty() {
+            return None;
+        }
+        let directed = |from: &Hull, to: &Hull| {
+            return from.points().iter().fold(0.0f64, |distance, point| {
+                let (_, candidate) = to.closest_point(*point).unwrap_or((*point, 0.0));
+                return distance.max(candidate);
+            });
+        };
+        return Some(directed(self, other).max(directed(other, self)));
+    }
+}
@@ -0,0 +1,170 @@
+//! QuickHull \[1\] with exact orientation predicates, see
+//! [`ConvexHull::convex_hull_quickhull`](crate::ConvexHull::convex_hull_quickhull).
+//! If the `rayon` feature is enabled, large subproblems are processed in
+//! parallel.
+//!
+//! # Literature
+//!
+//! 1. Barber, C. B., Dobkin, D. P., Huhdanpaa, H.: The quickhull algorithm for
+//!    convex hulls. ACM Transactions on Mathematical Software 22, 469–483
+//!    (1996). <https://doi.org/10.1145/235815.235821>
+
+use std::cmp::Ordering;
+
+use crate::monotone_chain::monotone_chain;
+use crate::predicates::orientation;
+
+/// Subproblems with fewer points are processed sequentially, since the
+/// overhead of spawning tasks outweighs the gain.
+const PARALLEL_THRESHOLD: usize = 4096;
+
+/// Runs `a` and `b`, in parallel if `large` is `true` and the `rayon` feature
+/// is enabled.
+#[cfg_attr(not(feature = "rayon"), allow(unused_variables))]
+fn join<A: Send, B: Send>(
+    large: bool,
+    a: impl FnOnce() -> A + Send,
+    b: impl FnOnce() -> B + Send,
+) -> (A, B) {
+    #[cfg(feature = "rayon")]
+    if large {
+        return rayon::join(a, b);
+    }
+    return (a(), b());
+}
+
+/// Returns the indices for which `predicate` holds, in their original order.
+fn filter(indices: &[usize], predicate: impl Fn(usize) -> bool + Sync) -> Vec<usize> {
+    #[cfg(feature = "rayon")]
+    if indices.len() >= PARALLEL_THRESHOLD {
+        use rayon::prelude::*;
+        return indices
+            .par_iter()
+            .copied()
+            .filter(|index| predicate(*index))
+            .collect();
+    }
+    return indices
+        .iter()
+        .copied()
+        .filter(|index| predicate(*index))
+        .collect();
+}
+
+/// Returns the index whose point is farthest right of the line from `a` to
+/// `b`. Of multiple points with the same distance, the one with the smallest
+/// index is returned.
+fn farthest(points: &[(usize, [f64; 2])], a: [f64; 2], b: [f64; 2], indices: &[usize]) -> usize {
+    let distance = |index: usize| {
+        let p = points[index].1;
+        return (b[1] - a[1]) * (p[0] - a[0]) - (b[0] - a[0]) * (p[1] - a[1]);
+    };
+    let compare = |i: &usize, j: &usize| distance(*i).total_cmp(&distance(*j)).then(j.cmp(i));
+
+    #[cfg(feature = "rayon")]
+    if indices.len() >= PARALLEL_THRESHOLD {
+        use rayon::prelude::*;
+        return indices.par_iter().copied().max_by(compare).unwrap_or(0);
+    }
+    return indices.iter().copied().max_by(compare).unwrap_or(0);
+}
+
+/**
+Returns the candidates among `indices`, which are located strictly right of the
+line from the point `a` to the point `b`. Points inside the triangle spanned by
+`a`, `b` and the farthest point are discarded, the remaining points are
+processed recursively on both sides of the triangle.
+ */
+fn candidates(points: &[(usize, [f64; 2])], a: usize, b: usize, indices: Vec<usize>) -> Vec<usize> {
+    if indices.is_empty() {
+        return indices;
+    }
+    let (pa, pb) = (points[a].1, points[b].1);
+    let c = farthest(points, pa, pb, &indices);
+    let pc = points[c].1;
+
+    // A point can not be located right of both lines
+    let large = indices.len() >= PARALLEL_THRESHOLD;
+    let (right_of_ac, right_of_cb) = join(
+        large,
+        || {
+            filter(&indices, |index| {
+                orientation(pa, pc, points[index].1) == Ordering::Less
+            })
+        },
+        || {
+            filter(&indices, |index| {
+                orientation(pc, pb, points[index].1) == Ordering::Less
+            })
+        },
+    );
+    drop(indices);
+    let (mut first, second) = join(
+        large,
+        || candidates(points, a, c, right_of_ac),
+        || candidates(points, c, b, right_of_cb),
+    );
+    first.push(c);
+    first.extend(second);
+    return first;
+}
+
+/**
+Calculates the convex hull of `points` with QuickHull and returns its corners
+in the same order as [`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull).
+Collinear points on the hull edges and nonreal points are discarded, and of
+multiple identical points the first one is kept.
+
+The points are partitioned with exact predicates, so the recursion never
+discards a corner of the hull. The farthest points are chosen with
+floating-point arithmetic however, so the candidates may contain a few
+additional points close to the hull boundary. The candidates are therefore
+finalized with the monotone chain algorithm.
+ */
+pub(crate) fn quickhull(mut points: Vec<(usize, [f64; 2])>) -> Vec<(usize, [f64; 2])> {
+    points.retain(|(_, p)| p[0].is_finite() && p[1].is_finite());
+    let lexicographic = |i: &usize, j: &usize| -> Ordering {
+        let (a, b) = (points[*i].1, points[*j].1);
+        return a[0].total_cmp(&b[0]).then(a[1].total_cmp(&b[1]));
+    };
+
+    // The first smallest and the first largest point
+    let (Some(min), Some(max)) = (
+        (0..points.len()).min_by(lexicographic),
+        (0..points.len()).min_by(|i, j| lexicographic(j, i)),
+    ) else {
+        return points;
+    };
+    let (pmin, pmax) = (points[min].1, points[max].1);
+
+    let indices: Vec<usize> = (0..points.len()).collect();
+    let large = indices.len() >= PARALLEL_THRESHOLD;
+    let (below, above) = join(
+        large,
+        || {
+            filter(&indices, |index| {
+                orientation(pmin, pmax, points[index].1) == Ordering::Less
+            })
+        },
+        || {
+            filter(&indices, |index| {
+                orientation(pmax, pmin, points[index].1) == Ordering::Less
+            })
+        },
+    );
+    drop(indices);
+    let (lower, upper) = join(
+        large,
+        || candidates(&points, min, max, below),
+        || candidates(&points, max, min, above),
+    );
+
+    // Restore the input order so that the first one of identical points is
+    // kept by the monotone chain algorithm.
+    let mut hull = vec![min, max];
+    hull.extend(lower);
+    hull.extend(upper);
+    hull.sort_unstable();
+    hull.dedup();
+    return monotone_chain(hull.into_iter().map(|index| points[index]).collect());
+}
@@ -0,0 +1,269 @@
+//! This module contains [`RangeHulls`], a preprocessed collection answering
+//! hull queries for index ranges.
+
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use crate::monotone_chain::{lexicographic, monotone_chain};
+use crate::predicates::orientation;
+use crate::{ConvexHull, Hull};
+
+/**
+Segment tree of hulls which calculates the hull of any contiguous range of
+points of a collection, e.g. of the points of a trajectory within a time
+window.
+
+The points are numbered by their position in the iteration order of
+[`ConvexHull::convex_hull_iter`] (the index order for slices and vectors).
+Each node of the tree stores the hull of a range of positions whose length is
+a power of two, split into its upper and lower chain. A query covers its range
+with O(log n) nodes and wraps around their hulls (like the gift wrapping
+algorithm): The next corner of the query hull is found by a binary search for
+the tangent from the current corner to each of the O(log n) chains. A query
+therefore takes O(h log² n) time for the h corners of the resulting hull,
+independent of the length of the range and of the sizes of the partial hulls.
+Building the tree takes O(n log² n) time and stores at most O(n log n) corners;
+for typical inputs the partial hulls are much smaller than their ranges.
+
+# Examples
+
+```
+use planar_convex_hull::range::RangeHulls;
+
+let trajectory = [[0.0, 0.0], [2.0, 0.0], [1.0, 1.0], [1.0, 3.0], [3.0, 1.0]];
+let ranges = RangeHulls::new(&trajectory);
+assert_eq!(ranges.len(), 5);
+
+assert_eq!(ranges.hull(1..4).keys(), &[1, 3, 2]);
+assert_eq!(ranges.hull(2..5).keys(), &[4, 3, 2]);
+assert_eq!(ranges.hull(0..5).keys(), &[4, 3, 0, 1]);
+```
+ */
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct RangeHulls {
+    /// Hull corners of all nodes in the order of [`ConvexHull::convex_hull`].
+    corners: Vec<(usize, [f64; 2])>,
+    /// Start and end of the corners of each node in `corners`. Node 1 is the
+    /// root, the children of node k are 2k and 2k + 1, and the leaves start
+    /// at `leaves`.
+    nodes: Vec<[usize; 3]>,
+    leaves: usize,
+    len: usize,
+}
+
+impl RangeHulls {
+    /// Builds the segment tree for `collection`.
+    pub fn new<T: ConvexHull + ?Sized>(collection: &T) -> Self {
+        let points: Vec<(usize, [f64; 2])> = collection.convex_hull_iter().collect();
+        let len = points.len();
+        let leaves = len.next_power_of_two();
+
+        let mut corners = Vec::new();
+        let mut nodes = vec![[0, 0, 0]; 2 * leaves];
+        for (position, point) in points.into_iter().enumerate() {
+            if point.1[0].is_finite() && point.1[1].is_finite() {
+                nodes[leaves + position] = push_chains(&mut corners, &[point]);
+            }
+        }
+
+        let mut merged = Vec::new();
+        for node in (1..leaves).rev() {
+            merged.clear();
+            for child in [2 * node, 2 * node + 1] {
+                let [start, split, end] = nodes[child];
+                merged.extend(counter_clockwise(
+                    &corners[start..split],
+                    &corners[split..end],
+                ));
+            }
+            // Each partial hull consists of two monotone runs, which the sort
+            // in the monotone chain algorithm merges in linear time.
+            let hull = monotone_chain(std::mem::take(&mut merged));
+            nodes[node] = push_chains(&mut corners, &hull);
+            merged = hull;
+        }
+        return Self {
+            corners,
+            nodes,
+            leaves,
+            len,
+        };
+    }
+
+    /// Returns the number of points of the collection.
+    pub fn len(&self) -> usize {
+        return self.len;
+    }
+
+    /// Returns `true` if the collection is empty.
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
+    /**
+    Returns the hull of the points at the positions within `range`, following
+    the conventions of [`ConvexHull::convex_hull`]. The range is clamped to the
+    length of the collection.
+     */
+    pub fn hull(&self, range: Range<usize>) -> Hull {
+        let mut start = range.start.min(self.len) + self.leaves;
+        let mut end = range.end.min(self.len).max(range.start.min(self.len)) + self.leaves;
+
+        // Nodes covering the range from the left and from the right
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        while start < end {
+            if start % 2 == 1 {
+                left.push(start);
+                start += 1;
+            }
+            if end % 2 == 1 {
+                end -= 1;
+                right.push(end);
+            }
+            start /= 2;
+            end /= 2;
+        }
+
+        // The chains are ordered by the positions of their points, so the
+        // first of multiple identical points is kept.
+        let nodes: Vec<[usize; 3]> = left
+            .into_iter()
+            .chain(right.into_iter().rev())
+            .map(|node| self.nodes[node])
+            .collect();
+        let upper: Vec<&[(usize, [f64; 2])]> = nodes
+            .iter()
+            .map(|[start, split, _]| &self.corners[*start..*split])
+            .collect();
+        let lower: Vec<&[(usize, [f64; 2])]> = nodes
+            .iter()
+            .map(|[_, split, end]| &self.corners[*split..*end])
+            .collect();
+        return Hull::from_vertices_unchecked(counter_clockwise(
+            &wrap(&upper, true),
+            &wrap(&lower, false),
+        ));
+    }
+}
+
+/**
+Appends the upper and the lower chain of `hull` (in the order of
+[`ConvexHull::convex_hull`]) to `corners` and returns their bounds.
+ */
+fn push_chains(corners: &mut Vec<(usize, [f64; 2])>, hull: &[(usize, [f64; 2])]) -> [usize; 3] {
+    let start = corners.len();
+    let Some(first) = hull.first() else {
+        return [start, start, start];
+    };
+
+    // The hull runs counter-clockwise from its largest to its smallest corner
+    // along the upper chain and back along the lower chain.
+    let smallest = (0..hull.len())
+        .min_by(|a, b| lexicographic(&hull[*a].1, &hull[*b].1))
+        .unwrap_or(0);
+    corners.extend(hull[..=smallest].iter().rev());
+    let split = corners.len();
+    corners.push(*first);
+    corners.extend(hull[smallest.max(1)..].iter().rev());
+    return [start, split, corners.len()];
+}
+
+/**
+Returns the corners of the hull with the given `upper` and `lower` chains in
+the order of [`ConvexHull::convex_hull`].
+ */
+fn counter_clockwise<'a>(
+    upper: &'a [(usize, [f64; 2])],
+    lower: &'a [(usize, [f64; 2])],
+) -> impl Iterator<Item = (usize, [f64; 2])> + 'a {
+    let interior = lower.len().saturating_sub(1);
+    return upper
+        .iter()
+        .rev()
+        .chain(lower[1.min(interior)..interior].iter().rev())
+        .copied();
+}
+
+/**
+Calculates the upper (`upper == true`) or lower chain of the union of the
+given chains by gift wrapping. The chains must all be upper or all be lower
+chains, and the first of multiple identical points is kept.
+
+Starting at the smallest point, the next corner is the point which is located
+furthest counter-clockwise (for upper chains) as seen from the current corner,
+and the furthest one of multiple collinear points. Since the current corner is
+located outside of the hull of the points of a chain which are larger than it,
+the direction to these points changes unimodally along the chain, so the
+tangent is found with a binary search.
+ */
+fn wrap(chains: &[&[(usize, [f64; 2])]], upper: bool) -> Vec<(usize, [f64; 2])> {
+    // Lower chains are sorted in descending order
+    let order = |a: &[f64; 2], b: &[f64; 2]| {
+        if upper {
+            return lexicographic(a, b);
+        }
+        return lexicographic(b, a);
+    };
+
+    let Some(mut current) = chains
+        .iter()
+        .filter_map(|chain| chain.first().copied())
+        .reduce(|smallest, point| {
+            if order(&point.1, &smallest.1) == Ordering::Less {
+                return point;
+            }
+            return smallest;
+        })
+    else {
+        return Vec::new();
+    };
+
+    let mut corners = vec![current];
+    loop {
+        let mut next: Option<(usize, [f64; 2])> = None;
+        for chain in chains {
+            let skipped =
+                chain.partition_point(|point| order(&point.1, &current.1) != Ordering::Greater);
+            let chain = &chain[skipped..];
+            if chain.is_empty() {
+                continue;
+            }
+            let (mut low, mut high) = (0, chain.len() - 1);
+            while low < high {
+                let middle = (low + high) / 2;
+                if orientation(current.1, chain[middle].1, chain[middle + 1].1) == Ordering::Less {
+                    high = middle;
+                } else {
+                    low = middle + 1;
+                }
+            }
+            let candidate = chain[low];
+            next = match next {
+                None => Some(candidate),
+                Some(best) => match orientation(current.1, best.1, candidate.1) {
+                    Ordering::Greater => Some(candidate),
+                    Ordering::Less => Some(best),
+                    // On the same ray: Keep the furthest (or the first of
+                    // identical points)
+                    Ordering::Equal => {
+                        if order(&candidate.1, &best.1) == Ordering::Greater {
+                            Some(candidate)
+                        } else {
+                            Some(best)
+                        }
+                    }
+                },
+            };
+        }
+        match next {
+            Some(point) => {
+                corners.push(point);
+                current = point;
+            }
+            None => return corners,
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Rasterization of a hull onto a grid, see [`Hull::rasterize`].
+
+use std::collections::BTreeMap;
+
+use crate::Hull;
+
+/**
+Range of cells `[first, last]` covered by the interval `[lo, hi]` in grid
+coordinates. Cells which only touch the interval at its ends are omitted, so
+an interval `[0, 2]` covers the cells 0 and 1. An interval of length zero
+covers the cell containing it.
+ */
+fn span(lo: f64, hi: f64) -> (i64, i64) {
+    let first = lo.floor();
+    let last = (hi.ceil() - 1.0).max(first);
+    return (first as i64, last as i64);
+}
+
+impl Hull {
+    /**
+    Returns the cells of a grid which are covered by the hull (fully or
+    partially), e.g. to mark an obstacle in an occupancy grid. The grid has
+    square cells of size `cell_size` and the cell `[i, j]` spans the x-range
+    `origin[0] + i * cell_size` to `origin[0] + (i + 1) * cell_size` and the
+    y-range accordingly. The cells are returned sorted by `j` and then by `i`.
+
+    A cell is covered if its intersection with the hull has a positive area,
+    so cells which only touch the hull at their boundary are omitted. Points
+    and segments cover the cells they pass through, where a point on a grid
+    line belongs to the cell above or to the right of it.
+
+    Each edge is clipped to the rows of the grid it crosses, which gives the
+    range of covered cells in each row. This takes O((h + r) log r + c) time
+    for r rows and c covered cells.
+
+    # Panics
+
+    Panics if `cell_size` is not positive and finite.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [0.0, 2.0]]);
+    assert_eq!(hull.rasterize([0.0, 0.0], 1.0), vec![[0, 0], [1, 0], [0, 1]]);
+
+    // Shifting the grid by half a cell
+    assert_eq!(
+        hull.rasterize([-0.5, -0.5], 1.0),
+        vec![[0, 0], [1, 0], [2, 0], [0, 1], [1, 1], [0, 2]]
+    );
+    ```
+     */
+    pub fn rasterize(&self, origin: [f64; 2], cell_size: f64) -> Vec<[i64; 2]> {
+        assert!(
+            cell_size > 0.0 && cell_size.is_finite(),
+            "the cell size must be positive and finite"
+        );
+        let grid = |p: [f64; 2]| {
+            return [
+                (p[0] - origin[0]) / cell_size,
+                (p[1] - origin[1]) / cell_size,
+            ];
+        };
+
+        // Range of x-values of the hull in each row
+        let mut rows: BTreeMap<i64, (f64, f64)> = BTreeMap::new();
+        let mut extend = |row: i64, lo: f64, hi: f64| {
+            let range = rows.entry(row).or_insert((lo, hi));
+            *range = (range.0.min(lo), range.1.max(hi));
+        };
+        if let [point] = self.points() {
+            let [x, y] = grid(*point);
+            extend(y.floor() as i64, x, x);
+        }
+        for (a, b) in self.edges() {
+            let (a, b) = (grid(a), grid(b));
+            let (lo, hi) = if a[1] <= b[1] { (a, b) } else { (b, a) };
+            if lo[1] == hi[1] {
+                // Horizontal edges of polygons are covered by their
+                // neighbors, whose rows do not include the edge if it
+                // only touches a row from below.
+                if self.len() < 3 {
+                    extend(lo[1].floor() as i64, lo[0].min(hi[0]), lo[0].max(hi[0]));
+                }
+                continue;
+            }
+            let x_at = |y: f64| {
+                let t = ((y - lo[1]) / (hi[1] - lo[1])).clamp(0.0, 1.0);
+                return lo[0] + t * (hi[0] - lo[0]);
+            };
+            let (first, last) = span(lo[1], hi[1]);
+            for row in first..=last {
+                let (start, end) = (
+                    x_at((row as f64).max(lo[1])),
+                    x_at((row as f64 + 1.0).min(hi[1])),
+                );
+                extend(row, start.min(end), start.max(end));
+            }
+        }
+
+        let mut cells = Vec::new();
+        for (row, (lo, hi)) in rows {
+            let (first, last) = span(lo, hi);
+            cells.extend((first..=last).map(|column| [column, row]));
+        }
+        return cells;
+    }
+}
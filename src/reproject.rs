@@ -0,0 +1,177 @@
+//! This module reprojects geographic point sets into a planar coordinate
+//! reference system (CRS) before their hull is calculated, and exports the hull
+//! back into the original CRS. It is only available with the `proj` feature
+//! flag enabled and uses the pure Rust PROJ port
+//! [proj4rs](https://docs.rs/proj4rs).
+//!
+//! The algorithms of this crate assume planar (Cartesian) coordinates.
+//! Calculating the hull directly on longitude / latitude values (e.g. in
+//! EPSG:4326) distorts it, since a degree of longitude corresponds to a
+//! different distance depending on the latitude. Reprojecting the points into
+//! a suitable planar CRS (e.g. the local UTM zone) first avoids this.
+//!
+//! # Examples
+//!
+//! ```
+//! use planar_convex_hull::reproject::Reprojection;
+//!
+//! // Longitude / latitude in degrees (WGS 84) to UTM zone 32N (EPSG:32632)
+//! let reprojection = Reprojection::new(
+//!     "+proj=longlat +datum=WGS84 +no_defs",
+//!     "+proj=utm +zone=32 +datum=WGS84 +units=m +no_defs",
+//! )
+//! .unwrap();
+//!
+//! let points = [[9.0, 48.0], [9.1, 48.0], [9.1, 48.1], [9.0, 48.1], [9.05, 48.05]];
+//! let hull = reprojection.convex_hull(&points).unwrap();
+//! assert_eq!(hull.keys(), &[1, 2, 3, 0]);
+//!
+//! // The hull in UTM coordinates (meters) has an area of roughly 83 km²
+//! assert!((hull.area() - 83.0e6).abs() < 1.0e6);
+//!
+//! // Export the corners back into longitude / latitude
+//! let corners = reprojection.export(&hull).unwrap();
+//! assert_eq!(corners.len(), 4);
+//! assert_eq!(corners[0].0, 1);
+//! assert!((corners[0].1[0] - 9.1).abs() < 1e-9);
+//! assert!((corners[0].1[1] - 48.0).abs() < 1e-9);
+//! ```
+
+use proj4rs::Proj;
+use proj4rs::adaptors::transform_xy;
+
+use crate::{ConvexHull, Hull};
+
+/// Errors which can occur when creating or using a [`Reprojection`].
+#[derive(Debug)]
+pub enum ReprojectionError {
+    /// A CRS definition could not be parsed.
+    InvalidCrs(proj4rs::errors::Error),
+    /// The point with the given key could not be transformed (e.g. because
+    /// it is located outside of the domain of the projection).
+    Transform {
+        /// Key of the point.
+        key: usize,
+        /// Error reported by proj4rs.
+        error: proj4rs::errors::Error,
+    },
+}
+
+impl std::fmt::Display for ReprojectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReprojectionError::InvalidCrs(error) => write!(f, "invalid CRS definition: {error}"),
+            ReprojectionError::Transform { key, error } => {
+                write!(f, "point {key} could not be transformed: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReprojectionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReprojectionError::InvalidCrs(error) => return Some(error),
+            ReprojectionError::Transform { error, .. } => return Some(error),
+        }
+    }
+}
+
+/**
+A pair of a source and a target CRS, both given as
+[PROJ strings](https://proj.org/en/stable/usage/quickstart.html) (which can be
+looked up for EPSG codes on e.g. <https://epsg.io>).
+
+Coordinates of geographic CRSs (`+proj=longlat`) are given in degrees, with the
+longitude as x-coordinate and the latitude as y-coordinate. See the
+[module documentation](self) for an example.
+ */
+#[derive(Debug, Clone)]
+pub struct Reprojection {
+    source: Proj,
+    target: Proj,
+}
+
+impl Reprojection {
+    /**
+    Creates a [`Reprojection`] from the PROJ strings of the `source` CRS (the
+    CRS of the input points) and the planar `target` CRS in which the hull is
+    calculated.
+     */
+    pub fn new(source: &str, target: &str) -> Result<Self, ReprojectionError> {
+        let source = Proj::from_proj_string(source).map_err(ReprojectionError::InvalidCrs)?;
+        let target = Proj::from_proj_string(target).map_err(ReprojectionError::InvalidCrs)?;
+        return Ok(Self { source, target });
+    }
+
+    /// Transforms `point` from the source into the target CRS.
+    pub fn forward(&self, point: [f64; 2]) -> Result<[f64; 2], proj4rs::errors::Error> {
+        return transform(&self.source, &self.target, point);
+    }
+
+    /// Transforms `point` from the target back into the source CRS.
+    pub fn inverse(&self, point: [f64; 2]) -> Result<[f64; 2], proj4rs::errors::Error> {
+        return transform(&self.target, &self.source, point);
+    }
+
+    /**
+    Reprojects the points of `collection` into the target CRS and calculates
+    their hull there. The keys of the hull refer to the points of
+    `collection`, while its corner points are given in the target CRS. Nonreal
+    points are skipped, as they are not part of the hull anyway.
+     */
+    pub fn convex_hull<T: ConvexHull + ?Sized>(
+        &self,
+        collection: &T,
+    ) -> Result<Hull, ReprojectionError> {
+        let mut keys: Vec<usize> = Vec::new();
+        let mut points: Vec<[f64; 2]> = Vec::new();
+        for (key, point) in collection.convex_hull_iter() {
+            if !point[0].is_finite() || !point[1].is_finite() {
+                continue;
+            }
+            let point = self
+                .forward(point)
+                .map_err(|error| ReprojectionError::Transform { key, error })?;
+            keys.push(key);
+            points.push(point);
+        }
+        return Ok(Hull::new(&points).map_keys(|position| keys[position]));
+    }
+
+    /**
+    Exports the corners of `hull` (in the target CRS) back into the source
+    CRS. The corners keep their keys and their counter-clockwise order.
+     */
+    pub fn export(&self, hull: &Hull) -> Result<Vec<(usize, [f64; 2])>, ReprojectionError> {
+        return hull
+            .iter()
+            .map(|(key, point)| {
+                let point = self
+                    .inverse(point)
+                    .map_err(|error| ReprojectionError::Transform { key, error })?;
+                return Ok((key, point));
+            })
+            .collect();
+    }
+}
+
+/// Transforms `point` from `source` to `target`, converting geographic
+/// coordinates from and to degrees.
+fn transform(
+    source: &Proj,
+    target: &Proj,
+    point: [f64; 2],
+) -> Result<[f64; 2], proj4rs::errors::Error> {
+    let [mut x, mut y] = point;
+    if source.is_latlong() {
+        x = x.to_radians();
+        y = y.to_radians();
+    }
+    let (mut x, mut y) = transform_xy(source, target, x, y)?;
+    if target.is_latlong() {
+        x = x.to_degrees();
+        y = y.to_degrees();
+    }
+    return Ok([x, y]);
+}
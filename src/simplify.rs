@@ -0,0 +1,142 @@
+//! Outer simplification of a hull, see [`Hull::simplify`].
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use ordered_float::OrderedFloat;
+
+use crate::Hull;
+use crate::geometry::{cross, difference};
+use crate::offset::renumber;
+
+/**
+Replaces the edge from `a` to `b` by extending the edges `before -> a` and
+`b -> after` until they meet. Returns the meeting point and the area added
+this way, or `None` if the extended edges diverge.
+ */
+fn collapse(
+    before: [f64; 2],
+    a: [f64; 2],
+    b: [f64; 2],
+    after: [f64; 2],
+) -> Option<([f64; 2], f64)> {
+    let (incoming, outgoing) = (difference(a, before), difference(after, b));
+    let det = cross(incoming, outgoing);
+    if det <= 0.0 {
+        return None;
+    }
+    let s = cross(difference(b, a), outgoing) / det;
+    let point = [a[0] + s * incoming[0], a[1] + s * incoming[1]];
+    let area = 0.5 * cross(difference(point, a), difference(b, a));
+    if !point[0].is_finite() || !point[1].is_finite() || !area.is_finite() {
+        return None;
+    }
+    return Some((point, area.max(0.0)));
+}
+
+impl Hull {
+    /**
+    Simplifies the hull to a convex polygon with at most `max_corners` corners
+    which contains the hull, e.g. to send a coarse collision shape over the
+    network. Returns `None` if this is impossible, i.e. if `max_corners` is
+    smaller than three and smaller than the number of hull corners.
+
+    The polygon is obtained by greedily removing edges: An edge is removed by
+    extending its two neighboring edges until they meet, and the edge which
+    adds the smallest area is removed first. With a priority queue, this takes
+    O(h log h) time. If the hull is a parallelogram (where no edge can be
+    removed this way) and a triangle is requested, the triangle with twice the
+    area of the parallelogram and two sides flush with it is returned.
+
+    Since most corners of the result are new points, the keys of the result
+    are the positions of its corners, starting at zero.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    // A square with a cut-off corner
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 1.9], [1.9, 2.0], [0.0, 2.0]]);
+    let simplified = hull.simplify(4).unwrap();
+    assert_eq!(simplified.points(), &[[2.0, 2.0], [0.0, 2.0], [0.0, 0.0], [2.0, 0.0]]);
+    assert_eq!(simplified.keys(), &[0, 1, 2, 3]);
+    ```
+     */
+    pub fn simplify(&self, max_corners: usize) -> Option<Hull> {
+        let n = self.len();
+        if n <= max_corners {
+            return Some(renumber(self.points().to_vec()));
+        }
+        if max_corners < 3 {
+            return None;
+        }
+
+        // Doubly linked list of the remaining corners. Edge i starts at
+        // corner i.
+        let mut points = self.points().to_vec();
+        let mut previous: Vec<usize> = (0..n).map(|i| (i + n - 1) % n).collect();
+        let mut next: Vec<usize> = (0..n).map(|i| (i + 1) % n).collect();
+        let mut version = vec![0usize; n];
+        let mut removed = vec![false; n];
+        let candidate = |points: &[[f64; 2]], previous: &[usize], next: &[usize], i: usize| {
+            let j = next[i];
+            return collapse(points[previous[i]], points[i], points[j], points[next[j]]);
+        };
+
+        let mut queue = BinaryHeap::new();
+        for i in 0..n {
+            if let Some((_, area)) = candidate(&points, &previous, &next, i) {
+                queue.push(Reverse((OrderedFloat(area), i, 0usize)));
+            }
+        }
+
+        let mut remaining = n;
+        while remaining > max_corners {
+            let Some(Reverse((_, i, event_version))) = queue.pop() else {
+                break;
+            };
+            if removed[i] || event_version != version[i] {
+                continue;
+            }
+            let Some((point, _)) = candidate(&points, &previous, &next, i) else {
+                continue;
+            };
+
+            // Corner i moves to the meeting point and its successor vanishes
+            let j = next[i];
+            points[i] = point;
+            removed[j] = true;
+            next[i] = next[j];
+            previous[next[j]] = i;
+            remaining -= 1;
+
+            // Only the edges adjacent to the moved corner change their cost
+            for k in [previous[i], i] {
+                version[k] += 1;
+                if let Some((_, area)) = candidate(&points, &previous, &next, k) {
+                    queue.push(Reverse((OrderedFloat(area), k, version[k])));
+                }
+            }
+        }
+
+        let mut start = (0..n).find(|i| !removed[*i])?;
+        let mut corners = Vec::with_capacity(remaining);
+        for _ in 0..remaining {
+            corners.push(points[start]);
+            start = next[start];
+        }
+
+        // Only a parallelogram is left: Extend two adjacent sides to twice
+        // their length.
+        if corners.len() > max_corners {
+            let [a, b, _, d] = <[[f64; 2]; 4]>::try_from(corners.as_slice()).ok()?;
+            corners = vec![
+                a,
+                [2.0 * b[0] - a[0], 2.0 * b[1] - a[1]],
+                [2.0 * d[0] - a[0], 2.0 * d[1] - a[1]],
+            ];
+        }
+        return Some(renumber(corners));
+    }
+}
@@ -0,0 +1,50 @@
+//! This module contains the [`ConvexHullStats`] returned by
+//! [`ConvexHull::convex_hull_with_stats`](crate::ConvexHull::convex_hull_with_stats).
+
+use std::time::Duration;
+
+/**
+Durations of the individual phases of the divide-and-conquer algorithm behind
+[`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull).
+
+The phases are:
+1. `extremes`: One pass over all points to find the extremum points which
+   define the four quadrants.
+2. `preparation`: Collecting the real points for the parallel candidate
+   filtering. This phase only exists if the `rayon` feature is enabled and is
+   zero otherwise.
+3. `quadrants`: Construction of the partial hull of each quadrant (in
+   counter-clockwise order, starting with the quadrant of the points with the
+   largest x- and y-values). If the `rayon` feature is enabled, the quadrants
+   are processed concurrently, so their durations overlap and can add up to
+   more than the wall-clock time of this phase.
+4. `merge`: Combining the partial hulls into the final hull.
+
+`total` is the wall-clock time of the whole computation.
+
+# Examples
+
+```
+use planar_convex_hull::ConvexHull;
+
+let slice = &[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.2, 0.2]];
+let (hull, stats) = slice.convex_hull_with_stats();
+assert_eq!(hull.count(), 3);
+assert!(stats.extremes <= stats.total);
+assert!(stats.merge <= stats.total);
+```
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConvexHullStats {
+    /// Duration of the search for the extremum points.
+    pub extremes: Duration,
+    /// Duration of collecting the points for the parallel candidate filtering
+    /// (only with the `rayon` feature enabled).
+    pub preparation: Duration,
+    /// Durations of the partial hull construction of the four quadrants.
+    pub quadrants: [Duration; 4],
+    /// Duration of combining the partial hulls.
+    pub merge: Duration,
+    /// Wall-clock time of the whole computation.
+    pub total: Duration,
+}
@@ -0,0 +1,145 @@
+//! This module contains the [`SupportTable`] for answering many support
+//! queries on the same hull.
+
+use std::f64::consts::TAU;
+
+use crate::Hull;
+
+/// Maps an angle in radians to the range [0, 2π).
+fn normalize_angle(angle: f64) -> f64 {
+    let angle = angle.rem_euclid(TAU);
+    // rem_euclid can round up to exactly TAU for tiny negative angles
+    if angle >= TAU {
+        return 0.0;
+    }
+    return angle;
+}
+
+/**
+Precomputed table for repeated support queries on a [`Hull`].
+
+The support point of a convex polygon in a direction `d` is the corner which
+maximizes the dot product with `d`. Corner `i` is the support point for all
+directions between the outward normals of its two adjacent edges. The table
+stores the edge normal angles in ascending order together with a bucket index
+over the full circle, so a query consists of a bucket lookup followed by a
+short forward scan. After the O(h) setup, many queries (e.g. 360 rays of a
+radar sweep) therefore take constant time on average, instead of a binary
+search each.
+
+# Examples
+
+```
+use planar_convex_hull::{Hull, support::SupportTable};
+
+let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 1.0], [0.0, 1.0], [1.0, 0.5]]);
+let table = SupportTable::new(&hull);
+
+assert_eq!(table.support([1.0, 1.0]), Some((2, [2.0, 1.0])));
+assert_eq!(table.support([-1.0, -0.1]), Some((0, [0.0, 0.0])));
+
+// 360 rays
+for degree in 0..360 {
+    let angle = (degree as f64).to_radians();
+    let (_, point) = table.support_angle(angle).unwrap();
+    assert!(hull.points().iter().all(|other| {
+        other[0] * angle.cos() + other[1] * angle.sin()
+            <= point[0] * angle.cos() + point[1] * angle.sin() + 1e-12
+    }));
+}
+```
+ */
+#[derive(Debug, Clone)]
+pub struct SupportTable {
+    /// Hull corners, rotated so that the edge normal angles are ascending.
+    /// Edge `i` runs from corner `i` to corner `i + 1`.
+    corners: Vec<(usize, [f64; 2])>,
+    /// Outward normal angle of each edge in [0, 2π), ascending.
+    angles: Vec<f64>,
+    /// For each bucket, the index of the first edge whose normal angle is not
+    /// smaller than the start angle of the bucket.
+    buckets: Vec<usize>,
+}
+
+impl SupportTable {
+    /// Precomputes the support table for `hull` in O(h).
+    pub fn new(hull: &Hull) -> Self {
+        let mut corners: Vec<(usize, [f64; 2])> = hull.iter().collect();
+        let n = corners.len();
+        if n < 2 {
+            return Self {
+                corners,
+                angles: Vec::new(),
+                buckets: Vec::new(),
+            };
+        }
+
+        let normal_angle = |corners: &[(usize, [f64; 2])], i: usize| {
+            let a = corners[i].1;
+            let b = corners[(i + 1) % n].1;
+            // Outward normal of a counter-clockwise edge
+            return normalize_angle((-(b[0] - a[0])).atan2(b[1] - a[1]));
+        };
+
+        // Rotate the corners so that the edge with the smallest normal angle
+        // comes first. For a convex polygon, the angles are then ascending.
+        let first = (0..n)
+            .min_by(|i, j| normal_angle(&corners, *i).total_cmp(&normal_angle(&corners, *j)))
+            .unwrap_or(0);
+        corners.rotate_left(first);
+        let angles: Vec<f64> = (0..n).map(|i| normal_angle(&corners, i)).collect();
+
+        let bucket_count = 2 * n;
+        let mut buckets = Vec::with_capacity(bucket_count);
+        let mut edge = 0;
+        for bucket in 0..bucket_count {
+            let start = bucket as f64 * TAU / bucket_count as f64;
+            while edge < n && angles[edge] < start {
+                edge += 1;
+            }
+            buckets.push(edge);
+        }
+
+        return Self {
+            corners,
+            angles,
+            buckets,
+        };
+    }
+
+    /**
+    Returns the key and the position of the hull corner which is farthest in
+    the given `direction` (i.e. which maximizes the dot product with it). If
+    an edge is perpendicular to `direction`, either of its corners is
+    returned. Returns `None` for an empty hull.
+     */
+    pub fn support(&self, direction: [f64; 2]) -> Option<(usize, [f64; 2])> {
+        return self.support_angle(direction[1].atan2(direction[0]));
+    }
+
+    /**
+    Like [`SupportTable::support`], but with the direction given as an angle
+    in radians, measured counter-clockwise from the positive x-axis.
+     */
+    pub fn support_angle(&self, angle: f64) -> Option<(usize, [f64; 2])> {
+        let n = self.angles.len();
+        if n == 0 {
+            return self.corners.first().copied();
+        }
+        let angle = normalize_angle(angle);
+        let bucket =
+            ((angle / TAU * self.buckets.len() as f64) as usize).min(self.buckets.len() - 1);
+
+        // The support corner is the start of the first edge whose normal
+        // angle is not smaller than the query angle.
+        let mut edge = self.buckets[bucket];
+        // Guard against rounding errors in the bucket index
+        while edge > 0 && self.angles[edge - 1] >= angle {
+            edge -= 1;
+        }
+        while edge < n && self.angles[edge] < angle {
+            edge += 1;
+        }
+        return Some(self.corners[edge % n]);
+    }
+}
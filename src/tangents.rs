@@ -0,0 +1,133 @@
+//! This module contains the [`CommonTangents`] type, which is returned by
+//! [`Hull::common_tangents`].
+
+use std::cmp::Ordering;
+
+use crate::Hull;
+use crate::hull::PointLocation;
+use crate::predicates::orientation;
+
+/**
+The common tangents of two hulls, see [`Hull::common_tangents`]. Each tangent
+is given by the corners at which it touches the hulls, as
+`[corner of the first hull, corner of the second hull]` with their keys. All
+sides refer to the line directed from the corner of the first hull to the
+corner of the second hull.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[allow(clippy::type_complexity)]
+pub struct CommonTangents {
+    /// The outer tangents, which have both hulls on the same side: Both hulls
+    /// are left of the first one and right of the second one.
+    pub outer: [[(usize, [f64; 2]); 2]; 2],
+    /// The inner tangents, which separate the hulls: The first hull is left of
+    /// the first one and right of the second one, the second hull vice versa.
+    /// They only exist if the hulls are disjoint.
+    pub inner: Option<[[(usize, [f64; 2]); 2]; 2]>,
+}
+
+/// Returns whether the neighbors of the corner `index` of the hull `points`
+/// are located left of and right of the line from this corner to `b` (or on
+/// it).
+fn side(points: &[[f64; 2]], index: usize, b: [f64; 2]) -> [bool; 2] {
+    let n = points.len();
+    let a = points[index];
+    let neighbors = [points[(index + n - 1) % n], points[(index + 1) % n]];
+    let left = neighbors
+        .iter()
+        .all(|neighbor| orientation(a, b, *neighbor) != Ordering::Less);
+    let right = neighbors
+        .iter()
+        .all(|neighbor| orientation(a, b, *neighbor) != Ordering::Greater);
+    return [left, right];
+}
+
+impl Hull {
+    /**
+    Calculates the common tangents of the hull and `other`, e.g. the edges of
+    a visibility graph among convex obstacles. The two outer tangents touch
+    both hulls with both hulls on the same side. If the hulls are disjoint,
+    there are also two inner tangents which separate them (see
+    [`CommonTangents`]).
+
+    Returns `None` if one of the hulls is empty or contained in the other one,
+    since then there are no outer tangents. If a tangent is collinear with an
+    edge of a hull, the corner of this edge closer to the other hull is
+    returned.
+
+    For each corner of the hull, the tangents to `other` are found in O(log m)
+    time (see [`Hull::tangents_from`]) and checked against the neighbors of
+    the corner, so the common tangents are calculated in O(n log m) time.
+
+    # Examples
+
+    ```
+    use planar_convex_hull::Hull;
+
+    let left = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+    let right = Hull::new(&[[3.0, -1.0], [5.0, -1.0], [5.0, 2.0], [3.0, 2.0]]);
+    let tangents = left.common_tangents(&right).unwrap();
+    let points = |tangent: [(usize, [f64; 2]); 2]| [tangent[0].1, tangent[1].1];
+
+    // Both hulls are left of the bottom tangent and right of the top tangent
+    assert_eq!(points(tangents.outer[0]), [[0.0, 0.0], [3.0, -1.0]]);
+    assert_eq!(points(tangents.outer[1]), [[0.0, 1.0], [3.0, 2.0]]);
+
+    // The inner tangents cross between the hulls
+    let inner = tangents.inner.unwrap();
+    assert_eq!(points(inner[0]), [[1.0, 0.0], [3.0, 2.0]]);
+    assert_eq!(points(inner[1]), [[1.0, 1.0], [3.0, -1.0]]);
+    ```
+     */
+    #[allow(clippy::type_complexity)]
+    pub fn common_tangents(&self, other: &Hull) -> Option<CommonTangents> {
+        let points = self.points();
+
+        // Tangents as [outer left, outer right, inner left, inner right],
+        // where left and right refer to the location of the first hull
+        let mut tangents: [Option<[(usize, [f64; 2]); 2]>; 4] = [None; 4];
+        let squared_length = |tangent: &[(usize, [f64; 2]); 2]| {
+            let [(_, a), (_, b)] = *tangent;
+            return (b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2);
+        };
+        for (index, corner) in self.iter().enumerate() {
+            if other.locate(corner.1) != PointLocation::Outside {
+                continue;
+            }
+            let Some([left, right]) = other.tangents_from(corner.1) else {
+                continue;
+            };
+            // The other hull is right of the line towards its left tangent
+            // and left of the line towards its right tangent.
+            let [left_of_left, right_of_left] = side(points, index, left.1);
+            let [left_of_right, right_of_right] = side(points, index, right.1);
+            let candidates = [
+                (right_of_left, 1, left),
+                (left_of_left, 2, left),
+                (left_of_right, 0, right),
+                (right_of_right, 3, right),
+            ];
+            for (found, slot, touching) in candidates {
+                let tangent = [corner, touching];
+                if found
+                    && tangents[slot]
+                        .is_none_or(|best| squared_length(&tangent) < squared_length(&best))
+                {
+                    tangents[slot] = Some(tangent);
+                }
+            }
+        }
+
+        let [outer_left, outer_right, inner_left, inner_right] = tangents;
+        let inner = match (inner_left, inner_right) {
+            (Some(left), Some(right)) if !self.overlaps(other) => Some([left, right]),
+            _ => None,
+        };
+        return Some(CommonTangents {
+            outer: [outer_left?, outer_right?],
+            inner,
+        });
+    }
+}
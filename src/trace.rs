@@ -0,0 +1,233 @@
+//! This module contains the [`Trace`] returned by
+//! [`ConvexHull::convex_hull_with_trace`](crate::ConvexHull::convex_hull_with_trace).
+
+/**
+A single step of the divide-and-conquer algorithm behind
+[`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull).
+
+The quadrants are numbered from 0 to 3 in counter-clockwise order, starting
+with the quadrant of the points with the largest x- and y-values (see
+[`ConvexHullStats`](crate::ConvexHullStats)). Each quadrant holds a partial
+hull, a chain of points between two extremum points.
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum TraceEvent {
+    /// An extremum point was chosen as an end point of the partial hull of
+    /// the quadrant.
+    Extreme {
+        /// Quadrant of the partial hull.
+        quadrant: usize,
+        /// Key of the point.
+        key: usize,
+        /// Coordinates of the point.
+        point: [f64; 2],
+    },
+    /// A point located on the boundary to the next quadrant was added to the
+    /// partial hull without an orientation test.
+    Boundary {
+        /// Quadrant of the partial hull.
+        quadrant: usize,
+        /// Key of the point.
+        key: usize,
+        /// Coordinates of the point.
+        point: [f64; 2],
+    },
+    /// A candidate was located on the outer side of the edge between the two
+    /// bracketing points of the partial hull and was added to it.
+    Accepted {
+        /// Quadrant of the partial hull.
+        quadrant: usize,
+        /// Key of the candidate.
+        key: usize,
+        /// Coordinates of the candidate.
+        point: [f64; 2],
+        /// Keys of the bracketing points.
+        bracket: [usize; 2],
+    },
+    /// A candidate was located on the inner side of the edge between the two
+    /// bracketing points of the partial hull (or on the edge) and was
+    /// discarded.
+    Rejected {
+        /// Quadrant of the partial hull.
+        quadrant: usize,
+        /// Key of the candidate.
+        key: usize,
+        /// Coordinates of the candidate.
+        point: [f64; 2],
+        /// Keys of the bracketing points.
+        bracket: [usize; 2],
+    },
+    /// A point was removed from the partial hull, since the previously added
+    /// point made it non-convex.
+    Removed {
+        /// Quadrant of the partial hull.
+        quadrant: usize,
+        /// Key of the point.
+        key: usize,
+        /// Coordinates of the point.
+        point: [f64; 2],
+    },
+}
+
+impl std::fmt::Display for TraceEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TraceEvent::Extreme {
+                quadrant,
+                key,
+                point,
+            } => write!(f, "q{}: extreme {key} {point:?}", quadrant + 1),
+            TraceEvent::Boundary {
+                quadrant,
+                key,
+                point,
+            } => write!(f, "q{}: boundary {key} {point:?}", quadrant + 1),
+            TraceEvent::Accepted {
+                quadrant,
+                key,
+                point,
+                bracket,
+            } => write!(
+                f,
+                "q{}: accepted {key} {point:?} between {} and {}",
+                quadrant + 1,
+                bracket[0],
+                bracket[1]
+            ),
+            TraceEvent::Rejected {
+                quadrant,
+                key,
+                point,
+                bracket,
+            } => write!(
+                f,
+                "q{}: rejected {key} {point:?} between {} and {}",
+                quadrant + 1,
+                bracket[0],
+                bracket[1]
+            ),
+            TraceEvent::Removed {
+                quadrant,
+                key,
+                point,
+            } => write!(f, "q{}: removed {key} {point:?}", quadrant + 1),
+        }
+    }
+}
+
+/**
+Sequence of [`TraceEvent`]s recorded while calculating a convex hull, e.g. for
+teaching the algorithm step by step or for attaching it to a bug report (its
+[`Display`](std::fmt::Display) implementation prints one event per line).
+
+The events are ordered by quadrant: First the extremum points of all
+quadrants, followed by the events of the first quadrant, the second quadrant
+and so on. [`Trace::replay`] reconstructs the partial hulls after any number
+of events.
+
+# Examples
+
+```
+use planar_convex_hull::ConvexHull;
+use planar_convex_hull::trace::TraceEvent;
+
+let slice = &[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.2, 0.2], [0.8, 0.8]];
+let (hull, trace) = slice.convex_hull_with_trace();
+assert_eq!(hull.count(), 4);
+
+assert!(trace.events().contains(&TraceEvent::Rejected {
+    quadrant: 0,
+    key: 3,
+    point: [0.2, 0.2],
+    bracket: [1, 2],
+}));
+assert!(trace.events().contains(&TraceEvent::Accepted {
+    quadrant: 0,
+    key: 4,
+    point: [0.8, 0.8],
+    bracket: [1, 2],
+}));
+
+// After all events, the partial hull of the first quadrant is complete
+let quadrants = trace.replay(trace.events().len());
+assert_eq!(quadrants[0], vec![(1, [1.0, 0.0]), (4, [0.8, 0.8]), (2, [0.0, 1.0])]);
+
+// The display lists one event per line
+assert_eq!(trace.to_string().lines().count(), trace.events().len());
+```
+ */
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct Trace {
+    events: Vec<TraceEvent>,
+}
+
+impl Trace {
+    /// Creates a trace from recorded events.
+    pub(crate) fn from_events(events: Vec<TraceEvent>) -> Self {
+        return Self { events };
+    }
+
+    /// Returns the recorded events in chronological order.
+    pub fn events(&self) -> &[TraceEvent] {
+        return &self.events;
+    }
+
+    /**
+    Returns the partial hulls of the four quadrants after the first `steps`
+    events. The points of each partial hull are in counter-clockwise order.
+     */
+    pub fn replay(&self, steps: usize) -> [Vec<(usize, [f64; 2])>; 4] {
+        let mut quadrants: [Vec<(usize, [f64; 2])>; 4] = Default::default();
+        for event in self.events.iter().take(steps) {
+            match *event {
+                TraceEvent::Extreme {
+                    quadrant,
+                    key,
+                    point,
+                }
+                | TraceEvent::Boundary {
+                    quadrant,
+                    key,
+                    point,
+                }
+                | TraceEvent::Accepted {
+                    quadrant,
+                    key,
+                    point,
+                    ..
+                } => quadrants[quadrant].push((key, point)),
+                TraceEvent::Removed { quadrant, key, .. } => {
+                    quadrants[quadrant].retain(|item| item.0 != key)
+                }
+                TraceEvent::Rejected { .. } => (),
+            }
+        }
+
+        // The partial hulls are monotone in x. Points with identical
+        // x-values only occur on the vertical boundaries of the second and
+        // fourth quadrant.
+        for (quadrant, points) in quadrants.iter_mut().enumerate() {
+            let direction = if quadrant < 2 { -1.0 } else { 1.0 };
+            let vertical = if quadrant == 1 { -1.0 } else { 1.0 };
+            points.sort_by(|(_, a), (_, b)| {
+                return (direction * a[0])
+                    .total_cmp(&(direction * b[0]))
+                    .then((vertical * a[1]).total_cmp(&(vertical * b[1])));
+            });
+        }
+        return quadrants;
+    }
+}
+
+impl std::fmt::Display for Trace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for event in self.events.iter() {
+            writeln!(f, "{event}")?;
+        }
+        return Ok(());
+    }
+}
@@ -0,0 +1,151 @@
+//! This module contains a harness for validating [`ConvexHull::convex_hull`]
+//! against other convex hull implementations, e.g. when hunting for
+//! regressions. It is only available with the `cross-validation` feature flag
+//! enabled, which pulls in the [geo](https://docs.rs/geo) crate.
+//!
+//! The hulls are compared by their corners: Both hulls are brought into the
+//! form returned by [`ConvexHull::convex_hull`] (duplicate and collinear
+//! corners removed, starting with the corner with the largest x-value and
+//! counter-clockwise order), so different starting points and collinear-point
+//! policies do not count as divergences. Keys are not compared, since the
+//! other implementations only return coordinates.
+
+use geo::{Coord, LineString};
+
+use crate::ConvexHull;
+use crate::monotone_chain::monotone_chain;
+use crate::polygon::canonicalize;
+
+/// Other convex hull implementations to compare against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Reference {
+    /// The QuickHull implementation of geo (`geo::convex_hull::quick_hull`).
+    GeoQuickHull,
+    /// The Graham scan implementation of geo (`geo::convex_hull::graham_hull`).
+    GeoGrahamHull,
+    /// Andrew's monotone chain algorithm with exact predicates, which this
+    /// crate uses internally as a fallback.
+    MonotoneChain,
+}
+
+impl Reference {
+    /// All available reference implementations.
+    pub const ALL: [Reference; 3] = [
+        Reference::GeoQuickHull,
+        Reference::GeoGrahamHull,
+        Reference::MonotoneChain,
+    ];
+}
+
+/**
+The first difference between the hull of [`ConvexHull::convex_hull`] and the
+hull of a [`Reference`] implementation.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Divergence {
+    /// Implementation which produced the expected hull.
+    pub reference: Reference,
+    /// Position of the first differing corner in the (normalized) hulls.
+    pub position: usize,
+    /// Corner of the reference hull at `position`, or `None` if the
+    /// reference hull has fewer corners.
+    pub expected: Option<[f64; 2]>,
+    /// Corner of the tested hull at `position`, or `None` if the tested hull
+    /// has fewer corners.
+    pub found: Option<[f64; 2]>,
+}
+
+impl std::fmt::Display for Divergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let corner = |corner: Option<[f64; 2]>| match corner {
+            Some(corner) => format!("{corner:?}"),
+            None => "no corner".to_string(),
+        };
+        write!(
+            f,
+            "hull diverges from {:?} at corner {}: expected {}, found {}",
+            self.reference,
+            self.position,
+            corner(self.expected),
+            corner(self.found)
+        )
+    }
+}
+
+impl std::error::Error for Divergence {}
+
+/// Calculates the hull of `points` with the `reference` implementation.
+fn reference_hull(points: &[[f64; 2]], reference: Reference) -> Vec<[f64; 2]> {
+    let ring = |ring: LineString<f64>| -> Vec<[f64; 2]> {
+        return ring.0.into_iter().map(|coord| [coord.x, coord.y]).collect();
+    };
+    let mut coords: Vec<Coord<f64>> = points.iter().map(|p| Coord { x: p[0], y: p[1] }).collect();
+    match reference {
+        Reference::GeoQuickHull => return ring(geo::convex_hull::quick_hull(&mut coords)),
+        Reference::GeoGrahamHull => {
+            return ring(geo::convex_hull::graham_hull(&mut coords, false));
+        }
+        Reference::MonotoneChain => {
+            return monotone_chain(points.iter().copied().enumerate().collect())
+                .into_iter()
+                .map(|(_, point)| point)
+                .collect();
+        }
+    }
+}
+
+/**
+Compares the hull returned by [`ConvexHull::convex_hull`] for `collection`
+with the hull calculated by `reference` and returns the first divergence.
+Nonreal points are removed before the points are handed to `reference`.
+
+# Examples
+
+```
+use planar_convex_hull::validation::{Reference, compare};
+
+let points = [[0.0, 0.0], [1.0, 0.0], [0.5, 0.0], [1.0, 1.0], [0.0, 1.0], [0.5, 0.5]];
+for reference in Reference::ALL {
+    assert_eq!(compare(&points, reference), Ok(()));
+}
+```
+ */
+pub fn compare<T: ConvexHull + ?Sized>(
+    collection: &T,
+    reference: Reference,
+) -> Result<(), Divergence> {
+    let points: Vec<[f64; 2]> = collection
+        .convex_hull_iter()
+        .map(|(_, point)| point)
+        .filter(|point| point[0].is_finite() && point[1].is_finite())
+        .collect();
+    let expected = canonicalize(reference_hull(&points, reference));
+    let found = canonicalize(collection.convex_hull().map(|(_, point)| point).collect());
+
+    for position in 0..expected.len().max(found.len()) {
+        let (expected, found) = (
+            expected.get(position).copied(),
+            found.get(position).copied(),
+        );
+        if expected != found {
+            return Err(Divergence {
+                reference,
+                position,
+                expected,
+                found,
+            });
+        }
+    }
+    return Ok(());
+}
+
+/**
+Compares [`ConvexHull::convex_hull`] for `collection` with all
+[`Reference`] implementations and returns all divergences.
+ */
+pub fn compare_all<T: ConvexHull + ?Sized>(collection: &T) -> Vec<Divergence> {
+    return Reference::ALL
+        .into_iter()
+        .filter_map(|reference| compare(collection, reference).err())
+        .collect();
+}
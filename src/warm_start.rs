@@ -0,0 +1,51 @@
+//! Warm start from a previous hull, see
+//! [`ConvexHull::convex_hull_warm_start`](crate::ConvexHull::convex_hull_warm_start).
+
+use std::collections::HashMap;
+
+use crate::monotone_chain::monotone_chain;
+use crate::predicates::strictly_inside_convex;
+use crate::{ConvexHull, ConvexHullIter, Hull};
+
+pub(crate) fn convex_hull_warm_start<T: ConvexHull + ?Sized>(
+    this: &T,
+    hint: &Hull,
+) -> ConvexHullIter {
+    // Current positions of the points which formed the previous hull
+    let mut previous: HashMap<usize, Option<[f64; 2]>> =
+        hint.keys().iter().map(|key| (*key, None)).collect();
+    for (key, point) in this.convex_hull_iter() {
+        if !point[0].is_finite() || !point[1].is_finite() {
+            continue;
+        }
+        if let Some(position) = previous.get_mut(&key) {
+            *position = Some(point);
+        }
+    }
+    let candidates: Vec<(usize, [f64; 2])> = hint
+        .keys()
+        .iter()
+        .filter_map(|key| Some((*key, previous.get(key).copied().flatten()?)))
+        .collect();
+    let polygon: Vec<[f64; 2]> = monotone_chain(candidates)
+        .into_iter()
+        .map(|(_, point)| point)
+        .collect();
+    if polygon.len() < 3 {
+        // Without an area, the hint cannot exclude any point
+        return ConvexHullIter::from_vec(monotone_chain(this.convex_hull_iter().collect()));
+    }
+
+    // Points strictly inside the hull of the moved previous corners cannot be
+    // corners of the new hull. Only the remaining points near the boundary
+    // are passed to the exact algorithm.
+    let outside: Vec<(usize, [f64; 2])> = this
+        .convex_hull_iter()
+        .filter(|(_, point)| {
+            return point[0].is_finite()
+                && point[1].is_finite()
+                && !strictly_inside_convex(&polygon, *point);
+        })
+        .collect();
+    return ConvexHullIter::from_vec(monotone_chain(outside));
+}
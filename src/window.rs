@@ -0,0 +1,184 @@
+//! This module contains the [`SlidingWindowHull`], the convex hull of the most
+//! recent points of a timestamped stream.
+
+use std::collections::VecDeque;
+
+use crate::Hull;
+use crate::online::OnlineHull;
+use crate::prefix::PrefixHulls;
+
+/**
+The convex hull of the points of a stream whose timestamps lie within a
+sliding window.
+
+Each point passed to [`SlidingWindowHull::push`] is given the next key
+(starting at zero), i.e. its index in the stream. A point with timestamp `t`
+belongs to the window until a timestamp `now` with `now - t >= window` is
+reached, i.e. the window covers the half-open interval `(now - window, now]`.
+
+The window is split into an older and a newer part (the classic queue made of
+two stacks): New points are inserted into an [`OnlineHull`], which takes
+O(log h) amortized time. Once the first of these points expires, the
+remaining ones become the older part, whose hulls of all suffixes are stored
+as the [`PrefixHulls`] of the reversed points in O(n log n) time. Since each
+point moves to the older part only once, pushing a point takes O(log n)
+amortized time, and [`SlidingWindowHull::hull`] merges the hulls of both
+parts without recomputing them from scratch.
+
+Collinear points on the hull edges and nonreal points are discarded. Of
+multiple identical points in the window, any one may be returned.
+
+# Examples
+
+```
+use planar_convex_hull::window::SlidingWindowHull;
+
+let mut window = SlidingWindowHull::new(10.0);
+window.push(0.0, [0.0, 0.0]);
+window.push(1.0, [4.0, 0.0]);
+window.push(5.0, [4.0, 4.0]);
+window.push(8.0, [1.0, 1.0]);
+assert_eq!(window.hull().keys(), &[2, 0, 1]);
+
+// The first point expires at time 10
+window.push(10.0, [0.0, 4.0]);
+assert_eq!(window.len(), 4);
+assert_eq!(window.hull().keys(), &[2, 4, 3, 1]);
+
+window.advance(15.0);
+assert_eq!(window.hull().keys(), &[3, 4]);
+```
+ */
+#[derive(Debug, Clone)]
+pub struct SlidingWindowHull {
+    window: f64,
+    /// Latest timestamp passed to the window.
+    now: f64,
+    /// Timestamp of each point in the window, ordered by their keys.
+    times: VecDeque<f64>,
+    /// Key of the oldest point in the window.
+    first: usize,
+    /// Key of the first point of the newer part.
+    split: usize,
+    /// Hulls of the suffixes of the older part, i.e. of the prefixes of its
+    /// reversed points (the point with key `split - 1` comes first).
+    older: PrefixHulls,
+    newer: OnlineHull,
+    /// Points of the newer part, ordered by their keys.
+    newer_points: Vec<[f64; 2]>,
+}
+
+impl SlidingWindowHull {
+    /**
+    Creates an empty [`SlidingWindowHull`] with the given `window` length.
+
+    # Panics
+
+    Panics if `window` is not positive (it may be infinite).
+     */
+    pub fn new(window: f64) -> Self {
+        assert!(window > 0.0, "the window length must be positive");
+        return Self {
+            window,
+            now: f64::NEG_INFINITY,
+            times: VecDeque::new(),
+            first: 0,
+            split: 0,
+            older: PrefixHulls::default(),
+            newer: OnlineHull::new(),
+            newer_points: Vec::new(),
+        };
+    }
+
+    /// Returns the length of the window.
+    pub fn window(&self) -> f64 {
+        return self.window;
+    }
+
+    /**
+    Advances the window to the timestamp `time`, removes the expired points
+    and adds `point`. Returns the key of the point, which is the number of
+    points pushed before.
+
+    # Panics
+
+    Panics if `time` is smaller than the timestamp of a previous call of
+    [`SlidingWindowHull::push`] or [`SlidingWindowHull::advance`], or if it
+    is NaN.
+     */
+    pub fn push(&mut self, time: f64, point: [f64; 2]) -> usize {
+        self.advance(time);
+        let key = self.split + self.newer_points.len();
+        self.times.push_back(time);
+        self.newer_points.push(point);
+        self.newer.insert(key, point);
+        return key;
+    }
+
+    /**
+    Advances the window to the timestamp `time` and removes the expired
+    points.
+
+    # Panics
+
+    Panics if `time` is smaller than the timestamp of a previous call of
+    [`SlidingWindowHull::push`] or [`SlidingWindowHull::advance`], or if it
+    is NaN.
+     */
+    pub fn advance(&mut self, time: f64) {
+        assert!(time >= self.now, "the timestamps must not decrease");
+        self.now = time;
+        while self
+            .times
+            .front()
+            .is_some_and(|oldest| time - oldest >= self.window)
+        {
+            self.times.pop_front();
+            self.first += 1;
+        }
+        if self.first > self.split {
+            self.rebuild();
+        }
+    }
+
+    /// Turns the remaining points of the newer part into the older part.
+    fn rebuild(&mut self) {
+        let expired = (self.first - self.split).min(self.newer_points.len());
+        let reversed: Vec<[f64; 2]> = self.newer_points[expired..].iter().rev().copied().collect();
+        self.older = PrefixHulls::new(&reversed);
+        self.split += self.newer_points.len();
+        self.newer = OnlineHull::new();
+        self.newer_points.clear();
+    }
+
+    /// Returns the number of points in the window.
+    pub fn len(&self) -> usize {
+        return self.split + self.newer_points.len() - self.first;
+    }
+
+    /// Returns `true` if the window contains no points.
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+
+    /// Returns the key of the oldest point in the window.
+    pub fn first_key(&self) -> usize {
+        return self.first;
+    }
+
+    /**
+    Returns the hull of the points in the window, following the conventions
+    of [`ConvexHull::convex_hull`](crate::ConvexHull::convex_hull): the
+    corners are in counter-clockwise order, starting with the point with the
+    largest x-value (and the largest y-value of those).
+     */
+    pub fn hull(&self) -> Hull {
+        let older = self.older.hull(self.split - self.first);
+        let older = Hull::from_vertices_unchecked(
+            older
+                .iter()
+                .map(|(position, point)| (self.split - 1 - position, point)),
+        );
+        return older.merge(&self.newer.hull());
+    }
+}
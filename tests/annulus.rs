@@ -0,0 +1,101 @@
+use planar_convex_hull::{annulus::min_width_annulus, generators};
+
+fn distance(a: [f64; 2], b: [f64; 2]) -> f64 {
+    return (b[0] - a[0]).hypot(b[1] - a[1]);
+}
+
+fn width(points: &[[f64; 2]], center: [f64; 2]) -> f64 {
+    let distances = points.iter().map(|point| distance(center, *point));
+    return distances.clone().fold(0.0, f64::max) - distances.fold(f64::INFINITY, f64::min);
+}
+
+fn circumcenter(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> Option<[f64; 2]> {
+    let (bx, by) = (b[0] - a[0], b[1] - a[1]);
+    let (cx, cy) = (c[0] - a[0], c[1] - a[1]);
+    let d = 2.0 * (bx * cy - by * cx);
+    if d.abs() < 1e-12 {
+        return None;
+    }
+    let (b2, c2) = (bx * bx + by * by, cx * cx + cy * cy);
+    return Some([
+        a[0] + (cy * b2 - by * c2) / d,
+        a[1] + (bx * c2 - cx * b2) / d,
+    ]);
+}
+
+/// Intersection of the bisectors of (a, b) and (c, d)
+fn bisector_intersection(a: [f64; 2], b: [f64; 2], c: [f64; 2], d: [f64; 2]) -> Option<[f64; 2]> {
+    // Points x with |x - a|² = |x - b|²: 2 (b - a) x = |b|² - |a|²
+    let row = |a: [f64; 2], b: [f64; 2]| {
+        return (
+            2.0 * (b[0] - a[0]),
+            2.0 * (b[1] - a[1]),
+            b[0] * b[0] + b[1] * b[1] - a[0] * a[0] - a[1] * a[1],
+        );
+    };
+    let (a1, b1, c1) = row(a, b);
+    let (a2, b2, c2) = row(c, d);
+    let det = a1 * b2 - a2 * b1;
+    if det.abs() < 1e-12 {
+        return None;
+    }
+    return Some([(c1 * b2 - c2 * b1) / det, (a1 * c2 - a2 * c1) / det]);
+}
+
+/// Minimum width over all candidate centers, without using Voronoi diagrams
+fn brute_force(points: &[[f64; 2]]) -> f64 {
+    let n = points.len();
+    let mut best = f64::INFINITY;
+    for i in 0..n {
+        for j in i + 1..n {
+            for k in j + 1..n {
+                if let Some(center) = circumcenter(points[i], points[j], points[k]) {
+                    best = best.min(width(points, center));
+                }
+            }
+            for k in 0..n {
+                for l in k + 1..n {
+                    if let Some(center) =
+                        bisector_intersection(points[i], points[j], points[k], points[l])
+                    {
+                        best = best.min(width(points, center));
+                    }
+                }
+            }
+        }
+    }
+    return best;
+}
+
+#[test]
+fn test_min_width_annulus() {
+    for seed in 0..20 {
+        let points = generators::uniform_in_disk(12, [2.0, -1.0], 3.0, seed);
+        let annulus = min_width_annulus(&points).unwrap();
+        for point in points.iter() {
+            let d = distance(annulus.center, *point);
+            assert!(d >= annulus.inner_radius - 1e-12 && d <= annulus.outer_radius + 1e-12);
+        }
+        assert!(
+            (annulus.width() - brute_force(&points)).abs() < 1e-9,
+            "seed {seed}"
+        );
+    }
+
+    // Noisy measurement of a circle
+    let mut points = generators::on_circle(500, [10.0, 20.0], 5.0, 3);
+    for (i, point) in points.iter_mut().enumerate() {
+        let scale = 1.0 + 1e-3 * ((i * 7919) % 13) as f64 / 13.0;
+        point[0] = 10.0 + (point[0] - 10.0) * scale;
+        point[1] = 20.0 + (point[1] - 20.0) * scale;
+    }
+    let annulus = min_width_annulus(&points).unwrap();
+    assert!(annulus.width() <= width(&points, [10.0, 20.0]) + 1e-12);
+    assert!(distance(annulus.center, [10.0, 20.0]) < 1e-2);
+
+    // Degenerate inputs
+    let collinear = generators::collinear(10, [0.0, 0.0], [1.0, 2.0], 3);
+    assert_eq!(min_width_annulus(&collinear), None);
+    let nonreal = vec![[0.0, 0.0], [f64::NAN, 1.0], [1.0, 0.0]];
+    assert_eq!(min_width_annulus(&nonreal), None);
+}
@@ -0,0 +1,47 @@
+use bevy_math::primitives::{BoxedPolygon, Polygon};
+use bevy_math::{DVec2, Vec2};
+use planar_convex_hull::{ConvexHull, Hull, Point2D};
+
+#[test]
+fn test_bevy_collections() {
+    let arrays = [[0.0, 0.0], [2.0, 0.0], [2.0, 1.0], [0.0, 1.0], [1.0, 0.5]];
+    let expected = Hull::new(&arrays);
+
+    let points: Vec<Vec2> = arrays
+        .iter()
+        .map(|p| Vec2::new(p[0] as f32, p[1] as f32))
+        .collect();
+    assert_eq!(Hull::from(points.convex_hull()), expected);
+    assert_eq!(Hull::new(points.as_slice()), expected);
+
+    let points: Vec<DVec2> = arrays.iter().map(|p| DVec2::from(*p)).collect();
+    assert_eq!(Hull::from(points.convex_hull()), expected);
+    assert_eq!(Hull::new(&points[..]), expected);
+
+    assert_eq!(Vec2::new(1.0, 2.0).to_array(), [1.0, 2.0]);
+    assert_eq!(Point2D::to_array(&DVec2::new(1.0, 2.0)), [1.0, 2.0]);
+}
+
+#[test]
+fn test_bevy_polygon() {
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 1.0], [0.0, 1.0]]);
+    let vertices = [
+        Vec2::new(2.0, 1.0),
+        Vec2::new(0.0, 1.0),
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+    ];
+    assert_eq!(hull.to_vec2(), vertices);
+    assert_eq!(hull.to_dvec2()[1], DVec2::new(0.0, 1.0));
+    assert_eq!(BoxedPolygon::from(&hull).vertices.as_ref(), &vertices);
+    assert_eq!(Polygon::<4>::from_iter(hull.to_vec2()).vertices, vertices);
+}
+
+#[test]
+fn test_reflect() {
+    use bevy_reflect::Reflect;
+
+    let hull = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]);
+    let reflected: &dyn Reflect = &hull;
+    assert!(reflected.reflect_partial_eq(&hull).unwrap_or(true));
+}
@@ -1,7 +1,4 @@
-use std::{
-    collections::{HashMap, HashSet},
-    f64::{INFINITY, NAN, NEG_INFINITY},
-};
+use std::collections::{HashMap, HashSet};
 
 use nalgebra::Point2;
 use ordered_float::OrderedFloat;
@@ -42,6 +39,31 @@ fn test_two_points() {
     }
 }
 
+#[test]
+fn test_size_hint() {
+    let slice = &[
+        [-3.0, -1.0],
+        [-2.0, 2.0],
+        [0.0, 0.0],
+        [1.0, 3.0],
+        [5.0, -1.0],
+        [6.0, 2.0],
+        [7.0, -4.0],
+        [8.0, -1.0],
+    ];
+    let mut hull = slice.convex_hull();
+    let (lower, upper) = hull.size_hint();
+    assert!(lower <= 6 && upper.unwrap() >= 6);
+    hull.next();
+    let (lower, upper) = hull.size_hint();
+    assert!(lower <= 5 && upper.unwrap() >= 5);
+    assert_eq!(hull.count(), 5);
+
+    // The exact hull knows its length
+    let hull = slice.convex_hull_exact();
+    assert_eq!(hull.size_hint(), (6, Some(6)));
+}
+
 #[test]
 fn test_three_points() {
     {
@@ -445,7 +467,7 @@ fn test_hashset() {
         let hashset: HashSet<MyPoint> = HashSet::from_iter(
             slice
                 .iter()
-                .map(|[x, y]| MyPoint([OrderedFloat(x.clone()), OrderedFloat(y.clone())])),
+                .map(|[x, y]| MyPoint([OrderedFloat(*x), OrderedFloat(*y)])),
         );
 
         let mut hull = hashset.convex_hull();
@@ -462,14 +484,14 @@ fn test_hashset() {
 #[test]
 fn test_nonreal_points() {
     let last_points = [
-        [INFINITY, -1.0],
-        [0.0, INFINITY],
-        [NAN, -1.0],
-        [0.0, NAN],
-        [INFINITY, NAN],
-        [NAN, INFINITY],
-        [INFINITY, NEG_INFINITY],
-        [NAN, NEG_INFINITY],
+        [f64::INFINITY, -1.0],
+        [0.0, f64::INFINITY],
+        [f64::NAN, -1.0],
+        [0.0, f64::NAN],
+        [f64::INFINITY, f64::NAN],
+        [f64::NAN, f64::INFINITY],
+        [f64::INFINITY, f64::NEG_INFINITY],
+        [f64::NAN, f64::NEG_INFINITY],
     ];
 
     for last_point in last_points {
@@ -531,6 +553,7 @@ fn test_issue_1() {
 }
 
 #[test]
+#[allow(clippy::approx_constant)]
 fn test_planar_geo_bug() {
     let points = &[
         [0.0, 0.0],
@@ -568,3 +591,907 @@ fn test_planar_geo_bug() {
     assert_eq!(hull.next(), Some((0, [0.0, 0.0])));
     assert_eq!(hull.next(), None);
 }
+
+#[test]
+fn test_edges() {
+    {
+        let slice: &[[f64; 2]] = &[];
+        assert_eq!(slice.convex_hull_edges(), vec![]);
+    }
+    {
+        let slice = &[[-3.0, -1.0], [-3.0, -1.0]];
+        assert_eq!(slice.convex_hull_edges(), vec![]);
+    }
+    {
+        let slice = &[[-3.0, -1.0], [-2.0, 2.0]];
+        assert_eq!(slice.convex_hull_edges(), vec![(1, 0), (0, 1)]);
+    }
+    {
+        let slice = &[
+            [-3.0, -1.0],
+            [-2.0, 2.0],
+            [0.0, 0.0],
+            [1.0, 3.0],
+            [5.0, -1.0],
+            [6.0, 2.0],
+            [7.0, -4.0],
+            [8.0, -1.0],
+        ];
+        assert_eq!(
+            slice.convex_hull_edges(),
+            vec![(7, 5), (5, 3), (3, 1), (1, 0), (0, 6), (6, 7)]
+        );
+    }
+}
+
+#[test]
+fn test_many_points() {
+    // Deterministic pseudo-random points in a disc, compared against the exact
+    // monotone chain fallback
+    let mut state: u64 = 0x2545F4914F6CDD1D;
+    let mut random = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        return (state >> 11) as f64 / (1u64 << 53) as f64;
+    };
+    let points: Vec<[f64; 2]> = (0..20000)
+        .map(|_| {
+            let radius = random().sqrt();
+            let angle = random() * std::f64::consts::TAU;
+            [radius * angle.cos(), radius * angle.sin()]
+        })
+        .collect();
+
+    let hull: Vec<_> = points.convex_hull().collect();
+    assert!(hull.len() > 3);
+    assert_eq!(hull, points.convex_hull_exact().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_with_stats() {
+    let points = planar_convex_hull::generators::uniform_in_disk(10000, [0.0, 0.0], 1.0, 11);
+    let (hull, stats) = points.convex_hull_with_stats();
+    assert_eq!(
+        hull.collect::<Vec<_>>(),
+        points.convex_hull().collect::<Vec<_>>()
+    );
+    assert!(stats.extremes <= stats.total);
+    assert!(stats.merge <= stats.total);
+    assert!(stats.quadrants.iter().any(|duration| !duration.is_zero()));
+
+    // Single point: Only the extremum search takes place
+    let (hull, stats) = [[1.0, 2.0]].convex_hull_with_stats();
+    assert_eq!(hull.collect::<Vec<_>>(), vec![(0, [1.0, 2.0])]);
+    assert!(stats.quadrants.iter().all(|duration| duration.is_zero()));
+    assert!(stats.merge.is_zero());
+}
+
+#[test]
+fn test_duplicate_extremes() {
+    // The duplicates of the point with the largest x- and y-value must not be
+    // returned twice, even though they have different keys
+    let slice = &[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [2.0, 2.0]];
+    let hull: Vec<_> = slice.convex_hull().map(|(_, point)| point).collect();
+    assert_eq!(hull, vec![[2.0, 2.0], [0.0, 0.0]]);
+
+    let points =
+        planar_convex_hull::generators::with_duplicates(1000, 20, [0.0, 0.0], [1.0, 1.0], 3);
+    let hull: Vec<_> = points.convex_hull().map(|(_, point)| point).collect();
+    for (i, point) in hull.iter().enumerate() {
+        assert!(!hull[i + 1..].contains(point));
+    }
+}
+
+#[test]
+fn test_convex_hull_into() {
+    use planar_convex_hull::{CapacityError, generators};
+
+    let datasets = [
+        generators::uniform_in_disk(64, [0.0, 0.0], 1.0, 1),
+        generators::uniform_in_rectangle(1000, [0.0, 0.0], [2.0, 1.0], 2),
+        generators::on_circle(50, [1.0, 1.0], 1.0, 3),
+        generators::grid(8, 8, [0.0, 0.0], 1.0),
+        generators::collinear(20, [0.0, 0.0], [1.0, 1.0], 4),
+        vec![[1.0, 1.0]; 3],
+        vec![[0.0, 0.0], [f64::NAN, 1.0], [1.0, f64::INFINITY]],
+        vec![],
+    ];
+    for points in datasets.iter() {
+        let expected: Vec<[f64; 2]> = points.convex_hull_exact().map(|(_, point)| point).collect();
+        let mut keys = [usize::MAX; 64];
+        let count = points.convex_hull_into(&mut keys).unwrap();
+        let hull: Vec<[f64; 2]> = keys[..count].iter().map(|key| points[*key]).collect();
+        assert_eq!(hull, expected);
+
+        if count > 0 {
+            let mut keys = vec![0; count - 1];
+            assert_eq!(
+                points.convex_hull_into(&mut keys),
+                Err(CapacityError {
+                    required: count,
+                    capacity: count - 1
+                })
+            );
+        }
+    }
+}
+
+#[test]
+fn test_convex_hull_with_anchors() {
+    use planar_convex_hull::AnchorError;
+
+    // Square with points on all edges, a duplicate corner and an interior point
+    let points = vec![
+        [0.0, 0.0],
+        [4.0, 0.0],
+        [4.0, 4.0],
+        [0.0, 4.0],
+        [1.0, 0.0],
+        [3.0, 0.0],
+        [4.0, 2.0],
+        [2.0, 4.0],
+        [0.0, 1.0],
+        [4.0, 4.0],
+        [2.0, 2.0],
+        [f64::NAN, 0.0],
+    ];
+    let keys = |anchors: &[usize]| {
+        return points
+            .convex_hull_with_anchors(anchors)
+            .map(|hull| hull.map(|(key, _)| key).collect::<Vec<_>>());
+    };
+
+    assert_eq!(
+        keys(&[]),
+        Ok(points.convex_hull_exact().map(|(key, _)| key).collect())
+    );
+    assert_eq!(keys(&[5, 4, 6, 7, 8]), Ok(vec![2, 7, 3, 8, 0, 4, 5, 1, 6]));
+    assert_eq!(keys(&[9, 4, 4]), Ok(vec![9, 3, 0, 4, 1]));
+    assert_eq!(keys(&[10]), Err(AnchorError::Interior { key: 10 }));
+    assert_eq!(keys(&[11]), Err(AnchorError::NonReal { key: 11 }));
+    assert_eq!(keys(&[12]), Err(AnchorError::NotFound { key: 12 }));
+    assert_eq!(
+        keys(&[2, 9]),
+        Err(AnchorError::Coincident { key: 9, other: 2 })
+    );
+
+    // Collinear input
+    let segment = [[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [3.0, 3.0]];
+    let hull: Vec<usize> = segment
+        .convex_hull_with_anchors(&[2, 1])
+        .unwrap()
+        .map(|(key, _)| key)
+        .collect();
+    assert_eq!(hull, vec![3, 2, 1, 0]);
+}
+
+#[test]
+fn test_convex_hull_facing() {
+    use std::f64::consts::{PI, TAU};
+
+    let points = planar_convex_hull::generators::uniform_in_disk(3000, [5.0, -2.0], 3.0, 17);
+    let hull: Vec<(usize, [f64; 2])> = points.convex_hull_exact().collect();
+    let n = hull.len();
+
+    // Reference: trim the whole hull to the edges whose normal is in range
+    let normal = |i: usize| {
+        let a = hull[i].1;
+        let b = hull[(i + 1) % n].1;
+        return (a[0] - b[0]).atan2(b[1] - a[1]);
+    };
+    let in_range = |angle: f64, start: f64, span: f64| (angle - start).rem_euclid(TAU) <= span;
+
+    for step in 0..40 {
+        let start = step as f64 * 0.37 - 3.0;
+        for span in [0.1, 1.0, 2.5, PI + 0.5, 6.0] {
+            let chain = points.convex_hull_facing(start, start + span);
+            // Every edge of the chain faces into the range, the edges before
+            // and after the chain do not
+            let position = |item: &(usize, [f64; 2])| hull.iter().position(|h| h == item).unwrap();
+            let first = position(&chain[0]);
+            let last = position(chain.last().unwrap());
+            for offset in 0..chain.len() - 1 {
+                assert_eq!(chain[offset], hull[(first + offset) % n]);
+                assert!(in_range(normal((first + offset) % n), start, span));
+            }
+            assert!(!in_range(normal((first + n - 1) % n), start, span));
+            assert!(!in_range(normal(last), start, span));
+        }
+    }
+
+    let full = points.convex_hull_facing(1.0, 1.0 + TAU);
+    assert_eq!(full.len(), n);
+    let empty: &[[f64; 2]] = &[];
+    assert!(empty.convex_hull_facing(0.0, 1.0).is_empty());
+}
+
+#[test]
+fn test_dyn_convex_hull() {
+    use planar_convex_hull::DynConvexHull;
+
+    let points = planar_convex_hull::generators::uniform_in_disk(500, [1.0, 2.0], 3.0, 4);
+    let expected: Vec<(usize, [f64; 2])> = points.convex_hull().collect();
+
+    let map: HashMap<usize, [f64; 2]> = points.iter().copied().enumerate().collect();
+    let sources: Vec<Box<dyn DynConvexHull + Send>> = vec![Box::new(points.clone()), Box::new(map)];
+    for source in sources.iter() {
+        assert_eq!(source.convex_hull().collect::<Vec<_>>(), expected);
+        assert_eq!(
+            source.convex_hull_exact().collect::<Vec<_>>(),
+            points.convex_hull_exact().collect::<Vec<_>>()
+        );
+        assert_eq!(source.dyn_convex_hull_iter().count(), points.len());
+    }
+
+    let empty: &dyn DynConvexHull = &Vec::<[f64; 2]>::new();
+    assert_eq!(empty.convex_hull().count(), 0);
+}
+
+#[test]
+fn test_convex_depth() {
+    use planar_convex_hull::generators;
+
+    // The depth of a grid point is its distance to the grid boundary
+    let mut grid = generators::grid(7, 5, [0.0, 0.0], 1.0);
+    grid.push([3.0, 2.0]); // Duplicate of the center
+    grid.push([f64::NAN, 0.0]);
+    let depth = grid.convex_depth();
+    assert_eq!(depth.len(), grid.len() - 1);
+    for (key, point) in grid.iter().enumerate().take(grid.len() - 1) {
+        let (x, y) = (point[0] as usize, point[1] as usize);
+        let expected = x.min(y).min(6 - x).min(4 - y);
+        assert_eq!(depth[&key], expected, "{point:?}");
+    }
+
+    // The hull corners of the points with at least depth d have depth d
+    let points = generators::uniform_in_disk(2000, [0.0, 0.0], 1.0, 5);
+    let depth = points.convex_depth();
+    let max_depth = depth.values().copied().max().unwrap();
+    assert!(max_depth > 10);
+    for d in 0..=max_depth {
+        let remaining: HashMap<usize, [f64; 2]> = points
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(key, _)| depth[key] >= d)
+            .collect();
+        assert!(remaining.convex_hull().all(|(key, _)| depth[&key] == d));
+    }
+}
+
+#[test]
+fn test_convex_layers() {
+    use planar_convex_hull::generators;
+
+    let points = generators::uniform_in_disk(2000, [0.0, 0.0], 1.0, 6);
+    let layers = points.convex_layers();
+    let depth = points.convex_depth();
+    assert_eq!(layers.iter().map(Vec::len).sum::<usize>(), points.len());
+    for (d, layer) in layers.iter().enumerate() {
+        assert!(layer.iter().all(|key| depth[key] == d));
+
+        // Without duplicates and collinear points, each layer is the hull of
+        // the remaining points
+        let remaining: Vec<(usize, [f64; 2])> = points
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(key, _)| depth[key] >= d)
+            .collect();
+        let hull: Vec<usize> = remaining
+            .iter()
+            .map(|(_, point)| *point)
+            .collect::<Vec<_>>()
+            .convex_hull()
+            .map(|(i, _)| remaining[i].0)
+            .collect();
+        assert_eq!(layer, &hull);
+    }
+
+    let empty: Vec<[f64; 2]> = vec![[f64::NAN, 0.0]];
+    assert!(empty.convex_layers().is_empty());
+}
+
+#[test]
+fn test_convex_hull_warm_start() {
+    use planar_convex_hull::{Hull, generators};
+
+    let mut points = generators::uniform_in_disk(3000, [0.0, 0.0], 1.0, 7);
+    let mut hull = Hull::new(&points);
+    for frame in 0..20u64 {
+        // Jitter all points and move a few of them far away
+        let jitter = generators::uniform_in_disk(points.len(), [0.0, 0.0], 0.01, frame);
+        for (point, offset) in points.iter_mut().zip(jitter) {
+            point[0] += offset[0];
+            point[1] += offset[1];
+        }
+        let key = (frame as usize * 131) % points.len();
+        points[key] = [1.5 * (frame as f64).cos(), 1.5 * (frame as f64).sin()];
+
+        let warm: Vec<(usize, [f64; 2])> = points.convex_hull_warm_start(&hull).collect();
+        let exact: Vec<(usize, [f64; 2])> = points.convex_hull_exact().collect();
+        assert_eq!(warm, exact);
+        hull = Hull::from_vertices_unchecked(warm);
+    }
+
+    // Removed keys and a useless hint
+    let map: HashMap<usize, [f64; 2]> = points.iter().copied().enumerate().skip(100).collect();
+    let warm: Vec<(usize, [f64; 2])> = map.convex_hull_warm_start(&hull).collect();
+    assert_eq!(warm, map.convex_hull_exact().collect::<Vec<_>>());
+    let hint = Hull::new(&[[0.0, 0.0], [1e-3, 0.0], [0.0, 1e-3]]);
+    let warm: Vec<(usize, [f64; 2])> = points.convex_hull_warm_start(&hint).collect();
+    assert_eq!(warm, points.convex_hull_exact().collect::<Vec<_>>());
+    assert_eq!(
+        points.convex_hull_warm_start(&Hull::default()).count(),
+        warm.len()
+    );
+
+    // A hint with only two corners excludes collinear points as well
+    let points = [
+        [4.0, 1.0],
+        [4.0, 4.0],
+        [4.0, 3.0],
+        [0.0, 4.0],
+        [2.0, 2.0],
+        [0.0, 0.0],
+    ];
+    let hint = Hull::from_vertices_unchecked([(1, [4.0, 4.0]), (5, [0.0, 0.0])]);
+    let warm: Vec<(usize, [f64; 2])> = points.convex_hull_warm_start(&hint).collect();
+    assert_eq!(warm, points.convex_hull_exact().collect::<Vec<_>>());
+    assert_eq!(
+        warm.iter().map(|(key, _)| *key).collect::<Vec<_>>(),
+        vec![1, 3, 5, 0]
+    );
+}
+
+#[test]
+fn test_convex_hull_with_certificate() {
+    use planar_convex_hull::{CertificateError, generators};
+
+    let datasets = [
+        generators::uniform_in_disk(2000, [1.0, 2.0], 3.0, 1),
+        generators::grid(6, 4, [0.0, 0.0], 0.5),
+        generators::collinear(20, [0.0, 0.0], [1.0, 3.0], 2),
+        vec![[1.0, 1.0]; 5],
+        vec![],
+    ];
+    for points in datasets.iter() {
+        let (hull, certificate) = points.convex_hull_with_certificate();
+        let hull: Vec<(usize, [f64; 2])> = hull.collect();
+        assert_eq!(hull, points.convex_hull_exact().collect::<Vec<_>>());
+        assert_eq!(certificate.witnesses().len(), points.len() - hull.len());
+        assert_eq!(certificate.verify(points, &hull), Ok(()));
+    }
+
+    // Tampered results are rejected
+    let points = generators::uniform_in_disk(100, [0.0, 0.0], 1.0, 3);
+    let (hull, certificate) = points.convex_hull_with_certificate();
+    let hull: Vec<(usize, [f64; 2])> = hull.collect();
+
+    let mut reversed = hull.clone();
+    reversed.reverse();
+    assert!(matches!(
+        certificate.verify(&points, &reversed),
+        Err(CertificateError::InvalidHull(_))
+    ));
+
+    let mut moved = points.clone();
+    let (key, _) = certificate.witnesses()[0];
+    moved[key] = [5.0, 5.0];
+    assert_eq!(
+        certificate.verify(&moved, &hull),
+        Err(CertificateError::Outside { key })
+    );
+
+    let mut shifted = hull.clone();
+    shifted[1].1[0] += 1e-9;
+    assert!(certificate.verify(&points, &shifted).is_err());
+
+    let mut extended = points.clone();
+    extended.push([0.0, 0.0]);
+    assert_eq!(
+        certificate.verify(&extended, &hull),
+        Err(CertificateError::Missing { key: 100 })
+    );
+}
+
+#[test]
+fn test_orthogonal_convex_hull() {
+    // Brute force: A point is a corner if no other point dominates it
+    // towards one of the four quadrants.
+    let mut points = planar_convex_hull::generators::uniform_in_disk(300, [0.0, 0.0], 1.0, 11);
+    points.extend_from_within(0..20);
+    points.push([f64::NAN, 0.0]);
+    let hull: Vec<(usize, [f64; 2])> = points.orthogonal_convex_hull().collect();
+
+    let mut expected: Vec<usize> = Vec::new();
+    for (key, point) in points.iter().enumerate() {
+        if !point[0].is_finite() || points[..key].contains(point) {
+            continue;
+        }
+        let maximal = [[1.0, 1.0], [-1.0, 1.0], [-1.0, -1.0], [1.0, -1.0]]
+            .iter()
+            .any(|[sx, sy]| {
+                return !points.iter().any(|other| {
+                    return other != point
+                        && sx * other[0] >= sx * point[0]
+                        && sy * other[1] >= sy * point[1];
+                });
+            });
+        if maximal {
+            expected.push(key);
+        }
+    }
+    let mut keys: Vec<usize> = hull.iter().map(|(key, _)| *key).collect();
+    assert_eq!(keys[0], points.convex_hull().next().unwrap().0);
+    keys.sort();
+    assert_eq!(keys, expected);
+
+    // The staircases wind counter-clockwise around the hull
+    for i in 0..hull.len() {
+        let a = hull[i].1;
+        let b = hull[(i + 1) % hull.len()].1;
+        assert!(a[0] != b[0] && a[1] != b[1]);
+    }
+
+    // Degenerate case: a descending line is passed twice
+    let line = [[0.0, 2.0], [1.0, 1.0], [2.0, 0.0]];
+    let keys: Vec<usize> = line.orthogonal_convex_hull().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec![2, 1, 0, 1]);
+    assert_eq!([[1.0, 1.0]].orthogonal_convex_hull().count(), 1);
+    assert_eq!(Vec::<[f64; 2]>::new().orthogonal_convex_hull().count(), 0);
+}
+
+#[test]
+fn test_pareto_front() {
+    use planar_convex_hull::Quadrant;
+
+    let mut points = planar_convex_hull::generators::uniform_in_disk(300, [3.0, -1.0], 2.0, 12);
+    points.extend_from_within(0..20);
+    // Ties in one coordinate
+    points.push([6.0, -1.0]);
+    points.push([6.0, -2.0]);
+    points.push([f64::NAN, 0.0]);
+
+    for quadrant in Quadrant::ALL {
+        let [sx, sy] = quadrant.signs();
+        let front = points.pareto_front(quadrant);
+
+        let mut expected: Vec<usize> = Vec::new();
+        for (key, point) in points.iter().enumerate() {
+            if !point[0].is_finite() || points[..key].contains(point) {
+                continue;
+            }
+            let dominated = points.iter().any(|other| {
+                return other != point
+                    && sx * other[0] >= sx * point[0]
+                    && sy * other[1] >= sy * point[1];
+            });
+            if !dominated {
+                expected.push(key);
+            }
+        }
+        let mut keys: Vec<usize> = front.iter().map(|(key, _)| *key).collect();
+        keys.sort();
+        assert_eq!(keys, expected);
+
+        // Counter-clockwise: the angle around the center increases
+        for pair in front.windows(2) {
+            let [a, b] = [pair[0].1, pair[1].1];
+            let cross = (a[0] - 3.0) * (b[1] + 1.0) - (a[1] + 1.0) * (b[0] - 3.0);
+            assert!(cross > 0.0);
+        }
+    }
+    assert!(
+        Vec::<[f64; 2]>::new()
+            .pareto_front(Quadrant::NorthEast)
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_is_in_convex_position() {
+    use planar_convex_hull::generators::{on_circle, uniform_in_disk};
+
+    // Compare with the number of distinct points on the exact hull
+    for seed in 0..50 {
+        let mut points = uniform_in_disk(6, [0.0, 0.0], 1.0, seed);
+        points.push(points[0]);
+        let distinct: HashSet<[OrderedFloat<f64>; 2]> = points
+            .iter()
+            .map(|p| [OrderedFloat(p[0]), OrderedFloat(p[1])])
+            .collect();
+        let expected = points.convex_hull_exact().count() == distinct.len();
+        assert_eq!(points.is_in_convex_position(), expected);
+    }
+
+    let circle = on_circle(100, [1.0, 2.0], 3.0, 0);
+    assert!(circle.is_in_convex_position());
+
+    // Collinear points and few points
+    assert!(!vec![[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]].is_in_convex_position());
+    assert!(vec![[0.0, 0.0], [2.0, 2.0], [2.0, 2.0]].is_in_convex_position());
+    assert!(vec![[0.0, 0.0], [f64::NAN, 1.0]].is_in_convex_position());
+    assert!(Vec::<[f64; 2]>::new().is_in_convex_position());
+}
+
+/// Inputs on which the alternative algorithms are compared with
+/// `convex_hull_exact`.
+fn differential_inputs() -> Vec<Vec<[f64; 2]>> {
+    use planar_convex_hull::generators;
+
+    let mut inputs = vec![
+        Vec::new(),
+        vec![[1.0, 2.0]],
+        vec![[1.0, 2.0], [1.0, 2.0]],
+        vec![[0.0, 0.0], [1.0, 0.0], [0.0, 0.0], [1.0, 1.0], [1.0, 1.0]],
+        vec![[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]],
+        vec![
+            [0.0, 0.0],
+            [f64::NAN, 1.0],
+            [1.0, f64::INFINITY],
+            [1.0, 0.0],
+        ],
+        generators::grid(6, 4, [0.0, 0.0], 0.5),
+        generators::collinear(20, [0.0, 0.0], [1.0, 3.0], 2),
+        generators::on_circle(100, [1.0, 2.0], 3.0, 3),
+    ];
+    for seed in 0..10 {
+        inputs.push(generators::uniform_in_disk(500, [1.0, 2.0], 3.0, seed));
+        inputs.push(generators::uniform_in_rectangle(
+            200,
+            [-1.0, -1.0],
+            [1.0, 1.0],
+            seed,
+        ));
+        inputs.push(generators::gaussian_clusters(
+            300,
+            4,
+            [0.0, 0.0],
+            [10.0, 10.0],
+            0.5,
+            seed,
+        ));
+    }
+    return inputs;
+}
+
+/// Corners of the hull without their keys, since the keys of identical points
+/// may differ between algorithms.
+fn corners(hull: impl Iterator<Item = (usize, [f64; 2])>) -> Vec<[f64; 2]> {
+    return hull.map(|(_, point)| point).collect();
+}
+
+#[test]
+fn test_convex_hull_monotone_chain() {
+    for points in differential_inputs() {
+        assert_eq!(
+            corners(points.convex_hull_monotone_chain()),
+            corners(points.convex_hull_exact())
+        );
+    }
+
+    // Of identical points, the first one is kept
+    let points = vec![[1.0, 1.0], [0.0, 0.0], [1.0, 1.0], [1.0, 0.0]];
+    let keys: Vec<usize> = points
+        .convex_hull_monotone_chain()
+        .map(|(key, _)| key)
+        .collect();
+    assert_eq!(keys, vec![0, 1, 3]);
+
+    // Negative zero is identical to zero
+    let points = vec![
+        [0.0, 0.0],
+        [1.0, 0.0],
+        [1.0, 1.0],
+        [0.0, 1.0],
+        [0.5, 0.5],
+        [-0.0, 1.0],
+    ];
+    let keys: Vec<usize> = points
+        .convex_hull_monotone_chain()
+        .map(|(key, _)| key)
+        .collect();
+    assert_eq!(keys, vec![2, 3, 0, 1]);
+    let keys: Vec<usize> = points.convex_hull_exact().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec![2, 3, 0, 1]);
+}
+
+#[test]
+fn test_convex_hull_graham() {
+    for points in differential_inputs() {
+        assert_eq!(
+            corners(points.convex_hull_graham()),
+            corners(points.convex_hull_monotone_chain())
+        );
+    }
+
+    // Duplicates of the lowest point and points on a vertical line
+    let points = vec![
+        [0.0, 0.0],
+        [0.0, 2.0],
+        [0.0, 0.0],
+        [0.0, 1.0],
+        [1.0, 1.0],
+        [0.0, 2.0],
+    ];
+    let hull: Vec<(usize, [f64; 2])> = points.convex_hull_graham().collect();
+    assert_eq!(
+        hull,
+        vec![(4, [1.0, 1.0]), (1, [0.0, 2.0]), (0, [0.0, 0.0])]
+    );
+}
+
+#[test]
+fn test_convex_hull_quickhull() {
+    use planar_convex_hull::generators;
+
+    for points in differential_inputs() {
+        assert_eq!(
+            corners(points.convex_hull_quickhull()),
+            corners(points.convex_hull_monotone_chain())
+        );
+    }
+
+    // Large enough for the parallel recursion
+    for points in [
+        generators::uniform_in_disk(50_000, [0.0, 0.0], 1.0, 1),
+        generators::on_circle(20_000, [1.0, 2.0], 3.0, 2),
+        generators::with_duplicates(20_000, 100, [0.0, 0.0], [1.0, 1.0], 3),
+    ] {
+        assert!(
+            points
+                .convex_hull_quickhull()
+                .eq(points.convex_hull_monotone_chain())
+        );
+    }
+}
+
+#[test]
+fn test_convex_hull_chan() {
+    use planar_convex_hull::generators;
+
+    for points in differential_inputs() {
+        assert_eq!(
+            corners(points.convex_hull_chan()),
+            corners(points.convex_hull_monotone_chain())
+        );
+    }
+
+    // Several rounds of guessing the hull size and duplicates in different
+    // groups
+    for points in [
+        generators::uniform_in_disk(20_000, [0.0, 0.0], 1.0, 1),
+        generators::on_circle(1_000, [1.0, 2.0], 3.0, 2),
+        generators::grid(40, 30, [0.0, 0.0], 0.5),
+        generators::with_duplicates(5_000, 50, [0.0, 0.0], [1.0, 1.0], 3),
+    ] {
+        assert!(
+            points
+                .convex_hull_chan()
+                .eq(points.convex_hull_monotone_chain())
+        );
+    }
+}
+
+#[test]
+fn test_convex_hull_jarvis() {
+    use planar_convex_hull::generators;
+
+    for points in differential_inputs() {
+        assert_eq!(
+            corners(points.convex_hull_jarvis()),
+            corners(points.convex_hull_monotone_chain())
+        );
+    }
+
+    let points = generators::with_duplicates(1_000, 20, [0.0, 0.0], [1.0, 1.0], 1);
+    assert!(
+        points
+            .convex_hull_jarvis()
+            .eq(points.convex_hull_monotone_chain())
+    );
+
+    // Same keys as the allocation-free variant
+    let points = generators::uniform_in_rectangle(500, [0.0, 0.0], [2.0, 1.0], 2);
+    let mut keys = [0usize; 64];
+    let count = points.convex_hull_into(&mut keys).unwrap();
+    let hull: Vec<usize> = points.convex_hull_jarvis().map(|(key, _)| key).collect();
+    assert_eq!(hull, keys[..count].to_vec());
+
+    // The coordinate differences overflow
+    let max = f64::MAX;
+    let points = vec![[max, 0.0], [-max, 0.0], [0.0, max], [0.0, -max], [1.0, 1.0]];
+    let hull: Vec<usize> = points.convex_hull_jarvis().map(|(key, _)| key).collect();
+    assert_eq!(hull, vec![0, 2, 1, 3]);
+    let count = points.convex_hull_into(&mut keys).unwrap();
+    assert_eq!(keys[..count].to_vec(), vec![0, 2, 1, 3]);
+
+    // Tiny coordinates next to huge ones, where the predicates are not exact
+    let points = vec![
+        [max, max],
+        [-max, 0.0],
+        [0.0, -max],
+        [f64::MIN_POSITIVE, 0.0],
+        [0.0, f64::MIN_POSITIVE],
+        [-f64::MIN_POSITIVE, -f64::MIN_POSITIVE],
+    ];
+    assert!(points.convex_hull_jarvis().count() <= points.len());
+    assert!(points.convex_hull_into(&mut keys).unwrap() <= points.len());
+}
+
+#[test]
+fn test_convex_hull_kirkpatrick_seidel() {
+    use planar_convex_hull::generators;
+
+    for points in differential_inputs() {
+        assert_eq!(
+            corners(points.convex_hull_kirkpatrick_seidel()),
+            corners(points.convex_hull_monotone_chain())
+        );
+    }
+
+    for points in [
+        generators::uniform_in_disk(10_000, [0.0, 0.0], 1.0, 1),
+        generators::on_circle(1_000, [1.0, 2.0], 3.0, 2),
+        generators::grid(40, 30, [0.0, 0.0], 0.5),
+        generators::with_duplicates(5_000, 50, [0.0, 0.0], [1.0, 1.0], 3),
+        generators::collinear(100, [0.0, 0.0], [0.0, 1.0], 4),
+    ] {
+        assert!(
+            points
+                .convex_hull_kirkpatrick_seidel()
+                .eq(points.convex_hull_monotone_chain())
+        );
+    }
+}
+
+#[test]
+fn test_convex_hull_of_polyline() {
+    use planar_convex_hull::generators;
+
+    // Star-shaped polygons are simple
+    for seed in 0..20 {
+        let mut polygon = generators::uniform_in_disk(200, [1.0, 2.0], 3.0, seed);
+        polygon.sort_by(|a, b| {
+            let angle = |p: &[f64; 2]| (p[1] - 2.0).atan2(p[0] - 1.0);
+            return angle(a).total_cmp(&angle(b));
+        });
+        assert_eq!(
+            corners(polygon.convex_hull_of_polyline()),
+            corners(polygon.convex_hull_monotone_chain())
+        );
+
+        // Closed by repeating the first vertex and open sections starting at
+        // any vertex
+        let mut closed = polygon.clone();
+        closed.push(polygon[0]);
+        assert!(
+            closed
+                .convex_hull_of_polyline()
+                .eq(polygon.convex_hull_monotone_chain())
+        );
+        let open = polygon[(seed as usize)..(seed as usize + 120)].to_vec();
+        assert_eq!(
+            corners(open.convex_hull_of_polyline()),
+            corners(open.convex_hull_monotone_chain())
+        );
+    }
+
+    // A spiral winding outwards
+    let spiral: Vec<[f64; 2]> = (0..500)
+        .map(|i| {
+            let angle = 0.1 * i as f64;
+            return [angle * angle.cos(), angle * angle.sin()];
+        })
+        .collect();
+    assert!(
+        spiral
+            .convex_hull_of_polyline()
+            .eq(spiral.convex_hull_monotone_chain())
+    );
+
+    // Straight sections, also at the start
+    let polyline = vec![
+        [0.0, 0.0],
+        [1.0, 0.0],
+        [1.0, 0.0],
+        [2.0, 0.0],
+        [2.0, 1.0],
+        [2.0, 2.0],
+        [1.0, 2.0],
+    ];
+    let hull: Vec<usize> = polyline
+        .convex_hull_of_polyline()
+        .map(|(key, _)| key)
+        .collect();
+    assert_eq!(hull, vec![5, 6, 0, 3]);
+    let line = vec![[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [f64::NAN, 0.0]];
+    assert!(
+        line.convex_hull_of_polyline()
+            .eq(line.convex_hull_monotone_chain())
+    );
+    assert_eq!(Vec::<[f64; 2]>::new().convex_hull_of_polyline().count(), 0);
+}
+
+#[test]
+fn test_convex_hull_sorted() {
+    for points in differential_inputs() {
+        let mut sorted = points.clone();
+        sorted.sort_by(|a, b| a[0].total_cmp(&b[0]).then(a[1].total_cmp(&b[1])));
+        assert!(
+            sorted
+                .convex_hull_sorted()
+                .eq(sorted.convex_hull_monotone_chain())
+        );
+
+        // Unsorted input is still handled correctly
+        assert!(
+            points
+                .convex_hull_sorted()
+                .eq(points.convex_hull_monotone_chain())
+        );
+    }
+
+    // Nonreal points between the sorted ones
+    let points = vec![
+        [0.0, 0.0],
+        [f64::NAN, -1.0],
+        [1.0, 1.0],
+        [f64::INFINITY, 0.0],
+        [2.0, 0.0],
+    ];
+    let keys: Vec<usize> = points.convex_hull_sorted().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec![4, 2, 0]);
+}
+
+#[test]
+fn test_convex_hull_approximate() {
+    use planar_convex_hull::Hull;
+
+    for tolerance in [1e-3, 0.01, 0.1, 1.0, 10.0] {
+        for points in differential_inputs() {
+            let exact = Hull::from_vertices_unchecked(points.convex_hull_monotone_chain());
+            let approximation =
+                Hull::from_vertices_unchecked(points.convex_hull_approximate(tolerance));
+
+            // The approximation is located inside the exact hull and all points
+            // are within the tolerance of it
+            assert!(
+                approximation
+                    .points()
+                    .iter()
+                    .all(|point| exact.contains(*point))
+            );
+            for point in points
+                .iter()
+                .filter(|p| p[0].is_finite() && p[1].is_finite())
+            {
+                assert!(approximation.signed_distance(*point) < tolerance);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_convex_hull_approximate_zero_tolerance() {
+    vec![[0.0, 0.0], [1.0, 1.0]].convex_hull_approximate(0.0);
+}
+
+#[test]
+fn test_convex_hulls() {
+    use planar_convex_hull::{Hull, convex_hulls};
+
+    let mut inputs = differential_inputs();
+    inputs.extend((0..200).map(|seed| {
+        planar_convex_hull::generators::uniform_in_disk(20, [seed as f64, 0.0], 1.0, seed)
+    }));
+    let hulls = convex_hulls(&inputs);
+    assert_eq!(hulls.len(), inputs.len());
+    for (hull, points) in hulls.iter().zip(inputs.iter()) {
+        assert_eq!(*hull, Hull::from(points.convex_hull()));
+    }
+    assert!(convex_hulls::<Vec<[f64; 2]>>(&[]).is_empty());
+}
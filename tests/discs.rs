@@ -0,0 +1,125 @@
+use std::f64::consts::TAU;
+
+use planar_convex_hull::{ConvexHull, Hull, discs::DiscHull, generators};
+
+/// Random discs with centers in a disk and radii between 0 and 1
+fn random_discs(n: usize, seed: u64) -> Vec<(usize, [f64; 2], f64)> {
+    let centers = generators::uniform_in_disk(n, [1.0, -2.0], 5.0, seed);
+    let radii = generators::uniform_in_disk(n, [0.5, 0.0], 0.5, seed + 1000);
+    return centers
+        .into_iter()
+        .zip(radii)
+        .enumerate()
+        .map(|(key, (center, radius))| (key, center, radius[0]))
+        .collect();
+}
+
+fn support(center: [f64; 2], radius: f64, angle: f64) -> f64 {
+    return center[0] * angle.cos() + center[1] * angle.sin() + radius;
+}
+
+#[test]
+fn test_disc_hull_envelope() {
+    for seed in 0..10 {
+        let discs = random_discs(30, seed);
+        let hull = DiscHull::new(discs.iter().copied());
+        let arcs = hull.arcs();
+        assert!(!arcs.is_empty() && arcs.len() < 2 * discs.len());
+
+        // The arcs cover all angles exactly once
+        assert_eq!(arcs[0].start_angle + TAU, arcs[arcs.len() - 1].end_angle);
+        for pair in arcs.windows(2) {
+            assert_eq!(pair[0].end_angle, pair[1].start_angle);
+        }
+
+        // At each angle, the disc of the arc is the one extending furthest
+        for arc in arcs.iter() {
+            for i in 1..10 {
+                let angle = arc.start_angle + (arc.end_angle - arc.start_angle) * i as f64 / 10.0;
+                let max = discs
+                    .iter()
+                    .map(|disc| support(disc.1, disc.2, angle))
+                    .fold(f64::NEG_INFINITY, f64::max);
+                assert!((support(arc.center, arc.radius, angle) - max).abs() < 1e-12);
+            }
+        }
+
+        // Compare with the hull of densely sampled circles
+        let samples: Vec<[f64; 2]> = discs
+            .iter()
+            .flat_map(|(_, center, radius)| {
+                return (0..2000).map(move |i| {
+                    let angle = TAU * i as f64 / 2000.0;
+                    return [
+                        center[0] + radius * angle.cos(),
+                        center[1] + radius * angle.sin(),
+                    ];
+                });
+            })
+            .collect();
+        let polygon = Hull::new(&samples);
+        let points = polygon.points();
+        let n = points.len();
+        let area: f64 = 0.5
+            * (0..n)
+                .map(|i| {
+                    let (a, b) = (points[i], points[(i + 1) % n]);
+                    return a[0] * b[1] - a[1] * b[0];
+                })
+                .sum::<f64>();
+        assert!((hull.area() - area).abs() < 1e-4 * area);
+        let perimeter: f64 = (0..n)
+            .map(|i| {
+                let (a, b) = (points[i], points[(i + 1) % n]);
+                return (b[0] - a[0]).hypot(b[1] - a[1]);
+            })
+            .sum();
+        assert!((hull.perimeter() - perimeter).abs() < 1e-4 * perimeter);
+
+        // The segments are tangent to both discs
+        for ((start, end), (arc, next)) in hull
+            .segments()
+            .into_iter()
+            .zip(arcs.iter().zip(arcs.iter().cycle().skip(1)))
+        {
+            assert_eq!(start, arc.end());
+            assert_eq!(end, next.start());
+        }
+    }
+}
+
+#[test]
+fn test_disc_hull_special_cases() {
+    // Points are discs with radius zero
+    let points = generators::uniform_in_disk(100, [0.0, 0.0], 1.0, 3);
+    let hull = DiscHull::new(points.iter().copied().enumerate().map(|(k, p)| (k, p, 0.0)));
+    let keys: Vec<usize> = hull.arcs().iter().map(|arc| arc.key).collect();
+    let expected: Vec<usize> = points.convex_hull().map(|(key, _)| key).collect();
+    assert_eq!(keys, expected);
+
+    // A single disc containing all others
+    let hull = DiscHull::new([
+        (0, [0.0, 0.0], 1.0),
+        (1, [10.0, 0.0], 5.0),
+        (2, [12.0, 1.0], 1.0),
+        (3, [10.0, 0.0], 5.0),
+    ]);
+    assert_eq!(hull.arcs().len(), 2);
+    assert!(hull.arcs().iter().all(|arc| arc.key != 2 && arc.key != 3));
+
+    let hull = DiscHull::new([
+        (4, [3.0, 1.0], 2.0),
+        (5, [3.0, 1.0], 1.0),
+        (6, [3.0, 1.0], -1.0),
+    ]);
+    assert_eq!(hull.arcs().len(), 1);
+    assert_eq!(hull.arcs()[0].key, 4);
+    assert_eq!(hull.arcs()[0].start_angle, 0.0);
+    assert_eq!(hull.arcs()[0].end_angle, TAU);
+    assert!(hull.segments().is_empty());
+    assert!((hull.area() - 4.0 * std::f64::consts::PI).abs() < 1e-12);
+
+    let hull = DiscHull::new([(0, [f64::NAN, 0.0], 1.0)]);
+    assert!(hull.arcs().is_empty());
+    assert_eq!(hull.area(), 0.0);
+}
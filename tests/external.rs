@@ -0,0 +1,65 @@
+use std::io::{ErrorKind, Read};
+
+use planar_convex_hull::external::{ExternalHull, RECORD_LEN, from_reader};
+use planar_convex_hull::{ConvexHull, Hull, generators::gaussian_clusters};
+
+/// A reader which returns the data in small, irregular chunks.
+struct Chunked<'a> {
+    data: &'a [u8],
+    step: usize,
+}
+
+impl Read for Chunked<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.step = self.step % 13 + 1;
+        let len = self.step.min(buf.len()).min(self.data.len());
+        buf[..len].copy_from_slice(&self.data[..len]);
+        self.data = &self.data[len..];
+        return Ok(len);
+    }
+}
+
+#[test]
+fn test_from_reader() {
+    let points = gaussian_clusters(10_000, 5, [-10.0, -10.0], [10.0, 10.0], 1.0, 11);
+    let mut bytes: Vec<u8> = points
+        .iter()
+        .flat_map(|point| [point[0].to_le_bytes(), point[1].to_le_bytes()])
+        .flatten()
+        .collect();
+    assert_eq!(bytes.len(), points.len() * RECORD_LEN);
+    let expected = Hull::from(points.convex_hull());
+
+    for block_len in [0, 1, 7, 1000, 10_000, 20_000] {
+        assert_eq!(from_reader(bytes.as_slice(), block_len).unwrap(), expected);
+    }
+    let chunked = Chunked {
+        data: &bytes,
+        step: 0,
+    };
+    assert_eq!(from_reader(chunked, 333).unwrap(), expected);
+
+    // Incomplete record
+    bytes.pop();
+    let error = from_reader(bytes.as_slice(), 100).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+
+    // Empty data
+    assert!(from_reader([].as_slice(), 100).unwrap().is_empty());
+}
+
+#[test]
+fn test_external_hull() {
+    let points = gaussian_clusters(5_000, 3, [0.0, 0.0], [20.0, 5.0], 2.0, 5);
+    let mut external = ExternalHull::new();
+    for (block, chunk) in points.chunks(128).enumerate() {
+        external.insert_block(
+            chunk
+                .iter()
+                .enumerate()
+                .map(|(index, point)| (block * 128 + index, *point)),
+        );
+        assert!(external.hull().len() <= (block + 1) * 128);
+    }
+    assert_eq!(external.into_hull(), Hull::from(points.convex_hull()));
+}
@@ -0,0 +1,66 @@
+use planar_convex_hull::{ConvexHull, generators::*};
+
+#[test]
+fn test_generators_reproducible() {
+    assert_eq!(
+        uniform_in_rectangle(100, [0.0, 0.0], [1.0, 2.0], 1),
+        uniform_in_rectangle(100, [0.0, 0.0], [1.0, 2.0], 1)
+    );
+    assert_ne!(
+        uniform_in_rectangle(100, [0.0, 0.0], [1.0, 2.0], 1),
+        uniform_in_rectangle(100, [0.0, 0.0], [1.0, 2.0], 2)
+    );
+
+    // Pin the first values of the generator to detect accidental changes of
+    // the datasets
+    let mut rng = Rng::new(0);
+    assert_eq!(rng.next_u64(), 0xE220A8397B1DCDAF);
+    assert_eq!(rng.next_u64(), 0x6E789E6AA1B965F4);
+}
+
+#[test]
+fn test_generators_properties() {
+    for point in uniform_in_disk(1000, [1.0, -1.0], 2.0, 3) {
+        assert!((point[0] - 1.0).hypot(point[1] + 1.0) <= 2.0);
+    }
+    for point in uniform_in_rectangle(1000, [0.0, 0.0], [1.0, 2.0], 3) {
+        assert!((0.0..1.0).contains(&point[0]) && (0.0..2.0).contains(&point[1]));
+    }
+    for point in on_circle(100, [0.0, 0.0], 3.0, 3) {
+        assert!((point[0].hypot(point[1]) - 3.0).abs() < 1e-12);
+    }
+    assert_eq!(
+        gaussian_clusters(500, 3, [0.0, 0.0], [10.0, 10.0], 0.1, 3).len(),
+        500
+    );
+
+    let points = collinear(1000, [0.0, 0.0], [2.0, 1.0], 3);
+    assert_eq!(points.convex_hull_exact().count(), 2);
+
+    // Integer endpoints -> exactly collinear (checked with integer arithmetic
+    // on the coordinates scaled by 2^16)
+    let (start, end) = ([-3.0, 7.0], [4_000_000_000.0, -3_999_999_999.0]);
+    let points = collinear(1000, start, end, 3);
+    assert_eq!(points.convex_hull_exact().count(), 2);
+    let scaled = |point: [f64; 2]| point.map(|value| (value * 65536.0) as i128);
+    let [sx, sy] = scaled(start);
+    let [ex, ey] = scaled(end);
+    for point in points {
+        let [px, py] = scaled(point);
+        assert_eq!(point.map(|value| value * 65536.0 % 1.0), [0.0, 0.0]);
+        assert_eq!((ex - sx) * (py - sy), (ey - sy) * (px - sx));
+    }
+
+    let points = grid(10, 5, [0.0, 0.0], 1.0);
+    assert_eq!(points.len(), 50);
+    assert_eq!(points.convex_hull().count(), 4);
+
+    let points = with_duplicates(1000, 4, [0.0, 0.0], [1.0, 1.0], 3);
+    let mut distinct: Vec<[u64; 2]> = points
+        .iter()
+        .map(|p| [p[0].to_bits(), p[1].to_bits()])
+        .collect();
+    distinct.sort();
+    distinct.dedup();
+    assert!(distinct.len() <= 4);
+}
@@ -0,0 +1,70 @@
+use planar_convex_hull::ConvexHull;
+use planar_convex_hull::generators::{on_circle, uniform_in_disk};
+use planar_convex_hull::gpu::GpuHull;
+
+/**
+Creates a [`GpuHull`] on the noop backend, which validates the shaders and
+all commands but does not execute them. The small storage buffer limit splits
+the input into chunks of 8192 points.
+ */
+fn noop_gpu() -> GpuHull {
+    let mut descriptor = wgpu::InstanceDescriptor::new_without_display_handle();
+    descriptor.backends = wgpu::Backends::NOOP;
+    descriptor.backend_options.noop = wgpu::NoopBackendOptions {
+        limits: Some(wgpu::Limits {
+            max_storage_buffer_binding_size: 8192 * 8,
+            ..wgpu::Limits::default()
+        }),
+        ..wgpu::NoopBackendOptions::enabled()
+    };
+    let instance = wgpu::Instance::new(descriptor);
+    let adapter = pollster::block_on(instance.request_adapter(&Default::default())).unwrap();
+    let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
+        required_limits: adapter.limits(),
+        ..Default::default()
+    }))
+    .unwrap();
+    return GpuHull::from_device(device, queue);
+}
+
+fn inputs() -> Vec<Vec<[f64; 2]>> {
+    let mut points = uniform_in_disk(40_000, [2e6, -1e6], 50.0, 3);
+    points.extend(on_circle(500, [2e6, -1e6], 50.0, 4));
+    points.extend_from_within(..1000);
+    points.push([f64::NAN, 0.0]);
+    points.push([0.0, f64::INFINITY]);
+    return vec![
+        Vec::new(),
+        vec![[f64::NAN, 1.0]],
+        vec![[1.0, 2.0], [1.0, 2.0]],
+        (0..20_000).map(|i| [i as f64, 2.0 * i as f64]).collect(),
+        points,
+    ];
+}
+
+#[test]
+fn test_gpu_hull_noop() {
+    let gpu = noop_gpu();
+    for points in inputs() {
+        let hull: Vec<_> = gpu.convex_hull(&points).unwrap().collect();
+        assert_eq!(
+            hull,
+            points.convex_hull_monotone_chain().collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn test_gpu_hull() {
+    // Not every machine running the tests has a GPU
+    let Ok(gpu) = GpuHull::new() else {
+        return;
+    };
+    for points in inputs() {
+        let hull: Vec<_> = gpu.convex_hull(&points).unwrap().collect();
+        assert_eq!(
+            hull,
+            points.convex_hull_monotone_chain().collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,2187 @@
+use std::collections::HashMap;
+
+use planar_convex_hull::{ConvexHull, Hull};
+
+#[test]
+fn test_hull_from_iter() {
+    let slice: &[[f64; 2]] = &[];
+    let hull = Hull::from(slice.convex_hull());
+    assert!(hull.is_empty());
+    assert_eq!(hull.len(), 0);
+
+    let slice = &[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0], [0.5, 0.5]];
+    let hull = Hull::from(slice.convex_hull());
+    assert_eq!(hull.len(), 4);
+    assert_eq!(
+        hull.iter().collect::<Vec<_>>(),
+        slice.convex_hull().collect::<Vec<_>>()
+    );
+
+    // The hull of a hull is the hull itself
+    assert_eq!(Hull::from(hull.convex_hull()), hull);
+}
+
+#[test]
+fn test_hull_combine() {
+    let points: Vec<[f64; 2]> = (0..200)
+        .map(|i| {
+            let angle = i as f64 * 0.1;
+            let radius = 1.0 + (i % 7) as f64;
+            [radius * angle.cos(), radius * angle.sin()]
+        })
+        .collect();
+    let full = Hull::from(points.convex_hull());
+
+    // Split into shards keyed by the global index and reduce in different orders
+    let shards: Vec<HashMap<usize, [f64; 2]>> = (0..4)
+        .map(|shard| {
+            points
+                .iter()
+                .copied()
+                .enumerate()
+                .filter(|(i, _)| i % 4 == shard)
+                .collect()
+        })
+        .collect();
+    let partials: Vec<Hull> = shards
+        .iter()
+        .map(|shard| Hull::from(shard.convex_hull()))
+        .collect();
+
+    let forward = partials[1..]
+        .iter()
+        .fold(partials[0].clone(), |acc, hull| acc.combine(hull));
+    let tree = partials[0]
+        .combine(&partials[3])
+        .combine(&partials[1].combine(&partials[2]));
+    assert_eq!(forward, full);
+    assert_eq!(tree, full);
+
+    // Combining with an empty hull is a no-op
+    assert_eq!(full.combine(&Hull::default()), full);
+}
+
+#[test]
+fn test_hull_combine_all() {
+    use planar_convex_hull::generators;
+
+    let points = generators::uniform_in_disk(1000, [-4.0, 7.0], 3.0, 5);
+    let full = Hull::from_vertices_unchecked(points.convex_hull_monotone_chain());
+
+    // Shards owning contiguous chunks of the points, with local keys
+    for size in [1, 7, 100, 1000] {
+        let partials = points.chunks(size).enumerate().map(|(shard, chunk)| {
+            return Hull::from_vertices_unchecked(chunk.to_vec().convex_hull_monotone_chain())
+                .map_keys(|key| shard * size + key);
+        });
+        assert_eq!(Hull::combine_all(partials), full);
+    }
+
+    assert!(Hull::combine_all(Vec::new()).is_empty());
+    assert_eq!(Hull::combine_all([full.clone()]), full);
+
+    // Identical corners of different shards
+    let shard = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]);
+    let combined = Hull::combine_all([shard.clone(), shard.clone().map_keys(|key| key + 3)]);
+    assert_eq!(combined.keys(), shard.keys());
+    assert!(combined.degeneracy().duplicates_on_hull);
+}
+
+#[test]
+fn test_hull_serde() {
+    let slice = &[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.1, 0.1]];
+    let hull = Hull::from(slice.convex_hull());
+    let json = serde_json::to_string(&hull).unwrap();
+    let deserialized: Hull = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, hull);
+
+    // The degeneracies of the input are kept
+    let hull = Hull::new(&[[0.0, 0.0], [1.0, 1.0], [1.0, 1.0]]);
+    let json = serde_json::to_string(&hull).unwrap();
+    let deserialized: Hull = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.degeneracy(), hull.degeneracy());
+
+    // Invalid hulls are rejected
+    for json in [
+        r#"{"keys":[0,1],"points":[[0.0,0.0],[1.0,0.0],[0.0,1.0]]}"#,
+        r#"{"keys":[0,1,2],"points":[[0.0,0.0],[0.0,1.0],[1.0,0.0]]}"#,
+        r#"{"keys":[0,1,2],"points":[[0.0,0.0],[0.5,0.0],[1.0,0.0]]}"#,
+        r#"{"keys":[0,1],"points":[[0.0,0.0],[0.0,0.0]]}"#,
+    ] {
+        assert!(serde_json::from_str::<Hull>(json).is_err());
+    }
+    let error = serde_json::from_str::<Hull>(r#"{"keys":[0],"points":[]}"#).unwrap_err();
+    assert!(error.to_string().contains("1 keys, but 0 points"));
+
+    // Without degeneracies
+    let json = r#"{"keys":[3,1,2],"points":[[1.0,0.0],[0.0,1.0],[0.0,0.0]]}"#;
+    let deserialized: Hull = serde_json::from_str(json).unwrap();
+    assert_eq!(deserialized.keys(), &[3, 1, 2]);
+}
+
+#[test]
+fn test_hull_degeneracy() {
+    use planar_convex_hull::{Degeneracy, generators};
+
+    let points = generators::uniform_in_disk(100, [0.0, 0.0], 1.0, 1);
+    assert_eq!(Hull::new(&points).degeneracy(), Degeneracy::default());
+
+    let empty: [[f64; 2]; 0] = [];
+    let degeneracy = Hull::new(&empty).degeneracy();
+    assert!(degeneracy.few_distinct_points);
+    assert!(!degeneracy.collinear && !degeneracy.coincident);
+
+    let coincident = [[1.0, 2.0], [1.0, 2.0], [f64::NAN, 0.0]];
+    let hull = Hull::new(&coincident);
+    assert_eq!(
+        hull.degeneracy(),
+        Degeneracy {
+            collinear: false,
+            coincident: true,
+            few_distinct_points: true,
+            duplicates_on_hull: true,
+        }
+    );
+
+    // Two distinct points
+    let degeneracy = Hull::new(&[[0.0, 0.0], [1.0, 0.0]]).degeneracy();
+    assert!(degeneracy.collinear && degeneracy.few_distinct_points);
+    assert!(!degeneracy.duplicates_on_hull);
+
+    // Many collinear points
+    let line = generators::collinear(100, [0.0, 0.0], [1.0, 2.0], 4);
+    let degeneracy = Hull::new(&line).degeneracy();
+    assert!(degeneracy.collinear && !degeneracy.few_distinct_points);
+
+    // Duplicates which are not part of the hull are ignored
+    let square = [
+        [0.0, 0.0],
+        [1.0, 0.0],
+        [1.0, 1.0],
+        [0.0, 1.0],
+        [0.5, 0.5],
+        [0.5, 0.5],
+    ];
+    assert!(!Hull::new(&square).degeneracy().any());
+    let mut with_duplicate = square.to_vec();
+    with_duplicate.push([-0.0, 1.0]);
+    let hull = Hull::new(&with_duplicate);
+    assert_eq!(hull.len(), 4);
+    assert!(hull.degeneracy().duplicates_on_hull);
+
+    // Conversion from an iterator only knows the corners
+    let hull = Hull::from(coincident.convex_hull());
+    assert!(hull.degeneracy().coincident);
+    assert!(!hull.degeneracy().duplicates_on_hull);
+
+    // The degeneracies of the input are not compared
+    let mut with_interior_duplicate = square.to_vec();
+    with_interior_duplicate.push([1.0, 1.0]);
+    let hull = Hull::new(&with_interior_duplicate);
+    assert!(hull.degeneracy().duplicates_on_hull);
+    assert_eq!(hull, Hull::new(&square));
+}
+
+#[test]
+fn test_hull_new_exact() {
+    // The floating-point algorithm replaces the corner [3, 3] with [3, 2]
+    let points = [[4.0, 1.0], [0.0, 4.0], [2.0, 3.0], [3.0, 3.0], [3.0, 2.0]];
+    let hull = Hull::new(&points);
+    assert_eq!(hull.keys(), &[0, 3, 1]);
+    assert_eq!(hull.points(), &[[4.0, 1.0], [3.0, 3.0], [0.0, 4.0]]);
+    assert!(hull.contains([3.0, 2.0]));
+    assert!(hull.contains_strictly([2.0, 3.0]));
+}
+
+#[test]
+fn test_hull_from_vertices() {
+    use planar_convex_hull::HullError;
+
+    // Hulls calculated by the crate are valid
+    let points = planar_convex_hull::generators::uniform_in_disk(1000, [0.0, 0.0], 1.0, 6);
+    let hull = Hull::from(points.convex_hull());
+    assert_eq!(Hull::from_vertices(hull.iter()), Ok(hull.clone()));
+    assert_eq!(Hull::from_vertices_unchecked(hull.iter()), hull);
+
+    // Trivial hulls
+    assert_eq!(Hull::from_vertices([]), Ok(Hull::default()));
+    let hull = Hull::from_vertices([(4, [1.0, 1.0])]).unwrap();
+    assert!(hull.degeneracy().coincident);
+    let hull = Hull::from_vertices([(4, [1.0, 1.0]), (2, [0.0, 0.0])]).unwrap();
+    assert!(hull.degeneracy().collinear);
+
+    // Invalid hulls
+    assert_eq!(
+        Hull::from_vertices([(0, [0.0, 0.0]), (1, [f64::NAN, 0.0])]),
+        Err(HullError::NonReal { position: 1 })
+    );
+    assert_eq!(
+        Hull::from_vertices([(0, [0.0, 0.0]), (1, [0.0, 0.0])]),
+        Err(HullError::Duplicate { position: 0 })
+    );
+    let reflex = [[0.0, 0.0], [2.0, 0.0], [1.0, 0.5], [2.0, 2.0], [0.0, 2.0]];
+    assert_eq!(
+        Hull::from_vertices(reflex.into_iter().enumerate()),
+        Err(HullError::NotConvex { position: 2 })
+    );
+    let pentagram: Vec<(usize, [f64; 2])> = (0..5)
+        .map(|i| {
+            let angle = (2 * i) as f64 * std::f64::consts::TAU / 5.0;
+            (i, [angle.cos(), angle.sin()])
+        })
+        .collect();
+    assert_eq!(
+        Hull::from_vertices(pentagram),
+        Err(HullError::SelfIntersecting)
+    );
+    assert_eq!(
+        HullError::SelfIntersecting.to_string(),
+        "the boundary winds around more than once"
+    );
+}
+
+#[test]
+fn test_hull_postcard_bincode() {
+    use planar_convex_hull::hull::DecodeError;
+    use planar_convex_hull::{HullError, generators};
+
+    // Round trip is bit-exact, including the degeneracies
+    let points = generators::uniform_in_disk(1000, [1e6, -1e6], 0.1, 8);
+    let hull = Hull::new(&points);
+    assert_eq!(Hull::from_postcard(&hull.to_postcard()), Ok(hull.clone()));
+    assert_eq!(Hull::from_bincode(&hull.to_bincode()), Ok(hull));
+
+    for points in [
+        vec![],
+        vec![[1.0, 1.0], [1.0, 1.0]],
+        vec![[0.0, 0.0], [1.0, 0.0]],
+    ] {
+        let hull = Hull::new(&points);
+        let decoded = Hull::from_postcard(&hull.to_postcard()).unwrap();
+        assert_eq!(decoded.degeneracy(), hull.degeneracy());
+        assert_eq!(decoded, hull);
+        let decoded = Hull::from_bincode(&hull.to_bincode()).unwrap();
+        assert_eq!(decoded.degeneracy(), hull.degeneracy());
+        assert_eq!(decoded, hull);
+    }
+
+    // Corrupted data
+    let hull = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]);
+    let bytes = hull.to_postcard();
+    assert_eq!(
+        Hull::from_postcard(&bytes[..5]),
+        Err(DecodeError::InvalidMagic)
+    );
+    assert_eq!(Hull::from_bincode(&bytes), Err(DecodeError::WrongEncoding));
+
+    let mut newer = bytes.clone();
+    newer[4] = 2;
+    assert_eq!(
+        Hull::from_postcard(&newer),
+        Err(DecodeError::UnsupportedVersion(2))
+    );
+
+    assert!(matches!(
+        Hull::from_postcard(&bytes[..bytes.len() - 1]),
+        Err(DecodeError::Malformed(_))
+    ));
+    let mut trailing = hull.to_bincode();
+    trailing.push(0);
+    assert!(matches!(
+        Hull::from_bincode(&trailing),
+        Err(DecodeError::Malformed(_))
+    ));
+
+    // Swap the last two vertices -> clockwise
+    let clockwise =
+        Hull::from_vertices_unchecked([(0, [1.0, 0.0]), (2, [0.0, 0.0]), (1, [0.0, 1.0])]);
+    for error in [
+        Hull::from_postcard(&clockwise.to_postcard()).unwrap_err(),
+        Hull::from_bincode(&clockwise.to_bincode()).unwrap_err(),
+    ] {
+        assert!(matches!(
+            error,
+            DecodeError::InvalidHull(HullError::NotConvex { .. })
+        ));
+        assert!(error.to_string().starts_with("invalid hull: "));
+    }
+}
+
+#[test]
+fn test_to_halfspaces() {
+    let points: Vec<[f64; 2]> = (0..500)
+        .map(|i| {
+            let angle = i as f64 * 0.37;
+            let radius = 1.0 + (i % 11) as f64 * 0.1;
+            [radius * angle.cos() + 3.0, radius * angle.sin() - 1.0]
+        })
+        .collect();
+    let hull = Hull::new(&points);
+    let halfspaces = hull.to_halfspaces();
+    assert_eq!(halfspaces.len(), hull.len());
+
+    for halfspace in halfspaces.iter() {
+        let norm = halfspace.normal[0].hypot(halfspace.normal[1]);
+        assert!((norm - 1.0).abs() < 1e-15);
+        assert!(points.iter().all(|point| halfspace.contains(*point)));
+    }
+    // The centroid of the corners is inside, points beyond the corners outside
+    let n = hull.len() as f64;
+    let center = hull.points().iter().fold([0.0, 0.0], |sum, point| {
+        [sum[0] + point[0] / n, sum[1] + point[1] / n]
+    });
+    assert!(
+        halfspaces
+            .iter()
+            .all(|halfspace| halfspace.contains(center))
+    );
+    for point in hull.points() {
+        let outside = [
+            point[0] + 1e-6 * (point[0] - center[0]),
+            point[1] + 1e-6 * (point[1] - center[1]),
+        ];
+        assert!(
+            !halfspaces
+                .iter()
+                .all(|halfspace| halfspace.contains(outside))
+        );
+    }
+
+    // Degenerate hulls
+    let contains = |hull: &Hull, point: [f64; 2]| {
+        return hull
+            .to_halfspaces()
+            .iter()
+            .all(|halfspace| halfspace.contains(point));
+    };
+    assert!(!contains(&Hull::default(), [0.0, 0.0]));
+
+    let single = Hull::new(&[[1.0, 2.0]]);
+    assert_eq!(single.to_halfspaces().len(), 4);
+    assert!(contains(&single, [1.0, 2.0]));
+    assert!(!contains(&single, [1.0, 2.1]));
+
+    let segment = Hull::new(&[[0.0, 0.0], [1.0, 1.0], [3.0, 3.0]]);
+    assert_eq!(segment.to_halfspaces().len(), 4);
+    assert!(contains(&segment, [0.0, 0.0]));
+    assert!(contains(&segment, [3.0, 3.0]));
+    assert!(contains(&segment, [1.5, 1.5]));
+    assert!(!contains(&segment, [1.5, 1.6]));
+    assert!(!contains(&segment, [3.1, 3.1]));
+    assert!(!contains(&segment, [-0.1, -0.1]));
+}
+
+#[test]
+fn test_polar_dual() {
+    let points = planar_convex_hull::generators::uniform_in_disk(300, [2.0, -1.0], 1.0, 3);
+    let hull = Hull::new(&points);
+    let center = [2.1, -0.9];
+    let dual = hull.polar_dual(center).unwrap();
+    assert_eq!(dual.len(), hull.len());
+
+    // Every hull point x and dual point y fulfill (y - c) · (x - c) <= 1, with
+    // equality for a corner of the dual and the corners of its edge
+    for (edge, y) in dual.iter() {
+        let y = [y[0] - center[0], y[1] - center[1]];
+        for x in points.iter() {
+            let x = [x[0] - center[0], x[1] - center[1]];
+            assert!(y[0] * x[0] + y[1] * x[1] <= 1.0 + 1e-12);
+        }
+        let x = hull.points()[edge];
+        let x = [x[0] - center[0], x[1] - center[1]];
+        assert!((y[0] * x[0] + y[1] * x[1] - 1.0).abs() < 1e-12);
+    }
+
+    let dual_of_dual = dual.polar_dual(center).unwrap();
+    assert_eq!(dual_of_dual.len(), hull.len());
+    for (a, b) in dual_of_dual.points().iter().zip(hull.points()) {
+        assert!((a[0] - b[0]).abs() < 1e-12 && (a[1] - b[1]).abs() < 1e-12);
+    }
+
+    // Center on a corner or outside
+    assert!(hull.polar_dual(hull.points()[0]).is_err());
+    assert!(hull.polar_dual([10.0, 0.0]).is_err());
+    assert_eq!(
+        Hull::new(&[[0.0, 0.0], [1.0, 1.0]]).polar_dual([0.5, 0.5]),
+        Err(planar_convex_hull::halfspace::PolarDualError::Degenerate)
+    );
+}
+
+#[test]
+fn test_turn_angles() {
+    let points = planar_convex_hull::generators::uniform_in_disk(1000, [0.0, 0.0], 1.0, 9);
+    let hull = Hull::new(&points);
+    let turns = hull.turn_angles();
+    assert_eq!(turns.len(), hull.len());
+    assert!(
+        turns
+            .iter()
+            .all(|(angle, _)| *angle > 0.0 && *angle < std::f64::consts::PI)
+    );
+    assert!(turns.windows(2).all(|pair| pair[0].1 < pair[1].1));
+    assert!((turns.last().unwrap().1 - std::f64::consts::TAU).abs() < 1e-12);
+
+    assert!(Hull::default().turn_angles().is_empty());
+    assert!(Hull::new(&[[1.0, 2.0]]).turn_angles().is_empty());
+}
+
+#[test]
+fn test_descriptors() {
+    use planar_convex_hull::generators;
+    use std::f64::consts::PI;
+
+    // Regular polygon with many corners
+    let circle = generators::on_circle(2000, [5.0, -3.0], 2.0, 1);
+    let descriptors = Hull::new(&circle).descriptors().unwrap();
+    assert!((descriptors.circularity - 1.0).abs() < 1e-3);
+    assert!(descriptors.eccentricity < 0.1);
+    assert!(descriptors.elongation < 1e-3);
+    assert!((descriptors.rectangularity - PI / 4.0).abs() < 1e-3);
+
+    // Rotated 3 x 1 rectangle
+    let (sin, cos) = 0.5f64.sin_cos();
+    let rectangle: Vec<[f64; 2]> = [[0.0, 0.0], [3.0, 0.0], [3.0, 1.0], [0.0, 1.0]]
+        .iter()
+        .map(|p| [1.0 + cos * p[0] - sin * p[1], 2.0 + sin * p[0] + cos * p[1]])
+        .collect();
+    let descriptors = Hull::new(&rectangle).descriptors().unwrap();
+    assert!((descriptors.circularity - PI * 12.0 / 64.0).abs() < 1e-12);
+    assert!((descriptors.eccentricity - (8.0f64 / 9.0).sqrt()).abs() < 1e-12);
+    assert!((descriptors.elongation - 2.0 / 3.0).abs() < 1e-12);
+    assert!((descriptors.rectangularity - 1.0).abs() < 1e-12);
+
+    // Invariance under translation and scaling, compared to a brute-force
+    // search of the minimum-area rectangle
+    for seed in 0..10 {
+        let points = generators::uniform_in_disk(50, [0.0, 0.0], 1.0, seed);
+        let hull = Hull::new(&points);
+        let corners = hull.points();
+        let mut min_rect = f64::INFINITY;
+        for i in 0..corners.len() {
+            let (a, b) = (corners[i], corners[(i + 1) % corners.len()]);
+            let length = (b[0] - a[0]).hypot(b[1] - a[1]);
+            let u = [(b[0] - a[0]) / length, (b[1] - a[1]) / length];
+            let along = corners.iter().map(|p| u[0] * p[0] + u[1] * p[1]);
+            let across = corners.iter().map(|p| u[0] * p[1] - u[1] * p[0]);
+            let extent = |values: &mut dyn Iterator<Item = f64>| {
+                let (min, max) = values
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+                        (min.min(v), max.max(v))
+                    });
+                return max - min;
+            };
+            min_rect = min_rect.min(extent(&mut along.clone()) * extent(&mut across.clone()));
+        }
+
+        let n = corners.len();
+        let area = 0.5
+            * (0..n)
+                .map(|i| {
+                    let (a, b) = (corners[i], corners[(i + 1) % n]);
+                    return a[0] * b[1] - a[1] * b[0];
+                })
+                .sum::<f64>();
+
+        let descriptors = hull.descriptors().unwrap();
+        assert!((descriptors.rectangularity - area / min_rect).abs() < 1e-12);
+        assert!(descriptors.rectangularity > 0.5 && descriptors.rectangularity < 1.0);
+
+        let transformed: Vec<[f64; 2]> = points
+            .iter()
+            .map(|p| [1e3 + 7.0 * p[0], -2e3 + 7.0 * p[1]])
+            .collect();
+        let other = Hull::new(&transformed).descriptors().unwrap();
+        assert!((descriptors.circularity - other.circularity).abs() < 1e-9);
+        assert!((descriptors.eccentricity - other.eccentricity).abs() < 1e-9);
+        assert!((descriptors.elongation - other.elongation).abs() < 1e-9);
+        assert!((descriptors.rectangularity - other.rectangularity).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_resolve() {
+    let points = vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.2, 0.2]];
+    let hull = Hull::new(&points);
+    let resolved: Vec<&[f64; 2]> = hull.resolve(&points).collect();
+    assert_eq!(resolved, vec![&[1.0, 0.0], &[0.0, 1.0], &[0.0, 0.0]]);
+    assert_eq!(hull.resolve(points.as_slice()).len(), 3);
+
+    let labels = ["a", "b", "c", "d"];
+    let labels: Vec<&str> = hull.resolve(&labels).copied().collect();
+    assert_eq!(labels, vec!["b", "c", "a"]);
+}
+
+#[test]
+fn test_hull_view() {
+    use planar_convex_hull::HullView;
+
+    let points = vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.2, 0.2]];
+    let view = HullView::new(&points);
+    assert_eq!(view.keys(), &[1, 2, 0]);
+    assert_eq!(
+        view.items().collect::<Vec<_>>(),
+        vec![&[1.0, 0.0], &[0.0, 1.0], &[0.0, 0.0]]
+    );
+    assert_eq!(view.items().len(), 3);
+    assert_eq!(view.item(2), &[0.0, 0.0]);
+    assert!(std::ptr::eq(view.collection(), &points));
+    assert_eq!(view.hull(), &Hull::new(&points));
+    assert_eq!(view.area(), 0.5);
+
+    // The items outlive the view
+    let first = {
+        let view = HullView::new(&points);
+        view.item(0)
+    };
+    assert_eq!(first, &[1.0, 0.0]);
+    assert_eq!(HullView::new(&points).into_hull(), Hull::new(&points));
+
+    // Collections without real points
+    let empty: Vec<[f64; 2]> = Vec::new();
+    let view = HullView::new(&empty);
+    assert!(view.is_empty());
+    assert_eq!(view.items().count(), 0);
+}
+
+#[test]
+fn test_area() {
+    let points = planar_convex_hull::generators::on_circle(1000, [1e6, 1e6], 1.0, 3);
+    let hull = Hull::new(&points);
+    let expected = std::f64::consts::PI;
+    assert!(hull.area() < expected && hull.area() > expected - 1e-3);
+
+    // Clockwise vertices give a negative area
+    let clockwise =
+        Hull::from_vertices_unchecked([(0, [0.0, 0.0]), (1, [0.0, 1.0]), (2, [1.0, 0.0])]);
+    assert_eq!(clockwise.area(), -0.5);
+    assert_eq!(Hull::new(&Vec::<[f64; 2]>::new()).area(), 0.0);
+}
+
+#[test]
+fn test_edges_and_perimeter() {
+    let hull = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    assert_eq!(hull.edges().len(), 4);
+    assert_eq!(hull.edges().last(), Some(([2.0, 0.0], [2.0, 2.0])));
+    assert_eq!(hull.perimeter(), 8.0);
+
+    let segment = Hull::new(&[[0.0, 0.0], [3.0, 4.0]]);
+    assert_eq!(segment.edges().len(), 2);
+    assert_eq!(segment.perimeter(), 10.0);
+
+    let point = Hull::new(&[[1.0, 1.0]]);
+    assert_eq!(point.edges().len(), 0);
+    assert_eq!(point.perimeter(), 0.0);
+}
+
+#[test]
+fn test_centroid() {
+    // Many corners on one side do not shift the centroid
+    let mut points = vec![[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]];
+    for i in 1..50 {
+        let angle = std::f64::consts::PI * i as f64 / 50.0;
+        points.push([1.0 + angle.sin() * 1e-3 + 1.0, 1.0 - angle.cos()]);
+    }
+    let centroid = Hull::new(&points).centroid().unwrap();
+    assert!((centroid[0] - 1.0).abs() < 1e-3 && (centroid[1] - 1.0).abs() < 1e-9);
+
+    // Far away from the origin
+    let square = Hull::new(&[
+        [1e8, 1e8],
+        [1e8 + 1.0, 1e8],
+        [1e8 + 1.0, 1e8 + 1.0],
+        [1e8, 1e8 + 1.0],
+    ]);
+    assert_eq!(square.centroid(), Some([1e8 + 0.5, 1e8 + 0.5]));
+
+    assert_eq!(
+        Hull::new(&[[3.0, 4.0], [3.0, 4.0]]).centroid(),
+        Some([3.0, 4.0])
+    );
+    assert_eq!(
+        Hull::new(&[[0.0, 4.0], [2.0, 0.0]]).centroid(),
+        Some([1.0, 2.0])
+    );
+}
+
+#[test]
+fn test_locate() {
+    use planar_convex_hull::PointLocation;
+
+    let points = planar_convex_hull::generators::on_circle(50, [0.0, 0.0], 1.0, 7);
+    let hull = Hull::new(&points);
+    for (key, point) in points.iter().enumerate() {
+        let expected = if hull.keys().contains(&key) {
+            PointLocation::OnBoundary
+        } else {
+            PointLocation::Inside
+        };
+        assert_eq!(hull.locate(*point), expected);
+    }
+    for (a, b) in hull.edges() {
+        assert_eq!(hull.locate(a), PointLocation::OnBoundary);
+        assert_eq!(
+            hull.locate([2.0 * b[0], 2.0 * b[1]]),
+            PointLocation::Outside
+        );
+    }
+    assert_eq!(hull.locate([0.0, 0.0]), PointLocation::Inside);
+    assert_eq!(hull.locate([f64::NAN, 0.0]), PointLocation::Outside);
+
+    // Edges through the first corner
+    let square = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    assert_eq!(square.keys()[0], 2);
+    assert_eq!(square.locate([1.0, 2.0]), PointLocation::OnBoundary);
+    assert_eq!(square.locate([2.0, 1.0]), PointLocation::OnBoundary);
+    assert_eq!(square.locate([3.0, 2.0]), PointLocation::Outside);
+    assert_eq!(square.locate([2.0, 3.0]), PointLocation::Outside);
+    assert!(!square.contains_strictly([0.0, 1.0]));
+    assert!(square.contains([0.0, 1.0]));
+
+    // Degenerate hulls
+    let segment = Hull::new(&[[0.0, 0.0], [2.0, 2.0]]);
+    assert_eq!(segment.locate([1.0, 1.0]), PointLocation::OnBoundary);
+    assert_eq!(segment.locate([3.0, 3.0]), PointLocation::Outside);
+    let point = Hull::new(&[[1.0, 1.0]]);
+    assert!(point.contains([1.0, 1.0]));
+    assert!(!point.contains_strictly([1.0, 1.0]));
+    assert!(!Hull::new(&Vec::<[f64; 2]>::new()).contains([0.0, 0.0]));
+}
+
+#[test]
+fn test_locate_all() {
+    let hull = Hull::new(&planar_convex_hull::generators::on_circle(
+        100,
+        [0.0, 0.0],
+        1.0,
+        8,
+    ));
+    let mut samples = planar_convex_hull::generators::uniform_in_disk(100_000, [0.0, 0.0], 1.5, 9);
+    samples.extend_from_slice(hull.points());
+    samples.push([f64::NAN, f64::NAN]);
+
+    let locations = hull.locate_all(&samples);
+    assert_eq!(locations.len(), samples.len());
+    for (sample, location) in samples.iter().zip(locations.iter()) {
+        assert_eq!(hull.locate(*sample), *location);
+    }
+    assert!(hull.locate_all(&[]).is_empty());
+}
+
+#[test]
+fn test_closest_point() {
+    let hull = Hull::new(&planar_convex_hull::generators::on_circle(
+        200,
+        [1.0, -2.0],
+        3.0,
+        10,
+    ));
+    let queries = planar_convex_hull::generators::uniform_in_disk(500, [1.0, -2.0], 6.0, 11);
+    for query in queries {
+        let (closest, distance) = hull.closest_point(query).unwrap();
+        assert!(hull.contains(closest) || hull.closest_point(closest).unwrap().1 < 1e-12);
+        assert!((distance - (closest[0] - query[0]).hypot(closest[1] - query[1])).abs() < 1e-12);
+        if hull.contains(query) {
+            assert_eq!((closest, distance), (query, 0.0));
+        } else {
+            // No corner is closer than the projection
+            for corner in hull.points() {
+                let corner_distance = (corner[0] - query[0]).hypot(corner[1] - query[1]);
+                assert!(distance <= corner_distance + 1e-12);
+            }
+        }
+    }
+
+    let segment = Hull::new(&[[0.0, 0.0], [4.0, 0.0]]);
+    assert_eq!(segment.closest_point([1.0, 2.0]), Some(([1.0, 0.0], 2.0)));
+    assert_eq!(segment.closest_point([-3.0, 4.0]), Some(([0.0, 0.0], 5.0)));
+    let point = Hull::new(&[[1.0, 1.0]]);
+    assert_eq!(point.closest_point([4.0, 5.0]), Some(([1.0, 1.0], 5.0)));
+    assert_eq!(
+        Hull::new(&Vec::<[f64; 2]>::new()).closest_point([0.0, 0.0]),
+        None
+    );
+}
+
+#[test]
+fn test_signed_distance() {
+    let hull = Hull::new(&[[0.0, 0.0], [4.0, 0.0], [0.0, 3.0]]);
+    assert_eq!(hull.signed_distance([0.0, 0.0]), 0.0);
+    assert_eq!(hull.signed_distance([-1.0, 1.0]), 1.0);
+    assert_eq!(hull.signed_distance([8.0, 0.0]), 4.0);
+    assert!((hull.signed_distance([8.0, 6.0]) - 7.2).abs() < 1e-12);
+    assert_eq!(hull.signed_distance([1.0, 0.5]), -0.5);
+    assert!((hull.signed_distance([1.0, 1.0]) + 1.0).abs() < 1e-12);
+
+    // Consistent with the classification
+    let samples = planar_convex_hull::generators::uniform_in_disk(1000, [1.0, 1.0], 5.0, 12);
+    for sample in samples {
+        let distance = hull.signed_distance(sample);
+        assert_eq!(distance < 0.0, hull.contains_strictly(sample));
+        assert_eq!(
+            distance.abs(),
+            hull.closest_point(sample).unwrap().1.max(distance.abs())
+        );
+    }
+
+    let segment = Hull::new(&[[0.0, 0.0], [2.0, 0.0]]);
+    assert_eq!(segment.signed_distance([1.0, 0.0]), 0.0);
+    assert_eq!(segment.signed_distance([1.0, -1.0]), 1.0);
+    assert_eq!(
+        Hull::new(&Vec::<[f64; 2]>::new()).signed_distance([0.0, 0.0]),
+        f64::INFINITY
+    );
+}
+
+#[test]
+fn test_extreme_point_in_direction() {
+    let hull = Hull::new(&planar_convex_hull::generators::on_circle(
+        500,
+        [3.0, 4.0],
+        2.0,
+        13,
+    ));
+    for degree in 0..720 {
+        let angle = (degree as f64 / 2.0).to_radians();
+        let direction = [angle.cos(), angle.sin()];
+        let dot = |point: &[f64; 2]| point[0] * direction[0] + point[1] * direction[1];
+        let (key, point) = hull.extreme_point_in_direction(direction).unwrap();
+        assert_eq!(
+            hull.points()[hull.keys().iter().position(|k| *k == key).unwrap()],
+            point
+        );
+        let best = hull
+            .points()
+            .iter()
+            .map(dot)
+            .fold(f64::NEG_INFINITY, f64::max);
+        assert!(dot(&point) >= best - 1e-12);
+    }
+
+    // Ties on axis-aligned edges
+    let square = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    assert_eq!(square.keys(), &[2, 3, 0, 1]);
+    assert_eq!(square.extreme_point_in_direction([1.0, 0.0]).unwrap().0, 1);
+    assert_eq!(square.extreme_point_in_direction([0.0, 1.0]).unwrap().0, 2);
+    assert_eq!(square.extreme_point_in_direction([-1.0, 0.0]).unwrap().0, 3);
+    assert_eq!(square.extreme_point_in_direction([0.0, -1.0]).unwrap().0, 0);
+    assert_eq!(square.extreme_point_in_direction([0.0, 0.0]).unwrap().0, 2);
+
+    let segment = Hull::new(&[[0.0, 0.0], [2.0, 1.0]]);
+    assert_eq!(
+        segment.extreme_point_in_direction([-1.0, 0.0]),
+        Some((0, [0.0, 0.0]))
+    );
+    assert_eq!(
+        segment.extreme_point_in_direction([1.0, 0.0]),
+        Some((1, [2.0, 1.0]))
+    );
+    assert_eq!(segment.extreme_point_in_direction([f64::NAN, 0.0]), None);
+    assert_eq!(
+        Hull::new(&Vec::<[f64; 2]>::new()).extreme_point_in_direction([1.0, 0.0]),
+        None
+    );
+}
+
+#[test]
+fn test_tangents_from() {
+    use planar_convex_hull::predicates::orientation;
+    use std::cmp::Ordering;
+
+    let mut points = planar_convex_hull::generators::on_circle(300, [0.0, 0.0], 1.0, 14);
+    points.extend([[2.0, 0.0], [0.0, 2.0], [-2.0, 0.0], [0.0, -2.0]]);
+    let hull = Hull::new(&points);
+
+    let mut queries = planar_convex_hull::generators::uniform_in_disk(2000, [0.0, 0.0], 5.0, 15);
+    // Collinear with the edges of the outer square
+    queries.extend([
+        [3.0, -1.0],
+        [-1.0, 3.0],
+        [-3.0, 1.0],
+        [1.0, -3.0],
+        [3.0, 4.0],
+    ]);
+    for query in queries {
+        let Some([left, right]) = hull.tangents_from(query) else {
+            assert!(hull.contains(query));
+            continue;
+        };
+        for corner in hull.points() {
+            assert_ne!(orientation(query, left.1, *corner), Ordering::Greater);
+            assert_ne!(orientation(query, right.1, *corner), Ordering::Less);
+            // The nearer one of collinear corners is chosen
+            for tangent in [left.1, right.1] {
+                if orientation(query, tangent, *corner) == Ordering::Equal {
+                    let distance = |p: [f64; 2]| (p[0] - query[0]).hypot(p[1] - query[1]);
+                    assert!(distance(tangent) <= distance(*corner));
+                }
+            }
+        }
+    }
+
+    let segment = Hull::new(&[[0.0, 0.0], [2.0, 0.0]]);
+    assert_eq!(
+        segment.tangents_from([1.0, 1.0]).map(|[l, r]| (l.0, r.0)),
+        Some((1, 0))
+    );
+    assert_eq!(
+        segment.tangents_from([1.0, -1.0]).map(|[l, r]| (l.0, r.0)),
+        Some((0, 1))
+    );
+    assert_eq!(
+        segment.tangents_from([3.0, 0.0]).map(|[l, r]| (l.0, r.0)),
+        Some((1, 1))
+    );
+    assert_eq!(segment.tangents_from([1.0, 0.0]), None);
+    let point = Hull::new(&[[1.0, 1.0]]);
+    assert_eq!(point.tangents_from([0.0, 0.0]), Some([(0, [1.0, 1.0]); 2]));
+}
+
+#[test]
+fn test_diameter() {
+    for seed in 0..20 {
+        let points = planar_convex_hull::generators::uniform_in_disk(200, [1.0, -2.0], 3.0, seed);
+        let hull = Hull::new(&points);
+        let mut expected = 0.0f64;
+        for a in hull.points() {
+            for b in hull.points() {
+                expected = expected.max((a[0] - b[0]).hypot(a[1] - b[1]));
+            }
+        }
+        let ([a, b], distance) = hull.diameter().unwrap();
+        assert_eq!(distance, expected);
+        assert_eq!(distance, (a.1[0] - b.1[0]).hypot(a.1[1] - b.1[1]));
+        assert_eq!(points[a.0], a.1);
+        assert_eq!(points[b.0], b.1);
+    }
+
+    // Regular polygons have many antipodal pairs of equal length
+    let square = Hull::new(&[[1.0, 1.0], [-1.0, 1.0], [-1.0, -1.0], [1.0, -1.0]]);
+    assert_eq!(square.diameter().unwrap().1, 8.0f64.sqrt());
+    let circle = Hull::new(&planar_convex_hull::generators::on_circle(
+        64,
+        [0.0, 0.0],
+        1.0,
+        3,
+    ));
+    assert!((circle.diameter().unwrap().1 - 2.0).abs() < 1e-2);
+
+    let segment = Hull::new(&[[0.0, 0.0], [3.0, 4.0]]);
+    assert_eq!(
+        segment.diameter(),
+        Some(([(1, [3.0, 4.0]), (0, [0.0, 0.0])], 5.0))
+    );
+    let point = Hull::new(&[[1.0, 1.0]]);
+    assert_eq!(point.diameter(), Some(([(0, [1.0, 1.0]); 2], 0.0)));
+    assert_eq!(Hull::new(&Vec::<[f64; 2]>::new()).diameter(), None);
+}
+
+#[test]
+fn test_width() {
+    for seed in 0..20 {
+        let points = planar_convex_hull::generators::uniform_in_disk(200, [1.0, -2.0], 3.0, seed);
+        let hull = Hull::new(&points);
+        let corners = hull.points();
+        let n = corners.len();
+
+        // Brute force: the largest distance from each edge line
+        let mut expected = f64::INFINITY;
+        for i in 0..n {
+            let (a, b) = (corners[i], corners[(i + 1) % n]);
+            let length = (b[0] - a[0]).hypot(b[1] - a[1]);
+            let height = corners
+                .iter()
+                .map(|p| ((b[0] - a[0]) * (p[1] - a[1]) - (b[1] - a[1]) * (p[0] - a[0])) / length)
+                .fold(0.0, f64::max);
+            expected = expected.min(height);
+        }
+
+        let width = hull.width().unwrap();
+        assert!((width.distance - expected).abs() < 1e-12);
+        let [(start_key, start), (end_key, end)] = width.edge;
+        assert_eq!(points[start_key], start);
+        assert_eq!(points[end_key], end);
+        assert_eq!(points[width.corner.0], width.corner.1);
+        let length = (end[0] - start[0]).hypot(end[1] - start[1]);
+        let corner = width.corner.1;
+        let height = ((end[0] - start[0]) * (corner[1] - start[1])
+            - (end[1] - start[1]) * (corner[0] - start[0]))
+            / length;
+        assert!((height - width.distance).abs() < 1e-12);
+    }
+
+    let square = Hull::new(&[[1.0, 1.0], [-1.0, 1.0], [-1.0, -1.0], [1.0, -1.0]]);
+    let width = square.width().unwrap();
+    assert_eq!(width.distance, 2.0);
+    assert_eq!(width.edge, [(0, [1.0, 1.0]), (1, [-1.0, 1.0])]);
+    assert_eq!(Hull::new(&[[1.0, 1.0]]).width(), None);
+}
+
+#[test]
+fn test_min_area_rect() {
+    for seed in 0..20 {
+        let points = planar_convex_hull::generators::uniform_in_disk(200, [1.0, -2.0], 3.0, seed);
+        let hull = Hull::new(&points);
+        let rectangle = hull.min_area_rect().unwrap();
+        let [a, b, c, d] = rectangle.corners;
+
+        // Consistency of the fields
+        let side = |p: [f64; 2], q: [f64; 2]| (q[0] - p[0]).hypot(q[1] - p[1]);
+        assert!((side(a, b) - rectangle.sides[0]).abs() < 1e-12);
+        assert!((side(b, c) - rectangle.sides[1]).abs() < 1e-12);
+        assert!((side(c, d) - rectangle.sides[0]).abs() < 1e-12);
+        assert!((rectangle.angle - (b[1] - a[1]).atan2(b[0] - a[0])).abs() < 1e-12);
+
+        // Flush with an edge and enclosing all points
+        let distance = |p: &[f64; 2]| {
+            return ((b[0] - a[0]) * (p[1] - a[1]) - (b[1] - a[1]) * (p[0] - a[0])).abs()
+                / rectangle.sides[0];
+        };
+        assert!(hull.points().iter().filter(|p| distance(p) < 1e-12).count() >= 2);
+        let rectangle_hull = Hull::new(&[a, b, c, d]);
+        for point in hull.points() {
+            assert!(rectangle_hull.signed_distance(*point) < 1e-12);
+        }
+
+        // No rotation of the axis-aligned bounding box is smaller
+        for step in 0..360 {
+            let angle = f64::from(step).to_radians() * 0.25;
+            let (sin, cos) = angle.sin_cos();
+            let extent = |axis: [f64; 2]| {
+                let values = hull
+                    .points()
+                    .iter()
+                    .map(|p| p[0] * axis[0] + p[1] * axis[1]);
+                let max = values.clone().fold(f64::NEG_INFINITY, f64::max);
+                let min = values.fold(f64::INFINITY, f64::min);
+                return max - min;
+            };
+            let area = extent([cos, sin]) * extent([-sin, cos]);
+            assert!(rectangle.area() <= area + 1e-12);
+        }
+    }
+
+    let rectangle = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 1.0], [0.0, 1.0], [1.0, 0.5]])
+        .min_area_rect()
+        .unwrap();
+    assert_eq!(rectangle.area(), 2.0);
+    assert_eq!(rectangle.perimeter(), 6.0);
+    assert_eq!(Hull::new(&[[0.0, 0.0], [2.0, 0.0]]).min_area_rect(), None);
+}
+
+#[test]
+fn test_min_perimeter_rect() {
+    for seed in 0..20 {
+        let points = planar_convex_hull::generators::uniform_in_disk(200, [1.0, -2.0], 3.0, seed);
+        let hull = Hull::new(&points);
+        let rectangle = hull.min_perimeter_rect().unwrap();
+        assert!(rectangle.perimeter() <= hull.min_area_rect().unwrap().perimeter());
+        assert!(rectangle.area() >= hull.min_area_rect().unwrap().area());
+
+        let rectangle_hull = Hull::new(&rectangle.corners);
+        for point in hull.points() {
+            assert!(rectangle_hull.signed_distance(*point) < 1e-12);
+        }
+
+        // No rotation of the axis-aligned bounding box is smaller
+        for step in 0..360 {
+            let angle = f64::from(step).to_radians() * 0.25;
+            let (sin, cos) = angle.sin_cos();
+            let extent = |axis: [f64; 2]| {
+                let values = hull
+                    .points()
+                    .iter()
+                    .map(|p| p[0] * axis[0] + p[1] * axis[1]);
+                let max = values.clone().fold(f64::NEG_INFINITY, f64::max);
+                let min = values.fold(f64::INFINITY, f64::min);
+                return max - min;
+            };
+            let perimeter = 2.0 * (extent([cos, sin]) + extent([-sin, cos]));
+            assert!(rectangle.perimeter() <= perimeter + 1e-12);
+        }
+    }
+    assert_eq!(Hull::new(&[[0.0, 0.0]]).min_perimeter_rect(), None);
+}
+
+#[test]
+fn test_antipodal_pairs() {
+    for seed in 0..10 {
+        let points = planar_convex_hull::generators::uniform_in_disk(100, [1.0, -2.0], 3.0, seed);
+        let hull = Hull::new(&points);
+        let corners = hull.points();
+        let n = corners.len();
+
+        // Brute force: two corners are antipodal if the range of outer
+        // normal angles of one corner overlaps the flipped range of the other
+        use std::f64::consts::{PI, TAU};
+        let normal = |k: usize| {
+            let (a, b) = (corners[k % n], corners[(k + 1) % n]);
+            return (a[0] - b[0]).atan2(b[1] - a[1]);
+        };
+        let cone = |k: usize, offset: f64| [normal(k + n - 1) + offset, normal(k) + offset];
+        let contains = |[start, end]: [f64; 2], angle: f64| {
+            return (angle - start).rem_euclid(TAU) <= (end - start).rem_euclid(TAU);
+        };
+        let mut expected = Vec::new();
+        for i in 0..n {
+            for j in i + 1..n {
+                let (a, b) = (cone(i, 0.0), cone(j, PI));
+                if contains(a, b[0]) || contains(b, a[0]) {
+                    expected.push([hull.keys()[i], hull.keys()[j]]);
+                }
+            }
+        }
+        let pairs: Vec<[usize; 2]> = hull.antipodal_pairs().map(|[a, b]| [a.0, b.0]).collect();
+        assert_eq!(pairs, expected);
+        for [a, b] in hull.antipodal_pairs() {
+            assert_eq!(points[a.0], a.1);
+            assert_eq!(points[b.0], b.1);
+        }
+    }
+
+    let segment = Hull::new(&[[0.0, 0.0], [2.0, 0.0]]);
+    assert_eq!(
+        segment.antipodal_pairs().collect::<Vec<_>>(),
+        [[(1, [2.0, 0.0]), (0, [0.0, 0.0])]]
+    );
+    assert_eq!(Hull::new(&[[0.0, 0.0]]).antipodal_pairs().len(), 0);
+}
+
+#[test]
+fn test_min_enclosing_circle() {
+    for seed in 0..20 {
+        let points = planar_convex_hull::generators::uniform_in_disk(500, [1.0, -2.0], 3.0, seed);
+        let hull = Hull::new(&points);
+        let circle = hull.min_enclosing_circle().unwrap();
+        for point in points.iter() {
+            assert!(circle.contains(*point));
+        }
+
+        // Optimal: at least two corners on the circle, and if there are only
+        // two, they are opposite. Otherwise, the corners on the circle must not
+        // fit into a half circle.
+        let on_circle: Vec<[f64; 2]> = hull
+            .points()
+            .iter()
+            .copied()
+            .filter(|p| {
+                let d = (p[0] - circle.center[0]).hypot(p[1] - circle.center[1]);
+                return circle.radius - d < 1e-9;
+            })
+            .collect();
+        assert!(on_circle.len() >= 2);
+        let mut angles: Vec<f64> = on_circle
+            .iter()
+            .map(|p| (p[1] - circle.center[1]).atan2(p[0] - circle.center[0]))
+            .collect();
+        angles.sort_by(f64::total_cmp);
+        let largest_gap = angles.windows(2).map(|w| w[1] - w[0]).fold(
+            angles[0] + std::f64::consts::TAU - angles[angles.len() - 1],
+            f64::max,
+        );
+        assert!(largest_gap <= std::f64::consts::PI + 1e-6);
+    }
+
+    // Many cocircular corners
+    let points = planar_convex_hull::generators::on_circle(200, [3.0, 4.0], 2.0, 8);
+    let circle = Hull::new(&points).min_enclosing_circle().unwrap();
+    assert!((circle.center[0] - 3.0).abs() < 1e-2 && (circle.center[1] - 4.0).abs() < 1e-2);
+    assert!(circle.radius <= 2.0 + 1e-12);
+
+    let segment = Hull::new(&[[0.0, 0.0], [4.0, 0.0], [1.0, 0.0]]);
+    let circle = segment.min_enclosing_circle().unwrap();
+    assert_eq!((circle.center, circle.radius), ([2.0, 0.0], 2.0));
+    let point = Hull::new(&[[1.0, 1.0]]).min_enclosing_circle().unwrap();
+    assert_eq!((point.center, point.radius), ([1.0, 1.0], 0.0));
+    assert_eq!(
+        Hull::new(&Vec::<[f64; 2]>::new()).min_enclosing_circle(),
+        None
+    );
+}
+
+#[test]
+fn test_max_inscribed_circle() {
+    for seed in 0..20 {
+        let points = planar_convex_hull::generators::uniform_in_disk(100, [1.0, -2.0], 3.0, seed);
+        let hull = Hull::new(&points);
+        let circle = hull.max_inscribed_circle().unwrap();
+
+        // Inside the hull and touching its boundary
+        let depth = |point: [f64; 2]| -hull.signed_distance(point);
+        assert!((depth(circle.center) - circle.radius).abs() < 1e-9);
+
+        // No sampled point is deeper inside
+        let samples = planar_convex_hull::generators::uniform_in_disk(5000, [1.0, -2.0], 3.0, 99);
+        for sample in samples {
+            assert!(depth(sample) <= circle.radius + 1e-9);
+        }
+    }
+
+    // Rectangle: the center is anywhere on the middle segment
+    let rectangle = Hull::new(&[[0.0, 0.0], [4.0, 0.0], [4.0, 1.0], [0.0, 1.0]]);
+    let circle = rectangle.max_inscribed_circle().unwrap();
+    assert!((circle.radius - 0.5).abs() < 1e-12);
+    assert!((circle.center[1] - 0.5).abs() < 1e-12);
+    assert!(circle.center[0] >= 0.5 - 1e-12 && circle.center[0] <= 3.5 + 1e-12);
+
+    // Regular polygon
+    let points = planar_convex_hull::generators::on_circle(500, [0.0, 0.0], 1.0, 4);
+    let circle = Hull::new(&points).max_inscribed_circle().unwrap();
+    assert!(circle.center[0].abs() < 1e-2 && circle.center[1].abs() < 1e-2);
+    assert!(circle.radius > 0.98);
+
+    let segment = Hull::new(&[[0.0, 0.0], [4.0, 0.0]]);
+    assert_eq!(segment.max_inscribed_circle(), None);
+}
+
+#[test]
+fn test_largest_inscribed_triangle() {
+    for seed in 0..20 {
+        let points = planar_convex_hull::generators::uniform_in_disk(300, [1.0, -2.0], 3.0, seed);
+        let hull = Hull::new(&points);
+        let corners = hull.points();
+        let n = corners.len();
+        let area = |a: [f64; 2], b: [f64; 2], c: [f64; 2]| {
+            return 0.5 * ((b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]));
+        };
+        let mut expected = 0.0f64;
+        for i in 0..n {
+            for j in i + 1..n {
+                for k in j + 1..n {
+                    expected = expected.max(area(corners[i], corners[j], corners[k]));
+                }
+            }
+        }
+
+        let (triangle, value) = hull.largest_inscribed_triangle().unwrap();
+        assert!((value - expected).abs() < 1e-12);
+        assert_eq!(value, area(triangle[0].1, triangle[1].1, triangle[2].1));
+        let positions = triangle.map(|(key, _)| hull.keys().iter().position(|k| *k == key));
+        assert!(positions[0] < positions[1] && positions[1] < positions[2]);
+        for (key, point) in triangle {
+            assert_eq!(points[key], point);
+        }
+    }
+
+    let triangle = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]);
+    assert_eq!(
+        triangle.largest_inscribed_triangle(),
+        Some(([(1, [1.0, 0.0]), (2, [0.0, 1.0]), (0, [0.0, 0.0])], 0.5))
+    );
+    let segment = Hull::new(&[[0.0, 0.0], [4.0, 0.0]]);
+    assert_eq!(segment.largest_inscribed_triangle(), None);
+}
+
+#[test]
+fn test_simplify() {
+    for seed in 0..10 {
+        let points = planar_convex_hull::generators::uniform_in_disk(1000, [1.0, -2.0], 3.0, seed);
+        let hull = Hull::new(&points);
+        let mut previous_area = hull.area();
+        for max_corners in (3..hull.len()).rev() {
+            let simplified = hull.simplify(max_corners).unwrap();
+            assert!(simplified.len() <= max_corners);
+            assert_eq!(simplified.keys(), (0..simplified.len()).collect::<Vec<_>>());
+            for point in hull.points() {
+                assert!(simplified.signed_distance(*point) < 1e-9);
+            }
+            // Coarser approximations are not smaller
+            assert!(simplified.area() >= previous_area - 1e-9);
+            previous_area = simplified.area();
+        }
+        assert_eq!(hull.simplify(hull.len()).unwrap().points(), hull.points());
+        assert_eq!(hull.simplify(2), None);
+    }
+
+    // Parallelogram to triangle
+    let parallelogram = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [3.0, 1.0], [1.0, 1.0]]);
+    let triangle = parallelogram.simplify(3).unwrap();
+    assert_eq!(triangle.len(), 3);
+    assert_eq!(triangle.area(), 2.0 * parallelogram.area());
+    for point in parallelogram.points() {
+        assert!(triangle.signed_distance(*point) <= 0.0);
+    }
+
+    let segment = Hull::new(&[[0.0, 0.0], [2.0, 0.0]]);
+    assert_eq!(segment.simplify(2).unwrap().points(), segment.points());
+    assert_eq!(segment.simplify(1), None);
+}
+
+#[test]
+fn test_offset() {
+    use planar_convex_hull::Join;
+
+    for seed in 0..10 {
+        let points = planar_convex_hull::generators::uniform_in_disk(200, [1.0, -2.0], 3.0, seed);
+        let hull = Hull::new(&points);
+        let samples = planar_convex_hull::generators::uniform_in_disk(2000, [1.0, -2.0], 5.0, 77);
+
+        // Round joins: contains the exact dilation and deviates at most by
+        // the tolerance
+        let tolerance = 1e-3;
+        let rounded = hull.offset(0.5, Join::Round { tolerance });
+        assert_eq!(rounded.keys(), (0..rounded.len()).collect::<Vec<_>>());
+        for sample in samples.iter() {
+            let distance = hull.signed_distance(*sample);
+            if distance <= 0.5 {
+                assert!(rounded.contains(*sample));
+            } else if distance > 0.5 + tolerance + 1e-12 {
+                assert!(!rounded.contains(*sample));
+            }
+        }
+
+        // Miter joins: the edges are shifted by the distance
+        let mitered = hull.offset(0.5, Join::Miter);
+        assert_eq!(mitered.len(), hull.len());
+        for (corner, mitered_corner) in hull.points().iter().zip(mitered.points()) {
+            assert!(hull.signed_distance(*mitered_corner) >= 0.5 - 1e-12);
+            assert!(mitered.signed_distance(*corner) <= -0.5 + 1e-12);
+        }
+
+        // Erosion: the points at least the distance inside the hull
+        let eroded = hull.offset(-0.5, Join::Miter);
+        for sample in samples.iter() {
+            let distance = hull.signed_distance(*sample);
+            if distance < -0.5 - 1e-9 {
+                assert!(eroded.contains(*sample));
+            } else if distance > -0.5 + 1e-9 {
+                assert!(!eroded.contains(*sample));
+            }
+        }
+        let radius = hull.max_inscribed_circle().unwrap().radius;
+        assert!(hull.offset(-radius * 0.999, Join::Miter).len() >= 2);
+        assert!(hull.offset(-radius * 1.001, Join::Miter).is_empty());
+    }
+
+    // Degenerate hulls
+    let point = Hull::new(&[[1.0, 1.0]]);
+    assert_eq!(point.offset(1.0, Join::Miter).area(), 4.0);
+    let circle = point.offset(1.0, Join::Round { tolerance: 1e-4 });
+    assert!(circle.area() > std::f64::consts::PI && circle.area() < std::f64::consts::PI + 1e-2);
+    let segment = Hull::new(&[[0.0, 0.0], [2.0, 0.0]]);
+    assert_eq!(segment.offset(1.0, Join::Miter).area(), 8.0);
+    let stadium = segment.offset(1.0, Join::Round { tolerance: 1e-4 });
+    assert!(stadium.area() > 4.0 + std::f64::consts::PI);
+    assert!(stadium.area() < 4.0 + std::f64::consts::PI + 1e-2);
+    assert!(segment.offset(-1.0, Join::Miter).is_empty());
+    assert!(
+        Hull::new(&Vec::<[f64; 2]>::new())
+            .offset(1.0, Join::Miter)
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_minkowski_sum() {
+    use planar_convex_hull::ConvexHull;
+
+    let brute_force = |a: &Hull, b: &Hull| {
+        let mut sums = Vec::new();
+        for p in a.points() {
+            for q in b.points() {
+                sums.push([p[0] + q[0], p[1] + q[1]]);
+            }
+        }
+        return Hull::from(sums.convex_hull_exact());
+    };
+    let assert_same = |sum: &Hull, expected: &Hull| {
+        assert_eq!(sum.len(), expected.len());
+        for (p, q) in sum.points().iter().zip(expected.points()) {
+            assert!((p[0] - q[0]).abs() < 1e-12 && (p[1] - q[1]).abs() < 1e-12);
+        }
+    };
+
+    for seed in 0..20 {
+        let a = Hull::new(&planar_convex_hull::generators::uniform_in_disk(
+            100,
+            [1.0, -2.0],
+            3.0,
+            seed,
+        ));
+        let b = Hull::new(&planar_convex_hull::generators::uniform_in_rectangle(
+            50,
+            [0.0, 0.0],
+            [1.0, 2.0],
+            seed + 100,
+        ));
+        let sum = a.minkowski_sum(&b);
+        assert_eq!(sum.keys(), (0..sum.len()).collect::<Vec<_>>());
+        assert_same(&sum, &brute_force(&a, &b));
+        assert_same(&b.minkowski_sum(&a), &sum);
+    }
+
+    // Degenerate hulls
+    let square = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+    let point = Hull::new(&[[2.0, 3.0]]);
+    let segment = Hull::new(&[[0.0, 0.0], [1.0, 1.0]]);
+    assert_eq!(
+        square.minkowski_sum(&point).points(),
+        &[[3.0, 4.0], [2.0, 4.0], [2.0, 3.0], [3.0, 3.0]]
+    );
+    assert_same(
+        &square.minkowski_sum(&segment),
+        &brute_force(&square, &segment),
+    );
+    assert_same(
+        &segment.minkowski_sum(&segment),
+        &brute_force(&segment, &segment),
+    );
+    assert_eq!(point.minkowski_sum(&point).points(), &[[4.0, 6.0]]);
+    assert!(
+        square
+            .minkowski_sum(&Hull::new(&Vec::<[f64; 2]>::new()))
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_distance_to() {
+    use planar_convex_hull::generators::{uniform_in_disk, uniform_in_rectangle};
+
+    // Brute force: the minimum distance between the corners of one hull and
+    // the edges of the other one, if the hulls are disjoint
+    let segment_distance = |p: [f64; 2], a: [f64; 2], b: [f64; 2]| {
+        let edge = [b[0] - a[0], b[1] - a[1]];
+        let length = edge[0] * edge[0] + edge[1] * edge[1];
+        let t = if length > 0.0 {
+            (((p[0] - a[0]) * edge[0] + (p[1] - a[1]) * edge[1]) / length).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        return (p[0] - a[0] - t * edge[0]).hypot(p[1] - a[1] - t * edge[1]);
+    };
+    let brute_force = |first: &Hull, second: &Hull| {
+        let mut distance = f64::INFINITY;
+        for (a, b) in [(first, second), (second, first)] {
+            for p in a.points() {
+                if b.contains(*p) {
+                    return 0.0;
+                }
+                for (start, end) in b.edges() {
+                    distance = distance.min(segment_distance(*p, start, end));
+                }
+            }
+        }
+        return distance;
+    };
+
+    for seed in 0..200 {
+        let center = uniform_in_rectangle(1, [-4.0, -4.0], [4.0, 4.0], seed)[0];
+        let first = Hull::new(&uniform_in_disk(30, [0.0, 0.0], 2.0, seed + 1000));
+        let second = match seed % 3 {
+            0 => Hull::new(&uniform_in_disk(20, center, 1.5, seed + 2000)),
+            1 => Hull::new(&[center, [center[0] + 1.0, center[1] + 0.5]]),
+            _ => Hull::new(&[center]),
+        };
+        let (distance, [a, b]) = first.distance_to(&second).unwrap();
+        let expected = brute_force(&first, &second);
+        assert!(
+            (distance - expected).abs() < 1e-9,
+            "{seed}: {distance} {expected}"
+        );
+        assert!(first.signed_distance(a) < 1e-9);
+        assert!(second.signed_distance(b) < 1e-9 || second.len() < 3);
+        assert!(((a[0] - b[0]).hypot(a[1] - b[1]) - distance).abs() < 1e-9);
+
+        let (reversed, _) = second.distance_to(&first).unwrap();
+        assert!((reversed - distance).abs() < 1e-9);
+    }
+
+    let empty = Hull::new(&Vec::<[f64; 2]>::new());
+    assert_eq!(empty.distance_to(&Hull::new(&[[0.0, 0.0]])), None);
+}
+
+#[test]
+fn test_intersection() {
+    use planar_convex_hull::generators::{uniform_in_disk, uniform_in_rectangle};
+
+    for seed in 0..200 {
+        let center = uniform_in_rectangle(1, [-3.0, -3.0], [3.0, 3.0], seed)[0];
+        let first = Hull::new(&uniform_in_disk(40, [0.0, 0.0], 2.0, seed + 1000));
+        let second = match seed % 4 {
+            0 => Hull::new(&[center, [center[0] + 2.0, center[1] - 1.0]]),
+            1 => Hull::new(&uniform_in_rectangle(
+                30,
+                center,
+                [center[0] + 3.0, center[1] + 1.0],
+                seed,
+            )),
+            _ => Hull::new(&uniform_in_disk(30, center, 1.5, seed + 2000)),
+        };
+        let intersection = first.intersection(&second);
+        assert_eq!(
+            intersection.keys(),
+            (0..intersection.len()).collect::<Vec<_>>()
+        );
+        assert!(Hull::from_vertices(intersection.points().iter().copied().enumerate()).is_ok());
+        let reversed = second.intersection(&first);
+        assert_eq!(reversed.len(), intersection.len());
+
+        // The intersection contains exactly the points contained in both
+        let samples = uniform_in_disk(500, [0.0, 0.0], 4.0, seed + 3000);
+        for sample in samples {
+            let inside = first
+                .signed_distance(sample)
+                .max(second.signed_distance(sample));
+            if inside < -1e-9 {
+                assert!(intersection.contains(sample));
+            } else if inside > 1e-9 {
+                assert!(!intersection.contains(sample));
+            }
+        }
+        for corner in intersection.points() {
+            assert!(first.signed_distance(*corner) < 1e-9);
+            assert!(second.signed_distance(*corner) < 1e-9);
+        }
+        if second.len() == 2 && first.signed_distance(second.points()[0]) < 0.0 {
+            assert_eq!(intersection.len(), 2);
+        }
+    }
+
+    // Touching hulls
+    let square = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+    let right = Hull::new(&[[1.0, 0.0], [2.0, 0.0], [2.0, 1.0], [1.0, 1.0]]);
+    assert_eq!(
+        square.intersection(&right).points(),
+        &[[1.0, 1.0], [1.0, 0.0]]
+    );
+    let corner = Hull::new(&[[1.0, 1.0], [2.0, 1.0], [2.0, 2.0]]);
+    assert_eq!(square.intersection(&corner).points(), &[[1.0, 1.0]]);
+    assert_eq!(square.intersection(&square).points(), square.points());
+    assert!(
+        square
+            .intersection(&Hull::new(&Vec::<[f64; 2]>::new()))
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_overlaps() {
+    use planar_convex_hull::generators::{uniform_in_disk, uniform_in_rectangle};
+
+    let mut overlapping = 0;
+    for seed in 0..300 {
+        let center = uniform_in_rectangle(1, [-4.0, -4.0], [4.0, 4.0], seed)[0];
+        let first = Hull::new(&uniform_in_disk(30, [0.0, 0.0], 2.0, seed + 1000));
+        let second = match seed % 3 {
+            0 => Hull::new(&uniform_in_disk(20, center, 1.5, seed + 2000)),
+            1 => Hull::new(&[center, [center[0] + 1.0, center[1] + 0.5]]),
+            _ => Hull::new(&[center]),
+        };
+        let (distance, _) = first.distance_to(&second).unwrap();
+        if distance > 1e-9 {
+            assert!(!first.overlaps(&second));
+            assert!(!second.overlaps(&first));
+        } else if distance == 0.0 {
+            assert!(first.overlaps(&second));
+            assert!(second.overlaps(&first));
+            overlapping += 1;
+        }
+    }
+    assert!(overlapping > 50);
+
+    // Collinear segments and points
+    let segment = Hull::new(&[[0.0, 0.0], [2.0, 2.0]]);
+    assert!(segment.overlaps(&Hull::new(&[[1.0, 1.0], [3.0, 3.0]])));
+    assert!(!segment.overlaps(&Hull::new(&[[3.0, 3.0], [4.0, 4.0]])));
+    assert!(segment.overlaps(&Hull::new(&[[2.0, 2.0]])));
+    assert!(!segment.overlaps(&Hull::new(&[[2.5, 2.5]])));
+    assert!(!segment.overlaps(&Hull::new(&Vec::<[f64; 2]>::new())));
+}
+
+#[test]
+fn test_contains_hull() {
+    use planar_convex_hull::generators::uniform_in_disk;
+
+    let hull = Hull::new(&uniform_in_disk(50, [0.0, 0.0], 2.0, 7));
+    for seed in 0..100 {
+        let other = Hull::new(&uniform_in_disk(
+            10,
+            [0.0, 0.0],
+            1.0 + 0.02 * seed as f64,
+            seed,
+        ));
+        let expected = other.points().iter().all(|p| hull.contains(*p));
+        assert_eq!(hull.contains_hull(&other), expected);
+        if hull.contains_hull_strictly(&other) {
+            assert!(hull.contains_hull(&other));
+        }
+    }
+
+    assert!(hull.contains_hull(&hull));
+    assert!(!hull.contains_hull_strictly(&hull));
+    assert!(hull.contains_hull(&Hull::new(&Vec::<[f64; 2]>::new())));
+}
+
+#[test]
+fn test_merge() {
+    use planar_convex_hull::generators::uniform_in_disk;
+
+    for seed in 0..50 {
+        let points = uniform_in_disk(200, [0.0, 0.0], 1.0, seed);
+        let expected = Hull::from(points.convex_hull_exact());
+
+        // Hierarchical merge of eight shards keyed by the global index
+        let mut hulls: Vec<Hull> = (0..8)
+            .map(|shard| {
+                let shard: HashMap<usize, [f64; 2]> = points
+                    .iter()
+                    .copied()
+                    .enumerate()
+                    .filter(|(i, _)| i % 8 == shard)
+                    .collect();
+                return Hull::from(shard.convex_hull_exact());
+            })
+            .collect();
+        while hulls.len() > 1 {
+            hulls = hulls
+                .chunks(2)
+                .map(|pair| {
+                    if pair.len() == 2 {
+                        return pair[0].merge(&pair[1]);
+                    }
+                    return pair[0].clone();
+                })
+                .collect();
+        }
+        assert_eq!(hulls[0], expected);
+    }
+
+    // Degenerate hulls and identical corners
+    let segment = Hull::new(&[[0.0, 0.0], [1.0, 1.0]]);
+    let point = Hull::new(&[[2.0, 2.0]]);
+    let merged = segment.merge(&point);
+    assert_eq!(merged.points(), &[[2.0, 2.0], [0.0, 0.0]]);
+    assert!(merged.degeneracy().collinear);
+    assert_eq!(segment.merge(&segment), segment);
+    assert_eq!(segment.merge(&Hull::default()), segment);
+    assert!(Hull::default().merge(&Hull::default()).is_empty());
+}
+
+#[test]
+fn test_common_tangents() {
+    use planar_convex_hull::generators::{uniform_in_disk, uniform_in_rectangle};
+    use planar_convex_hull::predicates::orientation;
+    use std::cmp::Ordering;
+
+    // All corners of the hull are on the given side of the tangent (or on it)
+    let on_side = |hull: &Hull, tangent: [(usize, [f64; 2]); 2], side: Ordering| {
+        let [(_, a), (_, b)] = tangent;
+        return hull
+            .points()
+            .iter()
+            .all(|p| orientation(a, b, *p) != side.reverse());
+    };
+
+    let mut disjoint = 0;
+    for seed in 0..200 {
+        let center = uniform_in_rectangle(1, [-5.0, -5.0], [5.0, 5.0], seed)[0];
+        let first = Hull::new(&uniform_in_disk(20, [0.0, 0.0], 2.0, seed + 1000));
+        let second = Hull::new(&uniform_in_disk(15, center, 1.5, seed + 2000));
+        let Some(tangents) = first.common_tangents(&second) else {
+            assert!(first.contains_hull(&second) || second.contains_hull(&first));
+            continue;
+        };
+        for (tangent, side) in tangents
+            .outer
+            .into_iter()
+            .zip([Ordering::Greater, Ordering::Less])
+        {
+            assert!(first.keys().contains(&tangent[0].0));
+            assert!(second.keys().contains(&tangent[1].0));
+            assert!(on_side(&first, tangent, side));
+            assert!(on_side(&second, tangent, side));
+        }
+        assert_eq!(tangents.inner.is_some(), !first.overlaps(&second));
+        if let Some(inner) = tangents.inner {
+            disjoint += 1;
+            for (tangent, side) in inner.into_iter().zip([Ordering::Greater, Ordering::Less]) {
+                assert!(on_side(&first, tangent, side));
+                assert!(on_side(&second, tangent, side.reverse()));
+            }
+        }
+    }
+    assert!(disjoint > 50);
+
+    // Nested hulls have no common tangents
+    let outer = Hull::new(&[[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]]);
+    let inner = Hull::new(&[[1.0, 1.0], [2.0, 1.0], [1.0, 2.0]]);
+    assert_eq!(outer.common_tangents(&inner), None);
+    assert_eq!(inner.common_tangents(&outer), None);
+    assert_eq!(outer.common_tangents(&Hull::default()), None);
+
+    // A point has the same inner and outer tangents
+    let point = Hull::new(&[[6.0, 2.0]]);
+    let tangents = outer.common_tangents(&point).unwrap();
+    assert_eq!(tangents.outer[0][0].1, [4.0, 0.0]);
+    assert_eq!(tangents.outer[1][0].1, [4.0, 4.0]);
+    assert_eq!(tangents.inner, Some(tangents.outer));
+}
+
+#[test]
+fn test_hausdorff_distance() {
+    use planar_convex_hull::generators::uniform_in_disk;
+
+    // Distance of a point to the boundary of a hull
+    let to_boundary = |hull: &Hull, point: [f64; 2]| {
+        return hull
+            .edges()
+            .map(|(a, b)| {
+                let edge = [b[0] - a[0], b[1] - a[1]];
+                let t = (((point[0] - a[0]) * edge[0] + (point[1] - a[1]) * edge[1])
+                    / (edge[0] * edge[0] + edge[1] * edge[1]))
+                    .clamp(0.0, 1.0);
+                return (a[0] + t * edge[0] - point[0]).hypot(a[1] + t * edge[1] - point[1]);
+            })
+            .fold(f64::INFINITY, f64::min);
+    };
+
+    // Brute force: sample both boundaries densely
+    for seed in 0..30 {
+        let first = Hull::new(&uniform_in_disk(15, [0.0, 0.0], 2.0, seed));
+        let second = Hull::new(&uniform_in_disk(10, [0.5, 0.2], 1.0, seed + 100));
+        let distance = first.hausdorff_distance(&second).unwrap();
+        assert_eq!(second.hausdorff_distance(&first), Some(distance));
+
+        let mut sampled = 0.0f64;
+        for (from, to) in [(&first, &second), (&second, &first)] {
+            for (a, b) in from.edges() {
+                for i in 0..=100 {
+                    let t = i as f64 / 100.0;
+                    let point = [a[0] + t * (b[0] - a[0]), a[1] + t * (b[1] - a[1])];
+                    sampled = sampled.max(to_boundary(to, point));
+                }
+            }
+        }
+        assert!(sampled <= distance + 1e-12);
+        assert!(sampled >= distance - 0.05);
+    }
+
+    assert_eq!(
+        Hull::default().hausdorff_distance(&Hull::new(&[[0.0, 0.0]])),
+        None
+    );
+    let hull = Hull::new(&uniform_in_disk(15, [0.0, 0.0], 2.0, 0));
+    assert_eq!(hull.hausdorff_distance(&hull), Some(0.0));
+}
+
+#[test]
+fn test_triangulate() {
+    use planar_convex_hull::generators::uniform_in_disk;
+
+    for seed in 0..20 {
+        let hull = Hull::new(&uniform_in_disk(100, [0.0, 0.0], 1.0, seed));
+        let triangles = hull.triangulate();
+        assert_eq!(triangles.len(), hull.len() - 2);
+
+        // The triangles are counter-clockwise and cover the hull
+        let points = hull.points();
+        let area: f64 = triangles
+            .iter()
+            .map(|[a, b, c]| {
+                let [a, b, c] = [
+                    points[*a as usize],
+                    points[*b as usize],
+                    points[*c as usize],
+                ];
+                let doubled = (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]);
+                assert!(doubled > 0.0);
+                return 0.5 * doubled;
+            })
+            .sum();
+        assert!((area - hull.area()).abs() < 1e-12);
+    }
+
+    assert!(
+        Hull::new(&[[0.0, 0.0], [1.0, 1.0]])
+            .triangulate()
+            .is_empty()
+    );
+    assert!(Hull::default().triangulate().is_empty());
+}
+
+#[test]
+fn test_half_edges() {
+    use planar_convex_hull::generators::uniform_in_disk;
+
+    let hull = Hull::new(&uniform_in_disk(100, [0.0, 0.0], 1.0, 3));
+    let half_edges = hull.half_edges();
+    assert_eq!(half_edges.vertices.len(), hull.len());
+    assert_eq!(half_edges.edges.len(), hull.len());
+    for (i, vertex) in half_edges.vertices.iter().enumerate() {
+        assert_eq!(
+            (vertex.key, vertex.point),
+            (hull.keys()[i], hull.points()[i])
+        );
+        assert_eq!(half_edges.vertices[vertex.next].previous, i);
+        let incoming = &half_edges.edges[vertex.incoming.unwrap()];
+        let outgoing = &half_edges.edges[vertex.outgoing.unwrap()];
+        assert_eq!((incoming.end, outgoing.start), (i, i));
+        assert_eq!(
+            half_edges.next_edge(vertex.incoming.unwrap()),
+            vertex.outgoing.unwrap()
+        );
+        assert_eq!(
+            half_edges.previous_edge(vertex.outgoing.unwrap()),
+            vertex.incoming.unwrap()
+        );
+    }
+    for (edge, (a, b)) in half_edges.edges.iter().zip(hull.edges()) {
+        assert_eq!(half_edges.vertices[edge.start].point, a);
+        assert_eq!(half_edges.vertices[edge.end].point, b);
+        assert!((edge.direction[0].hypot(edge.direction[1]) - 1.0).abs() < 1e-12);
+
+        // All corners are behind the outward normal
+        let offset = edge.normal[0] * a[0] + edge.normal[1] * a[1];
+        for p in hull.points() {
+            assert!(edge.normal[0] * p[0] + edge.normal[1] * p[1] <= offset + 1e-12);
+        }
+    }
+
+    // A segment has two edges, a point none
+    let segment = Hull::new(&[[0.0, 0.0], [3.0, 4.0]]).half_edges();
+    assert_eq!(segment.edges.len(), 2);
+    assert_eq!(segment.edges[0].length, 5.0);
+    assert_eq!(
+        segment.edges[0].normal,
+        [-segment.edges[1].normal[0], -segment.edges[1].normal[1]]
+    );
+    let point = Hull::new(&[[1.0, 1.0]]).half_edges();
+    assert!(point.edges.is_empty());
+    assert_eq!(point.vertices[0].outgoing, None);
+    assert_eq!(Hull::default().half_edges(), Default::default());
+}
+
+#[test]
+fn test_second_moments() {
+    use std::f64::consts::FRAC_PI_2;
+
+    // A rotated and shifted 4 x 1 rectangle
+    for angle in [0.0, 0.3, 1.0, -1.2, FRAC_PI_2] {
+        let (sin, cos) = f64::sin_cos(angle);
+        let corners: Vec<[f64; 2]> = [[-2.0, -0.5], [2.0, -0.5], [2.0, 0.5], [-2.0, 0.5]]
+            .iter()
+            .map(|[x, y]| [5.0 + cos * x - sin * y, -3.0 + sin * x + cos * y])
+            .collect();
+        let moments = Hull::new(&corners).second_moments();
+        let [largest, smallest] = moments.principal_moments();
+        assert!((largest - 64.0 / 12.0).abs() < 1e-9);
+        assert!((smallest - 4.0 / 12.0).abs() < 1e-9);
+
+        // The long axis has the direction of the rotation (modulo π)
+        let difference = (moments.principal_angle() - angle).rem_euclid(std::f64::consts::PI);
+        assert!((difference - FRAC_PI_2).abs() > FRAC_PI_2 - 1e-9);
+    }
+
+    // A right triangle with legs 3 (along x) and 6 (along y)
+    let moments = Hull::new(&[[0.0, 0.0], [3.0, 0.0], [0.0, 6.0]]).second_moments();
+    assert!((moments.ixx - 3.0 * 216.0 / 36.0).abs() < 1e-9);
+    assert!((moments.iyy - 6.0 * 27.0 / 36.0).abs() < 1e-9);
+    assert!((moments.ixy + 9.0 * 36.0 / 72.0).abs() < 1e-9);
+
+    let segment = Hull::new(&[[0.0, 0.0], [1.0, 1.0]]).second_moments();
+    assert_eq!(segment.principal_moments(), [0.0, 0.0]);
+}
+
+#[test]
+fn test_interior_angles_and_edge_normals() {
+    use planar_convex_hull::generators::uniform_in_disk;
+    use std::f64::consts::PI;
+
+    let hull = Hull::new(&uniform_in_disk(100, [0.0, 0.0], 1.0, 11));
+    let n = hull.len();
+    let angles: Vec<f64> = hull.interior_angles().collect();
+    assert_eq!(angles.len(), n);
+    for (angle, (turn, _)) in angles.iter().zip(hull.turn_angles()) {
+        assert!(*angle > 0.0 && *angle < PI);
+        assert!((angle + turn - PI).abs() < 1e-12);
+    }
+    assert!((angles.iter().sum::<f64>() - (n - 2) as f64 * PI).abs() < 1e-9);
+
+    let normals: Vec<[f64; 2]> = hull.edge_normals().collect();
+    assert_eq!(normals.len(), n);
+    for (normal, (a, _)) in normals.iter().zip(hull.edges()) {
+        assert!((normal[0].hypot(normal[1]) - 1.0).abs() < 1e-12);
+        let offset = normal[0] * a[0] + normal[1] * a[1];
+        for p in hull.points() {
+            assert!(normal[0] * p[0] + normal[1] * p[1] <= offset + 1e-12);
+        }
+    }
+
+    let segment = Hull::new(&[[0.0, 0.0], [3.0, 4.0]]);
+    assert_eq!(
+        segment.interior_angles().collect::<Vec<_>>(),
+        vec![0.0, 0.0]
+    );
+    assert_eq!(
+        segment.edge_normals().collect::<Vec<_>>(),
+        vec![[-0.8, 0.6], [0.8, -0.6]]
+    );
+    assert_eq!(Hull::new(&[[1.0, 1.0]]).interior_angles().len(), 0);
+    assert_eq!(Hull::new(&[[1.0, 1.0]]).edge_normals().len(), 0);
+}
+
+#[test]
+fn test_rasterize() {
+    use planar_convex_hull::generators::uniform_in_disk;
+
+    for seed in 0..20 {
+        let hull = Hull::new(&uniform_in_disk(30, [0.3, -0.2], 3.0, seed));
+        let (origin, size) = ([0.1, 0.05], 0.7);
+        let cells = hull.rasterize(origin, size);
+
+        // Brute force: cells whose intersection with the hull has an area
+        let mut expected = Vec::new();
+        for j in -7..7 {
+            for i in -7..7 {
+                let [x, y] = [origin[0] + i as f64 * size, origin[1] + j as f64 * size];
+                let cell = Hull::new(&[[x, y], [x + size, y], [x + size, y + size], [x, y + size]]);
+                if hull.intersection(&cell).area() > 0.0 {
+                    expected.push([i, j]);
+                }
+            }
+        }
+        assert_eq!(cells, expected);
+    }
+
+    // Cells touching an aligned square are not covered
+    let square = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    assert_eq!(
+        square.rasterize([0.0, 0.0], 1.0),
+        vec![[0, 0], [1, 0], [0, 1], [1, 1]]
+    );
+    assert_eq!(square.rasterize([0.0, 0.0], 4.0), vec![[0, 0]]);
+
+    // Points and segments
+    assert_eq!(
+        Hull::new(&[[1.5, -0.5]]).rasterize([0.0, 0.0], 1.0),
+        vec![[1, -1]]
+    );
+    let segment = Hull::new(&[[0.5, 0.5], [2.5, 1.5]]);
+    assert_eq!(
+        segment.rasterize([0.0, 0.0], 1.0),
+        vec![[0, 0], [1, 0], [1, 1], [2, 1]]
+    );
+    assert!(Hull::default().rasterize([0.0, 0.0], 1.0).is_empty());
+}
+
+#[test]
+fn test_clip_segment() {
+    use planar_convex_hull::generators::{uniform_in_disk, uniform_in_rectangle};
+
+    let hull = Hull::new(&uniform_in_disk(40, [0.0, 0.0], 2.0, 5));
+    let mut clipped = 0;
+    for seed in 0..200 {
+        let [a, b] =
+            <[[f64; 2]; 2]>::try_from(uniform_in_rectangle(2, [-3.0, -3.0], [3.0, 3.0], seed))
+                .unwrap();
+        let direction = [b[0] - a[0], b[1] - a[1]];
+        let at = |t: f64| [a[0] + t * direction[0], a[1] + t * direction[1]];
+        let inside: Vec<f64> = (0..=200)
+            .map(|i| i as f64 / 200.0)
+            .filter(|t| hull.signed_distance(at(*t)) < -1e-9)
+            .collect();
+        let Some([start, end]) = hull.clip_segment(a, b) else {
+            assert!(inside.is_empty());
+            continue;
+        };
+        clipped += 1;
+        for point in [start, end] {
+            assert!(hull.signed_distance(point) < 1e-9);
+        }
+        assert!(hull.clip_line(a, direction).is_some());
+        if hull.contains(a) {
+            assert_eq!(start, a);
+        }
+        if hull.contains(b) {
+            assert_eq!(end, b);
+        }
+
+        // The sampled inner points lie between the ends
+        let parameter = |p: [f64; 2]| {
+            return ((p[0] - a[0]) * direction[0] + (p[1] - a[1]) * direction[1])
+                / (direction[0] * direction[0] + direction[1] * direction[1]);
+        };
+        let (t0, t1) = (parameter(start), parameter(end));
+        assert!(t0 <= t1);
+        for t in inside {
+            assert!(t0 - 1e-12 <= t && t <= t1 + 1e-12);
+        }
+    }
+    assert!(clipped > 50);
+
+    // Lines extend beyond the segment
+    let square = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    assert_eq!(
+        square.clip_line([1.0, 1.0], [1.0, 1.0]),
+        Some([[0.0, 0.0], [2.0, 2.0]])
+    );
+    assert_eq!(square.clip_line([1.0, 1.0], [0.0, 0.0]), None);
+    assert_eq!(
+        square.clip_segment([2.0, 2.0], [3.0, 3.0]),
+        Some([[2.0, 2.0], [2.0, 2.0]])
+    );
+    assert_eq!(Hull::default().clip_segment([0.0, 0.0], [1.0, 1.0]), None);
+}
+
+#[test]
+fn test_point_at_perimeter() {
+    use planar_convex_hull::generators::uniform_in_disk;
+
+    let hull = Hull::new(&uniform_in_disk(50, [1.0, -2.0], 3.0, 17));
+    let perimeter = hull.perimeter();
+
+    // The corners are located at the cumulative edge lengths
+    let mut s = 0.0;
+    for (a, b) in hull.edges() {
+        let point = hull.point_at_perimeter(s).unwrap();
+        assert!((point[0] - a[0]).abs() < 1e-12 && (point[1] - a[1]).abs() < 1e-12);
+        s += (b[0] - a[0]).hypot(b[1] - a[1]);
+    }
+
+    // Evenly spaced points are on the boundary, equally far apart along it
+    let count = 100;
+    let samples: Vec<[f64; 2]> = (0..count)
+        .map(|i| {
+            hull.point_at_perimeter(i as f64 * perimeter / count as f64)
+                .unwrap()
+        })
+        .collect();
+    for point in &samples {
+        assert!(hull.signed_distance(*point).abs() < 1e-12);
+    }
+    for i in 0..count {
+        let (a, b) = (samples[i], samples[(i + 1) % count]);
+        assert!((a[0] - b[0]).hypot(a[1] - b[1]) <= perimeter / count as f64 + 1e-12);
+    }
+
+    // Wrapping
+    let point = hull.point_at_perimeter(0.3).unwrap();
+    for s in [0.3 + perimeter, 0.3 - perimeter, 0.3 + 5.0 * perimeter] {
+        let wrapped = hull.point_at_perimeter(s).unwrap();
+        assert!((wrapped[0] - point[0]).abs() < 1e-9 && (wrapped[1] - point[1]).abs() < 1e-9);
+    }
+
+    assert_eq!(
+        Hull::new(&[[1.0, 2.0]]).point_at_perimeter(5.0),
+        Some([1.0, 2.0])
+    );
+    assert_eq!(Hull::default().point_at_perimeter(0.0), None);
+    assert_eq!(hull.point_at_perimeter(f64::NAN), None);
+}
+
+#[test]
+fn test_hull_locator() {
+    use planar_convex_hull::generators::{uniform_in_disk, uniform_in_rectangle};
+    use planar_convex_hull::locator::HullLocator;
+
+    for seed in 0..20 {
+        let hull = Hull::new(&uniform_in_disk(200, [0.5, 0.5], 1.0, seed));
+        let locator = HullLocator::new(&hull);
+        let mut queries = uniform_in_rectangle(2000, [-1.0, -1.0], [2.0, 2.0], seed + 100);
+
+        // Corners, edge midpoints and points on the rays through the corners
+        queries.extend(hull.points());
+        for (a, b) in hull.edges() {
+            queries.push([0.5 * (a[0] + b[0]), 0.5 * (a[1] + b[1])]);
+            queries.push([1.5 * b[0] - 0.5 * a[0], 1.5 * b[1] - 0.5 * a[1]]);
+        }
+        queries.push([f64::NAN, 0.0]);
+        for query in queries {
+            assert_eq!(locator.locate(query), hull.locate(query));
+            assert_eq!(locator.contains(query), hull.contains(query));
+        }
+    }
+
+    // Degenerate hulls
+    for points in [vec![], vec![[1.0, 1.0]], vec![[0.0, 0.0], [2.0, 2.0]]] {
+        let hull = Hull::new(&points);
+        let locator = HullLocator::new(&hull);
+        for query in [[0.0, 0.0], [1.0, 1.0], [3.0, 3.0], [1.0, 0.0]] {
+            assert_eq!(locator.locate(query), hull.locate(query));
+        }
+    }
+}
+
+#[test]
+fn test_interpolate() {
+    use planar_convex_hull::generators::uniform_in_disk;
+
+    for seed in 0..20 {
+        let first = Hull::new(&uniform_in_disk(30, [0.0, 0.0], 1.0, seed));
+        let second = Hull::new(&uniform_in_disk(20, [3.0, 1.0], 2.0, seed + 100));
+        assert_eq!(first.interpolate(&second, 0.0).points(), first.points());
+        assert_eq!(first.interpolate(&second, 1.0).points(), second.points());
+
+        for t in [0.1, 0.5, 0.9] {
+            let morph = first.interpolate(&second, t);
+            assert!(morph.len() <= first.len() + second.len());
+
+            // The support function interpolates linearly
+            for degree in (0..360).step_by(7) {
+                let direction = [
+                    f64::cos((degree as f64).to_radians()),
+                    f64::sin((degree as f64).to_radians()),
+                ];
+                let support = |hull: &Hull| {
+                    let (_, p) = hull.extreme_point_in_direction(direction).unwrap();
+                    return p[0] * direction[0] + p[1] * direction[1];
+                };
+                let expected = (1.0 - t) * support(&first) + t * support(&second);
+                assert!((support(&morph) - expected).abs() < 1e-9);
+            }
+        }
+    }
+
+    let point = Hull::new(&[[1.0, 1.0]]);
+    let square = Hull::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]);
+    assert_eq!(
+        point.interpolate(&square, 0.5).points(),
+        &[[1.5, 1.5], [0.5, 1.5], [0.5, 0.5], [1.5, 0.5]]
+    );
+    assert!(point.interpolate(&Hull::default(), 0.5).is_empty());
+}
+
+#[test]
+fn test_aabb_and_extremes() {
+    use planar_convex_hull::generators::uniform_in_disk;
+
+    for seed in 0..20 {
+        let points = uniform_in_disk(100, [1.0, -1.0], 2.0, seed);
+        let hull = Hull::new(&points);
+        let [min, max] = hull.aabb().unwrap();
+        for p in &points {
+            assert!(min[0] <= p[0] && p[0] <= max[0] && min[1] <= p[1] && p[1] <= max[1]);
+        }
+        let corners = hull.points();
+        assert_eq!(corners[hull.leftmost().unwrap()][0], min[0]);
+        assert_eq!(corners[hull.rightmost().unwrap()][0], max[0]);
+        assert_eq!(corners[hull.bottom().unwrap()][1], min[1]);
+        assert_eq!(corners[hull.top().unwrap()][1], max[1]);
+        assert_eq!(hull.rightmost(), Some(0));
+    }
+
+    // Ties at axis-parallel edges, also for hulls with a different first corner
+    let octagon = [
+        [1.0, 0.0],
+        [2.0, 0.0],
+        [3.0, 1.0],
+        [3.0, 2.0],
+        [2.0, 3.0],
+        [1.0, 3.0],
+        [0.0, 2.0],
+        [0.0, 1.0],
+    ];
+    for shift in 0..8 {
+        let mut rotated = octagon;
+        rotated.rotate_left(shift);
+        let hull = Hull::from_vertices(rotated.into_iter().enumerate()).unwrap();
+        let corner = |index: Option<usize>| hull.points()[index.unwrap()];
+        assert_eq!(corner(hull.leftmost()), [0.0, 1.0]);
+        assert_eq!(corner(hull.bottom()), [2.0, 0.0]);
+        assert_eq!(corner(hull.rightmost()), [3.0, 2.0]);
+        assert_eq!(corner(hull.top()), [1.0, 3.0]);
+        assert_eq!(hull.aabb(), Some([[0.0, 0.0], [3.0, 3.0]]));
+    }
+
+    // Degenerate hulls
+    let segment = Hull::new(&[[1.0, 0.0], [1.0, 2.0]]);
+    assert_eq!(segment.points()[segment.rightmost().unwrap()], [1.0, 2.0]);
+    assert_eq!(segment.points()[segment.leftmost().unwrap()], [1.0, 0.0]);
+    assert_eq!(
+        Hull::new(&[[1.0, 1.0]]).aabb(),
+        Some([[1.0, 1.0], [1.0, 1.0]])
+    );
+    assert_eq!(Hull::default().aabb(), None);
+    assert_eq!(Hull::default().top(), None);
+
+    // The extremes kept from the divide-and-conquer algorithm agree with the
+    // binary search, including ties, duplicates and signed zeros
+    let mut octagon_points = octagon.to_vec();
+    octagon_points.extend(octagon);
+    octagon_points.push([-0.0, 1.0]);
+    let datasets = [
+        uniform_in_disk(1000, [3.0, 4.0], 2.0, 3),
+        planar_convex_hull::generators::grid(5, 4, [0.0, 0.0], 1.0),
+        octagon_points,
+        vec![[1.0, 0.0], [1.0, 2.0], [1.0, 1.0]],
+        vec![[2.0, 2.0], [2.0, 2.0]],
+        vec![[f64::NAN, 0.0]],
+    ];
+    for points in datasets.iter() {
+        for hull in [Hull::new(points), Hull::from(points.convex_hull())] {
+            let searched = Hull::from_vertices_unchecked(hull.iter());
+            assert_eq!(hull.leftmost(), searched.leftmost());
+            assert_eq!(hull.bottom(), searched.bottom());
+            assert_eq!(hull.rightmost(), searched.rightmost());
+            assert_eq!(hull.top(), searched.top());
+            assert_eq!(hull.aabb(), searched.aabb());
+        }
+    }
+}
+
+#[test]
+fn test_validate_hull() {
+    use planar_convex_hull::generators::uniform_in_disk;
+    use planar_convex_hull::{HullError, validate_hull};
+
+    for seed in 0..20 {
+        let points = uniform_in_disk(200, [0.0, 0.0], 1.0, seed);
+        let keys: Vec<usize> = points.convex_hull_exact().map(|(key, _)| key).collect();
+        assert_eq!(validate_hull(&points, &keys), Ok(()));
+
+        // Any rotation of the corners is valid as well
+        let mut rotated = keys.clone();
+        rotated.rotate_left(seed as usize % keys.len());
+        assert_eq!(validate_hull(&points, &rotated), Ok(()));
+
+        // Dropping a corner leaves it (and maybe other points) outside
+        let dropped = keys[1];
+        let fewer: Vec<usize> = keys.iter().copied().filter(|key| *key != dropped).collect();
+        let Err(HullError::Outside { key }) = validate_hull(&points, &fewer) else {
+            panic!("the dropped corner is outside");
+        };
+        assert!(
+            key == dropped
+                || !Hull::from_vertices(fewer.iter().map(|k| (*k, points[*k])))
+                    .unwrap()
+                    .contains(points[key])
+        );
+
+        // Clockwise order
+        let reversed: Vec<usize> = keys.iter().rev().copied().collect();
+        assert!(matches!(
+            validate_hull(&points, &reversed),
+            Err(HullError::NotConvex { .. })
+        ));
+
+        // An interior point as a corner
+        let interior = (0..points.len()).find(|key| !keys.contains(key)).unwrap();
+        let mut with_interior = keys.clone();
+        with_interior.insert(1, interior);
+        assert!(matches!(
+            validate_hull(&points, &with_interior),
+            Err(HullError::NotConvex { .. })
+        ));
+    }
+
+    // Nonreal points are ignored, but cannot be corners
+    let points = vec![[0.0, 0.0], [1.0, 0.0], [f64::NAN, 0.0], [0.0, 1.0]];
+    assert_eq!(validate_hull(&points, &[1, 3, 0]), Ok(()));
+    assert_eq!(
+        validate_hull(&points, &[1, 2, 3, 0]),
+        Err(HullError::NonReal { position: 1 })
+    );
+    assert_eq!(
+        validate_hull(&points, &[1, 3, 9]),
+        Err(HullError::UnknownKey { position: 2 })
+    );
+    assert_eq!(
+        HullError::UnknownKey { position: 2 }.to_string(),
+        "the key at position 2 does not exist in the collection"
+    );
+    assert_eq!(
+        HullError::Outside { key: 4 }.to_string(),
+        "point 4 is located outside of the hull"
+    );
+    assert_eq!(validate_hull(&Vec::<[f64; 2]>::new(), &[]), Ok(()));
+}
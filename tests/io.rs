@@ -0,0 +1,65 @@
+use planar_convex_hull::io::{CsvError, from_csv_reader};
+
+#[test]
+fn test_from_csv_reader() {
+    // Without header, with empty lines and additional columns
+    let data = "1.0,2.0,a\n\n3.5,-4.0\n  5e3 , 6 \n";
+    let points = from_csv_reader(data.as_bytes(), 0, 1).unwrap();
+    assert_eq!(points, vec![[1.0, 2.0], [3.5, -4.0], [5000.0, 6.0]]);
+
+    // Swapped columns
+    let points = from_csv_reader(data.as_bytes(), 1, 0).unwrap();
+    assert_eq!(points[0], [2.0, 1.0]);
+
+    // Empty input and header only
+    assert!(from_csv_reader("".as_bytes(), 0, 1).unwrap().is_empty());
+    assert!(
+        from_csv_reader("x,y\n".as_bytes(), 0, 1)
+            .unwrap()
+            .is_empty()
+    );
+
+    // Nonreal values are read as they are
+    let points = from_csv_reader("NaN,inf\n".as_bytes(), 0, 1).unwrap();
+    assert!(points[0][0].is_nan() && points[0][1].is_infinite());
+}
+
+#[test]
+fn test_from_csv_reader_errors() {
+    let error = from_csv_reader("x,y\n1,2\n3\n".as_bytes(), 0, 1).unwrap_err();
+    assert!(matches!(
+        error,
+        CsvError::MissingColumn {
+            line: 3,
+            column: 1,
+            len: 1
+        }
+    ));
+    assert_eq!(
+        error.to_string(),
+        "line 3: expected at least 2 columns to read column 1, found 1"
+    );
+
+    // Only the first row may be a header
+    let error = from_csv_reader("1,2\nx,y\n".as_bytes(), 0, 1).unwrap_err();
+    assert_eq!(error.to_string(), "line 2, column 0: \"x\" is not a number");
+
+    // A first row with only one invalid number is not a header
+    let error = from_csv_reader("1.0,abc\n1,2\n".as_bytes(), 0, 1).unwrap_err();
+    assert!(matches!(
+        error,
+        CsvError::InvalidNumber {
+            line: 1,
+            column: 1,
+            ..
+        }
+    ));
+
+    // A first row without the required columns is not a header
+    let error = from_csv_reader("x\n1,2\n".as_bytes(), 0, 1).unwrap_err();
+    assert!(matches!(error, CsvError::InvalidNumber { line: 1, .. }));
+
+    // Invalid UTF-8
+    let error = from_csv_reader(&[0x31, 0x2c, 0xff, 0xfe][..], 0, 1).unwrap_err();
+    assert!(matches!(error, CsvError::Csv(_)));
+}
@@ -0,0 +1,96 @@
+use planar_convex_hull::generators::{on_circle, uniform_in_disk};
+use planar_convex_hull::online::{OnlineHull, StreamingHull};
+use planar_convex_hull::{ConvexHull, Hull};
+
+/// Area and perimeter of a hull, calculated from scratch.
+fn area_and_perimeter(hull: &Hull) -> (f64, f64) {
+    let points = hull.points();
+    let n = points.len();
+    let mut area = 0.0;
+    let mut perimeter = 0.0;
+    for i in 0..n {
+        let a = points[i];
+        let b = points[(i + 1) % n];
+        area += 0.5
+            * ((a[0] - points[0][0]) * (b[1] - points[0][1])
+                - (a[1] - points[0][1]) * (b[0] - points[0][0]));
+        perimeter += (b[0] - a[0]).hypot(b[1] - a[1]);
+    }
+    return (area, perimeter);
+}
+
+#[test]
+fn test_online_hull_matches_batch() {
+    let mut points = uniform_in_disk(2000, [1e6, -3e6], 10.0, 1);
+    points.extend(on_circle(200, [1e6, -3e6], 10.0, 2));
+
+    let mut online = OnlineHull::new();
+    assert!(online.is_empty());
+    for (key, point) in points.iter().enumerate() {
+        online.insert(key, *point);
+        if key % 97 == 0 || key == points.len() - 1 {
+            let expected = Hull::from(points[..=key].to_vec().convex_hull());
+            let hull = online.hull();
+            assert_eq!(hull, expected);
+
+            let (area, perimeter) = area_and_perimeter(&hull);
+            assert!((online.area() - area).abs() <= 1e-9 * area.max(1.0));
+            assert!((online.perimeter() - perimeter).abs() <= 1e-9 * perimeter.max(1.0));
+        }
+    }
+}
+
+#[test]
+fn test_online_hull_degenerate() {
+    let mut online = OnlineHull::new();
+    assert!(!online.insert(0, [f64::NAN, 0.0]));
+    assert!(online.is_empty());
+    assert!(online.hull().is_empty());
+
+    assert!(online.insert(1, [1.0, 1.0]));
+    assert!(!online.insert(2, [1.0, 1.0]));
+    assert_eq!(online.hull().keys(), &[1]);
+    assert_eq!((online.area(), online.perimeter()), (0.0, 0.0));
+
+    // Collinear points
+    assert!(online.insert(3, [3.0, 3.0]));
+    assert!(!online.insert(4, [2.0, 2.0]));
+    assert!(online.insert(5, [0.0, 0.0]));
+    assert_eq!(online.hull().keys(), &[3, 5]);
+    assert_eq!(online.area(), 0.0);
+    assert!((online.perimeter() - 2.0 * 18.0f64.sqrt()).abs() < 1e-12);
+
+    // Vertical edges
+    let mut online = OnlineHull::new();
+    for (key, point) in [[0.0, 0.0], [0.0, 1.0], [0.0, 0.5], [1.0, 0.0], [1.0, 1.0]]
+        .into_iter()
+        .enumerate()
+    {
+        online.insert(key, point);
+    }
+    assert_eq!(online.hull().keys(), &[4, 1, 0, 3]);
+    assert_eq!(online.area(), 1.0);
+    assert_eq!(online.perimeter(), 4.0);
+}
+
+#[test]
+fn test_streaming_hull() {
+    let mut points = uniform_in_disk(1000, [5.0, 5.0], 2.0, 3);
+    points.insert(10, [f64::NAN, 1.0]);
+    points.insert(20, [1.0, f64::INFINITY]);
+    points.extend(on_circle(100, [5.0, 5.0], 2.0, 4));
+
+    let mut streaming = StreamingHull::new();
+    assert!(streaming.is_empty());
+    assert!(streaming.hull().is_empty());
+    for (index, point) in points.iter().enumerate() {
+        assert_eq!(streaming.push(*point), index);
+        if index % 50 == 0 || index == points.len() - 1 {
+            let expected = points[..=index].to_vec().convex_hull_monotone_chain();
+            assert_eq!(streaming.hull(), expected.collect::<Vec<_>>().as_slice());
+        }
+    }
+    assert_eq!(streaming.len(), points.len());
+    assert_eq!(streaming.area(), streaming.online_hull().area());
+    assert_eq!(streaming.perimeter(), streaming.online_hull().perimeter());
+}
@@ -0,0 +1,282 @@
+use planar_convex_hull::{ConvexHull, ConvexHullOptions, Normalization, generators};
+
+#[test]
+fn test_default_options() {
+    let points = generators::uniform_in_disk(1000, [0.0, 0.0], 1.0, 5);
+    let hull: Vec<_> = points
+        .convex_hull_with_options(&ConvexHullOptions::default())
+        .0
+        .collect();
+    assert_eq!(hull, points.convex_hull().collect::<Vec<_>>());
+
+    // Without normalization and recentering, the coordinates are not modified
+    let (_, normalization) = points.convex_hull_with_options(&ConvexHullOptions::default());
+    assert_eq!(normalization, Normalization::default());
+    assert_eq!(normalization.apply([3.0, -4.0]), [3.0, -4.0]);
+}
+
+#[test]
+fn test_normalize() {
+    let options = ConvexHullOptions {
+        normalize: true,
+        ..Default::default()
+    };
+
+    // Well-conditioned coordinates are not affected
+    let points = generators::uniform_in_disk(1000, [0.0, 0.0], 1000.0, 5);
+    let hull: Vec<_> = points.convex_hull_with_options(&options).0.collect();
+    assert_eq!(hull, points.convex_hull().collect::<Vec<_>>());
+
+    // Overflow and underflow of the products
+    for scale in [1e-200, 1e-170, 1e160, 1e200] {
+        let points = [
+            [scale, 0.0],
+            [0.0, scale],
+            [-scale, 0.0],
+            [0.0, -scale],
+            [0.5 * scale, 0.5001 * scale],
+            [0.0, 0.0],
+            [f64::NAN, 0.0],
+        ];
+        let (hull, normalization) = points.convex_hull_with_options(&options);
+        let hull: Vec<_> = hull.collect();
+
+        // The applied scale maps the largest coordinate into [0.5, 1)
+        assert_eq!(normalization.offset, [0.0, 0.0]);
+        let scaled = normalization.apply(points[0])[0];
+        assert!((0.5..1.0).contains(&scaled));
+        assert_eq!(normalization.revert([scaled, 0.0]), points[0]);
+        assert_eq!(
+            hull,
+            vec![
+                (0, points[0]),
+                (4, points[4]),
+                (1, points[1]),
+                (2, points[2]),
+                (3, points[3])
+            ]
+        );
+    }
+
+    // Degenerate inputs
+    let empty: [[f64; 2]; 0] = [];
+    assert_eq!(empty.convex_hull_with_options(&options).0.count(), 0);
+    let origin = [[0.0, 0.0]];
+    assert_eq!(
+        origin
+            .convex_hull_with_options(&options)
+            .0
+            .collect::<Vec<_>>(),
+        vec![(0, [0.0, 0.0])]
+    );
+}
+
+#[test]
+fn test_recenter() {
+    // UTM-like coordinates far away from the origin
+    let mut points = generators::uniform_in_disk(1000, [512345.6, 5432109.8], 250.0, 9);
+    points.push([f64::INFINITY, 0.0]);
+    let exact: Vec<_> = points.convex_hull_exact().collect();
+
+    for normalize in [false, true] {
+        let options = ConvexHullOptions {
+            normalize,
+            recenter: true,
+            ..Default::default()
+        };
+        let (hull, normalization) = points.convex_hull_with_options(&options);
+        let hull: Vec<_> = hull.collect();
+        assert_eq!(hull, exact);
+
+        // The offset is the centroid of the real points
+        assert!((normalization.offset[0] - 512345.6).abs() < 50.0);
+        assert!((normalization.offset[1] - 5432109.8).abs() < 50.0);
+        if normalize {
+            assert_eq!(normalization.scale, 2.0f64.powi(-8));
+        } else {
+            assert_eq!(normalization.scale, 1.0);
+        }
+
+        // The original points are reported
+        for (key, point) in hull {
+            assert_eq!(points[key], point);
+        }
+    }
+
+    // The sum of the coordinates overflows
+    let points = [[f64::MAX, 0.0], [f64::MAX, 1.0], [0.0, 0.0]];
+    let options = ConvexHullOptions {
+        normalize: true,
+        recenter: true,
+        ..Default::default()
+    };
+    let hull: Vec<_> = points.convex_hull_with_options(&options).0.collect();
+    assert_eq!(hull, points.convex_hull().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_recenter_coordinates_in_the_millions() {
+    // Two vertical edges whose points are only one ulp of the y-coordinate
+    // apart. The x-coordinates have a coarser ulp, so the quadrant keys of
+    // the divide-and-conquer algorithm collide without recentering.
+    let points = [
+        [1500031.0, 999953.0000000001],
+        [1500033.0, 999953.0],
+        [1500031.0, 999953.0000000003],
+        [1500033.0, 999953.0000000002],
+        [1500031.0, 999953.0000000001],
+        [1500033.0, 999953.0000000002],
+        [1500032.0, 999954.0],
+        [1500032.0, 999950.0],
+    ];
+    let exact: Vec<_> = points.convex_hull_exact().map(|(_, point)| point).collect();
+    assert_eq!(exact.len(), 6);
+
+    let plain: Vec<_> = points.convex_hull().map(|(_, point)| point).collect();
+    assert_ne!(plain, exact);
+
+    // Normalization keeps the relative precision and therefore doesn't help
+    let options = ConvexHullOptions {
+        normalize: true,
+        ..Default::default()
+    };
+    let normalized: Vec<_> = points
+        .convex_hull_with_options(&options)
+        .0
+        .map(|(_, point)| point)
+        .collect();
+    assert_ne!(normalized, exact);
+
+    for normalize in [false, true] {
+        let options = ConvexHullOptions {
+            normalize,
+            recenter: true,
+            ..Default::default()
+        };
+        let recentered: Vec<_> = points
+            .convex_hull_with_options(&options)
+            .0
+            .map(|(_, point)| point)
+            .collect();
+        assert_eq!(recentered, exact);
+    }
+}
+
+#[test]
+fn test_grid_prefilter() {
+    let options = ConvexHullOptions {
+        grid_prefilter: Some(100),
+        ..Default::default()
+    };
+
+    let datasets = [
+        generators::gaussian_clusters(100000, 5, [-100.0, -100.0], [100.0, 100.0], 2.0, 1),
+        generators::uniform_in_disk(10000, [3.0, 4.0], 2.0, 2),
+        generators::on_circle(1000, [0.0, 0.0], 1.0, 3),
+        generators::grid(30, 20, [0.0, 0.0], 1.0),
+        generators::with_duplicates(1000, 3, [0.0, 0.0], [1.0, 1.0], 4),
+        generators::collinear(100, [0.0, 0.0], [1.0, 1.0], 5),
+        vec![[1.0, 1.0]; 10],
+        vec![[0.0, 0.0], [f64::NAN, 1.0], [1.0, f64::INFINITY]],
+        vec![],
+    ];
+    for points in datasets.iter() {
+        let hull: Vec<_> = points.convex_hull_with_options(&options).0.collect();
+        assert_eq!(hull, points.convex_hull().collect::<Vec<_>>());
+    }
+
+    // A single cell and combined with the other options
+    for grid_prefilter in [Some(0), Some(1), Some(1000)] {
+        let options = ConvexHullOptions {
+            normalize: true,
+            recenter: true,
+            grid_prefilter,
+            ..Default::default()
+        };
+        let points = &datasets[1];
+        let hull: Vec<_> = points.convex_hull_with_options(&options).0.collect();
+        assert_eq!(hull, points.convex_hull().collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn test_grid_prefilter_collinear_and_duplicate_boundary() {
+    // Clusters inside an axis-aligned square whose edges are densely covered
+    // with collinear and duplicate points
+    let mut points = generators::gaussian_clusters(20000, 4, [10.0, 10.0], [90.0, 90.0], 3.0, 6);
+    for i in 0..=100 {
+        let t = i as f64;
+        for _ in 0..2 {
+            points.extend([[t, 0.0], [100.0, t], [100.0 - t, 100.0], [0.0, 100.0 - t]]);
+        }
+    }
+    let expected: Vec<_> = points.convex_hull().collect();
+    assert_eq!(expected.len(), 4);
+
+    for cells in [1, 7, 64, 1000] {
+        let options = ConvexHullOptions {
+            grid_prefilter: Some(cells),
+            ..Default::default()
+        };
+        let hull: Vec<_> = points.convex_hull_with_options(&options).0.collect();
+        assert_eq!(hull, expected);
+    }
+
+    // Few points limit the size of the grid
+    let points = vec![
+        [0.0, 0.0],
+        [1.0, 0.0],
+        [1.0, 0.0],
+        [0.5, 0.0],
+        [1.0, 1.0],
+        [0.5, 0.5],
+    ];
+    let options = ConvexHullOptions {
+        grid_prefilter: Some(1000),
+        ..Default::default()
+    };
+    let hull: Vec<_> = points.convex_hull_with_options(&options).0.collect();
+    assert_eq!(hull, points.convex_hull().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_akl_toussaint() {
+    let options = ConvexHullOptions {
+        akl_toussaint: true,
+        ..Default::default()
+    };
+
+    let datasets = [
+        generators::gaussian_clusters(100000, 5, [-100.0, -100.0], [100.0, 100.0], 2.0, 1),
+        generators::uniform_in_disk(10000, [3.0, 4.0], 2.0, 2),
+        generators::uniform_in_rectangle(10000, [0.0, 0.0], [2.0, 1.0], 6),
+        generators::on_circle(1000, [0.0, 0.0], 1.0, 3),
+        generators::grid(30, 20, [0.0, 0.0], 1.0),
+        generators::with_duplicates(1000, 3, [0.0, 0.0], [1.0, 1.0], 4),
+        generators::collinear(100, [0.0, 0.0], [1.0, 1.0], 5),
+        vec![[1.0, 1.0]; 10],
+        vec![[0.0, 0.0], [f64::NAN, 1.0], [1.0, f64::INFINITY]],
+        vec![
+            [f64::MAX, f64::MAX],
+            [-f64::MAX, f64::MAX],
+            [0.0, -f64::MAX],
+            [0.0, 0.0],
+        ],
+        vec![],
+    ];
+    for points in datasets.iter() {
+        let hull: Vec<_> = points.convex_hull_with_options(&options).0.collect();
+        assert_eq!(hull, points.convex_hull().collect::<Vec<_>>());
+    }
+
+    // Combined with the other options
+    let options = ConvexHullOptions {
+        normalize: true,
+        recenter: true,
+        grid_prefilter: Some(64),
+        akl_toussaint: true,
+    };
+    let points = &datasets[0];
+    let hull: Vec<_> = points.convex_hull_with_options(&options).0.collect();
+    assert_eq!(hull, points.convex_hull().collect::<Vec<_>>());
+}
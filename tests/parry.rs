@@ -0,0 +1,89 @@
+use parry2d_f64::math::{Isometry, Point, Vector};
+use parry2d_f64::query::PointQuery;
+use parry2d_f64::query::details::{
+    distance_support_map_support_map, intersection_test_support_map_support_map,
+};
+use parry2d_f64::shape::{ConvexPolygon, FeatureId, SupportMap};
+use planar_convex_hull::{Hull, generators::uniform_in_disk};
+
+#[test]
+fn test_support_map_matches_convex_polygon() {
+    let points = uniform_in_disk(500, [0.0, 0.0], 1.0, 7);
+    let hull = Hull::new(&points);
+    let polygon = ConvexPolygon::from_convex_polyline(
+        hull.points()
+            .iter()
+            .map(|point| Point::new(point[0], point[1]))
+            .collect(),
+    )
+    .unwrap();
+
+    for degree in 0..360 {
+        let angle = (degree as f64).to_radians();
+        let dir = Vector::new(angle.cos(), angle.sin());
+        assert!(
+            (hull.local_support_point(&dir) - polygon.local_support_point(&dir))
+                .dot(&dir)
+                .abs()
+                < 1e-12
+        );
+
+        let query = Point::new(2.0 * angle.cos(), 2.0 * angle.sin());
+        let expected = polygon.project_local_point(&query, true);
+        let projection = hull.project_local_point(&query, true);
+        assert!(!projection.is_inside);
+        assert!((projection.point - expected.point).norm() < 1e-12);
+    }
+
+    let shift = Isometry::translation(10.0, 0.0);
+    let distance = distance_support_map_support_map(&shift, &hull, &polygon);
+    let expected = distance_support_map_support_map(&shift, &polygon, &polygon);
+    assert!((distance - expected).abs() < 1e-9);
+    assert!(intersection_test_support_map_support_map(
+        &Isometry::translation(0.5, 0.5),
+        &hull,
+        &hull
+    ));
+}
+
+#[test]
+fn test_point_query_features() {
+    let square = Hull::new(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+    let corners = square.points();
+
+    let (projection, feature) = square.project_local_point_and_get_feature(&Point::new(2.0, 2.0));
+    assert!(!projection.is_inside);
+    assert_eq!(projection.point, Point::new(1.0, 1.0));
+    let FeatureId::Vertex(i) = feature else {
+        panic!("expected a vertex, got {feature:?}");
+    };
+    assert_eq!(corners[i as usize], [1.0, 1.0]);
+
+    // Inside points are projected onto the closest edge unless solid
+    let (projection, feature) = square.project_local_point_and_get_feature(&Point::new(0.5, 0.1));
+    assert!(projection.is_inside);
+    assert_eq!(projection.point, Point::new(0.5, 0.0));
+    let FeatureId::Face(i) = feature else {
+        panic!("expected a face, got {feature:?}");
+    };
+    assert_eq!(corners[i as usize][1], 0.0);
+    assert_eq!(corners[(i as usize + 1) % 4][1], 0.0);
+
+    let projection = square.project_local_point(&Point::new(0.5, 0.1), true);
+    assert!(projection.is_inside);
+    assert_eq!(projection.point, Point::new(0.5, 0.1));
+
+    // Degenerate hulls
+    let empty = Hull::default();
+    assert_eq!(empty.local_support_point(&Vector::x()), Point::origin());
+    assert_eq!(
+        empty.distance_to_local_point(&Point::new(3.0, 4.0), true),
+        5.0
+    );
+    let segment = Hull::new(&[[0.0, 0.0], [2.0, 0.0]]);
+    assert!(segment.contains_local_point(&Point::new(1.0, 0.0)));
+    assert_eq!(
+        segment.distance_to_local_point(&Point::new(1.0, 1.0), true),
+        1.0
+    );
+}
@@ -0,0 +1,30 @@
+use nalgebra::{Point2, Vector2};
+use planar_convex_hull::{ConvexHull, Point2D, Points};
+
+#[test]
+fn test_point2d_impls() {
+    assert_eq!([1.0f64, 2.0].to_array(), [1.0, 2.0]);
+    assert_eq!([1.0f32, 2.0].to_array(), [1.0, 2.0]);
+    assert_eq!((1.0f64, 2.0f64).to_array(), [1.0, 2.0]);
+    assert_eq!((1.0f32, 2.0f32).to_array(), [1.0, 2.0]);
+    assert_eq!(Point2::new(1.0f64, 2.0).to_array(), [1.0, 2.0]);
+    assert_eq!(Point2::new(1.0f32, 2.0).to_array(), [1.0, 2.0]);
+    assert_eq!(Vector2::new(1.0f64, 2.0).to_array(), [1.0, 2.0]);
+    assert_eq!(Vector2::new(1.0f32, 2.0).to_array(), [1.0, 2.0]);
+    let tuple = (1.0f64, 2.0f64);
+    assert_eq!(Point2D::to_array(&&tuple), [1.0, 2.0]);
+}
+
+#[test]
+fn test_points_adaptor() {
+    let tuples = vec![(0.0f32, 0.0f32), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0)];
+    let mut hull = Points(&tuples).convex_hull();
+    assert_eq!(hull.next(), Some((3, [1.0, 1.0])));
+    assert_eq!(hull.next(), Some((2, [0.0, 1.0])));
+    assert_eq!(hull.next(), Some((0, [0.0, 0.0])));
+    assert_eq!(hull.next(), Some((1, [1.0, 0.0])));
+    assert_eq!(hull.next(), None);
+
+    let references: Vec<&Vector2<f64>> = Vec::new();
+    assert_eq!(Points(&references).convex_hull().next(), None);
+}
@@ -0,0 +1,145 @@
+use planar_convex_hull::polygon::*;
+
+#[test]
+fn test_congruent() {
+    // Scalene triangle and its mirror image
+    let triangle = [[0.0, 0.0], [4.0, 0.0], [1.0, 2.0]];
+    let mirrored = [[0.0, 0.0], [3.0, 2.0], [-1.0, 2.0]];
+    assert!(congruent(&triangle, &triangle, 1e-9, false, false));
+    assert!(!congruent(&triangle, &mirrored, 1e-9, false, false));
+    assert!(congruent(&triangle, &mirrored, 1e-9, true, false));
+
+    // Start point rotated
+    let shifted = [[4.0, 0.0], [1.0, 2.0], [0.0, 0.0]];
+    assert!(congruent(&triangle, &shifted, 1e-9, false, false));
+
+    // Tolerance
+    let perturbed = [[0.0, 0.0], [4.0, 1e-6], [1.0, 2.0]];
+    assert!(!congruent(&triangle, &perturbed, 1e-9, false, false));
+    assert!(congruent(&triangle, &perturbed, 1e-4, false, false));
+
+    // Different number of points
+    let square = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+    assert!(!congruent(&triangle, &square, 1e-9, true, true));
+
+    // Degenerate polygons
+    assert!(congruent(&[], &[], 1e-9, false, false));
+    assert!(congruent(&[[0.0, 0.0]], &[[1.0, 3.0]], 1e-9, false, false));
+    assert!(congruent(
+        &[[0.0, 0.0], [1.0, 0.0]],
+        &[[0.0, 1.0], [0.0, 0.0]],
+        1e-9,
+        false,
+        false
+    ));
+}
+
+#[test]
+fn test_iou() {
+    let a = [[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]];
+
+    // Disjoint
+    let b = [[3.0, 0.0], [4.0, 0.0], [4.0, 1.0]];
+    assert_eq!(iou(&a, &b), 0.0);
+
+    // Touching edges
+    let b = [[2.0, 0.0], [4.0, 0.0], [4.0, 2.0], [2.0, 2.0]];
+    assert_eq!(iou(&a, &b), 0.0);
+
+    // Contained
+    let b = [[0.5, 0.5], [1.5, 0.5], [1.5, 1.5], [0.5, 1.5]];
+    assert!((iou(&a, &b) - 0.25).abs() < 1e-12);
+    assert!((iou(&b, &a) - 0.25).abs() < 1e-12);
+
+    // Diamond inside the square, touching its edges
+    let b = [[1.0, 0.0], [2.0, 1.0], [1.0, 2.0], [0.0, 1.0]];
+    assert!((iou(&a, &b) - 0.5).abs() < 1e-12);
+
+    // Triangle overlapping one corner
+    let b = [[1.0, 1.0], [3.0, 1.0], [1.0, 3.0]];
+    assert!((iou(&a, &b) - 0.2).abs() < 1e-12);
+
+    // Collinear corners which do not start at the rightmost corner
+    let b = [[1.0, 1.0], [2.0, 1.0], [3.0, 1.0], [3.0, 3.0], [1.0, 3.0]];
+    assert!((iou(&a, &b) - 1.0 / 7.0).abs() < 1e-12);
+
+    // Degenerate polygons
+    assert_eq!(iou(&a, &[[0.0, 0.0], [1.0, 1.0]]), 0.0);
+    assert_eq!(iou(&[], &[]), 0.0);
+}
+
+#[test]
+fn test_signed_area_and_winding() {
+    use planar_convex_hull::ConvexHull;
+    use std::collections::HashMap;
+
+    assert_eq!(signed_area(&[]), 0.0);
+    assert_eq!(signed_area(&[[0.0, 0.0], [1.0, 0.0]]), 0.0);
+    assert_eq!(winding(&[[0.0, 0.0], [1.0, 0.0]]), Winding::Degenerate);
+    assert_eq!(
+        winding(&[[0.0, 0.0], [f64::NAN, 0.0], [0.0, 1.0]]),
+        Winding::Degenerate
+    );
+
+    // Far away from the origin
+    let offset = 1e7;
+    let triangle = [
+        [offset, offset],
+        [offset + 0.5, offset],
+        [offset, offset + 0.5],
+    ];
+    assert_eq!(signed_area(&triangle), 0.125);
+
+    // Loops of keys over a map with arbitrary keys
+    let map = HashMap::from([
+        (10, [0.0, 0.0]),
+        (20, [3.0, 0.0]),
+        (30, [3.0, 2.0]),
+        (40, [0.0, 2.0]),
+    ]);
+    assert_eq!(signed_area_of_loop(&map, &[10, 20, 30, 40]), Some(6.0));
+    assert_eq!(signed_area_of_loop(&map, &[40, 30, 20, 10]), Some(-6.0));
+    assert_eq!(
+        winding_of_loop(&map, &[40, 30, 20, 10]),
+        Some(Winding::Clockwise)
+    );
+    assert_eq!(winding_of_loop(&map, &[10, 30]), Some(Winding::Degenerate));
+    assert_eq!(winding_of_loop(&map, &[10, 30, 50]), None);
+
+    let hull: Vec<usize> = map.convex_hull().map(|(key, _)| key).collect();
+    assert_eq!(signed_area_of_loop(&map, &hull), Some(6.0));
+}
+
+#[test]
+fn test_minkowski_difference() {
+    use planar_convex_hull::{ConvexHull, generators};
+
+    let hull = |points: &[[f64; 2]]| -> Vec<[f64; 2]> {
+        return points.convex_hull_exact().map(|(_, point)| point).collect();
+    };
+
+    let shapes = [
+        hull(&generators::uniform_in_disk(100, [5.0, 1.0], 2.0, 1)),
+        hull(&generators::on_circle(7, [-1.0, 0.0], 0.5, 2)),
+        hull(&generators::grid(3, 2, [0.0, 0.0], 1.0)),
+        vec![[1.0, 1.0], [0.0, 0.0]],
+        vec![[2.0, -1.0]],
+    ];
+    for a in shapes.iter() {
+        for b in shapes.iter() {
+            let brute_force: Vec<[f64; 2]> = a
+                .iter()
+                .flat_map(|p| b.iter().map(move |q| [p[0] - q[0], p[1] - q[1]]))
+                .collect();
+            let expected = hull(&brute_force);
+            let difference = minkowski_difference(a, b);
+            assert_eq!(difference.len(), expected.len());
+            for (p, q) in difference.iter().zip(expected.iter()) {
+                assert!((p[0] - q[0]).abs() < 1e-12 && (p[1] - q[1]).abs() < 1e-12);
+            }
+        }
+    }
+
+    assert!(minkowski_difference(&[], &shapes[0]).is_empty());
+    assert!(minkowski_difference(&shapes[0], &[]).is_empty());
+}
@@ -0,0 +1,194 @@
+use std::cmp::Ordering;
+
+use planar_convex_hull::{ConvexHull, predicates::orientation};
+
+/// Returns the next larger floating-point number
+fn next_up(value: f64) -> f64 {
+    return f64::from_bits(value.to_bits() + 1);
+}
+
+#[test]
+fn test_orientation_near_degenerate() {
+    // Points on a grid of neighboring floating-point numbers around the line
+    // y = x. The exact orientation is given by the sign of y - x for c.
+    let a = [0.5, 0.5];
+    let b = [12.0, 12.0];
+    let mut x = 24.0;
+    for _ in 0..16 {
+        let mut y = 24.0;
+        for _ in 0..16 {
+            let expected = y.partial_cmp(&x).unwrap();
+            assert_eq!(orientation(a, b, [x, y]), expected);
+            assert_eq!(orientation(b, a, [x, y]), expected.reverse());
+            y = next_up(y);
+        }
+        x = next_up(x);
+    }
+}
+
+#[test]
+fn test_orientation_trivial() {
+    assert_eq!(
+        orientation([0.0, 0.0], [0.0, 0.0], [1.0, 1.0]),
+        Ordering::Equal
+    );
+    assert_eq!(
+        orientation([1e300, 0.0], [-1e300, 0.0], [0.0, 1e-300]),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn test_orientation_overflow_underflow() {
+    // The coordinate differences and their products overflow
+    let max = f64::MAX;
+    assert_eq!(
+        orientation([max, 0.0], [0.0, max], [-max, 0.0]),
+        Ordering::Greater
+    );
+    assert_eq!(
+        orientation([0.0, max], [-max, 0.0], [0.0, -max]),
+        Ordering::Greater
+    );
+    assert_eq!(
+        orientation([max, 0.0], [-max, 0.0], [1.0, 1.0]),
+        Ordering::Less
+    );
+    assert_eq!(
+        orientation([max, max], [-max, -max], [1.0, 1.0]),
+        Ordering::Equal
+    );
+    assert_eq!(
+        orientation([1e300, 0.0], [-1e300, 1e300], [3.0, 4.0]),
+        Ordering::Greater
+    );
+
+    // The products of the coordinate differences underflow
+    assert_eq!(
+        orientation([0.0, 0.0], [1e-200, 0.0], [0.0, 1e-200]),
+        Ordering::Greater
+    );
+    assert_eq!(
+        orientation([0.0, 0.0], [1e-200, 1e-200], [next_up(2e-200), 2e-200]),
+        Ordering::Less
+    );
+    let tiny = f64::from_bits(1);
+    assert_eq!(
+        orientation([0.0, 0.0], [tiny, 0.0], [0.0, tiny]),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn test_convex_hull_exact() {
+    // Same results as the floating-point algorithm for well-conditioned input
+    let slice = &[
+        [-3.0, -1.0],
+        [-2.0, 2.0],
+        [0.0, 0.0],
+        [1.0, 3.0],
+        [5.0, -1.0],
+        [6.0, 2.0],
+        [7.0, -4.0],
+        [8.0, -1.0],
+    ];
+    assert_eq!(
+        slice.convex_hull_exact().collect::<Vec<_>>(),
+        slice.convex_hull().collect::<Vec<_>>()
+    );
+
+    // Degenerate input
+    let slice: &[[f64; 2]] = &[];
+    assert_eq!(slice.convex_hull_exact().next(), None);
+    let slice = &[[1.0, 1.0], [1.0, 1.0], [f64::NAN, 0.0]];
+    assert_eq!(slice.convex_hull_exact().count(), 1);
+    let slice = &[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [0.5, 0.5]];
+    assert_eq!(
+        slice.convex_hull_exact().collect::<Vec<_>>(),
+        vec![(2, [2.0, 2.0]), (0, [0.0, 0.0])]
+    );
+
+    // A point very slightly off the line through two other points
+    let slice = &[[0.5, 0.5], [12.0, 12.0], [24.0, next_up(24.0)]];
+    assert_eq!(slice.convex_hull_exact().count(), 3);
+    let slice = &[[0.5, 0.5], [12.0, 12.0], [24.0, 24.0]];
+    assert_eq!(slice.convex_hull_exact().count(), 2);
+
+    // Nearly collinear points on a hull edge
+    let mut points = vec![[0.0, 0.0], [1.0, -1.0], [1.0, 1.0]];
+    let mut y = 0.0;
+    for i in 0..100 {
+        y = next_up(y + 0.01 * i as f64);
+        points.push([0.0, -y]);
+    }
+    let hull: Vec<_> = points.convex_hull_exact().collect();
+    for i in 0..hull.len() {
+        let a = hull[i].1;
+        let b = hull[(i + 1) % hull.len()].1;
+        let c = hull[(i + 2) % hull.len()].1;
+        assert_eq!(orientation(a, b, c), Ordering::Greater);
+    }
+    for point in points.iter() {
+        for i in 0..hull.len() {
+            let a = hull[i].1;
+            let b = hull[(i + 1) % hull.len()].1;
+            assert_ne!(orientation(a, b, *point), Ordering::Less);
+        }
+    }
+}
+
+#[test]
+fn test_convex_hull_exact_repairs_result() {
+    // Points with tiny offsets to the line y = x
+    let ulp = f64::EPSILON / 2.0;
+    for i in 0..32 {
+        for j in 0..32 {
+            let slice = [
+                [12.0, 12.0],
+                [24.0, 24.0],
+                [0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp],
+                [18.0, -5.0],
+            ];
+            let hull: Vec<[f64; 2]> = slice.convex_hull_exact().map(|(_, p)| p).collect();
+            for k in 0..hull.len() {
+                let a = hull[k];
+                let b = hull[(k + 1) % hull.len()];
+                let c = hull[(k + 2) % hull.len()];
+                assert_eq!(orientation(a, b, c), Ordering::Greater);
+                for point in slice.iter() {
+                    assert_ne!(orientation(a, b, *point), Ordering::Less);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_convex_hull_exact_huge_coordinates() {
+    let max = f64::MAX;
+    let slice = &[[max, 0.0], [-max, 0.0], [0.0, max], [0.0, -max], [1.0, 1.0]];
+    let expected = vec![
+        (0, [max, 0.0]),
+        (2, [0.0, max]),
+        (1, [-max, 0.0]),
+        (3, [0.0, -max]),
+    ];
+    assert_eq!(slice.convex_hull_exact().collect::<Vec<_>>(), expected);
+    assert_eq!(slice.convex_hull_graham().collect::<Vec<_>>(), expected);
+
+    let slice = &[
+        [1e300, 0.0],
+        [-1e300, 1e300],
+        [0.0, -1e300],
+        [1e300, 1e300],
+        [3.0, 4.0],
+    ];
+    let expected = vec![
+        (3, [1e300, 1e300]),
+        (1, [-1e300, 1e300]),
+        (2, [0.0, -1e300]),
+        (0, [1e300, 0.0]),
+    ];
+    assert_eq!(slice.convex_hull_exact().collect::<Vec<_>>(), expected);
+    assert_eq!(slice.convex_hull_graham().collect::<Vec<_>>(), expected);
+}
@@ -0,0 +1,47 @@
+use planar_convex_hull::generators::{on_circle, uniform_in_disk};
+use planar_convex_hull::prefix::PrefixHulls;
+use planar_convex_hull::{ConvexHull, Hull};
+
+#[test]
+fn test_prefix_hulls_match_batch() {
+    let mut points = uniform_in_disk(500, [-2e5, 4e5], 10.0, 3);
+    points.extend(on_circle(100, [-2e5, 4e5], 10.0, 4));
+    // Duplicates and collinear points
+    points.extend_from_within(..50);
+    points.push([f64::NAN, 1.0]);
+    points.push([-2e5 - 20.0, 4e5]);
+    points.push([-2e5 + 20.0, 4e5]);
+    points.push([-2e5, 4e5]);
+
+    let prefixes = PrefixHulls::new(&points);
+    assert_eq!(prefixes.len(), points.len());
+    for len in 0..=points.len() {
+        let expected = Hull::from(points[..len].to_vec().convex_hull());
+        assert_eq!(prefixes.hull(len), expected);
+    }
+    assert_eq!(
+        prefixes.hull(points.len() + 10),
+        prefixes.hull(points.len())
+    );
+
+    // The lifetimes agree with the hulls
+    for (key, _, range) in prefixes.lifetimes() {
+        assert_eq!(prefixes.lifetime(*key), Some(range.clone()));
+        for len in [range.start - 1, range.start, range.end - 1, range.end] {
+            if len > points.len() {
+                continue;
+            }
+            let is_corner = prefixes.hull(len).keys().contains(key);
+            assert_eq!(is_corner, range.contains(&len));
+        }
+    }
+    assert_eq!(prefixes.lifetime(points.len() - 5), None);
+}
+
+#[test]
+fn test_prefix_hulls_empty() {
+    let prefixes = PrefixHulls::new(&Vec::<[f64; 2]>::new());
+    assert!(prefixes.is_empty());
+    assert!(prefixes.lifetimes().is_empty());
+    assert!(prefixes.hull(3).is_empty());
+}
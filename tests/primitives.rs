@@ -0,0 +1,66 @@
+use planar_convex_hull::ConvexHull;
+use planar_convex_hull::primitives::{Primitives, Provenance};
+
+#[test]
+fn test_primitives() {
+    let mut primitives = Primitives::new();
+    assert!(primitives.is_empty());
+    assert_eq!(primitives.push_polygon([]), 0);
+    assert_eq!(primitives.push_segment([-1.0, 0.0], [1.0, 0.0]), 1);
+    assert_eq!(primitives.push_polygon([]), 2);
+    assert_eq!(
+        primitives.push_polygon([[0.0, 0.5], [0.0, 2.0], [0.5, 0.5], [f64::NAN, 0.0]]),
+        3
+    );
+    assert_eq!(primitives.push_segment([0.0, -1.0], [0.0, -0.5]), 4);
+    assert_eq!(primitives.len(), 5);
+
+    assert_eq!(primitives.primitive(0), Some(&[][..]));
+    assert_eq!(
+        primitives.primitive(1),
+        Some(&[[-1.0, 0.0], [1.0, 0.0]][..])
+    );
+    assert_eq!(
+        primitives.primitive(4),
+        Some(&[[0.0, -1.0], [0.0, -0.5]][..])
+    );
+    assert_eq!(primitives.primitive(5), None);
+
+    assert_eq!(
+        primitives.provenance(0),
+        Some(Provenance {
+            primitive: 1,
+            vertex: 0
+        })
+    );
+    assert_eq!(
+        primitives.provenance(3),
+        Some(Provenance {
+            primitive: 3,
+            vertex: 1
+        })
+    );
+    assert_eq!(
+        primitives.provenance(7),
+        Some(Provenance {
+            primitive: 4,
+            vertex: 1
+        })
+    );
+    assert_eq!(primitives.provenance(8), None);
+
+    let hull = primitives.convex_hull_with_provenance();
+    let expected: Vec<(usize, usize, [f64; 2])> = vec![
+        (1, 1, [1.0, 0.0]),
+        (3, 1, [0.0, 2.0]),
+        (1, 0, [-1.0, 0.0]),
+        (4, 0, [0.0, -1.0]),
+    ];
+    assert_eq!(
+        hull.iter()
+            .map(|(provenance, point)| (provenance.primitive, provenance.vertex, *point))
+            .collect::<Vec<_>>(),
+        expected
+    );
+    assert_eq!(primitives.convex_hull().count(), hull.len());
+}
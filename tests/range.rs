@@ -0,0 +1,74 @@
+use planar_convex_hull::generators::{on_circle, uniform_in_disk};
+use planar_convex_hull::range::RangeHulls;
+use planar_convex_hull::{ConvexHull, Hull};
+
+#[test]
+fn test_range_hulls_match_batch() {
+    let mut points = uniform_in_disk(300, [5e4, 5e4], 1.0, 5);
+    points.extend(on_circle(50, [5e4, 5e4], 1.0, 6));
+    // Duplicates, collinear and nonreal points
+    points.extend_from_within(100..150);
+    points.push([f64::NAN, 1.0]);
+    points.push([5e4 - 2.0, 5e4]);
+    points.push([5e4 + 2.0, 5e4]);
+    points.push([5e4, 5e4]);
+
+    let ranges = RangeHulls::new(&points);
+    assert_eq!(ranges.len(), points.len());
+    for start in (0..points.len()).step_by(7) {
+        for end in (start..=points.len()).step_by(5) {
+            let expected = Hull::from_vertices_unchecked(
+                points[start..end]
+                    .to_vec()
+                    .convex_hull()
+                    .map(|(key, point)| (key + start, point)),
+            );
+            assert_eq!(ranges.hull(start..end), expected);
+        }
+    }
+    assert_eq!(ranges.hull(0..1000), Hull::from(points.convex_hull()));
+    assert!(ranges.hull(1000..2000).is_empty());
+}
+
+#[test]
+fn test_range_hulls_small() {
+    let ranges = RangeHulls::new(&Vec::<[f64; 2]>::new());
+    assert!(ranges.is_empty());
+    assert!(ranges.hull(0..3).is_empty());
+
+    let ranges = RangeHulls::new(&[[1.0, 2.0]]);
+    assert_eq!(ranges.hull(0..1).keys(), &[0]);
+    assert!(ranges.hull(1..1).is_empty());
+}
+
+#[test]
+fn test_range_hulls_degenerate() {
+    use planar_convex_hull::generators::{Rng, collinear, grid};
+
+    // Small integer coordinates with many duplicates, collinear points and
+    // vertical edges
+    let mut rng = Rng::new(3);
+    let mut points: Vec<[f64; 2]> = (0..400)
+        .map(|_| [rng.index(6) as f64, rng.index(6) as f64])
+        .collect();
+    points.extend(grid(7, 5, [-1.0, -1.0], 1.0));
+    points.extend(collinear(40, [0.0, 0.0], [6.0, 3.0], 4));
+    points.push([-0.0, 0.0]);
+
+    // A trajectory along a circle (all points in convex position)
+    points.extend(on_circle(200, [2.0, 2.0], 10.0, 7));
+
+    let ranges = RangeHulls::new(&points);
+    for start in (0..points.len()).step_by(7) {
+        for end in (start..=points.len()).step_by(13) {
+            // Of identical points, any one may be returned
+            let expected = Hull::new(&points[start..end].to_vec());
+            let hull = ranges.hull(start..end);
+            assert_eq!(hull.points(), expected.points(), "{start}..{end}");
+            for (key, point) in hull.iter() {
+                assert!((start..end).contains(&key));
+                assert_eq!(points[key], point);
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use planar_convex_hull::ConvexHull;
+use planar_convex_hull::reproject::{Reprojection, ReprojectionError};
+
+const WGS84: &str = "+proj=longlat +datum=WGS84 +no_defs";
+const UTM32: &str = "+proj=utm +zone=32 +datum=WGS84 +units=m +no_defs";
+
+#[test]
+fn test_reprojection_round_trip() {
+    let reprojection = Reprojection::new(WGS84, UTM32).unwrap();
+
+    // The central meridian of UTM zone 32 is located at 9° east
+    let [x, y] = reprojection.forward([9.0, 0.0]).unwrap();
+    assert!((x - 500_000.0).abs() < 1e-6);
+    assert!(y.abs() < 1e-6);
+
+    let point = [8.6821, 50.1109];
+    let [lon, lat] = reprojection
+        .inverse(reprojection.forward(point).unwrap())
+        .unwrap();
+    assert!((lon - point[0]).abs() < 1e-9);
+    assert!((lat - point[1]).abs() < 1e-9);
+}
+
+#[test]
+fn test_reprojection_convex_hull() {
+    let reprojection = Reprojection::new(WGS84, UTM32).unwrap();
+
+    // Keys of other collections and nonreal points
+    let mut points: HashMap<usize, [f64; 2]> = HashMap::new();
+    points.insert(10, [9.0, 48.0]);
+    points.insert(20, [9.1, 48.0]);
+    points.insert(30, [9.1, 48.1]);
+    points.insert(40, [9.0, 48.1]);
+    points.insert(50, [9.05, 48.05]);
+    points.insert(60, [f64::NAN, 48.05]);
+    let hull = reprojection.convex_hull(&points).unwrap();
+    assert_eq!(hull.keys(), &[20, 30, 40, 10]);
+    for (key, point) in hull.iter() {
+        assert_eq!(reprojection.forward(points[&key]).unwrap(), point);
+    }
+
+    // The hull in the target CRS follows the usual conventions
+    let projected: Vec<[f64; 2]> = hull.points().to_vec();
+    assert_eq!(
+        hull.points(),
+        projected
+            .convex_hull_exact()
+            .map(|(_, point)| point)
+            .collect::<Vec<_>>()
+    );
+
+    let corners = reprojection.export(&hull).unwrap();
+    for (key, point) in corners {
+        assert!((point[0] - points[&key][0]).abs() < 1e-9);
+        assert!((point[1] - points[&key][1]).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_reprojection_errors() {
+    assert!(matches!(
+        Reprojection::new("+proj=unknown", UTM32),
+        Err(ReprojectionError::InvalidCrs(_))
+    ));
+
+    // A latitude beyond the pole cannot be projected
+    let reprojection = Reprojection::new(WGS84, UTM32).unwrap();
+    let error = reprojection
+        .convex_hull(&[[9.0, 48.0], [9.0, 95.0]])
+        .unwrap_err();
+    assert!(matches!(error, ReprojectionError::Transform { key: 1, .. }));
+    assert!(error.to_string().starts_with("point 1"));
+}
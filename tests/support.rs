@@ -0,0 +1,50 @@
+use planar_convex_hull::{Hull, generators, support::SupportTable};
+
+/// Support value of the brute-force search
+fn brute_force(hull: &Hull, direction: [f64; 2]) -> f64 {
+    return hull
+        .points()
+        .iter()
+        .map(|point| point[0] * direction[0] + point[1] * direction[1])
+        .fold(f64::NEG_INFINITY, f64::max);
+}
+
+#[test]
+fn test_support_table() {
+    let datasets = [
+        generators::uniform_in_disk(1000, [3.0, -2.0], 2.0, 1),
+        generators::on_circle(200, [0.0, 0.0], 1.0, 2),
+        generators::grid(5, 3, [0.0, 0.0], 1.0),
+        generators::collinear(10, [0.0, 0.0], [1.0, 2.0], 3),
+        vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]],
+    ];
+    for points in datasets.iter() {
+        let hull = Hull::new(points);
+        let table = SupportTable::new(&hull);
+        for i in 0..3600 {
+            let angle = i as f64 * std::f64::consts::TAU / 3600.0 - 7.0;
+            let direction = [angle.cos(), angle.sin()];
+            let (key, point) = table.support(direction).unwrap();
+            assert_eq!(points[key], point);
+            let value = point[0] * direction[0] + point[1] * direction[1];
+            assert!((value - brute_force(&hull, direction)).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_support_table_degenerate() {
+    let table = SupportTable::new(&Hull::default());
+    assert_eq!(table.support([1.0, 0.0]), None);
+
+    let table = SupportTable::new(&Hull::new(&[[1.0, 2.0]]));
+    assert_eq!(table.support([1.0, 0.0]), Some((0, [1.0, 2.0])));
+
+    let table = SupportTable::new(&Hull::new(&[[0.0, 0.0], [1.0, 0.0]]));
+    assert_eq!(table.support([1.0, 0.1]), Some((1, [1.0, 0.0])));
+    assert_eq!(table.support([-1.0, 0.1]), Some((0, [0.0, 0.0])));
+    assert_eq!(
+        table.support_angle(f64::NAN).map(|(key, _)| key < 2),
+        Some(true)
+    );
+}
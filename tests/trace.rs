@@ -0,0 +1,72 @@
+use planar_convex_hull::generators::gaussian_clusters;
+use planar_convex_hull::{ConvexHull, TraceEvent};
+
+#[test]
+fn test_trace_replay() {
+    let points = gaussian_clusters(2000, 4, [0.0, 0.0], [10.0, 10.0], 1.0, 21);
+    let (hull, trace) = points.convex_hull_with_trace();
+    let hull: Vec<(usize, [f64; 2])> = hull.collect();
+    assert_eq!(hull, points.convex_hull().collect::<Vec<_>>());
+
+    // Every real point which is not an extremum point is either a candidate
+    // of a quadrant or outside of the range of all quadrants
+    let candidates = trace
+        .events()
+        .iter()
+        .filter(|event| {
+            matches!(
+                event,
+                TraceEvent::Accepted { .. }
+                    | TraceEvent::Rejected { .. }
+                    | TraceEvent::Boundary { .. }
+            )
+        })
+        .count();
+    assert!(candidates >= points.len() - 8);
+
+    // Replaying all events yields the partial hulls, which form the hull
+    let quadrants = trace.replay(trace.events().len());
+    let mut merged: Vec<(usize, [f64; 2])> = Vec::new();
+    for (key, point) in quadrants.into_iter().flatten() {
+        if merged.last().is_some_and(|last| last.0 == key)
+            || merged.first().is_some_and(|first| first.0 == key)
+        {
+            continue;
+        }
+        merged.push((key, point));
+    }
+    assert_eq!(merged, hull);
+
+    // The partial hulls before the first event only contain the extremum points
+    let initial = trace.replay(0);
+    assert!(initial.iter().all(|quadrant| quadrant.is_empty()));
+    let extremes = trace
+        .events()
+        .iter()
+        .take_while(|event| matches!(event, TraceEvent::Extreme { .. }))
+        .count();
+    let after_extremes = trace.replay(extremes);
+    assert!(after_extremes.iter().all(|quadrant| quadrant.len() <= 2));
+
+    // Display prints one line per event
+    assert_eq!(trace.to_string().lines().count(), trace.events().len());
+}
+
+#[test]
+fn test_trace_degenerate() {
+    let (hull, trace) = [[1.0, 2.0]].convex_hull_with_trace();
+    assert_eq!(hull.count(), 1);
+    assert_eq!(
+        trace.events(),
+        &[TraceEvent::Extreme {
+            quadrant: 0,
+            key: 0,
+            point: [1.0, 2.0]
+        }]
+    );
+
+    let empty: &[[f64; 2]] = &[];
+    let (hull, trace) = empty.convex_hull_with_trace();
+    assert_eq!(hull.count(), 0);
+    assert!(trace.events().is_empty());
+}
@@ -0,0 +1,44 @@
+use planar_convex_hull::generators::{gaussian_clusters, on_circle, uniform_in_disk};
+use planar_convex_hull::validation::{Divergence, Reference, compare, compare_all};
+
+#[test]
+fn test_compare_all() {
+    for seed in 0..5 {
+        let sets = [
+            uniform_in_disk(2000, [0.0, 0.0], 1.0, seed),
+            on_circle(500, [3.0, -2.0], 10.0, seed),
+            gaussian_clusters(2000, 5, [0.0, 0.0], [100.0, 100.0], 3.0, seed),
+        ];
+        for points in sets.iter() {
+            assert_eq!(compare_all(points), vec![]);
+        }
+    }
+
+    // Degenerate inputs
+    let empty: &[[f64; 2]] = &[];
+    assert_eq!(compare_all(&empty), vec![]);
+    assert_eq!(compare_all(&[[1.0, 1.0]]), vec![]);
+    assert_eq!(compare_all(&[[1.0, 1.0], [2.0, 2.0], [3.0, 3.0]]), vec![]);
+    assert_eq!(
+        compare_all(&[[1.0, 1.0], [f64::NAN, 2.0], [3.0, 0.0]]),
+        vec![]
+    );
+    assert_eq!(
+        compare(&[[0.0, 0.0], [0.0, 0.0]], Reference::GeoQuickHull),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_divergence_display() {
+    let divergence = Divergence {
+        reference: Reference::GeoGrahamHull,
+        position: 2,
+        expected: Some([1.0, 0.5]),
+        found: None,
+    };
+    assert_eq!(
+        divergence.to_string(),
+        "hull diverges from GeoGrahamHull at corner 2: expected [1.0, 0.5], found no corner"
+    );
+}
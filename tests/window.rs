@@ -0,0 +1,47 @@
+use planar_convex_hull::ConvexHull;
+use planar_convex_hull::generators::uniform_in_disk;
+use planar_convex_hull::window::SlidingWindowHull;
+
+#[test]
+fn test_sliding_window_hull_matches_batch() {
+    let mut points = uniform_in_disk(1500, [3e5, 1e5], 5.0, 7);
+    points[100] = [f64::NAN, 0.0];
+    // Irregular timestamps with gaps which empty the window
+    let times: Vec<f64> = (0..points.len())
+        .map(|index| (index + 40 * (index / 500)) as f64 * 0.5)
+        .collect();
+
+    let mut window = SlidingWindowHull::new(60.0);
+    assert!(window.is_empty());
+    assert!(window.hull().is_empty());
+    for (key, (time, point)) in times.iter().zip(points.iter()).enumerate() {
+        assert_eq!(window.push(*time, *point), key);
+        if key % 23 != 0 && key != points.len() - 1 {
+            continue;
+        }
+        let first = times.partition_point(|other| time - other >= 60.0);
+        assert_eq!(window.first_key(), first);
+        assert_eq!(window.len(), key + 1 - first);
+
+        let expected: Vec<(usize, [f64; 2])> = points[first..=key]
+            .to_vec()
+            .convex_hull_monotone_chain()
+            .map(|(position, point)| (first + position, point))
+            .collect();
+        assert_eq!(window.hull().iter().collect::<Vec<_>>(), expected);
+    }
+
+    window.advance(1e9);
+    assert!(window.is_empty());
+    assert!(window.hull().is_empty());
+    assert_eq!(window.push(1e9, [1.0, 2.0]), points.len());
+    assert_eq!(window.hull().keys(), &[points.len()]);
+}
+
+#[test]
+#[should_panic]
+fn test_sliding_window_hull_decreasing_time() {
+    let mut window = SlidingWindowHull::new(1.0);
+    window.push(2.0, [0.0, 0.0]);
+    window.push(1.0, [1.0, 0.0]);
+}